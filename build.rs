@@ -1,15 +1,28 @@
 use dotenv::dotenv;
+use std::collections::HashSet;
+use std::fmt::Write;
+use std::path::Path;
 use std::{env, fs};
 
 const DOTENV_PATH: &str = ".env";
+const SUBJECT_CODES_DIR: &str = "src/models/subject/codes";
+/// Subject schemes with a controlled vocabulary, as the constant and file name of their code list
+const SUBJECT_CODE_LISTS: [(&str, &str); 3] = [
+    ("BIC", "bic.tsv"),
+    ("BISAC", "bisac.tsv"),
+    ("THEMA", "thema.tsv"),
+];
 
-/// This build script is responsible for optionally loading environment variables from a `.env` file,
-/// setting them in Cargo's environment using `cargo:rustc-env`, and printing them out.
+/// This build script is responsible for generating the subject code lists, and for optionally
+/// loading environment variables from a `.env` file, setting them in Cargo's environment using
+/// `cargo:rustc-env`, and printing them out.
 ///
 /// Simply loading environment variables using `dotenv()` is not sufficient for them to be
 /// available during the build process. Hence, they need to be explicitly set in Cargo's
 /// environment using `cargo:rustc-env`.
 fn main() {
+    generate_subject_codes();
+
     println!("cargo:rerun-if-changed={DOTENV_PATH}");
     // load environment variables from `.env`
     if dotenv().is_err() {
@@ -26,3 +39,45 @@ fn main() {
         }
     }
 }
+
+/// Turn each tab-separated code list into a static array of `SubjectCode`s, so that codes can be
+/// checked against thousands of entries without parsing them when the app starts. A malformed
+/// line or a repeated code fails the build rather than leaving a code that can never be entered.
+fn generate_subject_codes() {
+    let mut generated = String::new();
+    for (name, file) in SUBJECT_CODE_LISTS {
+        let path = Path::new(SUBJECT_CODES_DIR).join(file);
+        println!("cargo:rerun-if-changed={}", path.display());
+        let list = fs::read_to_string(&path)
+            .unwrap_or_else(|e| panic!("Cannot read {}: {e}", path.display()));
+        let mut codes = HashSet::new();
+        writeln!(generated, "static {name}: &[SubjectCode] = &[").unwrap();
+        for (number, line) in list.lines().enumerate() {
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let (code, heading) = line
+                .split_once('\t')
+                .map(|(code, heading)| (code.trim(), heading.trim()))
+                .filter(|(code, heading)| !code.is_empty() && !heading.is_empty())
+                .unwrap_or_else(|| {
+                    panic!(
+                        "{}:{}: expected a code and heading",
+                        path.display(),
+                        number + 1
+                    )
+                });
+            if !codes.insert(code.to_uppercase()) {
+                panic!("{}:{}: {code} is listed twice", path.display(), number + 1);
+            }
+            writeln!(
+                generated,
+                "    SubjectCode {{ code: {code:?}, heading: {heading:?} }},"
+            )
+            .unwrap();
+        }
+        writeln!(generated, "];").unwrap();
+    }
+    let out = Path::new(&env::var("OUT_DIR").unwrap()).join("subject_codes.rs");
+    fs::write(out, generated).unwrap();
+}
@@ -0,0 +1,203 @@
+use chrono::{DateTime, Duration, Utc};
+use std::collections::HashMap;
+use uuid::Uuid;
+use yew_agent::{Agent, AgentLink, Context, HandlerId};
+
+use crate::models::utils::{Contributor, ImprintWithPublisher, Timestamp, WorkWithRelations};
+
+/// How long an entity is served from the cache before it is considered stale and dropped
+const ENTITY_TTL_MINUTES: i64 = 5;
+
+pub enum EntityCacheRequest {
+    /// Store works, e.g. from a list query (`complete: false`) or a full work query or mutation
+    /// result (`complete: true`)
+    StoreWorks(Vec<WorkWithRelations>, bool),
+    StoreContributors(Vec<Contributor>),
+    StoreImprints(Vec<ImprintWithPublisher>),
+    GetWork(Uuid),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum EntityCacheResponse {
+    /// The cached work, and whether it holds everything the full work query returns
+    Work(Uuid, Option<(WorkWithRelations, bool)>),
+}
+
+struct CacheEntry<T> {
+    value: T,
+    updated_at: Timestamp,
+    // Whether the value was read from a query returning every field and relation
+    complete: bool,
+    stored_at: DateTime<Utc>,
+}
+
+/// Entities of one type, keyed by their ID
+struct EntityStore<T> {
+    entries: HashMap<Uuid, CacheEntry<T>>,
+}
+
+impl<T: Clone> EntityStore<T> {
+    fn new() -> Self {
+        EntityStore {
+            entries: HashMap::new(),
+        }
+    }
+
+    /// Keep whichever version is newer. At the same `updated_at`, a complete value replaces a
+    /// partial one but not the reverse, so list data never overwrites a fully loaded entity.
+    fn insert(&mut self, id: Uuid, updated_at: Timestamp, value: T, complete: bool) {
+        let keep_existing = self.entries.get(&id).is_some_and(|existing| {
+            existing.updated_at > updated_at
+                || (existing.updated_at == updated_at && existing.complete && !complete)
+        });
+        if !keep_existing {
+            self.entries.insert(
+                id,
+                CacheEntry {
+                    value,
+                    updated_at,
+                    complete,
+                    stored_at: Utc::now(),
+                },
+            );
+        }
+    }
+
+    fn get(&mut self, id: &Uuid) -> Option<(T, bool)> {
+        let expiry = Utc::now() - Duration::minutes(ENTITY_TTL_MINUTES);
+        if self.entries.get(id)?.stored_at <= expiry {
+            self.entries.remove(id);
+            return None;
+        }
+        self.entries
+            .get(id)
+            .map(|entry| (entry.value.clone(), entry.complete))
+    }
+}
+
+/// Normalised store of the entities fetched by any component, so that a record already seen
+/// elsewhere (e.g. in a list) can be shown immediately while its full query is still loading.
+pub struct EntityCacheAgent {
+    link: AgentLink<EntityCacheAgent>,
+    works: EntityStore<WorkWithRelations>,
+    contributors: EntityStore<Contributor>,
+    imprints: EntityStore<ImprintWithPublisher>,
+}
+
+impl Agent for EntityCacheAgent {
+    type Reach = Context<Self>;
+    type Message = ();
+    type Input = EntityCacheRequest;
+    type Output = EntityCacheResponse;
+
+    fn create(link: AgentLink<Self>) -> Self {
+        EntityCacheAgent {
+            link,
+            works: EntityStore::new(),
+            contributors: EntityStore::new(),
+            imprints: EntityStore::new(),
+        }
+    }
+
+    fn update(&mut self, _msg: Self::Message) {}
+
+    fn handle_input(&mut self, msg: Self::Input, id: HandlerId) {
+        match msg {
+            EntityCacheRequest::StoreWorks(works, complete) => {
+                for work in works {
+                    // Works carry their imprint and contributors, which are cached in their own
+                    // right, and are only as complete as the query the works came from
+                    let imprint = work.imprint.clone();
+                    if !imprint.imprint_id.is_nil() {
+                        self.imprints.insert(
+                            imprint.imprint_id,
+                            imprint.updated_at.clone(),
+                            imprint,
+                            complete,
+                        );
+                    }
+                    for contribution in work.contributions.iter().flatten() {
+                        let contributor = contribution.contributor.clone();
+                        // List queries may leave out the contributor of a contribution
+                        if contributor.contributor_id.is_nil() {
+                            continue;
+                        }
+                        self.contributors.insert(
+                            contributor.contributor_id,
+                            contributor.updated_at.clone(),
+                            contributor,
+                            complete,
+                        );
+                    }
+                    self.works
+                        .insert(work.work_id, work.updated_at.clone(), work, complete);
+                }
+            }
+            EntityCacheRequest::StoreContributors(contributors) => {
+                for contributor in contributors {
+                    self.contributors.insert(
+                        contributor.contributor_id,
+                        contributor.updated_at.clone(),
+                        contributor,
+                        true,
+                    );
+                }
+            }
+            EntityCacheRequest::StoreImprints(imprints) => {
+                for imprint in imprints {
+                    self.imprints.insert(
+                        imprint.imprint_id,
+                        imprint.updated_at.clone(),
+                        imprint,
+                        true,
+                    );
+                }
+            }
+            EntityCacheRequest::GetWork(work_id) => {
+                let work = self.works.get(&work_id).map(|(mut work, complete)| {
+                    // Embedded records may have been refreshed since the work itself was stored,
+                    // but a complete work only takes them from complete records
+                    if let Some((imprint, imprint_complete)) =
+                        self.imprints.get(&work.imprint.imprint_id)
+                    {
+                        if imprint_complete || !complete {
+                            work.imprint = imprint;
+                        }
+                    }
+                    for contribution in work.contributions.iter_mut().flatten() {
+                        if let Some((contributor, contributor_complete)) =
+                            self.contributors.get(&contribution.contributor_id)
+                        {
+                            if contributor_complete || !complete {
+                                contribution.contributor = contributor;
+                            }
+                        }
+                    }
+                    (work, complete)
+                });
+                self.respond(id, EntityCacheResponse::Work(work_id, work));
+            }
+        }
+    }
+}
+
+/// Entities listed by `pagination_component!`, shared with the cache as soon as they are fetched
+pub trait CacheEntities: Sized {
+    fn cache_request(entities: Vec<Self>) -> EntityCacheRequest;
+}
+
+impl CacheEntities for WorkWithRelations {
+    fn cache_request(entities: Vec<Self>) -> EntityCacheRequest {
+        // List queries only return a subset of each work's fields
+        EntityCacheRequest::StoreWorks(entities, false)
+    }
+}
+
+impl EntityCacheAgent {
+    fn respond(&self, id: HandlerId, response: EntityCacheResponse) {
+        // Dispatchers only send requests, they cannot receive responses
+        if id.is_respondable() {
+            self.link.respond(id, response);
+        }
+    }
+}
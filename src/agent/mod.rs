@@ -0,0 +1,2 @@
+pub mod entity_cache;
+pub mod session;
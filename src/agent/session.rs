@@ -0,0 +1,129 @@
+use std::collections::HashSet;
+use yew_agent::{Agent, AgentLink, Context, Dispatched, HandlerId};
+use yewtil::fetch::{Fetch, FetchAction, FetchError, FetchState};
+
+use crate::models::account::AccountDetails;
+use crate::models::utils::ThothError;
+use crate::service::account::{
+    AccountService, FetchActionRenewToken, FetchRenewToken, RenewTokenRequest,
+};
+
+pub enum Msg {
+    SetRenewState(FetchActionRenewToken),
+}
+
+pub enum SessionRequest {
+    Login(AccountDetails),
+    Logout,
+    /// The API rejected the stored token: drop the session and send the user to log in again
+    Expire,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SessionResponse {
+    LoggedIn(AccountDetails),
+    LoggedOut,
+    Expired,
+}
+
+/// End the current session if a request failed because the API no longer accepts our token
+pub fn expire_on_invalid_token(error: &FetchError) {
+    if ThothError::from(error.clone()) == ThothError::InvalidToken {
+        SessionAgent::dispatcher().send(SessionRequest::Expire);
+    }
+}
+
+/// Holds the account of the logged in user, shared by every component which bridges to it.
+pub struct SessionAgent {
+    link: AgentLink<SessionAgent>,
+    subscribers: HashSet<HandlerId>,
+    account: Option<AccountDetails>,
+    account_service: AccountService,
+    renew_token: FetchRenewToken,
+}
+
+impl SessionAgent {
+    fn current(&self) -> SessionResponse {
+        match &self.account {
+            Some(account) => SessionResponse::LoggedIn(account.clone()),
+            None => SessionResponse::LoggedOut,
+        }
+    }
+
+    fn broadcast(&self, response: SessionResponse) {
+        for subscriber in self.subscribers.iter() {
+            self.link.respond(*subscriber, response.clone());
+        }
+    }
+
+    fn start_session(&mut self, account: AccountDetails) {
+        if let Some(token) = account.token.clone() {
+            self.account_service.set_token(token);
+        }
+        self.account = Some(account);
+        self.broadcast(self.current());
+    }
+
+    fn end_session(&mut self, response: SessionResponse) {
+        self.account_service.logout();
+        self.account = None;
+        self.broadcast(response);
+    }
+}
+
+impl Agent for SessionAgent {
+    type Reach = Context<Self>;
+    type Message = Msg;
+    type Input = SessionRequest;
+    type Output = SessionResponse;
+
+    fn create(link: AgentLink<Self>) -> Self {
+        let account_service = AccountService::new();
+        let renew_token: FetchRenewToken = Fetch::new(RenewTokenRequest::default());
+        // A token left over from a previous visit is only trusted once the API has renewed it
+        if account_service.is_loggedin() {
+            link.send_future(renew_token.fetch(Msg::SetRenewState));
+            link.send_message(Msg::SetRenewState(FetchAction::Fetching));
+        }
+        SessionAgent {
+            link,
+            subscribers: HashSet::new(),
+            account: None,
+            account_service,
+            renew_token,
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) {
+        match msg {
+            Msg::SetRenewState(fetch_state) => {
+                self.renew_token.apply(fetch_state);
+                match self.renew_token.as_ref().state() {
+                    FetchState::Fetched(account) => self.start_session(account.clone()),
+                    FetchState::Failed(_, _) => self.end_session(SessionResponse::LoggedOut),
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    fn connected(&mut self, id: HandlerId) {
+        // Dispatchers only send requests, they cannot receive responses
+        if id.is_respondable() {
+            self.subscribers.insert(id);
+            self.link.respond(id, self.current());
+        }
+    }
+
+    fn handle_input(&mut self, msg: Self::Input, _id: HandlerId) {
+        match msg {
+            SessionRequest::Login(account) => self.start_session(account),
+            SessionRequest::Logout => self.end_session(SessionResponse::LoggedOut),
+            SessionRequest::Expire => self.end_session(SessionResponse::Expired),
+        }
+    }
+
+    fn disconnected(&mut self, id: HandlerId) {
+        self.subscribers.remove(&id);
+    }
+}
@@ -0,0 +1,185 @@
+//! Reading the catalogue outside the browser, for the build steps run by native builds
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::fs;
+use std::path::{Path, PathBuf};
+use yewtil::fetch::FetchError;
+
+use crate::config::{self, config, Config};
+use crate::models::book::books_query::{
+    BooksRequestBody, BooksResponseBody, Variables, BOOK_TYPES,
+};
+use crate::models::book::catalogue_query::{CatalogueRequestBody, CatalogueResponseBody};
+use crate::models::book::imprints_query::{
+    ImprintsRequestBody, ImprintsResponseBody, Variables as ImprintsVariables,
+};
+use crate::models::utils::{ImprintWithPublisher, ThothError, ThothResult, WorkWithRelations};
+
+// Works requested at a time when listing the catalogue
+const BATCH_SIZE: i32 = 100;
+
+/// Use the deployment's configuration, so that output shows the same publishers and branding as
+/// the app. Without an explicit `file`, `config.json` in `dist` is used if there is one, and the
+/// compile-time defaults otherwise.
+pub fn load_config(dist: &Path, file: Option<&Path>) -> ThothResult<()> {
+    let path = file.map_or_else(|| dist.join("config.json"), PathBuf::from);
+    let config = match file.is_some() || path.exists() {
+        true => serde_json::from_str(&fs::read_to_string(&path)?)
+            .map_err(|e| ThothError::InternalError(format!("Invalid {}: {e}", path.display())))?,
+        false => Config::default(),
+    };
+    config::init(config)
+}
+
+/// Source of work records: the configured GraphQL API, or a fixture file holding a JSON array of
+/// works as returned by the `work` query, which is expected to be in the order requested already.
+/// Works are requested a batch at a time, each batch with their full records if needed.
+pub struct Catalogue {
+    client: reqwest::Client,
+    fixture: Option<Vec<WorkWithRelations>>,
+}
+
+impl Catalogue {
+    pub fn new(fixture: Option<&Path>) -> ThothResult<Self> {
+        let fixture = match fixture {
+            Some(path) => Some(
+                serde_json::from_str(&fs::read_to_string(path)?)
+                    .map_err(|e| ThothError::InternalError(format!("Invalid fixture: {e}")))?,
+            ),
+            None => None,
+        };
+        Ok(Catalogue {
+            client: reqwest::Client::new(),
+            fixture,
+        })
+    }
+
+    async fn query<B: Serialize, R: DeserializeOwned>(&self, body: &B) -> ThothResult<R> {
+        let content = self
+            .client
+            .post(format!("{}/graphql", config().graphql_api))
+            .json(body)
+            .send()
+            .await?
+            .text()
+            .await?;
+        // Reuse the app's handling of GraphQL error responses
+        serde_json::from_str(&content).map_err(|e| {
+            ThothError::from(FetchError::DeserializeError {
+                error: e.to_string(),
+                content,
+            })
+        })
+    }
+
+    /// Every work requested with `variables`, as returned by the books list query, which
+    /// leaves abstracts, series and the like out
+    pub async fn list(&self, variables: Variables) -> ThothResult<Vec<WorkWithRelations>> {
+        self.fetch(variables, false, |_| true, None).await
+    }
+
+    /// Full records of the works requested with `variables` which `keep` accepts, stopping once
+    /// `limit` of them have been found
+    pub async fn works(
+        &self,
+        variables: Variables,
+        keep: impl Fn(&WorkWithRelations) -> bool,
+        limit: Option<usize>,
+    ) -> ThothResult<Vec<WorkWithRelations>> {
+        self.fetch(variables, true, keep, limit).await
+    }
+
+    /// Imprints of the configured publishers, with the publisher of each
+    pub async fn imprints(&self) -> ThothResult<Vec<ImprintWithPublisher>> {
+        if let Some(works) = &self.fixture {
+            let mut imprints: Vec<ImprintWithPublisher> = vec![];
+            for work in works {
+                if !imprints
+                    .iter()
+                    .any(|i| i.imprint_id == work.imprint.imprint_id)
+                {
+                    imprints.push(work.imprint.clone());
+                }
+            }
+            return Ok(imprints);
+        }
+        let body = ImprintsRequestBody {
+            variables: ImprintsVariables {
+                publishers: config().publisher_scope(),
+            },
+            ..Default::default()
+        };
+        let response: ImprintsResponseBody = self.query(&body).await?;
+        Ok(response.data.imprints)
+    }
+
+    /// Request the works a batch at a time, either as listed or with their full records
+    async fn fetch(
+        &self,
+        variables: Variables,
+        full: bool,
+        keep: impl Fn(&WorkWithRelations) -> bool,
+        limit: Option<usize>,
+    ) -> ThothResult<Vec<WorkWithRelations>> {
+        let limit = limit.unwrap_or(usize::MAX);
+        if let Some(works) = &self.fixture {
+            return Ok(works
+                .iter()
+                .filter(|work| is_requested(&variables, work) && keep(work))
+                .take(limit)
+                .cloned()
+                .collect());
+        }
+        let mut works = vec![];
+        let mut offset = 0;
+        while works.len() < limit {
+            let variables = Variables {
+                limit: Some(BATCH_SIZE),
+                offset: Some(offset),
+                ..variables.clone()
+            };
+            let (batch, count) = match full {
+                true => {
+                    let body = CatalogueRequestBody {
+                        variables,
+                        ..Default::default()
+                    };
+                    let response: CatalogueResponseBody = self.query(&body).await?;
+                    (response.data.books, response.data.book_count)
+                }
+                false => {
+                    let body = BooksRequestBody {
+                        variables,
+                        ..Default::default()
+                    };
+                    let response: BooksResponseBody = self.query(&body).await?;
+                    (response.data.books, response.data.book_count)
+                }
+            };
+            offset += BATCH_SIZE;
+            let is_last = batch.len() < BATCH_SIZE as usize || offset >= count;
+            works.extend(batch.into_iter().filter(|work| keep(work)));
+            if is_last {
+                break;
+            }
+        }
+        works.truncate(limit);
+        Ok(works)
+    }
+}
+
+/// Whether a fixture's work is one the API would return for `variables`
+fn is_requested(variables: &Variables, work: &WorkWithRelations) -> bool {
+    let publisher_id = work.imprint.publisher.publisher_id.to_string();
+    let work_types = variables.work_types.as_deref().unwrap_or(&BOOK_TYPES);
+    variables
+        .publishers
+        .as_ref()
+        .is_none_or(|publishers| publishers.contains(&publisher_id))
+        && work_types.contains(&work.work_type)
+        && variables
+            .work_statuses
+            .as_ref()
+            .is_none_or(|statuses| statuses.contains(&work.work_status))
+}
@@ -0,0 +1,277 @@
+use std::str::FromStr;
+use yew::html;
+use yew::prelude::*;
+
+use crate::component::utils::{FormTextInput, FormWorkStatusSelect, FormWorkTypeSelect};
+use crate::component::ToElementValue;
+use crate::i18n::Message;
+use crate::models::license::LicenseKind;
+use crate::models::search::query::{
+    SearchQuery, SearchTerm, TermValue, LICENSE_KINDS, WORK_STATUSES, WORK_TYPES,
+};
+use crate::models::utils::{WorkStatus, WorkType};
+use crate::string::{
+    ADVANCED_SEARCH_ANY, ADVANCED_SEARCH_AUTHOR, ADVANCED_SEARCH_EXCLUDE, ADVANCED_SEARCH_ISBN,
+    ADVANCED_SEARCH_LICENSE, ADVANCED_SEARCH_PHRASE, ADVANCED_SEARCH_STATUS,
+    ADVANCED_SEARCH_SUBJECT, ADVANCED_SEARCH_TYPE, ADVANCED_SEARCH_WORDS,
+    ADVANCED_SEARCH_YEAR_FROM, ADVANCED_SEARCH_YEAR_TO, SEARCH_BUTTON,
+};
+
+/// Form fields, each of which contributes terms to the search query
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct AdvancedSearch {
+    words: String,
+    phrase: String,
+    exclude: String,
+    author: String,
+    isbn: String,
+    subject: String,
+    year_from: String,
+    year_to: String,
+    work_type: Option<WorkType>,
+    work_status: Option<WorkStatus>,
+    license: Option<LicenseKind>,
+}
+
+impl From<&SearchQuery> for AdvancedSearch {
+    /// Fill in the form from an existing query. Terms the form has no field for are dropped.
+    fn from(query: &SearchQuery) -> Self {
+        let mut form = AdvancedSearch::default();
+        let mut words = vec![];
+        let mut exclude = vec![];
+        for term in &query.terms {
+            match (&term.value, term.negated) {
+                (TermValue::Text(text), false) if text.contains(char::is_whitespace) => {
+                    form.phrase = text.clone()
+                }
+                (TermValue::Text(text), false) => words.push(text.clone()),
+                (TermValue::Text(text), true) => exclude.push(text.clone()),
+                (TermValue::Author(author), false) => form.author = author.clone(),
+                (TermValue::Isbn(isbn), false) => form.isbn = isbn.clone(),
+                (TermValue::Subject(subject), false) => form.subject = subject.clone(),
+                (TermValue::Year(from, to), false) => {
+                    form.year_from = from.map(|y| y.to_string()).unwrap_or_default();
+                    form.year_to = to.map(|y| y.to_string()).unwrap_or_default();
+                }
+                (TermValue::Type(work_type), false) => form.work_type = Some(work_type.clone()),
+                (TermValue::Status(status), false) => form.work_status = Some(status.clone()),
+                (TermValue::License(kind), false) => form.license = Some(*kind),
+                _ => {}
+            }
+        }
+        form.words = words.join(" ");
+        form.exclude = exclude.join(" ");
+        form
+    }
+}
+
+impl AdvancedSearch {
+    fn to_query(&self) -> SearchQuery {
+        let term = |value: TermValue, negated: bool| SearchTerm { value, negated };
+        let mut terms = vec![];
+        for word in self.words.split_whitespace() {
+            terms.push(term(TermValue::Text(word.to_string()), false));
+        }
+        if !self.phrase.trim().is_empty() {
+            terms.push(term(TermValue::Text(self.phrase.trim().to_string()), false));
+        }
+        for word in self.exclude.split_whitespace() {
+            terms.push(term(TermValue::Text(word.to_string()), true));
+        }
+        if !self.author.trim().is_empty() {
+            terms.push(term(
+                TermValue::Author(self.author.trim().to_string()),
+                false,
+            ));
+        }
+        if !self.isbn.trim().is_empty() {
+            terms.push(term(
+                TermValue::Isbn(self.isbn.replace(['-', ' '], "")),
+                false,
+            ));
+        }
+        if !self.subject.trim().is_empty() {
+            terms.push(term(
+                TermValue::Subject(self.subject.trim().to_string()),
+                false,
+            ));
+        }
+        let year_from = self.year_from.trim().parse().ok();
+        let year_to = self.year_to.trim().parse().ok();
+        if year_from.is_some() || year_to.is_some() {
+            terms.push(term(TermValue::Year(year_from, year_to), false));
+        }
+        if let Some(work_type) = &self.work_type {
+            terms.push(term(TermValue::Type(work_type.clone()), false));
+        }
+        if let Some(status) = &self.work_status {
+            terms.push(term(TermValue::Status(status.clone()), false));
+        }
+        if let Some(kind) = self.license {
+            terms.push(term(TermValue::License(kind), false));
+        }
+        SearchQuery { terms }
+    }
+}
+
+/// Form which composes a query in the advanced search syntax, e.g. for users unfamiliar with it
+pub struct AdvancedSearchComponent {
+    form: AdvancedSearch,
+}
+
+pub enum Msg {
+    Change(Box<dyn FnOnce(&mut AdvancedSearch)>),
+    Search,
+}
+
+#[derive(PartialEq, Properties)]
+pub struct Props {
+    // Query currently searched for, which the form starts from
+    pub query: String,
+    pub onsearch: Callback<String>,
+}
+
+impl AdvancedSearchComponent {
+    #[allow(clippy::unnecessary_operation)]
+    fn text_input(
+        ctx: &Context<Self>,
+        label: Message,
+        value: &str,
+        field: fn(&mut AdvancedSearch) -> &mut String,
+    ) -> Html {
+        html! {
+            <FormTextInput
+                label={ label.to_string() }
+                value={ value.to_string() }
+                oninput={ ctx.link().callback(move |e: InputEvent| {
+                    let value = e.to_value();
+                    Msg::Change(Box::new(move |form: &mut AdvancedSearch| *field(form) = value))
+                }) }
+            />
+        }
+    }
+}
+
+impl Component for AdvancedSearchComponent {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        let form = ctx
+            .props()
+            .query
+            .parse::<SearchQuery>()
+            .map(|query| AdvancedSearch::from(&query))
+            .unwrap_or_default();
+        AdvancedSearchComponent { form }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::Change(change) => {
+                change(&mut self.form);
+                true
+            }
+            Msg::Search => {
+                ctx.props().onsearch.emit(self.form.to_query().to_string());
+                false
+            }
+        }
+    }
+
+    #[allow(clippy::unnecessary_operation)]
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let form = &self.form;
+        html! {
+            <form
+                class="box"
+                onsubmit={ ctx.link().callback(|e: FocusEvent| {
+                    e.prevent_default();
+                    Msg::Search
+                }) }
+            >
+                <div class="columns is-multiline">
+                    <div class="column is-one-third">
+                        { Self::text_input(ctx, ADVANCED_SEARCH_WORDS, &form.words, |f| &mut f.words) }
+                    </div>
+                    <div class="column is-one-third">
+                        { Self::text_input(ctx, ADVANCED_SEARCH_PHRASE, &form.phrase, |f| &mut f.phrase) }
+                    </div>
+                    <div class="column is-one-third">
+                        { Self::text_input(ctx, ADVANCED_SEARCH_EXCLUDE, &form.exclude, |f| &mut f.exclude) }
+                    </div>
+                    <div class="column is-one-third">
+                        { Self::text_input(ctx, ADVANCED_SEARCH_AUTHOR, &form.author, |f| &mut f.author) }
+                    </div>
+                    <div class="column is-one-third">
+                        { Self::text_input(ctx, ADVANCED_SEARCH_ISBN, &form.isbn, |f| &mut f.isbn) }
+                    </div>
+                    <div class="column is-one-third">
+                        { Self::text_input(ctx, ADVANCED_SEARCH_SUBJECT, &form.subject, |f| &mut f.subject) }
+                    </div>
+                    <div class="column is-one-third">
+                        { Self::text_input(ctx, ADVANCED_SEARCH_YEAR_FROM, &form.year_from, |f| &mut f.year_from) }
+                    </div>
+                    <div class="column is-one-third">
+                        { Self::text_input(ctx, ADVANCED_SEARCH_YEAR_TO, &form.year_to, |f| &mut f.year_to) }
+                    </div>
+                    <div class="column is-one-third">
+                        <FormWorkTypeSelect
+                            label={ ADVANCED_SEARCH_TYPE.to_string() }
+                            value={ form.work_type.clone() }
+                            data={ WORK_TYPES.to_vec() }
+                            placeholder={ ADVANCED_SEARCH_ANY.to_string() }
+                            onchange={ ctx.link().callback(|t: WorkType| {
+                                Msg::Change(Box::new(move |form: &mut AdvancedSearch| form.work_type = Some(t)))
+                            }) }
+                            onclear={ ctx.link().callback(|_| {
+                                Msg::Change(Box::new(|form: &mut AdvancedSearch| form.work_type = None))
+                            }) }
+                        />
+                    </div>
+                    <div class="column is-one-third">
+                        <FormWorkStatusSelect
+                            label={ ADVANCED_SEARCH_STATUS.to_string() }
+                            value={ form.work_status.clone() }
+                            data={ WORK_STATUSES.to_vec() }
+                            placeholder={ ADVANCED_SEARCH_ANY.to_string() }
+                            onchange={ ctx.link().callback(|s: WorkStatus| {
+                                Msg::Change(Box::new(move |form: &mut AdvancedSearch| form.work_status = Some(s)))
+                            }) }
+                            onclear={ ctx.link().callback(|_| {
+                                Msg::Change(Box::new(|form: &mut AdvancedSearch| form.work_status = None))
+                            }) }
+                        />
+                    </div>
+                    <div class="column is-one-third">
+                        <div class="field">
+                            <label class="label">{ ADVANCED_SEARCH_LICENSE }</label>
+                            <div class="control is-expanded">
+                                <div class="select is-fullwidth">
+                                    <select onchange={ ctx.link().callback(|e: Event| {
+                                        let kind = LicenseKind::from_str(&e.to_value()).ok();
+                                        Msg::Change(Box::new(move |form: &mut AdvancedSearch| form.license = kind))
+                                    }) }>
+                                        <option value="" selected={ form.license.is_none() }>
+                                            { ADVANCED_SEARCH_ANY }
+                                        </option>
+                                        {
+                                            for LICENSE_KINDS.iter().map(|kind| html! {
+                                                <option value={ kind.code() } selected={ form.license == Some(*kind) }>
+                                                    { kind.short_name() }
+                                                </option>
+                                            })
+                                        }
+                                    </select>
+                                </div>
+                            </div>
+                        </div>
+                    </div>
+                </div>
+                <div class="control">
+                    <button class="button is-primary" type="submit">{ SEARCH_BUTTON }</button>
+                </div>
+            </form>
+        }
+    }
+}
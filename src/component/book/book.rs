@@ -1,18 +1,72 @@
+use std::str::FromStr;
 use uuid::Uuid;
 use yew::{html, Context};
-use yew::{Component, Html, Properties};
+use yew::{Component, Html, InputEvent, Properties};
+use yew_agent::{Bridge, Bridged};
 use yewtil::fetch::{Fetch, FetchAction, FetchState};
+use yewtil::NeqAssign;
 
+use crate::agent::entity_cache::{EntityCacheAgent, EntityCacheRequest, EntityCacheResponse};
+use crate::agent::session::expire_on_invalid_token;
+use crate::component::book::contributions_form::ContributionsFormComponent;
+use crate::component::book::publications_form::PublicationsFormComponent;
+use crate::component::book::relations_form::RelationsFormComponent;
+use crate::component::book::subjects_form::SubjectsFormComponent;
+use crate::component::utils::{
+    FormDateInput, FormImprintSelect, FormNumberInput, FormTextInput, FormTextInputExtended,
+    FormTextarea, FormUrlInput, FormWorkStatusSelect, FormWorkTypeSelect, Loader, Reloader,
+};
+use crate::component::{ToElementValue, ToOption};
+use crate::config::config;
+use crate::i18n::format::{format_date, format_integer};
+use crate::i18n::{Localized, Message};
 use crate::models::book::book_query::{
     FetchActionBook, FetchWork, Variables, WorkRequest, WorkRequestBody,
 };
+use crate::models::book::current_work_query::{
+    CurrentWorkRequest, CurrentWorkRequestBody, FetchActionCurrentWork, FetchCurrentWork,
+    Variables as CurrentWorkVariables,
+};
+use crate::models::book::head_metadata::HeadMetadata;
+use crate::models::book::update_work_mutation::{
+    PushActionUpdateWork, PushUpdateWork, UpdateWorkRequest, UpdateWorkRequestBody,
+    Variables as UpdateVariables,
+};
+use crate::models::book::work_form_data_query::{
+    FetchActionWorkFormData, FetchWorkFormData, Variables as WorkFormDataVariables,
+    WorkFormDataRequest, WorkFormDataRequestBody, WorkFormDataResponseData,
+};
+use crate::models::book::{changes_since, merge_changes, FieldChange};
 use crate::models::utils::{
-    ImprintWithPublisher, Orcid, SubjectType, ThothError, WorkStatus, WorkStatusValues, WorkType,
-    WorkTypeValues, WorkWithRelations, DOI_DOMAIN, ROR_DOMAIN,
+    Contribution, ContributionTypeValues, Doi, ImprintWithPublisher, Publication,
+    PublicationTypeValues, RelationTypeValues, Subject, SubjectType, SubjectTypeValues, ThothError,
+    Work, WorkRelationWithRelatedWork, WorkStatus, WorkStatusValues, WorkType, WorkTypeValues,
+    WorkWithRelations, DOI_DOMAIN, ROR_DOMAIN,
+};
+use crate::models::validation::validate;
+use crate::models::{EnumValues, MutationResult};
+use crate::service::account::AccountService;
+use crate::service::cache::QueryCache;
+use crate::service::head::PageHead;
+use crate::service::unsaved::UnsavedChangesGuard;
+use crate::string::{
+    CANCEL_BUTTON, CONTRIBUTOR_WEBSITE, COVER_ALT, CURRENT_VALUE, DISCARD_CHANGES_BUTTON,
+    EDIT_BUTTON, EDIT_CONFLICT, FIELD_AUDIO_COUNT, FIELD_BIBLIOGRAPHY_NOTE, FIELD_CONTRIBUTOR,
+    FIELD_COPYRIGHT, FIELD_COPYRIGHT_HOLDER, FIELD_COVER_CAPTION, FIELD_COVER_URL,
+    FIELD_DIMENSIONS, FIELD_DOI, FIELD_EDITION, FIELD_FIRST_PAGE, FIELD_FUNDING,
+    FIELD_GENERAL_NOTE, FIELD_IMAGE_COUNT, FIELD_IMPRINT, FIELD_ISBN, FIELD_KEYWORDS,
+    FIELD_LANDING_PAGE, FIELD_LANGUAGE, FIELD_LAST_PAGE, FIELD_LCCN, FIELD_LCCN_NUMBER,
+    FIELD_LICENSE, FIELD_LONG_ABSTRACT, FIELD_OCLC, FIELD_PAGE_BREAKDOWN, FIELD_PAGE_COUNT,
+    FIELD_PLACE, FIELD_PRINT_LENGTH, FIELD_PUBLICATION_DATE, FIELD_PUBLICATION_PLACE,
+    FIELD_PUBLISHED_ON, FIELD_PUBLISHER, FIELD_REFERENCE, FIELD_SHORT_ABSTRACT, FIELD_SUBTITLE,
+    FIELD_TABLE_COUNT, FIELD_TITLE, FIELD_TOC, FIELD_VIDEO_COUNT, FIELD_WITHDRAWN_DATE,
+    FIELD_WORK_STATUS, FIELD_WORK_TYPE, LOCATIONS_TABLE, METADATA_FIELDS, METADATA_HEALTH,
+    METADATA_HEALTHY, ORCID_RECORD, OVERWRITE_BUTTON, PAGE_COUNT, SAVE_BUTTON, SAVE_FAILED,
+    SAVE_SUCCESS, SECTION_CONTRIBUTORS, SECTION_EXPORT_METADATA, SECTION_LOCATIONS,
+    SECTION_METADATA, STATUS_CANCELLED, STATUS_EXPECTED_ON, STATUS_FORTHCOMING,
+    STATUS_OUT_OF_PRINT, STATUS_POSTPONED_INDEFINITELY, STATUS_WITHDRAWN_FROM_SALE,
+    STATUS_WITHDRAWN_ON, UNRECOGNISED_LICENSE, UNSAVED_CHANGES, YOUR_VALUE,
 };
-use crate::THOTH_EXPORT_API;
-
-use crate::component::utils::Loader;
 
 pub struct BookDetailComponent {
     book: WorkWithRelations,
@@ -23,21 +77,100 @@ pub struct BookDetailComponent {
     imprint_id: Uuid,
     // Track work_type stored in database, as distinct from work_type selected in dropdown
     work_type: WorkType,
+    // Options for the editor's select inputs, only fetched once editing starts
     data: WorkFormData,
+    fetch_form_data: FetchWorkFormData,
     fetch_work: FetchWork,
+    push_work: PushUpdateWork,
     book_id: Uuid,
+    // Copy of the work as last loaded or saved, restored if the user cancels their edits
+    saved_book: Option<WorkWithRelations>,
+    save_message: Option<Result<String, String>>,
+    // The work as currently stored, fetched before saving to detect concurrent edits
+    fetch_current: FetchCurrentWork,
+    // Fields changed by someone else since editing started, awaiting the user's decision
+    conflicts: Option<Vec<FieldChange>>,
+    // The work as stored when those changes were found, which saving our version starts from
+    stored_work: Option<Work>,
+    unsaved: Option<UnsavedChangesGuard>,
+    cache: Box<dyn Bridge<EntityCacheAgent>>,
 }
 
 #[derive(Default)]
 struct WorkFormData {
+    loaded: bool,
     imprints: Vec<ImprintWithPublisher>,
     work_types: Vec<WorkTypeValues>,
     work_statuses: Vec<WorkStatusValues>,
+    contribution_types: Vec<ContributionTypeValues>,
+    relation_types: Vec<RelationTypeValues>,
+    publication_types: Vec<PublicationTypeValues>,
+    subject_types: Vec<SubjectTypeValues>,
+}
+
+impl From<WorkFormDataResponseData> for WorkFormData {
+    fn from(data: WorkFormDataResponseData) -> Self {
+        WorkFormData {
+            loaded: true,
+            imprints: data.imprints,
+            work_types: data.work_types.enum_values,
+            work_statuses: data.work_statuses.enum_values,
+            contribution_types: data.contribution_types.enum_values,
+            relation_types: data.relation_types.enum_values,
+            publication_types: data.publication_types.enum_values,
+            subject_types: data.subject_types.enum_values,
+        }
+    }
 }
 
 pub enum Msg {
     GetBook,
     SetBookFetchState(FetchActionBook),
+    CacheResponse(EntityCacheResponse),
+    GetFormData,
+    SetFormDataFetchState(FetchActionWorkFormData),
+    StartEditing,
+    CancelEditing,
+    UpdateWork,
+    SetCurrentWorkFetchState(FetchActionCurrentWork),
+    SaveWork,
+    DiscardChanges,
+    SetWorkPushState(PushActionUpdateWork),
+    UpdateContributions(Option<Vec<Contribution>>),
+    UpdateRelations(Option<Vec<WorkRelationWithRelatedWork>>),
+    UpdatePublications(Option<Vec<Publication>>),
+    UpdateSubjects(Option<Vec<Subject>>),
+    ChangeWorkType(WorkType),
+    ChangeWorkStatus(WorkStatus),
+    ChangeImprint(String),
+    ChangeTitle(String),
+    ChangeSubtitle(String),
+    ChangeReference(String),
+    ChangeEdition(String),
+    ChangeDoi(String),
+    ChangeDate(String),
+    ChangeWithdrawnDate(String),
+    ChangePlace(String),
+    ChangePageCount(String),
+    ChangePageBreakdown(String),
+    ChangeFirstPage(String),
+    ChangeLastPage(String),
+    ChangeImageCount(String),
+    ChangeTableCount(String),
+    ChangeAudioCount(String),
+    ChangeVideoCount(String),
+    ChangeLicense(String),
+    ChangeCopyright(String),
+    ChangeLandingPage(String),
+    ChangeLccn(String),
+    ChangeOclc(String),
+    ChangeShortAbstract(String),
+    ChangeLongAbstract(String),
+    ChangeNote(String),
+    ChangeBibliographyNote(String),
+    ChangeToc(String),
+    ChangeCoverUrl(String),
+    ChangeCoverCaption(String),
 }
 
 #[derive(PartialEq, Eq, Properties)]
@@ -45,6 +178,476 @@ pub struct Props {
     pub book_id: Uuid,
 }
 
+impl BookDetailComponent {
+    /// Share the work as stored in the database, i.e. without any unsaved edits, with the cache
+    /// and describe it in the document head
+    fn cache_book(&mut self) {
+        let book = self.saved_book.as_ref().unwrap_or(&self.book).clone();
+        if !book.work_id.is_nil() {
+            PageHead::set(&HeadMetadata::from_work(&book, &config().branding.name));
+            self.cache
+                .send(EntityCacheRequest::StoreWorks(vec![book], true));
+        }
+    }
+
+    fn form_data_request_body() -> WorkFormDataRequestBody {
+        WorkFormDataRequestBody {
+            variables: WorkFormDataVariables {
+                publishers: config().publisher_scope(),
+            },
+            ..Default::default()
+        }
+    }
+
+    /// Whether the metadata form holds edits which have not been saved yet
+    fn is_dirty(&self) -> bool {
+        self.saved_book
+            .as_ref()
+            .is_some_and(|saved_book| saved_book != &self.book)
+    }
+
+    fn track_unsaved_changes(&mut self) {
+        if self.is_dirty() != self.unsaved.is_some() {
+            self.unsaved = self.is_dirty().then(UnsavedChangesGuard::new);
+        }
+    }
+
+    fn conflicts_view(&self, ctx: &Context<Self>, conflicts: &[FieldChange]) -> Html {
+        // The work only refers to its imprint by ID, so show the name where it is known
+        let imprint_name = |id: &str| {
+            self.data
+                .imprints
+                .iter()
+                .find(|i| i.imprint_id.to_string() == id)
+                .map(|i| i.imprint_name.clone())
+                .unwrap_or_else(|| id.to_string())
+        };
+        html! {
+            <div class="notification is-warning is-light">
+                <p class="mb-2">{ EDIT_CONFLICT }</p>
+                <table class="table is-narrow is-fullwidth">
+                    <thead>
+                        <tr>
+                            <th></th>
+                            <th>{ YOUR_VALUE }</th>
+                            <th>{ CURRENT_VALUE }</th>
+                        </tr>
+                    </thead>
+                    <tbody>
+                        {
+                            for conflicts.iter().map(|change| {
+                                let (ours, theirs) = if change.field == FIELD_IMPRINT {
+                                    (imprint_name(&change.ours), imprint_name(&change.theirs))
+                                } else {
+                                    (change.ours.clone(), change.theirs.clone())
+                                };
+                                html! {
+                                    <tr class={ if change.conflicting { "has-text-danger" } else { "" } }>
+                                        <th>{ change.field }</th>
+                                        <td>{ ours }</td>
+                                        <td>{ theirs }</td>
+                                    </tr>
+                                }
+                            })
+                        }
+                    </tbody>
+                </table>
+                <div class="field is-grouped">
+                    <div class="control">
+                        <button
+                            class="button is-danger is-small"
+                            type="button"
+                            onclick={ ctx.link().callback(|_| Msg::SaveWork) }
+                        >
+                            { OVERWRITE_BUTTON }
+                        </button>
+                    </div>
+                    <div class="control">
+                        <button
+                            class="button is-light is-small"
+                            type="button"
+                            onclick={ ctx.link().callback(|_| Msg::DiscardChanges) }
+                        >
+                            { DISCARD_CHANGES_BUTTON }
+                        </button>
+                    </div>
+                </div>
+            </div>
+        }
+    }
+
+    fn status_banner(&self) -> Html {
+        let banner = |class: &str, title: Message, detail: Option<String>| {
+            html! {
+                <div class={ format!("notification {} py-3 my-2", class) } role="status">
+                    <span class="font-semibold uppercase">{ title }</span>
+                    {
+                        match detail {
+                            Some(detail) => html! { <span class="ml-2">{ detail }</span> },
+                            None => html! {},
+                        }
+                    }
+                </div>
+            }
+        };
+        let dated = |label: Message, date: &Option<String>| {
+            date.as_ref()
+                .map(|d| format!("{}: {}", label, format_date(d)))
+        };
+        match self.book.work_status {
+            WorkStatus::Forthcoming => banner(
+                "is-warning",
+                STATUS_FORTHCOMING,
+                dated(STATUS_EXPECTED_ON, &self.book.publication_date),
+            ),
+            WorkStatus::WithdrawnFromSale => banner(
+                "is-danger is-light",
+                STATUS_WITHDRAWN_FROM_SALE,
+                dated(STATUS_WITHDRAWN_ON, &self.book.withdrawn_date),
+            ),
+            WorkStatus::OutOfPrint => banner(
+                "is-danger is-light",
+                STATUS_OUT_OF_PRINT,
+                dated(STATUS_WITHDRAWN_ON, &self.book.withdrawn_date),
+            ),
+            WorkStatus::Cancelled => banner("is-danger", STATUS_CANCELLED, None),
+            WorkStatus::PostponedIndefinitely => {
+                banner("is-warning is-light", STATUS_POSTPONED_INDEFINITELY, None)
+            }
+            _ => html! {},
+        }
+    }
+
+    #[allow(clippy::unnecessary_operation)]
+    fn edit_form(&self, ctx: &Context<Self>) -> Html {
+        // Only logged in users may edit, the API will reject changes from anyone else
+        if !AccountService::new().is_loggedin() {
+            return html! {};
+        }
+        // A cached copy of the work may lack fields, so only edit once it is fully loaded
+        if !matches!(self.fetch_work.as_ref().state(), FetchState::Fetched(_)) {
+            return html! {};
+        }
+        let save_message = match &self.save_message {
+            Some(Ok(message)) => html! {
+                <div class="notification is-success is-light py-2">{ message }</div>
+            },
+            Some(Err(message)) => html! {
+                <div class="notification is-danger is-light py-2">{ message }</div>
+            },
+            None => html! {},
+        };
+        if self.saved_book.is_none() {
+            return html! {
+                <>
+                    { save_message }
+                    <button class="button is-small" onclick={ ctx.link().callback(|_| Msg::StartEditing) }>
+                        { EDIT_BUTTON }
+                    </button>
+                </>
+            };
+        }
+        if !self.data.loaded {
+            return match self.fetch_form_data.as_ref().state() {
+                FetchState::Failed(_, _) => {
+                    html! { <Reloader onclick={ ctx.link().callback(|_| Msg::GetFormData) }/> }
+                }
+                _ => html! { <Loader/> },
+            };
+        }
+        let is_saving = matches!(self.push_work.as_ref().state(), FetchState::Fetching(_))
+            || matches!(self.fetch_current.as_ref().state(), FetchState::Fetching(_));
+        html! {
+            <>
+            <form
+                id="edit-work"
+                class="box my-4"
+                onsubmit={ ctx.link().callback(|e: yew::FocusEvent| {
+                    e.prevent_default();
+                    Msg::UpdateWork
+                }) }
+            >
+                { save_message }
+                {
+                    match &self.conflicts {
+                        Some(conflicts) => self.conflicts_view(ctx, conflicts),
+                        None => html! {},
+                    }
+                }
+                <div class="field is-horizontal">
+                    <div class="field-body">
+                        <FormWorkTypeSelect
+                            label={ FIELD_WORK_TYPE.as_str() }
+                            value={ self.book.work_type.clone() }
+                            data={ WorkTypeValues::to_options(&self.data.work_types) }
+                            onchange={ ctx.link().callback(Msg::ChangeWorkType) }
+                            required = true
+                        />
+                        <FormWorkStatusSelect
+                            label={ FIELD_WORK_STATUS.as_str() }
+                            value={ self.book.work_status.clone() }
+                            data={ WorkStatusValues::to_options(&self.data.work_statuses) }
+                            onchange={ ctx.link().callback(Msg::ChangeWorkStatus) }
+                            required = true
+                        />
+                        <FormImprintSelect
+                            label={ FIELD_IMPRINT.as_str() }
+                            value={ self.book.imprint.imprint_id }
+                            data={ self.data.imprints.clone() }
+                            onchange={ ctx.link().callback(|e: yew::Event| Msg::ChangeImprint(e.to_value())) }
+                            required = true
+                        />
+                    </div>
+                </div>
+                <FormTextInput
+                    label={ FIELD_TITLE.as_str() }
+                    value={ self.book.title.clone() }
+                    oninput={ ctx.link().callback(|e: InputEvent| Msg::ChangeTitle(e.to_value())) }
+                    required = true
+                />
+                <FormTextInput
+                    label={ FIELD_SUBTITLE.as_str() }
+                    value={ self.book.subtitle.clone() }
+                    oninput={ ctx.link().callback(|e: InputEvent| Msg::ChangeSubtitle(e.to_value())) }
+                />
+                <div class="field is-horizontal">
+                    <div class="field-body">
+                        <FormTextInput
+                            label={ FIELD_REFERENCE.as_str() }
+                            value={ self.book.reference.clone() }
+                            oninput={ ctx.link().callback(|e: InputEvent| Msg::ChangeReference(e.to_value())) }
+                        />
+                        <FormNumberInput
+                            label={ FIELD_EDITION.as_str() }
+                            value={ self.book.edition }
+                            oninput={ ctx.link().callback(|e: InputEvent| Msg::ChangeEdition(e.to_value())) }
+                            min={ "1".to_string() }
+                        />
+                    </div>
+                </div>
+                <FormTextInputExtended
+                    label={ FIELD_DOI.as_str() }
+                    statictext={ DOI_DOMAIN }
+                    value={ self.doi.clone() }
+                    tooltip={ self.doi_warning.clone() }
+                    oninput={ ctx.link().callback(|e: InputEvent| Msg::ChangeDoi(e.to_value())) }
+                />
+                <div class="field is-horizontal">
+                    <div class="field-body">
+                        <FormDateInput
+                            label={ FIELD_PUBLICATION_DATE.as_str() }
+                            value={ self.book.publication_date.clone() }
+                            oninput={ ctx.link().callback(|e: InputEvent| Msg::ChangeDate(e.to_value())) }
+                        />
+                        <FormDateInput
+                            label={ FIELD_WITHDRAWN_DATE.as_str() }
+                            value={ self.book.withdrawn_date.clone() }
+                            oninput={ ctx.link().callback(|e: InputEvent| Msg::ChangeWithdrawnDate(e.to_value())) }
+                            deactivated={ !self.book.work_status.is_withdrawn() }
+                        />
+                        <FormTextInput
+                            label={ FIELD_PLACE.as_str() }
+                            value={ self.book.place.clone() }
+                            oninput={ ctx.link().callback(|e: InputEvent| Msg::ChangePlace(e.to_value())) }
+                        />
+                    </div>
+                </div>
+                <div class="field is-horizontal">
+                    <div class="field-body">
+                        <FormNumberInput
+                            label={ FIELD_PAGE_COUNT.as_str() }
+                            value={ self.book.page_count }
+                            oninput={ ctx.link().callback(|e: InputEvent| Msg::ChangePageCount(e.to_value())) }
+                        />
+                        <FormTextInput
+                            label={ FIELD_PAGE_BREAKDOWN.as_str() }
+                            value={ self.book.page_breakdown.clone() }
+                            oninput={ ctx.link().callback(|e: InputEvent| Msg::ChangePageBreakdown(e.to_value())) }
+                        />
+                        <FormTextInput
+                            label={ FIELD_FIRST_PAGE.as_str() }
+                            value={ self.book.first_page.clone() }
+                            oninput={ ctx.link().callback(|e: InputEvent| Msg::ChangeFirstPage(e.to_value())) }
+                        />
+                        <FormTextInput
+                            label={ FIELD_LAST_PAGE.as_str() }
+                            value={ self.book.last_page.clone() }
+                            oninput={ ctx.link().callback(|e: InputEvent| Msg::ChangeLastPage(e.to_value())) }
+                        />
+                    </div>
+                </div>
+                <div class="field is-horizontal">
+                    <div class="field-body">
+                        <FormNumberInput
+                            label={ FIELD_IMAGE_COUNT.as_str() }
+                            value={ self.book.image_count }
+                            oninput={ ctx.link().callback(|e: InputEvent| Msg::ChangeImageCount(e.to_value())) }
+                        />
+                        <FormNumberInput
+                            label={ FIELD_TABLE_COUNT.as_str() }
+                            value={ self.book.table_count }
+                            oninput={ ctx.link().callback(|e: InputEvent| Msg::ChangeTableCount(e.to_value())) }
+                        />
+                        <FormNumberInput
+                            label={ FIELD_AUDIO_COUNT.as_str() }
+                            value={ self.book.audio_count }
+                            oninput={ ctx.link().callback(|e: InputEvent| Msg::ChangeAudioCount(e.to_value())) }
+                        />
+                        <FormNumberInput
+                            label={ FIELD_VIDEO_COUNT.as_str() }
+                            value={ self.book.video_count }
+                            oninput={ ctx.link().callback(|e: InputEvent| Msg::ChangeVideoCount(e.to_value())) }
+                        />
+                    </div>
+                </div>
+                <FormUrlInput
+                    label={ FIELD_LICENSE.as_str() }
+                    value={ self.book.license.clone() }
+                    oninput={ ctx.link().callback(|e: InputEvent| Msg::ChangeLicense(e.to_value())) }
+                />
+                <FormTextInput
+                    label={ FIELD_COPYRIGHT_HOLDER.as_str() }
+                    value={ self.book.copyright_holder.clone() }
+                    oninput={ ctx.link().callback(|e: InputEvent| Msg::ChangeCopyright(e.to_value())) }
+                />
+                <FormUrlInput
+                    label={ FIELD_LANDING_PAGE.as_str() }
+                    value={ self.book.landing_page.clone() }
+                    oninput={ ctx.link().callback(|e: InputEvent| Msg::ChangeLandingPage(e.to_value())) }
+                />
+                <div class="field is-horizontal">
+                    <div class="field-body">
+                        <FormTextInput
+                            label={ FIELD_LCCN_NUMBER.as_str() }
+                            value={ self.book.lccn.clone() }
+                            oninput={ ctx.link().callback(|e: InputEvent| Msg::ChangeLccn(e.to_value())) }
+                        />
+                        <FormTextInput
+                            label={ FIELD_OCLC.as_str() }
+                            value={ self.book.oclc.clone() }
+                            oninput={ ctx.link().callback(|e: InputEvent| Msg::ChangeOclc(e.to_value())) }
+                        />
+                    </div>
+                </div>
+                <FormUrlInput
+                    label={ FIELD_COVER_URL.as_str() }
+                    value={ self.book.cover_url.clone() }
+                    oninput={ ctx.link().callback(|e: InputEvent| Msg::ChangeCoverUrl(e.to_value())) }
+                />
+                <FormTextarea
+                    label={ FIELD_COVER_CAPTION.as_str() }
+                    value={ self.book.cover_caption.clone() }
+                    oninput={ ctx.link().callback(|e: InputEvent| Msg::ChangeCoverCaption(e.to_value())) }
+                />
+                <FormTextarea
+                    label={ FIELD_SHORT_ABSTRACT.as_str() }
+                    value={ self.book.short_abstract.clone() }
+                    oninput={ ctx.link().callback(|e: InputEvent| Msg::ChangeShortAbstract(e.to_value())) }
+                />
+                <FormTextarea
+                    label={ FIELD_LONG_ABSTRACT.as_str() }
+                    value={ self.book.long_abstract.clone() }
+                    oninput={ ctx.link().callback(|e: InputEvent| Msg::ChangeLongAbstract(e.to_value())) }
+                />
+                <FormTextarea
+                    label={ FIELD_GENERAL_NOTE.as_str() }
+                    value={ self.book.general_note.clone() }
+                    oninput={ ctx.link().callback(|e: InputEvent| Msg::ChangeNote(e.to_value())) }
+                />
+                <FormTextarea
+                    label={ FIELD_BIBLIOGRAPHY_NOTE.as_str() }
+                    value={ self.book.bibliography_note.clone() }
+                    oninput={ ctx.link().callback(|e: InputEvent| Msg::ChangeBibliographyNote(e.to_value())) }
+                />
+                <FormTextarea
+                    label={ FIELD_TOC.as_str() }
+                    value={ self.book.toc.clone() }
+                    oninput={ ctx.link().callback(|e: InputEvent| Msg::ChangeToc(e.to_value())) }
+                />
+                <div class="field is-grouped">
+                    <div class="control">
+                        <button
+                            class="button is-success"
+                            type="submit"
+                            disabled={ is_saving || self.conflicts.is_some() }
+                        >
+                            { SAVE_BUTTON }
+                        </button>
+                    </div>
+                    {
+                        if self.is_dirty() {
+                            html! {
+                                <div class="control">
+                                    <span class="tag is-warning is-light is-medium">{ UNSAVED_CHANGES }</span>
+                                </div>
+                            }
+                        } else {
+                            html! {}
+                        }
+                    }
+                    <div class="control">
+                        <button
+                            class="button is-light"
+                            type="button"
+                            onclick={ ctx.link().callback(|_| Msg::CancelEditing) }
+                        >
+                            { CANCEL_BUTTON }
+                        </button>
+                    </div>
+                </div>
+            </form>
+            <ContributionsFormComponent
+                contributions={ self.book.contributions.clone() }
+                contribution_types={ self.data.contribution_types.clone() }
+                work_id={ self.book.work_id }
+                update_contributions={ ctx.link().callback(Msg::UpdateContributions) }
+            />
+            <RelationsFormComponent
+                relations={ self.book.relations.clone() }
+                relation_types={ self.data.relation_types.clone() }
+                work_id={ self.book.work_id }
+                update_relations={ ctx.link().callback(Msg::UpdateRelations) }
+            />
+            <PublicationsFormComponent
+                publications={ self.book.publications.clone() }
+                publication_types={ self.data.publication_types.clone() }
+                work_id={ self.book.work_id }
+                work_type={ self.work_type.clone() }
+                update_publications={ ctx.link().callback(Msg::UpdatePublications) }
+            />
+            <SubjectsFormComponent
+                subjects={ self.book.subjects.clone() }
+                subject_types={ self.data.subject_types.clone() }
+                work_id={ self.book.work_id }
+                update_subjects={ ctx.link().callback(Msg::UpdateSubjects) }
+            />
+            </>
+        }
+    }
+
+    fn metadata_health(&self) -> Html {
+        let errors = validate(&self.book);
+        html! {
+            <div id="metadata-health" class="bg-ternary-200 dark:bg-gray-800 container py-4 my-2">
+                <div class="py-4 font-semibold text-header">{ METADATA_HEALTH }</div>
+                {
+                    if errors.is_empty() {
+                        html! {
+                            <p class="has-text-success">{ METADATA_HEALTHY }</p>
+                        }
+                    } else {
+                        html! {
+                            <ul class="list-disc list-inside has-text-danger">
+                                { for errors.iter().map(|e| html! { <li>{ e.to_string() }</li> }) }
+                            </ul>
+                        }
+                    }
+                }
+            </div>
+        }
+    }
+}
+
 impl Component for BookDetailComponent {
     type Message = Msg;
     type Properties = Props;
@@ -59,6 +662,9 @@ impl Component for BookDetailComponent {
         let data: WorkFormData = Default::default();
         let book_id = ctx.props().book_id;
 
+        let mut cache = EntityCacheAgent::bridge(ctx.link().callback(Msg::CacheResponse));
+        // Show whatever is already known about the work while the full query loads
+        cache.send(EntityCacheRequest::GetWork(book_id));
         ctx.link().send_message(Msg::GetBook);
         BookDetailComponent {
             book,
@@ -67,13 +673,39 @@ impl Component for BookDetailComponent {
             imprint_id,
             work_type,
             data,
+            fetch_form_data: Default::default(),
             fetch_work,
+            push_work: Default::default(),
             book_id,
+            saved_book: None,
+            save_message: None,
+            fetch_current: Default::default(),
+            conflicts: None,
+            stored_work: None,
+            unsaved: None,
+            cache,
+        }
+    }
+
+    fn changed(&mut self, ctx: &Context<Self>) -> bool {
+        // Linking from one work to another reuses this component with new props
+        if self.book_id.neq_assign(ctx.props().book_id) {
+            self.book = Default::default();
+            self.saved_book = None;
+            self.conflicts = None;
+            self.stored_work = None;
+            self.save_message = None;
+            self.unsaved = None;
+            self.cache.send(EntityCacheRequest::GetWork(self.book_id));
+            ctx.link().send_message(Msg::GetBook);
+            true
+        } else {
+            false
         }
     }
 
     fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
-        match msg {
+        let should_render = match msg {
             Msg::SetBookFetchState(fetch_state) => {
                 self.fetch_work.apply(fetch_state);
                 match self.fetch_work.as_ref().state() {
@@ -84,29 +716,26 @@ impl Component for BookDetailComponent {
                             Some(w) => w.to_owned(),
                             None => Default::default(),
                         };
+                        self.saved_book = None;
+                        self.conflicts = None;
+                        self.stored_work = None;
                         // Initialise user-entered DOI variable to match DOI in database
                         self.doi = self.book.doi.clone().unwrap_or_default().to_string();
                         self.imprint_id = self.book.imprint.imprint_id;
                         self.work_type = self.book.work_type.clone();
-                        body.data.imprints.clone_into(&mut self.data.imprints);
-                        body.data
-                            .work_types
-                            .enum_values
-                            .clone_into(&mut self.data.work_types);
-                        body.data
-                            .work_statuses
-                            .enum_values
-                            .clone_into(&mut self.data.work_statuses);
+                        self.cache_book();
                         true
                     }
-                    FetchState::Failed(_, _err) => false,
+                    FetchState::Failed(_, err) => {
+                        expire_on_invalid_token(&err);
+                        false
+                    }
                 }
             }
             Msg::GetBook => {
                 let body = WorkRequestBody {
                     variables: Variables {
                         work_id: Some(ctx.props().book_id),
-                        publishers: None,
                     },
                     ..Default::default()
                 };
@@ -119,14 +748,384 @@ impl Component for BookDetailComponent {
                     .send_message(Msg::SetBookFetchState(FetchAction::Fetching));
                 false
             }
-        }
+            Msg::CacheResponse(EntityCacheResponse::Work(work_id, Some((work, _)))) => {
+                // Only useful until the full query has returned, or while nothing is shown yet
+                let is_loaded = matches!(self.fetch_work.as_ref().state(), FetchState::Fetched(_));
+                if work_id == self.book_id && !is_loaded && self.book.work_id != work_id {
+                    self.doi = work.doi.clone().unwrap_or_default().to_string();
+                    self.imprint_id = work.imprint.imprint_id;
+                    self.work_type = work.work_type.clone();
+                    self.book = work;
+                    true
+                } else {
+                    false
+                }
+            }
+            Msg::CacheResponse(_) => false,
+            Msg::GetFormData => {
+                let body = Self::form_data_request_body();
+                // The same options apply to every work, so reuse them if already fetched
+                if let Some(data) = QueryCache::get::<_, WorkFormDataResponseData>(&body) {
+                    self.data = WorkFormData::from(data);
+                    return true;
+                }
+                self.fetch_form_data = Fetch::new(WorkFormDataRequest { body });
+                ctx.link()
+                    .send_future(self.fetch_form_data.fetch(Msg::SetFormDataFetchState));
+                ctx.link()
+                    .send_message(Msg::SetFormDataFetchState(FetchAction::Fetching));
+                false
+            }
+            Msg::SetFormDataFetchState(fetch_state) => {
+                self.fetch_form_data.apply(fetch_state);
+                match self.fetch_form_data.as_ref().state() {
+                    FetchState::NotFetching(_) => false,
+                    FetchState::Fetching(_) => true,
+                    FetchState::Fetched(body) => {
+                        QueryCache::insert(&Self::form_data_request_body(), &body.data);
+                        self.cache.send(EntityCacheRequest::StoreImprints(
+                            body.data.imprints.clone(),
+                        ));
+                        self.data = WorkFormData::from(body.data.clone());
+                        true
+                    }
+                    FetchState::Failed(_, err) => {
+                        expire_on_invalid_token(&err);
+                        true
+                    }
+                }
+            }
+            Msg::StartEditing => {
+                if !self.data.loaded {
+                    ctx.link().send_message(Msg::GetFormData);
+                }
+                self.saved_book = Some(self.book.clone());
+                self.save_message = None;
+                true
+            }
+            Msg::CancelEditing => {
+                if self.is_dirty() && !UnsavedChangesGuard::confirm_discard() {
+                    return false;
+                }
+                self.conflicts = None;
+                self.stored_work = None;
+                if let Some(book) = self.saved_book.take() {
+                    self.doi = book.doi.clone().unwrap_or_default().to_string();
+                    self.doi_warning.clear();
+                    self.book = book;
+                }
+                true
+            }
+            Msg::UpdateWork => {
+                // Don't submit a DOI which we already know the API will reject
+                if !self.doi_warning.is_empty() {
+                    self.save_message = Some(Err(self.doi_warning.clone()));
+                    return true;
+                }
+                // Check whether anyone else saved the work since we started editing it
+                let body = CurrentWorkRequestBody {
+                    variables: CurrentWorkVariables {
+                        work_id: self.book.work_id,
+                    },
+                    ..Default::default()
+                };
+                self.fetch_current = Fetch::new(CurrentWorkRequest { body });
+                ctx.link()
+                    .send_future(self.fetch_current.fetch(Msg::SetCurrentWorkFetchState));
+                ctx.link()
+                    .send_message(Msg::SetCurrentWorkFetchState(FetchAction::Fetching));
+                false
+            }
+            Msg::SetCurrentWorkFetchState(fetch_state) => {
+                self.fetch_current.apply(fetch_state);
+                match self.fetch_current.as_ref().state() {
+                    FetchState::NotFetching(_) => false,
+                    FetchState::Fetching(_) => true,
+                    FetchState::Fetched(body) => {
+                        match (&body.data.work, &self.saved_book) {
+                            (Some(current), Some(saved_book))
+                                if current.updated_at != saved_book.updated_at =>
+                            {
+                                let changes = changes_since(saved_book, &self.book, current);
+                                // Only fields outside the form were changed, e.g. its relations
+                                if changes.is_empty() {
+                                    ctx.link().send_message(Msg::SaveWork);
+                                } else {
+                                    self.conflicts = Some(changes);
+                                    self.stored_work = Some(current.clone());
+                                }
+                            }
+                            // Unchanged since loading, or deleted in the meantime, in which case
+                            // the API will report the error when saving
+                            _ => ctx.link().send_message(Msg::SaveWork),
+                        }
+                        true
+                    }
+                    FetchState::Failed(_, err) => {
+                        let error = ThothError::from(err.clone());
+                        self.save_message = Some(Err(format!("{}: {}", SAVE_FAILED, error)));
+                        expire_on_invalid_token(&err);
+                        true
+                    }
+                }
+            }
+            Msg::DiscardChanges => {
+                self.conflicts = None;
+                self.stored_work = None;
+                self.saved_book = None;
+                ctx.link().send_message(Msg::GetBook);
+                true
+            }
+            Msg::SaveWork => {
+                self.conflicts = None;
+                // Save our version of the fields we edited over the stored work, rather than
+                // reverting everyone else's edits of the fields we left alone
+                if let (Some(stored_work), Some(saved_book)) =
+                    (self.stored_work.take(), &self.saved_book)
+                {
+                    self.book =
+                        merge_changes(saved_book, &self.book, &stored_work, &self.data.imprints);
+                    self.doi = self.book.doi.clone().unwrap_or_default().to_string();
+                }
+                let body = UpdateWorkRequestBody {
+                    variables: UpdateVariables {
+                        work_id: self.book.work_id,
+                        work_type: self.book.work_type.clone(),
+                        work_status: self.book.work_status.clone(),
+                        full_title: self.book.compile_fulltitle(),
+                        title: self.book.title.clone(),
+                        subtitle: self.book.subtitle.clone(),
+                        reference: self.book.reference.clone(),
+                        edition: self.book.edition,
+                        doi: self.book.doi.clone(),
+                        publication_date: self
+                            .book
+                            .publication_date
+                            .clone()
+                            .and_then(|d| d.to_opt_date()),
+                        withdrawn_date: self
+                            .book
+                            .withdrawn_date
+                            .clone()
+                            .and_then(|d| d.to_opt_date()),
+                        place: self.book.place.clone(),
+                        page_count: self.book.page_count,
+                        page_breakdown: self.book.page_breakdown.clone(),
+                        image_count: self.book.image_count,
+                        table_count: self.book.table_count,
+                        audio_count: self.book.audio_count,
+                        video_count: self.book.video_count,
+                        license: self.book.license.clone(),
+                        copyright_holder: self.book.copyright_holder.clone(),
+                        landing_page: self.book.landing_page.clone(),
+                        lccn: self.book.lccn.clone(),
+                        oclc: self.book.oclc.clone(),
+                        short_abstract: self.book.short_abstract.clone(),
+                        long_abstract: self.book.long_abstract.clone(),
+                        general_note: self.book.general_note.clone(),
+                        bibliography_note: self.book.bibliography_note.clone(),
+                        toc: self.book.toc.clone(),
+                        cover_url: self.book.cover_url.clone(),
+                        cover_caption: self.book.cover_caption.clone(),
+                        imprint_id: self.book.imprint.imprint_id,
+                        first_page: self.book.first_page.clone(),
+                        last_page: self.book.last_page.clone(),
+                        page_interval: self.book.page_interval.clone(),
+                    },
+                    ..Default::default()
+                };
+                let request = UpdateWorkRequest { body };
+                self.push_work = Fetch::new(request);
+                ctx.link()
+                    .send_future(self.push_work.fetch(Msg::SetWorkPushState));
+                ctx.link()
+                    .send_message(Msg::SetWorkPushState(FetchAction::Fetching));
+                false
+            }
+            Msg::SetWorkPushState(fetch_state) => {
+                self.push_work.apply(fetch_state);
+                if let FetchState::Failed(_, err) = self.push_work.as_ref().state() {
+                    expire_on_invalid_token(&err);
+                }
+                match self.push_work.result() {
+                    None => true,
+                    Some(Ok(w)) => {
+                        self.book.full_title = w.full_title;
+                        self.book.updated_at = w.updated_at;
+                        self.work_type = w.work_type;
+                        self.imprint_id = w.imprint_id;
+                        self.saved_book = None;
+                        self.cache_book();
+                        self.save_message = Some(Ok(SAVE_SUCCESS.to_string()));
+                        true
+                    }
+                    Some(Err(e)) => {
+                        self.save_message = Some(Err(format!("{}: {}", SAVE_FAILED, e)));
+                        true
+                    }
+                }
+            }
+            Msg::UpdateContributions(contributions) => {
+                // Contributions are saved as soon as they change, so cancelling must not revert them
+                if let Some(saved_book) = self.saved_book.as_mut() {
+                    saved_book.contributions = contributions.clone();
+                }
+                let changed = self.book.contributions.neq_assign(contributions);
+                self.cache_book();
+                changed
+            }
+            Msg::UpdateRelations(relations) => {
+                if let Some(saved_book) = self.saved_book.as_mut() {
+                    saved_book.relations = relations.clone();
+                }
+                let changed = self.book.relations.neq_assign(relations);
+                self.cache_book();
+                changed
+            }
+            Msg::UpdatePublications(publications) => {
+                if let Some(saved_book) = self.saved_book.as_mut() {
+                    saved_book.publications = publications.clone();
+                }
+                let changed = self.book.publications.neq_assign(publications);
+                self.cache_book();
+                changed
+            }
+            Msg::UpdateSubjects(subjects) => {
+                if let Some(saved_book) = self.saved_book.as_mut() {
+                    saved_book.subjects = subjects.clone();
+                }
+                let changed = self.book.subjects.neq_assign(subjects);
+                self.cache_book();
+                changed
+            }
+            Msg::ChangeWorkType(work_type) => self.book.work_type.neq_assign(work_type),
+            Msg::ChangeWorkStatus(work_status) => {
+                if self.book.work_status.neq_assign(work_status) {
+                    // Only withdrawn works may carry a withdrawn date
+                    if !self.book.work_status.is_withdrawn() {
+                        self.book.withdrawn_date = None;
+                    }
+                    true
+                } else {
+                    false
+                }
+            }
+            Msg::ChangeImprint(imprint_id) => {
+                let imprint = Uuid::parse_str(&imprint_id).ok().and_then(|id| {
+                    self.data
+                        .imprints
+                        .iter()
+                        .find(|i| i.imprint_id == id)
+                        .cloned()
+                });
+                match imprint {
+                    Some(imprint) => self.book.imprint.neq_assign(imprint),
+                    None => false,
+                }
+            }
+            Msg::ChangeTitle(title) => self.book.title.neq_assign(title.trim().to_owned()),
+            Msg::ChangeSubtitle(subtitle) => {
+                self.book.subtitle.neq_assign(subtitle.to_opt_string())
+            }
+            Msg::ChangeReference(value) => self.book.reference.neq_assign(value.to_opt_string()),
+            Msg::ChangeEdition(edition) => self.book.edition.neq_assign(edition.to_opt_int()),
+            Msg::ChangeDoi(value) => {
+                if self.doi.neq_assign(value.trim().to_owned()) {
+                    // If DOI is not correctly formatted, display a warning.
+                    // Don't update self.book.doi yet, as user may later
+                    // overwrite a new valid value with an invalid one.
+                    self.doi_warning.clear();
+                    match Doi::from_str(&self.doi) {
+                        Err(e) => {
+                            match e {
+                                // If no DOI was provided, no warning is required.
+                                ThothError::DoiEmptyError => {}
+                                _ => self.doi_warning = e.to_string(),
+                            }
+                            self.book.doi = None;
+                        }
+                        Ok(doi) => self.book.doi = Some(doi),
+                    }
+                    true
+                } else {
+                    false
+                }
+            }
+            Msg::ChangeDate(value) => self.book.publication_date.neq_assign(value.to_opt_string()),
+            Msg::ChangeWithdrawnDate(value) => {
+                self.book.withdrawn_date.neq_assign(value.to_opt_string())
+            }
+            Msg::ChangePlace(value) => self.book.place.neq_assign(value.to_opt_string()),
+            Msg::ChangePageCount(value) => self.book.page_count.neq_assign(value.to_opt_int()),
+            Msg::ChangePageBreakdown(value) => {
+                self.book.page_breakdown.neq_assign(value.to_opt_string())
+            }
+            Msg::ChangeFirstPage(value) => {
+                if self.book.first_page.neq_assign(value.to_opt_string()) {
+                    self.book.page_interval = self.book.compile_page_interval();
+                    true
+                } else {
+                    false
+                }
+            }
+            Msg::ChangeLastPage(value) => {
+                if self.book.last_page.neq_assign(value.to_opt_string()) {
+                    self.book.page_interval = self.book.compile_page_interval();
+                    true
+                } else {
+                    false
+                }
+            }
+            Msg::ChangeImageCount(value) => self.book.image_count.neq_assign(value.to_opt_int()),
+            Msg::ChangeTableCount(value) => self.book.table_count.neq_assign(value.to_opt_int()),
+            Msg::ChangeAudioCount(value) => self.book.audio_count.neq_assign(value.to_opt_int()),
+            Msg::ChangeVideoCount(value) => self.book.video_count.neq_assign(value.to_opt_int()),
+            Msg::ChangeLicense(value) => self.book.license.neq_assign(value.to_opt_string()),
+            Msg::ChangeCopyright(value) => {
+                self.book.copyright_holder.neq_assign(value.to_opt_string())
+            }
+            Msg::ChangeLandingPage(value) => {
+                self.book.landing_page.neq_assign(value.to_opt_string())
+            }
+            Msg::ChangeLccn(value) => self.book.lccn.neq_assign(value.to_opt_string()),
+            Msg::ChangeOclc(value) => self.book.oclc.neq_assign(value.to_opt_string()),
+            Msg::ChangeShortAbstract(value) => {
+                self.book.short_abstract.neq_assign(value.to_opt_string())
+            }
+            Msg::ChangeLongAbstract(value) => {
+                self.book.long_abstract.neq_assign(value.to_opt_string())
+            }
+            Msg::ChangeNote(value) => self.book.general_note.neq_assign(value.to_opt_string()),
+            Msg::ChangeBibliographyNote(value) => self
+                .book
+                .bibliography_note
+                .neq_assign(value.to_opt_string()),
+            Msg::ChangeToc(value) => self.book.toc.neq_assign(value.to_opt_string()),
+            Msg::ChangeCoverUrl(value) => self.book.cover_url.neq_assign(value.to_opt_string()),
+            Msg::ChangeCoverCaption(value) => {
+                self.book.cover_caption.neq_assign(value.to_opt_string())
+            }
+        };
+        self.track_unsaved_changes();
+        should_render
     }
 
-    fn view(&self, _ctx: &yew::Context<Self>) -> Html {
+    fn destroy(&mut self, _ctx: &Context<Self>) {
+        PageHead::clear();
+    }
+
+    fn view(&self, ctx: &yew::Context<Self>) -> Html {
         match self.fetch_work.as_ref().state() {
-            FetchState::NotFetching(_) => html! {<Loader/>},
-            FetchState::Fetching(_) => html! {<Loader/>},
-            FetchState::Fetched(_body) => {
+            FetchState::Failed(_, err) => html! {
+                { ThothError::from(err).to_string() }
+            },
+            // Until the full query returns, render any copy of the work found in the cache
+            FetchState::NotFetching(_) | FetchState::Fetching(_)
+                if self.book.work_id != self.book_id =>
+            {
+                html! {<Loader/>}
+            }
+            _ => {
                 // let languages = match &self.book.languages {
                 //     Some(w) => w
                 //         .to_owned()
@@ -140,7 +1139,11 @@ impl Component for BookDetailComponent {
                             .clone()
                             .iter()
                             .map(|c| {
-                                format!("{}({})", c.full_name.clone(), c.contribution_type.clone())
+                                format!(
+                                    "{}({})",
+                                    c.full_name.clone(),
+                                    c.contribution_type.localized()
+                                )
                             })
                             .collect::<Vec<_>>()
                             .join(", ");
@@ -155,7 +1158,21 @@ impl Component for BookDetailComponent {
                     .clone()
                     .unwrap_or_default()
                     .to_string();
-                let license = self.book.license.clone().unwrap_or_default().to_string();
+                let license = match self.book.parsed_license() {
+                    Some(Ok(license)) => license.as_badge(),
+                    Some(Err(_)) => {
+                        let url = self.book.license.clone().unwrap_or_default();
+                        html! {
+                            <>
+                                <a href={ url.clone() } target="_blank"> { url } </a>
+                                <span class="tag is-warning ml-2 has-tooltip-arrow" data-tooltip={ UNRECOGNISED_LICENSE.as_str() }>
+                                    { "?" }
+                                </span>
+                            </>
+                        }
+                    }
+                    None => html! {},
+                };
                 let copyright_holder = self
                     .book
                     .copyright_holder
@@ -166,9 +1183,9 @@ impl Component for BookDetailComponent {
                 let publication_date = self
                     .book
                     .publication_date
-                    .clone()
-                    .unwrap_or_default()
-                    .to_string();
+                    .as_deref()
+                    .map(format_date)
+                    .unwrap_or_default();
                 let publications = match self.book.publications.clone() {
                     Some(p) => p.to_owned(),
                     None => Default::default(),
@@ -183,7 +1200,7 @@ impl Component for BookDetailComponent {
                             Some(i) => i.to_owned(),
                             None => Default::default(),
                         };
-                        html! { <td> { format!("{}({})", isbn, p.publication_type) } </td> }
+                        html! { <td> { format!("{}({})", isbn, p.publication_type.localized()) } </td> }
                     })
                     .collect::<Vec<Html>>();
                 let long_abstract = self
@@ -193,7 +1210,7 @@ impl Component for BookDetailComponent {
                     .unwrap_or_default()
                     .to_string();
                 let page_count =
-                    format!("{} pages", self.book.page_count.clone().unwrap_or_default());
+                    PAGE_COUNT.fill(&[&format_integer(self.book.page_count.unwrap_or_default())]);
                 let languages = match self.book.languages.clone() {
                     Some(p) => p.to_owned(),
                     None => Default::default(),
@@ -204,17 +1221,9 @@ impl Component for BookDetailComponent {
                 let languages_html = languages
                     .iter()
                     .map(|l| {
-                        html! { <td> { format!("{}({})", l.language_code, l.language_relation) } </td> }
+                        html! { <td title={ l.language_code.display_name() }> { l.description() } </td> }
                     })
                     .collect::<Vec<Html>>();
-                let work_status = if let WorkStatus::Forthcoming = self.book.work_status {
-                    html! {
-                        <span class="inline-block text-xs mr-1 md:mr-2 mb-2 px-2 md:px-4 py-1 opacity-90 text-black bg-yellow-400"> { "FORTHCOMING" }  </span>
-                    }
-                } else {
-                    html! {}
-                };
-
                 let fundings = match self.book.fundings.clone() {
                     Some(f) => f.to_owned(),
                     None => Default::default(),
@@ -278,13 +1287,13 @@ impl Component for BookDetailComponent {
 
                 let contributors = contributions.iter().map(|c| {
                     let contribution = c.clone();
-                    let ordinal = c.contribution_ordinal.clone();
+                    let ordinal = c.contribution_ordinal;
                     let contributor_full_name = c.contributor.full_name.clone();
                     let orcid_html = if let Some(orcid) = contribution.contributor.orcid {
                         html! {
                             <div class="flex flex-row gap-1">
                                 <span class="ai ai-orcid text-orcid pt-1 w-5 h-5" aria-hidden="true"></span>
-                                <a href= { format!("{}", orcid.clone()) } title = { format!("{}'s ORCID record", contributor_full_name.clone())} > { orcid } </a>
+                                <a href= { format!("{}", orcid.clone()) } title = { ORCID_RECORD.fill(&[&contributor_full_name]) } > { orcid } </a>
                             </div>
                         }
                     } else { html!{} };
@@ -295,7 +1304,7 @@ impl Component for BookDetailComponent {
                                     <svg xmlns="http://www.w3.org/2000/svg" fill="none" viewBox="0 0 24 24" stroke-width="1.5" stroke="currentColor" aria-hidden="true" data-slot="icon" class="w-5 h-5">
                                         <path stroke-linecap="round" stroke-linejoin="round" d="M12 21a9.004 9.004 0 0 0 8.716-6.747M12 21a9.004 9.004 0 0 1-8.716-6.747M12 21c2.485 0 4.5-4.03 4.5-9S14.485 3 12 3m0 18c-2.485 0-4.5-4.03-4.5-9S9.515 3 12 3m0 0a8.997        8.997 0 0 1 7.843 4.582M12 3a8.997 8.997 0 0 0-7.843 4.582m15.686 0A11.953 11.953 0 0 1 12 10.5c-2.998 0-5.74-1.1-7.843-2.918m15.686 0A8.959 8.959 0 0 1 21 12c0 .778-.099 1.533-.284 2.253m0 0A17.919 17.919 0 0 1 12 16.5c-3.162 0-6.133-.815-8.716-2.247m0 0A9.015 9.015 0 0 1 3 12c0-1.605.42-3.113 1.157-4.418"></path>
                                     </svg>
-                                    <a href= { website.clone() } title= { format!("{}'s website",contributor_full_name.clone()) }> { website } </a>
+                                    <a href= { website.clone() } title= { CONTRIBUTOR_WEBSITE.fill(&[&contributor_full_name]) }> { website } </a>
                                 </div>
                             </div>
                         }
@@ -317,36 +1326,36 @@ impl Component for BookDetailComponent {
                         <div class="flex flex-col lg:flex-row gap-10">
                             <div>
                                 <div class="w-max ml-auto mr-auto">
-                                    <img src={self.book.cover_url.clone()} alt="Book cover placeholder" height="500" width="333"
+                                    <img src={self.book.cover_url.clone()} alt={ COVER_ALT.as_str() } height="500" width="333"
                                         sizes="75vw" class="w-32 lg:w-48 object-cover " role="presentation" />
                                 </div>
                                 <div id="export-metadata" class="px-2 py-4 my-2 hidden lg:block">
-                                    <div class="py-4 font-semibold text-header">{"Export Metadata"}</div>
+                                    <div class="py-4 font-semibold text-header">{ SECTION_EXPORT_METADATA }</div>
                                     <ul>
                                         <li class="py-1"> { "ONIX 3.0" }
                                             <ul class="list-inside pl-5">
                                                 <li class="py-1">
-                                                    <a href = { format!("{}/specifications/onix_3.0::thoth/work/{}",THOTH_EXPORT_API,self.book_id.clone()) }
+                                                    <a href = { format!("{}/specifications/onix_3.0::thoth/work/{}",config().export_api,self.book_id.clone()) }
                                                         target="_blank" rel="noopener noreferrer" tabindex="0"> { "Thoth" }</a>
                                                 </li>
                                                 <li class="py-1">
-                                                    <a href = { format!("{}/specifications/onix_3.0::project_muse/work/{}",THOTH_EXPORT_API,self.book_id.clone()) }
+                                                    <a href = { format!("{}/specifications/onix_3.0::project_muse/work/{}",config().export_api,self.book_id.clone()) }
                                                         target="_blank" rel="noopener noreferrer" tabindex="0"> { "Project MUSE" } </a>
                                                 </li>
                                                 <li class="py-1">
-                                                    <a href = { format!("{}/specifications/onix_3.0::oapen/work/{}",THOTH_EXPORT_API,self.book_id.clone()) }
+                                                    <a href = { format!("{}/specifications/onix_3.0::oapen/work/{}",config().export_api,self.book_id.clone()) }
                                                         target="_blank" rel="noopener noreferrer" tabindex="0"> {"OAPEN"} </a>
                                                 </li>
                                                 <li class="py-1">
-                                                    <a href = { format!("{}/specifications/onix_3.0::jstor/work/{}",THOTH_EXPORT_API,self.book_id.clone()) }
+                                                    <a href = { format!("{}/specifications/onix_3.0::jstor/work/{}",config().export_api,self.book_id.clone()) }
                                                         target="_blank" rel="noopener noreferrer" tabindex="0"> {"JSTOR"} </a>
                                                 </li>
                                                 <li class="py-1">
-                                                    <a href = { format!("{}/specifications/onix_3.0::google_books/work/{}",THOTH_EXPORT_API,self.book_id.clone()) }
+                                                    <a href = { format!("{}/specifications/onix_3.0::google_books/work/{}",config().export_api,self.book_id.clone()) }
                                                         target="_blank" rel="noopener noreferrer" tabindex="0"> {"Google Books"} </a>
                                                 </li>
                                                 <li class="py-1">
-                                                    <a href = { format!("{}/specifications/onix_3.0::overdrive/work/{}",THOTH_EXPORT_API,self.book_id.clone()) }
+                                                    <a href = { format!("{}/specifications/onix_3.0::overdrive/work/{}",config().export_api,self.book_id.clone()) }
                                                         target="_blank" rel="noopener noreferrer" tabindex="0"> {"OverDrive"} </a>
                                                 </li>
                                             </ul>
@@ -354,45 +1363,45 @@ impl Component for BookDetailComponent {
                                         <li class="py-1">{ "ONIX 2.1" }
                                             <ul class="list-inside pl-5">
                                                 <li class="py-1">
-                                                    <a href = { format!("{}/specifications/onix_2.1::ebsco_host/work/{}",THOTH_EXPORT_API,self.book_id.clone()) }
+                                                    <a href = { format!("{}/specifications/onix_2.1::ebsco_host/work/{}",config().export_api,self.book_id.clone()) }
                                                         target="_blank" rel="noopener noreferrer" tabindex="5"> {"EBSCO Host"} </a>
                                                 </li>
                                                 <li class="py-1">
-                                                    <a href = { format!("{}/specifications/onix_2.1::proquest_ebrary/work/{}",THOTH_EXPORT_API,self.book_id.clone()) }
+                                                    <a href = { format!("{}/specifications/onix_2.1::proquest_ebrary/work/{}",config().export_api,self.book_id.clone()) }
                                                         target="_blank" rel="noopener noreferrer" tabindex="5"> {"ProQuest Ebrary"} </a>
                                                 </li>
                                             </ul>
                                         </li>
                                         <li class="py-1">
-                                            <a href = { format!("{}/specifications/csv::thoth/work/{}",THOTH_EXPORT_API,self.book_id.clone()) }
+                                            <a href = { format!("{}/specifications/csv::thoth/work/{}",config().export_api,self.book_id.clone()) }
                                                 target="_blank" rel="noopener noreferrer" tabindex="0">{"CSV"}</a>
                                         </li>
                                         <li class="py-1">
-                                            <a href = { format!("{}/specifications/json::thoth/work/{}",THOTH_EXPORT_API,self.book_id.clone()) }
+                                            <a href = { format!("{}/specifications/json::thoth/work/{}",config().export_api,self.book_id.clone()) }
                                                 target="_blank" rel="noopener noreferrer" tabindex="0">{"JSON"}</a>
                                         </li>
                                         <li class="py-1">
-                                            <a href = { format!("{}/specifications/kbart::oclc/work/{}",THOTH_EXPORT_API,self.book_id.clone()) }
+                                            <a href = { format!("{}/specifications/kbart::oclc/work/{}",config().export_api,self.book_id.clone()) }
                                                 target="_blank" rel="noopener noreferrer" tabindex="0">{"OCLC KBART"} </a>
                                         </li>
                                         <li class="py-1">
-                                            <a href = { format!("{}/specifications/bibtex::thoth/work/{}",THOTH_EXPORT_API,self.book_id.clone()) }
+                                            <a href = { format!("{}/specifications/bibtex::thoth/work/{}",config().export_api,self.book_id.clone()) }
                                                 target="_blank" rel="noopener noreferrer" tabindex="0">{"BibTeX"}</a>
                                         </li>
                                         <li class="py-1">
-                                            <a href = { format!("{}/specifications/doideposit::crossref/work/{}",THOTH_EXPORT_API,self.book_id.clone()) }
+                                            <a href = { format!("{}/specifications/doideposit::crossref/work/{}",config().export_api,self.book_id.clone()) }
                                                 target="_blank" rel="noopener noreferrer" tabindex="0">{"CrossRef DOI deposit"}</a>
                                         </li>
                                         <li class="py-1">
-                                            <a href = { format!("{}/specifications/marc21record::thoth/work/{}",THOTH_EXPORT_API,self.book_id.clone()) }
+                                            <a href = { format!("{}/specifications/marc21record::thoth/work/{}",config().export_api,self.book_id.clone()) }
                                                 target="_blank" rel="noopener noreferrer" tabindex="0"> { "MARC 21 Record" } </a>
                                         </li>
                                         <li class="py-1">
-                                            <a href = { format!("{}/specifications/marc21markup::thoth/work/{}",THOTH_EXPORT_API,self.book_id.clone()) }
+                                            <a href = { format!("{}/specifications/marc21markup::thoth/work/{}",config().export_api,self.book_id.clone()) }
                                                 target="_blank" rel="noopener noreferrer" tabindex="0">{"MARC 21 Markup"}</a>
                                         </li>
                                         <li class="py-1">
-                                            <a href = { format!("{}/specifications/marc21xml::thoth/work/{}",THOTH_EXPORT_API,self.book_id.clone()) }
+                                            <a href = { format!("{}/specifications/marc21xml::thoth/work/{}",config().export_api,self.book_id.clone()) }
                                                 target="_blank" rel="noopener noreferrer" tabindex="0">{"MARC 21 XML"}</a>
                                         </li>
                                     </ul>
@@ -404,12 +1413,11 @@ impl Component for BookDetailComponent {
                                 <ul class="my-2 bullet-seperated" role="list">
                                     <span class="inline-block"> { contributors_text.clone()} </span>
                                 </ul>
-                                <div class="flex flex-row gap-2">
-                                    { work_status }
-                                </div>
+                                { self.status_banner() }
+                                { self.edit_form(ctx) }
                                 <nav class="my-4 pc-5 border-2 border-primary-200 dark:border-gray-400 rounded-2xl">
                                     <ul class="flex flex-wrap md:flex-row list-none gap-2 place-content-center">
-                                        <li tabindex="0" aria-label="Export Metadata" role="link">
+                                        <li tabindex="0" aria-label={ SECTION_EXPORT_METADATA.as_str() } role="link">
                                             <a class="flex flex-col items-center w-16 md:w-20 lg:w-24 xl:w-32 text-center text-xs text-primary-700 dark:text-gray-400 hover:text-primary-400 dark:hover:text-primary-400 px-4 py-3
                                                 border-b-2 border-transparent hover:border-primary-600 dark:hover:border-primary-400 cursor-pointer group hover:no-underline" href="#export-metadata">
                                                 <svg xmlns="http://www.w3.org/2000/svg" fill="none" viewBox="0 0 24 24" stroke-width="1.5" stroke="currentColor" aria-hidden="true" data-slot="icon" class="w-5 h-5">
@@ -417,10 +1425,10 @@ impl Component for BookDetailComponent {
                                                         3m0 0 3-3m-3 3V1.5m6 9h.75a2.25 2.25 0 0 1 2.25 2.25v7.5a2.25 2.25 0 0 1-2.25 2.25h-7.5a2.25 2.25 0 0 1-2.25-2.25v-.75">
                                                     </path>
                                                 </svg>
-                                                <span class="hidden md:block">{"Export Metadata"}</span>
+                                                <span class="hidden md:block">{ SECTION_EXPORT_METADATA }</span>
                                             </a>
                                         </li>
-                                        <li tabindex="0" aria-label="Metadata" role="link">
+                                        <li tabindex="0" aria-label={ SECTION_METADATA.as_str() } role="link">
                                             <a class="flex flex-col items-center w-16 md:w-20 lg:w-24 xl:w-32 text-center text-xs text-primary-700 dark:text-gray-400 hover:text-primary-400
                                                 dark:hover:text-primary-400 px-4 py-3 border-b-2 border-transparent hover:border-primary-600 dark:hover:border-primary-400 cursor-pointer group hover:no-underline" href="#metadata">
                                                 <svg xmlns="http://www.w3.org/2000/svg" fill="none" viewBox="0 0 24 24" stroke-width="1.5" stroke="currentColor" aria-hidden="true" data-slot="icon" class="w-5 h-5">
@@ -428,92 +1436,92 @@ impl Component for BookDetailComponent {
                                                         989 3.559A11.209 11.209 0 0 0 8.25 10.5a3.75 3.75 0 1 1 7.5 0c0 .527-.021 1.049-.064 1.565M12 10.5a14.94 14.94 0 0 1-3.6 9.75m6.633-4.596a18.666 18.666 0 0 1-2.485 5.33">
                                                     </path>
                                                 </svg>
-                                                <span class="hidden md:block">{"Metadata"}</span>
+                                                <span class="hidden md:block">{ SECTION_METADATA }</span>
                                             </a>
                                         </li>
-                                        <li tabindex="0" aria-label="Locations" role="link">
+                                        <li tabindex="0" aria-label={ SECTION_LOCATIONS.as_str() } role="link">
                                             <a class="flex flex-col items-center w-16 md:w-20 lg:w-24 xl:w-32 text-center text-xs text-primary-700 dark:text-gray-400 hover:text-primary-400 dark:hover:text-primary-400 px-4 py-3 border-b-2 border-transparent hover:border-primary-600 dark:hover:border-primary-400 cursor-pointer group hover:no-underline" href="#locations">
                                                 <svg xmlns="http://www.w3.org/2000/svg" fill="none" viewBox="0 0 24 24" stroke-width="1.5" stroke="currentColor" aria-hidden="true" data-slot="icon" class="w-5 h-5">
                                                     <path stroke-linecap="round" stroke-linejoin="round" d="M13.19 8.688a4.5 4.5 0 0 1 1.242 7.244l-4.5 4.5a4.5 4.5 0 0 1-6.364-6.364l1.757-1.757m13.35-.622 1.757-1.757a4.5 4.5 0 0 0-6.364-6.364l-4.5 4.5a4.5 4.5 0 0 0 1.242 7.244"></path>
                                                 </svg>
-                                                <span class="hidden md:block">{"Locations"}</span>
+                                                <span class="hidden md:block">{ SECTION_LOCATIONS }</span>
                                             </a>
                                         </li>
-                                        <li tabindex="0" aria-label="Contributors" role="link">
+                                        <li tabindex="0" aria-label={ SECTION_CONTRIBUTORS.as_str() } role="link">
                                             <a class="flex flex-col items-center w-16 md:w-20 lg:w-24 xl:w-32 text-center text-xs text-primary-700 dark:text-gray-400 hover:text-primary-400
                                             dark:hover:text-primary-400 px-4 py-3 border-b-2 border-transparent hover:border-primary-600 dark:hover:border-primary-400 cursor-pointer group hover:no-underline"
                                             href="#contributors"><svg xmlns="http://www.w3.org/2000/svg" fill="none" viewBox="0 0 24 24" stroke-width="1.5" stroke="currentColor" aria-hidden="true" data-slot="icon" class="w-5 h-5">
                                                     <path stroke-linecap="round" stroke-linejoin="round" d="M18 18.72a9.094 9.094 0 0 0 3.741-.479 3 3 0 0 0-4.682-2.72m.94 3.198.001.031c0 .225-.012.447-.037.666A11.944 11.944 0 0 1 12 21c-2.17 0-4.207-.576-5.963-1.584A6.062 6.062 0 0 1 6 18.719m12 0a5.971 5.971 0 0 0-.941-3.197m0 0A5.995 5.995 0 0 0 12 12.75a5.995 5.995 0 0 0-5.058 2.772m0 0a3 3 0 0 0-4.681 2.72 8.986 8.986 0 0 0 3.74.477m.94-3.197a5.971 5.971 0 0 0-.94 3.197M15 6.75a3 3 0 1 1-6 0 3 3 0 0 1 6 0Zm6 3a2.25 2.25 0 1 1-4.5 0 2.25 2.25 0 0 1 4.5 0Zm-13.5 0a2.25 2.25 0 1 1-4.5 0 2.25 2.25 0 0 1 4.5 0Z"></path>
                                                 </svg>
-                                                <span class="hidden md:block">{"Contributors"}</span>
+                                                <span class="hidden md:block">{ SECTION_CONTRIBUTORS }</span>
                                             </a>
                                         </li>
                                     </ul>
                                 </nav>
 
                                 <div id="metadata" class="bg-ternary-200 dark:bg-gray-800 py-6 container overflow-x-auto">
-                                    <table class="table-fixed border-separate border-spacing-2 -ml-2" aria-label="Metadata fields">
-                                        <caption class="text-left font-semibold text-header pl-2 py-4"> { "Metadata" } </caption>
+                                    <table class="table-fixed border-separate border-spacing-2 -ml-2" aria-label={ METADATA_FIELDS.as_str() }>
+                                        <caption class="text-left font-semibold text-header pl-2 py-4"> { SECTION_METADATA } </caption>
                                         <tbody>
                                             <tr>
-                                                <th class="font-semibold align-top w-40 text-left">{"Title"}</th>
+                                                <th class="font-semibold align-top w-40 text-left">{ FIELD_TITLE }</th>
                                                 <td>{self.book.compile_fulltitle()}</td>
                                             </tr>
                                             <tr>
-                                                <th class="font-semibold align-top text-left">{"Contributor"}</th>
+                                                <th class="font-semibold align-top text-left">{ FIELD_CONTRIBUTOR }</th>
                                                 <td>{contributors_text.clone()} </td>
                                             </tr>
                                             <tr>
-                                                <th class="font-semibold align-top text-left">{"DOI"}</th>
+                                                <th class="font-semibold align-top text-left">{ FIELD_DOI }</th>
                                                 <td> <a href = { doi_url.clone() }> { doi_url } </a> </td>
                                             </tr>
                                             <tr>
-                                                <th class="font-semibold align-top text-left">{"Landing page"}</th>
+                                                <th class="font-semibold align-top text-left">{ FIELD_LANDING_PAGE }</th>
                                                 <td> <a href = { landing_page.clone() } target="_blank"> { landing_page } </a> </td>
                                             </tr>
                                             <tr>
-                                                <th class="font-semibold align-top text-left">{"License"}</th>
-                                                <td> <a href = { license.clone() } target="_blank"> { license } </a>  </td>
+                                                <th class="font-semibold align-top text-left">{ FIELD_LICENSE }</th>
+                                                <td> { license } </td>
                                             </tr>
                                             <tr>
-                                                <th class="font-semibold align-top text-left">{"Copyright"}</th>
+                                                <th class="font-semibold align-top text-left">{ FIELD_COPYRIGHT }</th>
                                                 <td> { copyright_holder } </td>
                                             </tr>
                                             <tr>
-                                                <th class="font-semibold align-top text-left">{"Publisher"}</th>
+                                                <th class="font-semibold align-top text-left">{ FIELD_PUBLISHER }</th>
                                                 <td> { self.book.publisher() } </td>
                                             </tr>
                                             <tr>
-                                                <th class="font-semibold align-top text-left">{"Publication place"} </th>
+                                                <th class="font-semibold align-top text-left">{ FIELD_PUBLICATION_PLACE } </th>
                                                 <td> { place_of_publication } </td>
                                             </tr>
                                             <tr>
-                                                <th class="font-semibold align-top text-left">{"Published on"}</th>
+                                                <th class="font-semibold align-top text-left">{ FIELD_PUBLISHED_ON }</th>
                                                 <td>{ publication_date }</td>
                                             </tr>
                                             <tr>
-                                                <th class="font-semibold align-top text-left" rowspan = { publications_count } >{"ISBN"}</th>
+                                                <th class="font-semibold align-top text-left" rowspan = { publications_count } >{ FIELD_ISBN }</th>
                                                 { publications_html }
                                             </tr>
 
                                             <tr>
-                                                <th class="font-semibold align-top text-left">{"Long abstract"} </th>
+                                                <th class="font-semibold align-top text-left">{ FIELD_LONG_ABSTRACT } </th>
                                                 <td> { long_abstract } </td>
                                             </tr>
                                             <tr>
-                                                <th class="font-semibold align-top text-left">{"Print length"}</th>
+                                                <th class="font-semibold align-top text-left">{ FIELD_PRINT_LENGTH }</th>
                                                 <td>{ page_count } </td>
                                             </tr>
                                             <tr>
-                                                <th class="font-semibold} align-top text-left" rowspan = { languages_count }>{"Language"}</th>
+                                                <th class="font-semibold} align-top text-left" rowspan = { languages_count }>{ FIELD_LANGUAGE }</th>
                                                 { languages_html }
                                             </tr>
                                             <tr>
-                                                <th class="font-semibold align-top text-left">{"Dimensions"}</th>
+                                                <th class="font-semibold align-top text-left">{ FIELD_DIMENSIONS }</th>
                                                 // <td class="pr-4">{"127 x 203 mm | 5 x 8 (Paperback)"}</td>
                                             </tr>
                                             <tr>
-                                                <th class="font-semibold align-top text-left">{"LCCN"}</th>
+                                                <th class="font-semibold align-top text-left">{ FIELD_LCCN }</th>
                                                 <td> { lccn } </td>
                                             </tr>
                                             <tr>
@@ -525,11 +1533,11 @@ impl Component for BookDetailComponent {
                                                 <td> { bisac } </td>
                                             </tr>
                                             <tr>
-                                                <th class="font-semibold align-top text-left">{"Keywords"}</th>
+                                                <th class="font-semibold align-top text-left">{ FIELD_KEYWORDS }</th>
                                                 <td> { keywords } </td>
                                             </tr>
                                             <tr>
-                                                <th class="font-semibold align-top text-left" rowspan = { fundings_count }>{"Funding"}</th>
+                                                <th class="font-semibold align-top text-left" rowspan = { fundings_count }>{ FIELD_FUNDING }</th>
                                                 { fundings_html }
                                             </tr>
                                         </tbody>
@@ -537,14 +1545,16 @@ impl Component for BookDetailComponent {
                                 </div>
 
                                 <div id="locations" class="bg-ternary-200 dark:bg-gray-800 container py-4 my-2">
-                                    <table class="table-fixed border-separate border-spacing-4 lg:border-spacing-2 -mt-4 -ml-4 lg:-mt-2 lg:-ml-2" aria-label="Locations by platform and publication type">
-                                        <caption class="text-left font-semibold text-header pl-2 py-5"> {"Locations"} </caption>
+                                    <table class="table-fixed border-separate border-spacing-4 lg:border-spacing-2 -mt-4 -ml-4 lg:-mt-2 lg:-ml-2" aria-label={ LOCATIONS_TABLE.as_str() }>
+                                        <caption class="text-left font-semibold text-header pl-2 py-5"> { SECTION_LOCATIONS } </caption>
 
                                     </table>
                                 </div>
 
+                                { self.metadata_health() }
+
                                 <div class="bg-ternary-200 dark:bg-gray-800 container py-4 my-2" id="contributors">
-                                    <div class="py-4 font-semibold text-header"> {"Contributors"} </div>
+                                    <div class="py-4 font-semibold text-header"> { SECTION_CONTRIBUTORS } </div>
                                     <div class="grid gap-6 divide-y divide-ternary-400">
                                         { contributors }
                                     </div>
@@ -555,9 +1565,6 @@ impl Component for BookDetailComponent {
                     </div>
                 }
             }
-            FetchState::Failed(_, err) => html! {
-                { ThothError::from(err).to_string() }
-            },
         }
     }
 }
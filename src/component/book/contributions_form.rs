@@ -0,0 +1,637 @@
+use gloo_timers::callback::Timeout;
+use std::str::FromStr;
+use uuid::Uuid;
+use yew::html;
+use yew::prelude::*;
+use yew_agent::Dispatched;
+use yewtil::fetch::{Fetch, FetchAction, FetchState};
+use yewtil::NeqAssign;
+
+use crate::agent::entity_cache::{EntityCacheAgent, EntityCacheRequest};
+use crate::agent::session::expire_on_invalid_token;
+use crate::component::utils::{
+    FormBooleanSelect, FormContributionTypeSelect, FormContributorSelect, FormInput,
+};
+use crate::component::{Reorder, ReorderStep, ToElementValue, ToOption};
+use crate::config::config;
+use crate::models::contribution::create_contribution_mutation::{
+    CreateContributionRequest, CreateContributionRequestBody, PushActionCreateContribution,
+    PushCreateContribution, Variables as CreateVariables,
+};
+use crate::models::contribution::delete_contribution_mutation::{
+    DeleteContributionRequest, DeleteContributionRequestBody, PushActionDeleteContribution,
+    PushDeleteContribution, Variables as DeleteVariables,
+};
+use crate::models::contribution::update_contribution_mutation::{
+    PushActionUpdateContribution, PushUpdateContribution, UpdateContributionRequest,
+    UpdateContributionRequestBody,
+};
+use crate::models::contributor::contributors_query::{
+    ContributorsRequest, ContributorsRequestBody, FetchActionContributors, FetchContributors,
+    Variables as ContributorsVariables,
+};
+use crate::models::utils::{Contribution, ContributionType, ContributionTypeValues};
+use crate::models::{EnumValues, MutationResult};
+use crate::string::{
+    ADD_CONTRIBUTION, CANCEL_BUTTON, CONTRIBUTIONS_INFO, FIELD_BIOGRAPHY, FIELD_CONTRIBUTION_TYPE,
+    FIELD_CONTRIBUTOR, FIELD_MAIN, REMOVE_BUTTON, SEARCH_CONTRIBUTORS, SECTION_CONTRIBUTIONS,
+};
+
+pub struct ContributionsFormComponent {
+    // Local copy of the contributions, so that free text fields can be edited before saving
+    contributions: Vec<Contribution>,
+    new_contribution: Contribution,
+    show_add_form: bool,
+    contributor_search: String,
+    debounce_timeout: Option<Timeout>,
+    fetch_contributors: FetchContributors,
+    push_contribution: PushCreateContribution,
+    delete_contribution: PushDeleteContribution,
+    dragged: Option<usize>,
+    reorder: Option<Reorder<Contribution>>,
+    error: Option<String>,
+}
+
+pub enum Msg {
+    ToggleAddForm,
+    SearchContributor(String),
+    GetContributors,
+    SetContributorsFetchState(FetchActionContributors),
+    ChangeContributor(String),
+    ChangeNewContributionType(ContributionType),
+    ChangeNewMainContribution(bool),
+    CreateContribution,
+    SetContributionPushState(PushActionCreateContribution),
+    DeleteContribution(Uuid),
+    SetContributionDeleteState(PushActionDeleteContribution),
+    ChangeContributionType(Uuid, ContributionType),
+    ChangeMainContribution(Uuid, bool),
+    ChangeBiography(Uuid, String),
+    SaveContribution(Uuid),
+    SetContributionUpdateState(PushActionUpdateContribution),
+    DragStart(usize),
+    Drop(usize),
+    SetTemporaryOrdinalState(PushActionUpdateContribution),
+    SetFinalOrdinalState(PushActionUpdateContribution),
+}
+
+#[derive(PartialEq, Properties)]
+pub struct Props {
+    pub contributions: Option<Vec<Contribution>>,
+    pub contribution_types: Vec<ContributionTypeValues>,
+    pub work_id: Uuid,
+    pub update_contributions: Callback<Option<Vec<Contribution>>>,
+}
+
+impl ContributionsFormComponent {
+    fn sorted(contributions: &Option<Vec<Contribution>>) -> Vec<Contribution> {
+        let mut contributions = contributions.clone().unwrap_or_default();
+        contributions.sort_by_key(|c| c.contribution_ordinal);
+        contributions
+    }
+
+    fn next_ordinal(&self) -> i32 {
+        self.contributions
+            .iter()
+            .map(|c| c.contribution_ordinal)
+            .max()
+            .unwrap_or_default()
+            + 1
+    }
+
+    fn emit(&mut self, ctx: &Context<Self>) {
+        self.contributions.sort_by_key(|c| c.contribution_ordinal);
+        ctx.props()
+            .update_contributions
+            .emit(Some(self.contributions.clone()));
+    }
+
+    fn push_update(
+        ctx: &Context<Self>,
+        contribution: &Contribution,
+        to_msg: fn(PushActionUpdateContribution) -> Msg,
+    ) {
+        let body = UpdateContributionRequestBody {
+            variables: contribution.into(),
+            ..Default::default()
+        };
+        let push: PushUpdateContribution = Fetch::new(UpdateContributionRequest { body });
+        ctx.link().send_future(push.fetch(to_msg));
+    }
+
+    fn save(&self, ctx: &Context<Self>, contribution_id: Uuid) {
+        if let Some(contribution) = self
+            .contributions
+            .iter()
+            .find(|c| c.contribution_id == contribution_id)
+        {
+            Self::push_update(ctx, contribution, Msg::SetContributionUpdateState);
+        }
+    }
+
+    /// Read the outcome of a request whose state is not otherwise kept by the component
+    fn update_result(action: PushActionUpdateContribution) -> Option<Result<Contribution, String>> {
+        let mut push = PushUpdateContribution::default();
+        push.apply(action);
+        if let FetchState::Failed(_, err) = push.as_ref().state() {
+            expire_on_invalid_token(&err);
+        }
+        push.result().map(|r| r.map_err(|e| e.to_string()))
+    }
+
+    /// Save whatever the reordering in progress needs saved next
+    fn reorder_step(&mut self, ctx: &Context<Self>, step: ReorderStep<Contribution>) -> bool {
+        match step {
+            ReorderStep::Park(parked) => {
+                Self::push_update(ctx, &parked, Msg::SetTemporaryOrdinalState);
+                false
+            }
+            ReorderStep::Finish(moved) => {
+                for c in moved.iter() {
+                    Self::push_update(ctx, c, Msg::SetFinalOrdinalState);
+                }
+                false
+            }
+            ReorderStep::RollBack(parked, e) => {
+                self.reorder = None;
+                self.error = Some(e);
+                self.contributions = Self::sorted(&ctx.props().contributions);
+                for c in parked.iter() {
+                    Self::push_update(ctx, c, Msg::SetContributionUpdateState);
+                }
+                true
+            }
+            ReorderStep::Wait => false,
+            ReorderStep::Done => {
+                self.reorder = None;
+                self.error = None;
+                self.emit(ctx);
+                true
+            }
+        }
+    }
+
+    #[allow(clippy::unnecessary_operation)]
+    fn render_contribution(&self, ctx: &Context<Self>, index: usize, c: &Contribution) -> Html {
+        let contribution_id = c.contribution_id;
+        let is_dragged = self.dragged == Some(index);
+        html! {
+            <div
+                class={ if is_dragged { "box mb-2 has-background-light" } else { "box mb-2" } }
+                draggable="true"
+                ondragstart={ ctx.link().callback(move |_: DragEvent| Msg::DragStart(index)) }
+                // Elements only accept drops if the default dragover handling is prevented
+                ondragover={ Callback::from(|e: DragEvent| e.prevent_default()) }
+                ondrop={ ctx.link().callback(move |e: DragEvent| {
+                    e.prevent_default();
+                    Msg::Drop(index)
+                }) }
+            >
+                <div class="columns is-vcentered">
+                    <div class="column is-narrow">
+                        <span class="icon is-medium" style="cursor: grab">
+                            <i class="fas fa-grip-vertical"></i>
+                        </span>
+                    </div>
+                    <div class="column">
+                        <p class="has-text-weight-semibold">{ &c.full_name }</p>
+                        <FormContributionTypeSelect
+                            label={ FIELD_CONTRIBUTION_TYPE.as_str() }
+                            value={ c.contribution_type }
+                            data={ ContributionTypeValues::to_options(&ctx.props().contribution_types) }
+                            onchange={ ctx.link().callback(move |t| Msg::ChangeContributionType(contribution_id, t)) }
+                            required = true
+                        />
+                        <FormBooleanSelect
+                            label={ FIELD_MAIN.as_str() }
+                            value={ c.main_contribution }
+                            onchange={ ctx.link().callback(move |e: Event| {
+                                Msg::ChangeMainContribution(contribution_id, e.to_value() == "true")
+                            }) }
+                        />
+                    </div>
+                    <div class="column is-half">
+                        <div class="field">
+                            <label class="label">{ FIELD_BIOGRAPHY }</label>
+                            <div class="control">
+                                <textarea
+                                    class="textarea"
+                                    value={ c.biography.clone().unwrap_or_default() }
+                                    oninput={ ctx.link().callback(move |e: InputEvent| Msg::ChangeBiography(contribution_id, e.to_value())) }
+                                    onblur={ ctx.link().callback(move |_| Msg::SaveContribution(contribution_id)) }
+                                />
+                            </div>
+                        </div>
+                    </div>
+                    <div class="column is-narrow">
+                        <button
+                            class="button is-danger is-outlined is-small"
+                            type="button"
+                            onclick={ ctx.link().callback(move |_| Msg::DeleteContribution(contribution_id)) }
+                        >
+                            { REMOVE_BUTTON }
+                        </button>
+                    </div>
+                </div>
+            </div>
+        }
+    }
+
+    #[allow(clippy::unnecessary_operation)]
+    fn render_add_form(&self, ctx: &Context<Self>) -> Html {
+        let contributors = match self.fetch_contributors.as_ref().state() {
+            FetchState::Fetched(body) => body.data.contributors.clone(),
+            _ => Default::default(),
+        };
+        html! {
+            <div class="box">
+                <FormInput
+                    label={ SEARCH_CONTRIBUTORS.as_str() }
+                    value={ self.contributor_search.clone() }
+                    input_type="search"
+                    oninput={ ctx.link().callback(|e: InputEvent| Msg::SearchContributor(e.to_value())) }
+                />
+                <FormContributorSelect
+                    label={ FIELD_CONTRIBUTOR.as_str() }
+                    value={ self.new_contribution.contributor_id }
+                    data={ contributors }
+                    onchange={ ctx.link().callback(|e: Event| Msg::ChangeContributor(e.to_value())) }
+                    required = true
+                />
+                <FormContributionTypeSelect
+                    label={ FIELD_CONTRIBUTION_TYPE.as_str() }
+                    value={ self.new_contribution.contribution_type }
+                    data={ ContributionTypeValues::to_options(&ctx.props().contribution_types) }
+                    onchange={ ctx.link().callback(Msg::ChangeNewContributionType) }
+                    required = true
+                />
+                <FormBooleanSelect
+                    label={ FIELD_MAIN.as_str() }
+                    value={ self.new_contribution.main_contribution }
+                    onchange={ ctx.link().callback(|e: Event| Msg::ChangeNewMainContribution(e.to_value() == "true")) }
+                />
+                <div class="field is-grouped">
+                    <div class="control">
+                        <button
+                            class="button is-success"
+                            type="button"
+                            disabled={ self.new_contribution.contributor_id.is_nil() }
+                            onclick={ ctx.link().callback(|_| Msg::CreateContribution) }
+                        >
+                            { ADD_CONTRIBUTION }
+                        </button>
+                    </div>
+                    <div class="control">
+                        <button class="button is-light" type="button" onclick={ ctx.link().callback(|_| Msg::ToggleAddForm) }>
+                            { CANCEL_BUTTON }
+                        </button>
+                    </div>
+                </div>
+            </div>
+        }
+    }
+}
+
+impl Component for ContributionsFormComponent {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        ContributionsFormComponent {
+            contributions: Self::sorted(&ctx.props().contributions),
+            new_contribution: Default::default(),
+            show_add_form: false,
+            contributor_search: Default::default(),
+            debounce_timeout: None,
+            fetch_contributors: Default::default(),
+            push_contribution: Default::default(),
+            delete_contribution: Default::default(),
+            dragged: None,
+            reorder: None,
+            error: None,
+        }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::ToggleAddForm => {
+                self.show_add_form = !self.show_add_form;
+                if self.show_add_form {
+                    self.new_contribution = Contribution {
+                        main_contribution: true,
+                        ..Default::default()
+                    };
+                    ctx.link().send_message(Msg::GetContributors);
+                }
+                true
+            }
+            Msg::SearchContributor(query) => {
+                self.contributor_search = query;
+                // cancel previous timeout
+                self.debounce_timeout = self.debounce_timeout.take().and_then(|timeout| {
+                    timeout.cancel();
+                    None
+                });
+                // start new timeout
+                let link = ctx.link().clone();
+                let timeout = Timeout::new(config().debounce_timeout, move || {
+                    link.send_message(Msg::GetContributors);
+                });
+                self.debounce_timeout = Some(timeout);
+                true
+            }
+            Msg::GetContributors => {
+                let body = ContributorsRequestBody {
+                    variables: ContributorsVariables {
+                        limit: Some(50),
+                        filter: self.contributor_search.clone().to_opt_string(),
+                    },
+                    ..Default::default()
+                };
+                self.fetch_contributors = Fetch::new(ContributorsRequest { body });
+                ctx.link().send_future(
+                    self.fetch_contributors
+                        .fetch(Msg::SetContributorsFetchState),
+                );
+                ctx.link()
+                    .send_message(Msg::SetContributorsFetchState(FetchAction::Fetching));
+                false
+            }
+            Msg::SetContributorsFetchState(fetch_state) => {
+                self.fetch_contributors.apply(fetch_state);
+                if let FetchState::Fetched(body) = self.fetch_contributors.as_ref().state() {
+                    EntityCacheAgent::dispatcher().send(EntityCacheRequest::StoreContributors(
+                        body.data.contributors.clone(),
+                    ));
+                }
+                true
+            }
+            Msg::ChangeContributor(contributor_id) => {
+                let contributor = match self.fetch_contributors.as_ref().state() {
+                    FetchState::Fetched(body) => {
+                        Uuid::from_str(&contributor_id).ok().and_then(|id| {
+                            body.data
+                                .contributors
+                                .iter()
+                                .find(|c| c.contributor_id == id)
+                                .cloned()
+                        })
+                    }
+                    _ => None,
+                };
+                match contributor {
+                    Some(contributor) => {
+                        // Names are copied onto the contribution, as they may differ between works
+                        self.new_contribution.contributor_id = contributor.contributor_id;
+                        self.new_contribution.first_name = contributor.first_name.clone();
+                        self.new_contribution.last_name = contributor.last_name.clone();
+                        self.new_contribution.full_name = contributor.full_name.clone();
+                        self.new_contribution.contributor = contributor;
+                    }
+                    None => self.new_contribution.contributor_id = Uuid::nil(),
+                }
+                true
+            }
+            Msg::ChangeNewContributionType(contribution_type) => self
+                .new_contribution
+                .contribution_type
+                .neq_assign(contribution_type),
+            Msg::ChangeNewMainContribution(main) => {
+                self.new_contribution.main_contribution.neq_assign(main)
+            }
+            Msg::CreateContribution => {
+                let body = CreateContributionRequestBody {
+                    variables: CreateVariables {
+                        work_id: ctx.props().work_id,
+                        contributor_id: self.new_contribution.contributor_id,
+                        contribution_type: self.new_contribution.contribution_type,
+                        main_contribution: self.new_contribution.main_contribution,
+                        biography: None,
+                        first_name: self.new_contribution.first_name.clone(),
+                        last_name: self.new_contribution.last_name.clone(),
+                        full_name: self.new_contribution.full_name.clone(),
+                        contribution_ordinal: self.next_ordinal(),
+                    },
+                    ..Default::default()
+                };
+                self.push_contribution = Fetch::new(CreateContributionRequest { body });
+                ctx.link()
+                    .send_future(self.push_contribution.fetch(Msg::SetContributionPushState));
+                ctx.link()
+                    .send_message(Msg::SetContributionPushState(FetchAction::Fetching));
+                false
+            }
+            Msg::SetContributionPushState(fetch_state) => {
+                self.push_contribution.apply(fetch_state);
+                if let FetchState::Failed(_, err) = self.push_contribution.as_ref().state() {
+                    expire_on_invalid_token(&err);
+                }
+                match self.push_contribution.result() {
+                    None => false,
+                    Some(Ok(contribution)) => {
+                        self.contributions.push(contribution);
+                        self.show_add_form = false;
+                        self.error = None;
+                        self.emit(ctx);
+                        true
+                    }
+                    Some(Err(e)) => {
+                        self.error = Some(e.to_string());
+                        true
+                    }
+                }
+            }
+            Msg::DeleteContribution(contribution_id) => {
+                let body = DeleteContributionRequestBody {
+                    variables: DeleteVariables { contribution_id },
+                    ..Default::default()
+                };
+                self.delete_contribution = Fetch::new(DeleteContributionRequest { body });
+                ctx.link().send_future(
+                    self.delete_contribution
+                        .fetch(Msg::SetContributionDeleteState),
+                );
+                ctx.link()
+                    .send_message(Msg::SetContributionDeleteState(FetchAction::Fetching));
+                false
+            }
+            Msg::SetContributionDeleteState(fetch_state) => {
+                self.delete_contribution.apply(fetch_state);
+                if let FetchState::Failed(_, err) = self.delete_contribution.as_ref().state() {
+                    expire_on_invalid_token(&err);
+                }
+                match self.delete_contribution.result() {
+                    None => false,
+                    Some(Ok(deleted)) => {
+                        self.contributions
+                            .retain(|c| c.contribution_id != deleted.contribution_id);
+                        self.error = None;
+                        self.emit(ctx);
+                        true
+                    }
+                    Some(Err(e)) => {
+                        self.error = Some(e.to_string());
+                        true
+                    }
+                }
+            }
+            Msg::ChangeContributionType(contribution_id, contribution_type) => {
+                if let Some(c) = self
+                    .contributions
+                    .iter_mut()
+                    .find(|c| c.contribution_id == contribution_id)
+                {
+                    if c.contribution_type.neq_assign(contribution_type) {
+                        self.save(ctx, contribution_id);
+                    }
+                }
+                false
+            }
+            Msg::ChangeMainContribution(contribution_id, main) => {
+                if let Some(c) = self
+                    .contributions
+                    .iter_mut()
+                    .find(|c| c.contribution_id == contribution_id)
+                {
+                    if c.main_contribution.neq_assign(main) {
+                        self.save(ctx, contribution_id);
+                    }
+                }
+                false
+            }
+            Msg::ChangeBiography(contribution_id, biography) => {
+                // Free text is only saved once the user leaves the field
+                match self
+                    .contributions
+                    .iter_mut()
+                    .find(|c| c.contribution_id == contribution_id)
+                {
+                    Some(c) => c.biography.neq_assign(biography.to_opt_string()),
+                    None => false,
+                }
+            }
+            Msg::SaveContribution(contribution_id) => {
+                let saved = Self::sorted(&ctx.props().contributions);
+                let changed = self
+                    .contributions
+                    .iter()
+                    .find(|c| c.contribution_id == contribution_id)
+                    != saved.iter().find(|c| c.contribution_id == contribution_id);
+                if changed {
+                    self.save(ctx, contribution_id);
+                }
+                false
+            }
+            Msg::SetContributionUpdateState(action) => match Self::update_result(action) {
+                None => false,
+                Some(Ok(contribution)) => {
+                    if let Some(c) = self
+                        .contributions
+                        .iter_mut()
+                        .find(|c| c.contribution_id == contribution.contribution_id)
+                    {
+                        *c = contribution;
+                    }
+                    self.error = None;
+                    self.emit(ctx);
+                    true
+                }
+                Some(Err(e)) => {
+                    self.error = Some(e);
+                    true
+                }
+            },
+            Msg::DragStart(index) => {
+                self.dragged = Some(index);
+                true
+            }
+            Msg::Drop(target) => {
+                let source = match self.dragged.take() {
+                    Some(source) if source != target && self.reorder.is_none() => source,
+                    _ => return true,
+                };
+                let mut contributions = self.contributions.clone();
+                if let Some((reorder, parked)) = Reorder::start(
+                    &mut contributions,
+                    source,
+                    target,
+                    |c| c.contribution_id,
+                    |c| &mut c.contribution_ordinal,
+                ) {
+                    Self::push_update(ctx, &parked, Msg::SetTemporaryOrdinalState);
+                    self.reorder = Some(reorder);
+                    self.contributions = contributions;
+                }
+                true
+            }
+            Msg::SetTemporaryOrdinalState(action) => {
+                let step = match (Self::update_result(action), self.reorder.as_mut()) {
+                    (Some(result), Some(reorder)) => reorder.parked(result.map(|_| ())),
+                    _ => return false,
+                };
+                self.reorder_step(ctx, step)
+            }
+            Msg::SetFinalOrdinalState(action) => {
+                let step = match (Self::update_result(action), self.reorder.as_mut()) {
+                    (Some(result), Some(reorder)) => {
+                        if let Ok(contribution) = &result {
+                            if let Some(c) = self
+                                .contributions
+                                .iter_mut()
+                                .find(|c| c.contribution_id == contribution.contribution_id)
+                            {
+                                *c = contribution.clone();
+                            }
+                        }
+                        reorder.finished(result.map(|c| c.contribution_id))
+                    }
+                    _ => return false,
+                };
+                self.reorder_step(ctx, step)
+            }
+        }
+    }
+
+    fn changed(&mut self, ctx: &Context<Self>) -> bool {
+        // Keep any reordering in progress rather than jumping back to the previous order
+        if self.reorder.is_none() {
+            self.contributions = Self::sorted(&ctx.props().contributions);
+        }
+        true
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        html! {
+            <div id="edit-contributions" class="my-4">
+                <div class="py-4 font-semibold text-header">{ SECTION_CONTRIBUTIONS }</div>
+                <p class="help mb-2">{ CONTRIBUTIONS_INFO }</p>
+                {
+                    match &self.error {
+                        Some(error) => html! {
+                            <div class="notification is-danger is-light py-2">{ error }</div>
+                        },
+                        None => html! {},
+                    }
+                }
+                {
+                    for self
+                        .contributions
+                        .iter()
+                        .enumerate()
+                        .map(|(i, c)| self.render_contribution(ctx, i, c))
+                }
+                {
+                    if self.show_add_form {
+                        self.render_add_form(ctx)
+                    } else {
+                        html! {
+                            <button class="button is-small" type="button" onclick={ ctx.link().callback(|_| Msg::ToggleAddForm) }>
+                                { ADD_CONTRIBUTION }
+                            </button>
+                        }
+                    }
+                }
+            </div>
+        }
+    }
+}
@@ -1,2 +1,8 @@
+#[allow(clippy::module_inception)]
 pub mod book;
+pub mod contributions_form;
 pub mod navbar;
+pub mod new_book;
+pub mod publications_form;
+pub mod relations_form;
+pub mod subjects_form;
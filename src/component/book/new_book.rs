@@ -0,0 +1,1320 @@
+use gloo_timers::callback::Timeout;
+use std::collections::VecDeque;
+use std::str::FromStr;
+use uuid::Uuid;
+use yew::html;
+use yew::prelude::*;
+use yew_router::prelude::*;
+use yewtil::fetch::{Fetch, FetchAction, FetchState};
+use yewtil::NeqAssign;
+
+use crate::agent::session::expire_on_invalid_token;
+use crate::component::utils::{
+    FormBooleanSelect, FormContributionTypeSelect, FormContributorSelect, FormDateInput,
+    FormFloatInput, FormImprintSelect, FormInput, FormLanguageCodeSelect,
+    FormLanguageRelationSelect, FormNumberInput, FormPublicationTypeSelect, FormSubjectTypeSelect,
+    FormTextInput, FormTextInputExtended, FormTextarea, FormUrlInput, FormWorkStatusSelect,
+    FormWorkTypeSelect, Loader,
+};
+use crate::component::{ToElementValue, ToOption};
+use crate::config::config;
+use crate::i18n::Message;
+use crate::models::book::create_work_mutation::{
+    CreateWorkRequest, CreateWorkRequestBody, PushActionCreateWork, PushCreateWork,
+};
+use crate::models::book::delete_work_mutation::{
+    DeleteWorkRequest, DeleteWorkRequestBody, PushActionDeleteWork, PushDeleteWork,
+    Variables as DeleteWorkVariables,
+};
+use crate::models::book::new_work_query::{
+    FetchActionNewWork, FetchNewWork, NewWorkRequest, NewWorkRequestBody, NewWorkResponseData,
+    Variables as NewWorkVariables,
+};
+use crate::models::contribution::create_contribution_mutation::{
+    CreateContributionRequest, CreateContributionRequestBody, PushActionCreateContribution,
+    PushCreateContribution, Variables as CreateContributionVariables,
+};
+use crate::models::contributor::contributors_query::{
+    ContributorsRequest, ContributorsRequestBody, FetchActionContributors, FetchContributors,
+    Variables as ContributorsVariables,
+};
+use crate::models::language::create_language_mutation::{
+    CreateLanguageRequest, CreateLanguageRequestBody, PushActionCreateLanguage, PushCreateLanguage,
+    Variables as CreateLanguageVariables,
+};
+use crate::models::publication::create_publication_mutation::{
+    CreatePublicationRequest, CreatePublicationRequestBody, PushActionCreatePublication,
+    PushCreatePublication, Variables as CreatePublicationVariables,
+};
+use crate::models::publication::{Dimension, Units};
+use crate::models::subject::create_subject_mutation::{
+    CreateSubjectRequest, CreateSubjectRequestBody, PushActionCreateSubject, PushCreateSubject,
+    Variables as CreateSubjectVariables,
+};
+use crate::models::utils::{
+    Contribution, ContributionType, ContributionTypeValues, Doi, ImprintWithPublisher, Isbn,
+    Language, LanguageCode, LanguageCodeValues, LanguageRelation, LanguageRelationValues,
+    Publication, PublicationType, PublicationTypeValues, Subject, SubjectType, SubjectTypeValues,
+    ThothError, ThothResult, WorkStatus, WorkStatusValues, WorkType, WorkTypeValues,
+    WorkWithRelations, DOI_DOMAIN,
+};
+use crate::models::validation::{
+    validate_publication_details, validate_subject_code, validate_withdrawn_date,
+};
+use crate::models::{Dropdown, EnumValues, MutationResult};
+use crate::route::AppRoute;
+use crate::service::account::AccountService;
+use crate::service::cache::QueryCache;
+use crate::string::{
+    ADD_CONTRIBUTION, ADD_LANGUAGE, ADD_PUBLICATION, ADD_SUBJECT, CREATE_WORK_BUTTON,
+    CREATE_WORK_FAILED, FIELD_CONTRIBUTION_TYPE, FIELD_CONTRIBUTOR, FIELD_DEPTH, FIELD_DOI,
+    FIELD_EDITION, FIELD_HEIGHT, FIELD_IMPRINT, FIELD_ISBN, FIELD_LANDING_PAGE, FIELD_LANGUAGE,
+    FIELD_LANGUAGE_RELATION, FIELD_LICENSE, FIELD_LONG_ABSTRACT, FIELD_MAIN, FIELD_PLACE,
+    FIELD_PUBLICATION_DATE, FIELD_PUBLICATION_TYPE, FIELD_SUBJECT_CODE, FIELD_SUBJECT_TYPE,
+    FIELD_SUBTITLE, FIELD_TITLE, FIELD_WEIGHT, FIELD_WIDTH, FIELD_WITHDRAWN_DATE,
+    FIELD_WORK_STATUS, FIELD_WORK_TYPE, IMPRINT_REQUIRED, NEW_WORK_TITLE, NEXT_STEP_BUTTON,
+    PREVIOUS_STEP_BUTTON, REMOVE_BUTTON, SEARCH_CONTRIBUTORS, STEP_CONTRIBUTIONS, STEP_LANGUAGES,
+    STEP_METADATA, STEP_PUBLICATIONS, STEP_SUBJECTS, STEP_TYPE_AND_IMPRINT, SUBJECT_CODE_SEARCH,
+    TITLE_REQUIRED,
+};
+
+// Maximum number of code list entries offered while typing a subject code
+const MAX_SUGGESTIONS: usize = 10;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WizardStep {
+    TypeAndImprint,
+    Metadata,
+    Contributions,
+    Publications,
+    Languages,
+    Subjects,
+}
+
+impl WizardStep {
+    const ALL: [WizardStep; 6] = [
+        WizardStep::TypeAndImprint,
+        WizardStep::Metadata,
+        WizardStep::Contributions,
+        WizardStep::Publications,
+        WizardStep::Languages,
+        WizardStep::Subjects,
+    ];
+
+    fn index(&self) -> usize {
+        Self::ALL.iter().position(|s| s == self).unwrap_or_default()
+    }
+
+    fn next(&self) -> Option<WizardStep> {
+        Self::ALL.get(self.index() + 1).copied()
+    }
+
+    fn previous(&self) -> Option<WizardStep> {
+        self.index().checked_sub(1).map(|i| Self::ALL[i])
+    }
+
+    fn title(&self) -> Message {
+        match self {
+            WizardStep::TypeAndImprint => STEP_TYPE_AND_IMPRINT,
+            WizardStep::Metadata => STEP_METADATA,
+            WizardStep::Contributions => STEP_CONTRIBUTIONS,
+            WizardStep::Publications => STEP_PUBLICATIONS,
+            WizardStep::Languages => STEP_LANGUAGES,
+            WizardStep::Subjects => STEP_SUBJECTS,
+        }
+    }
+}
+
+/// A mutation still to be sent once the work itself has been created
+enum PendingMutation {
+    Contribution(CreateContributionVariables),
+    Publication(CreatePublicationVariables),
+    Language(CreateLanguageVariables),
+    Subject(CreateSubjectVariables),
+}
+
+pub struct NewBookComponent {
+    step: WizardStep,
+    // Draft of the work and everything attached to it, only sent to the API at the final step
+    work: WorkWithRelations,
+    // Track the user-entered DOI string, which may not be validly formatted
+    doi: String,
+    doi_warning: String,
+    data: NewWorkFormData,
+    fetch_data: FetchNewWork,
+    contributor_search: String,
+    debounce_timeout: Option<Timeout>,
+    fetch_contributors: FetchContributors,
+    new_contribution: Contribution,
+    new_publication: Publication,
+    isbn: String,
+    isbn_warning: String,
+    new_language: Language,
+    new_subject_type: SubjectType,
+    new_subject_code: String,
+    errors: Vec<String>,
+    push_work: PushCreateWork,
+    // Work created so far, deleted again if any of the subsequent mutations fails
+    created_work_id: Option<Uuid>,
+    pending: VecDeque<PendingMutation>,
+    delete_work: PushDeleteWork,
+    is_creating: bool,
+}
+
+#[derive(Default)]
+struct NewWorkFormData {
+    imprints: Vec<ImprintWithPublisher>,
+    work_types: Vec<WorkTypeValues>,
+    work_statuses: Vec<WorkStatusValues>,
+    contribution_types: Vec<ContributionTypeValues>,
+    publication_types: Vec<PublicationTypeValues>,
+    language_codes: Vec<LanguageCodeValues>,
+    language_relations: Vec<LanguageRelationValues>,
+    subject_types: Vec<SubjectTypeValues>,
+}
+
+impl From<NewWorkResponseData> for NewWorkFormData {
+    fn from(data: NewWorkResponseData) -> Self {
+        NewWorkFormData {
+            imprints: data.imprints,
+            work_types: data.work_types.enum_values,
+            work_statuses: data.work_statuses.enum_values,
+            contribution_types: data.contribution_types.enum_values,
+            publication_types: data.publication_types.enum_values,
+            language_codes: data.language_codes.enum_values,
+            language_relations: data.language_relations.enum_values,
+            subject_types: data.subject_types.enum_values,
+        }
+    }
+}
+
+pub enum Msg {
+    SetDataFetchState(FetchActionNewWork),
+    GetData,
+    NextStep,
+    PreviousStep,
+    ChangeWorkType(WorkType),
+    ChangeImprint(String),
+    ChangeWorkStatus(WorkStatus),
+    ChangeTitle(String),
+    ChangeSubtitle(String),
+    ChangeEdition(String),
+    ChangeDoi(String),
+    ChangeDate(String),
+    ChangeWithdrawnDate(String),
+    ChangePlace(String),
+    ChangeLicense(String),
+    ChangeLandingPage(String),
+    ChangeLongAbstract(String),
+    SearchContributor(String),
+    GetContributors,
+    SetContributorsFetchState(FetchActionContributors),
+    ChangeContributor(String),
+    ChangeContributionType(ContributionType),
+    ChangeMainContribution(bool),
+    AddContribution,
+    RemoveContribution(usize),
+    ChangePublicationType(PublicationType),
+    ChangeIsbn(String),
+    ChangeMeasure(Dimension, Units, String),
+    AddPublication,
+    RemovePublication(usize),
+    ChangeLanguageCode(LanguageCode),
+    ChangeLanguageRelation(LanguageRelation),
+    ChangeMainLanguage(bool),
+    AddLanguage,
+    RemoveLanguage(usize),
+    ChangeSubjectType(SubjectType),
+    ChangeSubjectCode(String),
+    AddSubject,
+    RemoveSubject(usize),
+    CreateWork,
+    SetWorkPushState(PushActionCreateWork),
+    CreateNext,
+    SetContributionPushState(PushActionCreateContribution),
+    SetPublicationPushState(PushActionCreatePublication),
+    SetLanguagePushState(PushActionCreateLanguage),
+    SetSubjectPushState(PushActionCreateSubject),
+    SetWorkDeleteState(PushActionDeleteWork),
+}
+
+impl NewBookComponent {
+    fn data_request_body() -> NewWorkRequestBody {
+        NewWorkRequestBody {
+            variables: NewWorkVariables {
+                publishers: config().publisher_scope(),
+            },
+            ..Default::default()
+        }
+    }
+
+    fn contributions(&self) -> &[Contribution] {
+        self.work.contributions.as_deref().unwrap_or_default()
+    }
+
+    fn publications(&self) -> &[Publication] {
+        self.work.publications.as_deref().unwrap_or_default()
+    }
+
+    fn languages(&self) -> &[Language] {
+        self.work.languages.as_deref().unwrap_or_default()
+    }
+
+    fn subjects(&self) -> &[Subject] {
+        self.work.subjects.as_deref().unwrap_or_default()
+    }
+
+    /// Check a step against the rules the API will apply once the work is created
+    fn validate_step(&self, step: WizardStep) -> Vec<String> {
+        let mut errors: Vec<String> = vec![];
+        match step {
+            WizardStep::TypeAndImprint => {
+                if self.work.imprint.imprint_id.is_nil() {
+                    errors.push(IMPRINT_REQUIRED.to_string());
+                }
+            }
+            WizardStep::Metadata => {
+                if self.work.title.trim().is_empty() {
+                    errors.push(TITLE_REQUIRED.to_string());
+                }
+                if !self.doi_warning.is_empty() {
+                    errors.push(self.doi_warning.clone());
+                }
+                errors.extend(
+                    validate_withdrawn_date(&self.work)
+                        .iter()
+                        .map(|e| e.to_string()),
+                );
+            }
+            WizardStep::Contributions | WizardStep::Languages => {}
+            WizardStep::Publications => {
+                for publication in self.publications() {
+                    errors.extend(
+                        validate_publication_details(&self.work.work_type, publication)
+                            .iter()
+                            .map(|e| e.to_string()),
+                    );
+                }
+            }
+            WizardStep::Subjects => {
+                for subject in self.subjects() {
+                    if let Err(e) =
+                        validate_subject_code(&subject.subject_type, &subject.subject_code)
+                    {
+                        errors.push(e.to_string());
+                    }
+                }
+            }
+        }
+        errors.dedup();
+        errors
+    }
+
+    /// Everything to create once the work exists, in the order shown in the wizard
+    fn pending_mutations(&self, work_id: Uuid) -> VecDeque<PendingMutation> {
+        let mut pending = VecDeque::new();
+        for (ordinal, c) in (1..).zip(self.contributions()) {
+            pending.push_back(PendingMutation::Contribution(CreateContributionVariables {
+                work_id,
+                contributor_id: c.contributor_id,
+                contribution_type: c.contribution_type,
+                main_contribution: c.main_contribution,
+                biography: None,
+                first_name: c.first_name.clone(),
+                last_name: c.last_name.clone(),
+                full_name: c.full_name.clone(),
+                contribution_ordinal: ordinal,
+            }));
+        }
+        for p in self.publications() {
+            pending.push_back(PendingMutation::Publication(CreatePublicationVariables {
+                work_id,
+                ..p.into()
+            }));
+        }
+        for l in self.languages() {
+            pending.push_back(PendingMutation::Language(CreateLanguageVariables {
+                work_id,
+                language_code: l.language_code.clone(),
+                language_relation: l.language_relation.clone(),
+                main_language: l.main_language,
+            }));
+        }
+        for s in self.subjects() {
+            pending.push_back(PendingMutation::Subject(CreateSubjectVariables {
+                work_id,
+                subject_type: s.subject_type.clone(),
+                subject_code: s.subject_code.clone(),
+                subject_ordinal: s.subject_ordinal,
+            }));
+        }
+        pending
+    }
+
+    /// Continue with the next mutation, or undo everything created so far if one failed
+    fn advance<T>(&mut self, ctx: &Context<Self>, result: Option<ThothResult<T>>) -> bool {
+        match result {
+            None => false,
+            Some(Ok(_)) => {
+                ctx.link().send_message(Msg::CreateNext);
+                false
+            }
+            Some(Err(e)) => {
+                self.errors = vec![format!("{}: {}", CREATE_WORK_FAILED, e)];
+                self.pending.clear();
+                // Deleting the work also deletes everything that was attached to it
+                match self.created_work_id.take() {
+                    Some(work_id) => {
+                        let body = DeleteWorkRequestBody {
+                            variables: DeleteWorkVariables { work_id },
+                            ..Default::default()
+                        };
+                        self.delete_work = Fetch::new(DeleteWorkRequest { body });
+                        ctx.link()
+                            .send_future(self.delete_work.fetch(Msg::SetWorkDeleteState));
+                    }
+                    None => self.is_creating = false,
+                }
+                true
+            }
+        }
+    }
+
+    #[allow(clippy::unnecessary_operation)]
+    fn view_step(&self, ctx: &Context<Self>) -> Html {
+        match self.step {
+            WizardStep::TypeAndImprint => html! {
+                <>
+                    <FormWorkTypeSelect
+                        label={ FIELD_WORK_TYPE.as_str() }
+                        value={ self.work.work_type.clone() }
+                        data={ WorkTypeValues::to_options(&self.data.work_types) }
+                        onchange={ ctx.link().callback(Msg::ChangeWorkType) }
+                        required = true
+                    />
+                    <FormImprintSelect
+                        label={ FIELD_IMPRINT.as_str() }
+                        value={ self.work.imprint.imprint_id }
+                        data={ self.data.imprints.clone() }
+                        onchange={ ctx.link().callback(|e: Event| Msg::ChangeImprint(e.to_value())) }
+                        required = true
+                    />
+                </>
+            },
+            WizardStep::Metadata => self.view_metadata(ctx),
+            WizardStep::Contributions => self.view_contributions(ctx),
+            WizardStep::Publications => self.view_publications(ctx),
+            WizardStep::Languages => self.view_languages(ctx),
+            WizardStep::Subjects => self.view_subjects(ctx),
+        }
+    }
+
+    #[allow(clippy::unnecessary_operation)]
+    fn view_metadata(&self, ctx: &Context<Self>) -> Html {
+        html! {
+            <>
+                <FormWorkStatusSelect
+                    label={ FIELD_WORK_STATUS.as_str() }
+                    value={ self.work.work_status.clone() }
+                    data={ WorkStatusValues::to_options(&self.data.work_statuses) }
+                    onchange={ ctx.link().callback(Msg::ChangeWorkStatus) }
+                    required = true
+                />
+                <FormTextInput
+                    label={ FIELD_TITLE.as_str() }
+                    value={ self.work.title.clone() }
+                    oninput={ ctx.link().callback(|e: InputEvent| Msg::ChangeTitle(e.to_value())) }
+                    required = true
+                />
+                <FormTextInput
+                    label={ FIELD_SUBTITLE.as_str() }
+                    value={ self.work.subtitle.clone() }
+                    oninput={ ctx.link().callback(|e: InputEvent| Msg::ChangeSubtitle(e.to_value())) }
+                />
+                <FormNumberInput
+                    label={ FIELD_EDITION.as_str() }
+                    value={ self.work.edition }
+                    oninput={ ctx.link().callback(|e: InputEvent| Msg::ChangeEdition(e.to_value())) }
+                    deactivated={ self.work.work_type == WorkType::BookChapter }
+                />
+                <FormTextInputExtended
+                    label={ FIELD_DOI.as_str() }
+                    statictext={ DOI_DOMAIN }
+                    value={ self.doi.clone() }
+                    tooltip={ self.doi_warning.clone() }
+                    oninput={ ctx.link().callback(|e: InputEvent| Msg::ChangeDoi(e.to_value())) }
+                />
+                <FormDateInput
+                    label={ FIELD_PUBLICATION_DATE.as_str() }
+                    value={ self.work.publication_date.clone() }
+                    oninput={ ctx.link().callback(|e: InputEvent| Msg::ChangeDate(e.to_value())) }
+                />
+                <FormDateInput
+                    label={ FIELD_WITHDRAWN_DATE.as_str() }
+                    value={ self.work.withdrawn_date.clone() }
+                    oninput={ ctx.link().callback(|e: InputEvent| Msg::ChangeWithdrawnDate(e.to_value())) }
+                    deactivated={ !self.work.work_status.is_withdrawn() }
+                />
+                <FormTextInput
+                    label={ FIELD_PLACE.as_str() }
+                    value={ self.work.place.clone() }
+                    oninput={ ctx.link().callback(|e: InputEvent| Msg::ChangePlace(e.to_value())) }
+                />
+                <FormUrlInput
+                    label={ FIELD_LICENSE.as_str() }
+                    value={ self.work.license.clone() }
+                    oninput={ ctx.link().callback(|e: InputEvent| Msg::ChangeLicense(e.to_value())) }
+                />
+                <FormUrlInput
+                    label={ FIELD_LANDING_PAGE.as_str() }
+                    value={ self.work.landing_page.clone() }
+                    oninput={ ctx.link().callback(|e: InputEvent| Msg::ChangeLandingPage(e.to_value())) }
+                />
+                <FormTextarea
+                    label={ FIELD_LONG_ABSTRACT.as_str() }
+                    value={ self.work.long_abstract.clone() }
+                    oninput={ ctx.link().callback(|e: InputEvent| Msg::ChangeLongAbstract(e.to_value())) }
+                />
+            </>
+        }
+    }
+
+    #[allow(clippy::unnecessary_operation)]
+    fn view_contributions(&self, ctx: &Context<Self>) -> Html {
+        let contributors = match self.fetch_contributors.as_ref().state() {
+            FetchState::Fetched(body) => body.data.contributors.clone(),
+            _ => Default::default(),
+        };
+        html! {
+            <>
+                {
+                    for self.contributions().iter().enumerate().map(|(i, c)| html! {
+                        <div class="box mb-2 py-2 level">
+                            <span class="level-left">
+                                { format!("{}. {} ({})", i + 1, c.full_name, c.contribution_type) }
+                            </span>
+                            <button
+                                class="button is-danger is-outlined is-small level-right"
+                                type="button"
+                                onclick={ ctx.link().callback(move |_| Msg::RemoveContribution(i)) }
+                            >
+                                { REMOVE_BUTTON }
+                            </button>
+                        </div>
+                    })
+                }
+                <div class="box">
+                    <FormInput
+                        label={ SEARCH_CONTRIBUTORS.as_str() }
+                        value={ self.contributor_search.clone() }
+                        input_type="search"
+                        oninput={ ctx.link().callback(|e: InputEvent| Msg::SearchContributor(e.to_value())) }
+                    />
+                    <FormContributorSelect
+                        label={ FIELD_CONTRIBUTOR.as_str() }
+                        value={ self.new_contribution.contributor_id }
+                        data={ contributors }
+                        onchange={ ctx.link().callback(|e: Event| Msg::ChangeContributor(e.to_value())) }
+                    />
+                    <FormContributionTypeSelect
+                        label={ FIELD_CONTRIBUTION_TYPE.as_str() }
+                        value={ self.new_contribution.contribution_type }
+                        data={ ContributionTypeValues::to_options(&self.data.contribution_types) }
+                        onchange={ ctx.link().callback(Msg::ChangeContributionType) }
+                    />
+                    <FormBooleanSelect
+                        label={ FIELD_MAIN.as_str() }
+                        value={ self.new_contribution.main_contribution }
+                        onchange={ ctx.link().callback(|e: Event| Msg::ChangeMainContribution(e.to_value() == "true")) }
+                    />
+                    <button
+                        class="button is-small"
+                        type="button"
+                        disabled={ self.new_contribution.contributor_id.is_nil() }
+                        onclick={ ctx.link().callback(|_| Msg::AddContribution) }
+                    >
+                        { ADD_CONTRIBUTION }
+                    </button>
+                </div>
+            </>
+        }
+    }
+
+    #[allow(clippy::unnecessary_operation)]
+    fn view_measure(&self, ctx: &Context<Self>, dimension: Dimension) -> Html {
+        let p = &self.new_publication;
+        let (label, metric, imperial, metric_unit, imperial_unit) = match dimension {
+            Dimension::Width => (FIELD_WIDTH, p.width_mm, p.width_in, "mm", "in"),
+            Dimension::Height => (FIELD_HEIGHT, p.height_mm, p.height_in, "mm", "in"),
+            Dimension::Depth => (FIELD_DEPTH, p.depth_mm, p.depth_in, "mm", "in"),
+            Dimension::Weight => (FIELD_WEIGHT, p.weight_g, p.weight_oz, "g", "oz"),
+        };
+        html! {
+            <div class="columns">
+                <div class="column">
+                    <FormFloatInput
+                        label={ format!("{} ({})", label, metric_unit) }
+                        value={ metric }
+                        step={ "0.1".to_string() }
+                        oninput={ ctx.link().callback(move |e: InputEvent| Msg::ChangeMeasure(dimension, Units::Metric, e.to_value())) }
+                    />
+                </div>
+                <div class="column">
+                    <FormFloatInput
+                        label={ format!("{} ({})", label, imperial_unit) }
+                        value={ imperial }
+                        step={ "0.01".to_string() }
+                        oninput={ ctx.link().callback(move |e: InputEvent| Msg::ChangeMeasure(dimension, Units::Imperial, e.to_value())) }
+                    />
+                </div>
+            </div>
+        }
+    }
+
+    #[allow(clippy::unnecessary_operation)]
+    fn view_publications(&self, ctx: &Context<Self>) -> Html {
+        let is_chapter = self.work.work_type == WorkType::BookChapter;
+        html! {
+            <>
+                {
+                    for self.publications().iter().enumerate().map(|(i, p)| html! {
+                        <div class="box mb-2 py-2 level">
+                            <span class="level-left">
+                                {
+                                    match &p.isbn {
+                                        Some(isbn) => format!("{} ({})", p.publication_type, isbn),
+                                        None => p.publication_type.to_string(),
+                                    }
+                                }
+                            </span>
+                            <button
+                                class="button is-danger is-outlined is-small level-right"
+                                type="button"
+                                onclick={ ctx.link().callback(move |_| Msg::RemovePublication(i)) }
+                            >
+                                { REMOVE_BUTTON }
+                            </button>
+                        </div>
+                    })
+                }
+                <div class="box">
+                    <FormPublicationTypeSelect
+                        label={ FIELD_PUBLICATION_TYPE.as_str() }
+                        value={ self.new_publication.publication_type.clone() }
+                        data={ PublicationTypeValues::to_options(&self.data.publication_types) }
+                        onchange={ ctx.link().callback(Msg::ChangePublicationType) }
+                    />
+                    {
+                        if is_chapter {
+                            html! {}
+                        } else {
+                            html! {
+                                <FormTextInputExtended
+                                    label={ FIELD_ISBN.as_str() }
+                                    value={ self.isbn.clone() }
+                                    tooltip={ self.isbn_warning.clone() }
+                                    oninput={ ctx.link().callback(|e: InputEvent| Msg::ChangeIsbn(e.to_value())) }
+                                />
+                            }
+                        }
+                    }
+                    {
+                        if self.new_publication.publication_type.is_physical() && !is_chapter {
+                            html! {
+                                <>
+                                    { self.view_measure(ctx, Dimension::Width) }
+                                    { self.view_measure(ctx, Dimension::Height) }
+                                    { self.view_measure(ctx, Dimension::Depth) }
+                                    { self.view_measure(ctx, Dimension::Weight) }
+                                </>
+                            }
+                        } else {
+                            html! {}
+                        }
+                    }
+                    <button class="button is-small" type="button" onclick={ ctx.link().callback(|_| Msg::AddPublication) }>
+                        { ADD_PUBLICATION }
+                    </button>
+                </div>
+            </>
+        }
+    }
+
+    #[allow(clippy::unnecessary_operation)]
+    fn view_languages(&self, ctx: &Context<Self>) -> Html {
+        html! {
+            <>
+                {
+                    for self.languages().iter().enumerate().map(|(i, l)| html! {
+                        <div class="box mb-2 py-2 level">
+                            <span class="level-left">
+                                { format!("{} ({})", l.language_code.english_name(), l.language_relation) }
+                            </span>
+                            <button
+                                class="button is-danger is-outlined is-small level-right"
+                                type="button"
+                                onclick={ ctx.link().callback(move |_| Msg::RemoveLanguage(i)) }
+                            >
+                                { REMOVE_BUTTON }
+                            </button>
+                        </div>
+                    })
+                }
+                <div class="box">
+                    <FormLanguageCodeSelect
+                        label={ FIELD_LANGUAGE.as_str() }
+                        value={ self.new_language.language_code.clone() }
+                        data={ LanguageCodeValues::to_options(&self.data.language_codes) }
+                        onchange={ ctx.link().callback(Msg::ChangeLanguageCode) }
+                    />
+                    <FormLanguageRelationSelect
+                        label={ FIELD_LANGUAGE_RELATION.as_str() }
+                        value={ self.new_language.language_relation.clone() }
+                        data={ LanguageRelationValues::to_options(&self.data.language_relations) }
+                        onchange={ ctx.link().callback(Msg::ChangeLanguageRelation) }
+                    />
+                    <FormBooleanSelect
+                        label={ FIELD_MAIN.as_str() }
+                        value={ self.new_language.main_language }
+                        onchange={ ctx.link().callback(|e: Event| Msg::ChangeMainLanguage(e.to_value() == "true")) }
+                    />
+                    <button class="button is-small" type="button" onclick={ ctx.link().callback(|_| Msg::AddLanguage) }>
+                        { ADD_LANGUAGE }
+                    </button>
+                </div>
+            </>
+        }
+    }
+
+    #[allow(clippy::unnecessary_operation)]
+    fn view_subjects(&self, ctx: &Context<Self>) -> Html {
+        let suggestions = self
+            .new_subject_type
+            .suggest_codes(&self.new_subject_code, MAX_SUGGESTIONS);
+        html! {
+            <>
+                {
+                    for self.subjects().iter().enumerate().map(|(i, s)| html! {
+                        <div class="box mb-2 py-2 level">
+                            <span class="level-left">
+                                { format!("{}: {}", s.subject_type, s.subject_code) }
+                            </span>
+                            <button
+                                class="button is-danger is-outlined is-small level-right"
+                                type="button"
+                                onclick={ ctx.link().callback(move |_| Msg::RemoveSubject(i)) }
+                            >
+                                { REMOVE_BUTTON }
+                            </button>
+                        </div>
+                    })
+                }
+                <div class="box">
+                    <FormSubjectTypeSelect
+                        label={ FIELD_SUBJECT_TYPE.as_str() }
+                        value={ self.new_subject_type.clone() }
+                        data={ SubjectTypeValues::to_options(&self.data.subject_types) }
+                        onchange={ ctx.link().callback(Msg::ChangeSubjectType) }
+                    />
+                    <div class={ if suggestions.is_empty() { "dropdown" } else { "dropdown is-active" } } style="width: 100%">
+                        <div class="dropdown-trigger" style="width: 100%">
+                            <FormInput
+                                label={ FIELD_SUBJECT_CODE.as_str() }
+                                value={ self.new_subject_code.clone() }
+                                input_type="search"
+                                help_text={ SUBJECT_CODE_SEARCH.as_str() }
+                                oninput={ ctx.link().callback(|e: InputEvent| Msg::ChangeSubjectCode(e.to_value())) }
+                            />
+                        </div>
+                        <div class="dropdown-menu" role="menu">
+                            <div class="dropdown-content">
+                                {
+                                    for suggestions.into_iter().map(|entry| {
+                                        let code = entry.code.to_string();
+                                        entry.as_dropdown_item(
+                                            ctx.link().callback(move |_| Msg::ChangeSubjectCode(code.clone())),
+                                        )
+                                    })
+                                }
+                            </div>
+                        </div>
+                    </div>
+                    <button class="button is-small" type="button" onclick={ ctx.link().callback(|_| Msg::AddSubject) }>
+                        { ADD_SUBJECT }
+                    </button>
+                </div>
+            </>
+        }
+    }
+}
+
+impl Component for NewBookComponent {
+    type Message = Msg;
+    type Properties = ();
+
+    fn create(ctx: &Context<Self>) -> Self {
+        ctx.link().send_message(Msg::GetData);
+        NewBookComponent {
+            step: WizardStep::TypeAndImprint,
+            work: Default::default(),
+            doi: Default::default(),
+            doi_warning: Default::default(),
+            data: Default::default(),
+            fetch_data: Default::default(),
+            contributor_search: Default::default(),
+            debounce_timeout: None,
+            fetch_contributors: Default::default(),
+            new_contribution: Contribution {
+                main_contribution: true,
+                ..Default::default()
+            },
+            new_publication: Default::default(),
+            isbn: Default::default(),
+            isbn_warning: Default::default(),
+            new_language: Language {
+                main_language: true,
+                ..Default::default()
+            },
+            new_subject_type: SubjectType::Thema,
+            new_subject_code: Default::default(),
+            errors: vec![],
+            push_work: Default::default(),
+            created_work_id: None,
+            pending: Default::default(),
+            delete_work: Default::default(),
+            is_creating: false,
+        }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::GetData => {
+                let body = Self::data_request_body();
+                if let Some(data) = QueryCache::get::<_, NewWorkResponseData>(&body) {
+                    self.data = NewWorkFormData::from(data);
+                    return true;
+                }
+                self.fetch_data = Fetch::new(NewWorkRequest { body });
+                ctx.link()
+                    .send_future(self.fetch_data.fetch(Msg::SetDataFetchState));
+                ctx.link()
+                    .send_message(Msg::SetDataFetchState(FetchAction::Fetching));
+                false
+            }
+            Msg::SetDataFetchState(fetch_state) => {
+                self.fetch_data.apply(fetch_state);
+                match self.fetch_data.as_ref().state() {
+                    FetchState::Fetched(body) => {
+                        QueryCache::insert(&Self::data_request_body(), &body.data);
+                        self.data = NewWorkFormData::from(body.data.clone());
+                        true
+                    }
+                    FetchState::Failed(_, err) => {
+                        expire_on_invalid_token(&err);
+                        true
+                    }
+                    _ => true,
+                }
+            }
+            Msg::NextStep => {
+                self.errors = self.validate_step(self.step);
+                if self.errors.is_empty() {
+                    if let Some(next) = self.step.next() {
+                        self.step = next;
+                    }
+                }
+                true
+            }
+            Msg::PreviousStep => {
+                self.errors.clear();
+                if let Some(previous) = self.step.previous() {
+                    self.step = previous;
+                }
+                true
+            }
+            Msg::ChangeWorkType(work_type) => {
+                if self.work.work_type.neq_assign(work_type) {
+                    // Chapters are not published in editions
+                    if self.work.work_type == WorkType::BookChapter {
+                        self.work.edition = None;
+                    }
+                    true
+                } else {
+                    false
+                }
+            }
+            Msg::ChangeImprint(imprint_id) => {
+                let imprint_id = Uuid::parse_str(&imprint_id).unwrap_or_default();
+                let imprint = self
+                    .data
+                    .imprints
+                    .iter()
+                    .find(|i| i.imprint_id == imprint_id)
+                    .cloned()
+                    .unwrap_or_default();
+                self.work.imprint.neq_assign(imprint)
+            }
+            Msg::ChangeWorkStatus(work_status) => {
+                if self.work.work_status.neq_assign(work_status) {
+                    // Only withdrawn works may carry a withdrawn date
+                    if !self.work.work_status.is_withdrawn() {
+                        self.work.withdrawn_date = None;
+                    }
+                    true
+                } else {
+                    false
+                }
+            }
+            Msg::ChangeTitle(title) => self.work.title.neq_assign(title.trim().to_owned()),
+            Msg::ChangeSubtitle(subtitle) => {
+                self.work.subtitle.neq_assign(subtitle.to_opt_string())
+            }
+            Msg::ChangeEdition(edition) => self.work.edition.neq_assign(edition.to_opt_int()),
+            Msg::ChangeDoi(value) => {
+                if self.doi.neq_assign(value.trim().to_owned()) {
+                    self.doi_warning.clear();
+                    match Doi::from_str(&self.doi) {
+                        Err(e) => {
+                            match e {
+                                // If no DOI was provided, no warning is required.
+                                ThothError::DoiEmptyError => {}
+                                _ => self.doi_warning = e.to_string(),
+                            }
+                            self.work.doi = None;
+                        }
+                        Ok(doi) => self.work.doi = Some(doi),
+                    }
+                    true
+                } else {
+                    false
+                }
+            }
+            Msg::ChangeDate(value) => self.work.publication_date.neq_assign(value.to_opt_string()),
+            Msg::ChangeWithdrawnDate(value) => {
+                self.work.withdrawn_date.neq_assign(value.to_opt_string())
+            }
+            Msg::ChangePlace(value) => self.work.place.neq_assign(value.to_opt_string()),
+            Msg::ChangeLicense(value) => self.work.license.neq_assign(value.to_opt_string()),
+            Msg::ChangeLandingPage(value) => {
+                self.work.landing_page.neq_assign(value.to_opt_string())
+            }
+            Msg::ChangeLongAbstract(value) => {
+                self.work.long_abstract.neq_assign(value.to_opt_string())
+            }
+            Msg::SearchContributor(query) => {
+                self.contributor_search = query;
+                // cancel previous timeout
+                self.debounce_timeout = self.debounce_timeout.take().and_then(|timeout| {
+                    timeout.cancel();
+                    None
+                });
+                // start new timeout
+                let link = ctx.link().clone();
+                let timeout = Timeout::new(config().debounce_timeout, move || {
+                    link.send_message(Msg::GetContributors);
+                });
+                self.debounce_timeout = Some(timeout);
+                true
+            }
+            Msg::GetContributors => {
+                let body = ContributorsRequestBody {
+                    variables: ContributorsVariables {
+                        limit: Some(50),
+                        filter: self.contributor_search.clone().to_opt_string(),
+                    },
+                    ..Default::default()
+                };
+                self.fetch_contributors = Fetch::new(ContributorsRequest { body });
+                ctx.link().send_future(
+                    self.fetch_contributors
+                        .fetch(Msg::SetContributorsFetchState),
+                );
+                ctx.link()
+                    .send_message(Msg::SetContributorsFetchState(FetchAction::Fetching));
+                false
+            }
+            Msg::SetContributorsFetchState(fetch_state) => {
+                self.fetch_contributors.apply(fetch_state);
+                true
+            }
+            Msg::ChangeContributor(contributor_id) => {
+                let contributor = match self.fetch_contributors.as_ref().state() {
+                    FetchState::Fetched(body) => {
+                        Uuid::from_str(&contributor_id).ok().and_then(|id| {
+                            body.data
+                                .contributors
+                                .iter()
+                                .find(|c| c.contributor_id == id)
+                                .cloned()
+                        })
+                    }
+                    _ => None,
+                };
+                match contributor {
+                    Some(contributor) => {
+                        self.new_contribution.contributor_id = contributor.contributor_id;
+                        self.new_contribution.first_name = contributor.first_name.clone();
+                        self.new_contribution.last_name = contributor.last_name.clone();
+                        self.new_contribution.full_name = contributor.full_name.clone();
+                        self.new_contribution.contributor = contributor;
+                    }
+                    None => self.new_contribution.contributor_id = Uuid::nil(),
+                }
+                true
+            }
+            Msg::ChangeContributionType(contribution_type) => self
+                .new_contribution
+                .contribution_type
+                .neq_assign(contribution_type),
+            Msg::ChangeMainContribution(main) => {
+                self.new_contribution.main_contribution.neq_assign(main)
+            }
+            Msg::AddContribution => {
+                let contribution = std::mem::replace(
+                    &mut self.new_contribution,
+                    Contribution {
+                        main_contribution: true,
+                        ..Default::default()
+                    },
+                );
+                self.work
+                    .contributions
+                    .get_or_insert_with(Vec::new)
+                    .push(contribution);
+                true
+            }
+            Msg::RemoveContribution(index) => {
+                if let Some(contributions) = self.work.contributions.as_mut() {
+                    if index < contributions.len() {
+                        contributions.remove(index);
+                    }
+                }
+                true
+            }
+            Msg::ChangePublicationType(publication_type) => {
+                if self
+                    .new_publication
+                    .publication_type
+                    .neq_assign(publication_type)
+                {
+                    // Dimensions only apply to physical publications
+                    if !self.new_publication.publication_type.is_physical() {
+                        self.new_publication.clear_dimensions();
+                    }
+                    true
+                } else {
+                    false
+                }
+            }
+            Msg::ChangeIsbn(value) => {
+                if self.isbn.neq_assign(value.trim().to_owned()) {
+                    self.isbn_warning.clear();
+                    match Isbn::from_str(&self.isbn) {
+                        Err(e) => {
+                            match e {
+                                // If no ISBN was provided, no warning is required.
+                                ThothError::IsbnEmptyError => {}
+                                _ => self.isbn_warning = e.to_string(),
+                            }
+                            self.new_publication.isbn = None;
+                        }
+                        Ok(isbn) => self.new_publication.isbn = Some(isbn),
+                    }
+                    true
+                } else {
+                    false
+                }
+            }
+            Msg::ChangeMeasure(dimension, units, value) => {
+                self.new_publication
+                    .set_dimension(dimension, units, value.to_opt_float());
+                true
+            }
+            Msg::AddPublication => {
+                let mut errors: Vec<String> =
+                    validate_publication_details(&self.work.work_type, &self.new_publication)
+                        .iter()
+                        .map(|e| e.to_string())
+                        .collect();
+                if !self.isbn_warning.is_empty() {
+                    errors.push(self.isbn_warning.clone());
+                }
+                self.errors = errors;
+                if self.errors.is_empty() {
+                    let publication = std::mem::take(&mut self.new_publication);
+                    self.work
+                        .publications
+                        .get_or_insert_with(Vec::new)
+                        .push(publication);
+                    self.isbn.clear();
+                }
+                true
+            }
+            Msg::RemovePublication(index) => {
+                if let Some(publications) = self.work.publications.as_mut() {
+                    if index < publications.len() {
+                        publications.remove(index);
+                    }
+                }
+                true
+            }
+            Msg::ChangeLanguageCode(code) => self.new_language.language_code.neq_assign(code),
+            Msg::ChangeLanguageRelation(relation) => {
+                self.new_language.language_relation.neq_assign(relation)
+            }
+            Msg::ChangeMainLanguage(main) => self.new_language.main_language.neq_assign(main),
+            Msg::AddLanguage => {
+                let language = self.new_language.clone();
+                self.work
+                    .languages
+                    .get_or_insert_with(Vec::new)
+                    .push(language);
+                true
+            }
+            Msg::RemoveLanguage(index) => {
+                if let Some(languages) = self.work.languages.as_mut() {
+                    if index < languages.len() {
+                        languages.remove(index);
+                    }
+                }
+                true
+            }
+            Msg::ChangeSubjectType(subject_type) => self.new_subject_type.neq_assign(subject_type),
+            Msg::ChangeSubjectCode(code) => self.new_subject_code.neq_assign(code),
+            Msg::AddSubject => {
+                let subject_type = self.new_subject_type.clone();
+                let code = self.new_subject_code.trim().to_string();
+                match validate_subject_code(&subject_type, &code) {
+                    Err(e) => self.errors = vec![e.to_string()],
+                    Ok(()) => {
+                        // Prefer the code's canonical capitalisation, as listed in the code list
+                        let subject_code = subject_type
+                            .find_code(&code)
+                            .map(|entry| entry.code.to_string())
+                            .unwrap_or(code);
+                        let subject_ordinal = self
+                            .subjects()
+                            .iter()
+                            .filter(|s| s.subject_type == subject_type)
+                            .count() as i32
+                            + 1;
+                        self.work
+                            .subjects
+                            .get_or_insert_with(Vec::new)
+                            .push(Subject {
+                                subject_type,
+                                subject_code,
+                                subject_ordinal,
+                                ..Default::default()
+                            });
+                        self.new_subject_code.clear();
+                        self.errors.clear();
+                    }
+                }
+                true
+            }
+            Msg::RemoveSubject(index) => {
+                if let Some(subjects) = self.work.subjects.as_mut() {
+                    if index < subjects.len() {
+                        let removed = subjects.remove(index);
+                        // Close the gap left in the ordinals of the removed subject's scheme
+                        for s in subjects.iter_mut().filter(|s| {
+                            s.subject_type == removed.subject_type
+                                && s.subject_ordinal > removed.subject_ordinal
+                        }) {
+                            s.subject_ordinal -= 1;
+                        }
+                    }
+                }
+                true
+            }
+            Msg::CreateWork => {
+                self.errors = WizardStep::ALL
+                    .iter()
+                    .flat_map(|step| self.validate_step(*step))
+                    .collect();
+                if !self.errors.is_empty() {
+                    return true;
+                }
+                self.is_creating = true;
+                let body = CreateWorkRequestBody {
+                    variables: (&self.work).into(),
+                    ..Default::default()
+                };
+                self.push_work = Fetch::new(CreateWorkRequest { body });
+                ctx.link()
+                    .send_future(self.push_work.fetch(Msg::SetWorkPushState));
+                ctx.link()
+                    .send_message(Msg::SetWorkPushState(FetchAction::Fetching));
+                true
+            }
+            Msg::SetWorkPushState(fetch_state) => {
+                self.push_work.apply(fetch_state);
+                if let FetchState::Failed(_, err) = self.push_work.as_ref().state() {
+                    expire_on_invalid_token(&err);
+                }
+                let result = self.push_work.result();
+                if let Some(Ok(work)) = &result {
+                    self.created_work_id = Some(work.work_id);
+                    self.pending = self.pending_mutations(work.work_id);
+                }
+                self.advance(ctx, result)
+            }
+            Msg::CreateNext => {
+                let link = ctx.link();
+                match self.pending.pop_front() {
+                    Some(PendingMutation::Contribution(variables)) => {
+                        let body = CreateContributionRequestBody {
+                            variables,
+                            ..Default::default()
+                        };
+                        let push: PushCreateContribution =
+                            Fetch::new(CreateContributionRequest { body });
+                        link.send_future(push.fetch(Msg::SetContributionPushState));
+                    }
+                    Some(PendingMutation::Publication(variables)) => {
+                        let body = CreatePublicationRequestBody {
+                            variables,
+                            ..Default::default()
+                        };
+                        let push: PushCreatePublication =
+                            Fetch::new(CreatePublicationRequest { body });
+                        link.send_future(push.fetch(Msg::SetPublicationPushState));
+                    }
+                    Some(PendingMutation::Language(variables)) => {
+                        let body = CreateLanguageRequestBody {
+                            variables,
+                            ..Default::default()
+                        };
+                        let push: PushCreateLanguage = Fetch::new(CreateLanguageRequest { body });
+                        link.send_future(push.fetch(Msg::SetLanguagePushState));
+                    }
+                    Some(PendingMutation::Subject(variables)) => {
+                        let body = CreateSubjectRequestBody {
+                            variables,
+                            ..Default::default()
+                        };
+                        let push: PushCreateSubject = Fetch::new(CreateSubjectRequest { body });
+                        link.send_future(push.fetch(Msg::SetSubjectPushState));
+                    }
+                    None => {
+                        // Everything was created, so the new work can be opened
+                        if let Some(book_id) = self.created_work_id.take() {
+                            link.history()
+                                .unwrap()
+                                .push(AppRoute::BookDetail { book_id });
+                        }
+                    }
+                }
+                false
+            }
+            Msg::SetContributionPushState(action) => {
+                let mut push = PushCreateContribution::default();
+                push.apply(action);
+                if let FetchState::Failed(_, err) = push.as_ref().state() {
+                    expire_on_invalid_token(&err);
+                }
+                self.advance(ctx, push.result())
+            }
+            Msg::SetPublicationPushState(action) => {
+                let mut push = PushCreatePublication::default();
+                push.apply(action);
+                if let FetchState::Failed(_, err) = push.as_ref().state() {
+                    expire_on_invalid_token(&err);
+                }
+                self.advance(ctx, push.result())
+            }
+            Msg::SetLanguagePushState(action) => {
+                let mut push = PushCreateLanguage::default();
+                push.apply(action);
+                if let FetchState::Failed(_, err) = push.as_ref().state() {
+                    expire_on_invalid_token(&err);
+                }
+                self.advance(ctx, push.result())
+            }
+            Msg::SetSubjectPushState(action) => {
+                let mut push = PushCreateSubject::default();
+                push.apply(action);
+                if let FetchState::Failed(_, err) = push.as_ref().state() {
+                    expire_on_invalid_token(&err);
+                }
+                self.advance(ctx, push.result())
+            }
+            Msg::SetWorkDeleteState(fetch_state) => {
+                self.delete_work.apply(fetch_state);
+                if let Some(Err(e)) = self.delete_work.result() {
+                    self.errors.push(e.to_string());
+                }
+                self.is_creating = false;
+                true
+            }
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        // Only logged in users may create works, the API will reject anyone else
+        if !AccountService::new().is_loggedin() {
+            return html! { <Redirect<AppRoute> to={ AppRoute::Login }/> };
+        }
+        if let FetchState::Fetching(_) = self.fetch_data.as_ref().state() {
+            return html! { <Loader/> };
+        }
+        let is_last = self.step.next().is_none();
+        html! {
+            <div class="container box my-4">
+                <div class="py-4 font-semibold text-header">{ NEW_WORK_TITLE }</div>
+                <div class="tabs is-small">
+                    <ul>
+                        {
+                            for WizardStep::ALL.iter().map(|step| html! {
+                                <li class={ if *step == self.step { "is-active" } else { "" } }>
+                                    <a>{ step.title() }</a>
+                                </li>
+                            })
+                        }
+                    </ul>
+                </div>
+                {
+                    for self.errors.iter().map(|error| html! {
+                        <div class="notification is-danger is-light py-2">{ error }</div>
+                    })
+                }
+                { self.view_step(ctx) }
+                <div class="field is-grouped mt-4">
+                    <div class="control">
+                        <button
+                            class="button is-light"
+                            type="button"
+                            disabled={ self.step.previous().is_none() || self.is_creating }
+                            onclick={ ctx.link().callback(|_| Msg::PreviousStep) }
+                        >
+                            { PREVIOUS_STEP_BUTTON }
+                        </button>
+                    </div>
+                    <div class="control">
+                        {
+                            if is_last {
+                                html! {
+                                    <button
+                                        class={ if self.is_creating { "button is-success is-loading" } else { "button is-success" } }
+                                        type="button"
+                                        disabled={ self.is_creating }
+                                        onclick={ ctx.link().callback(|_| Msg::CreateWork) }
+                                    >
+                                        { CREATE_WORK_BUTTON }
+                                    </button>
+                                }
+                            } else {
+                                html! {
+                                    <button class="button is-link" type="button" onclick={ ctx.link().callback(|_| Msg::NextStep) }>
+                                        { NEXT_STEP_BUTTON }
+                                    </button>
+                                }
+                            }
+                        }
+                    </div>
+                </div>
+            </div>
+        }
+    }
+}
@@ -0,0 +1,510 @@
+use std::str::FromStr;
+use uuid::Uuid;
+use yew::html;
+use yew::prelude::*;
+use yewtil::fetch::{Fetch, FetchAction, FetchState};
+use yewtil::NeqAssign;
+
+use crate::agent::session::expire_on_invalid_token;
+use crate::component::utils::{FormFloatInput, FormPublicationTypeSelect, FormTextInputExtended};
+use crate::component::{ToElementValue, ToOption};
+use crate::models::publication::create_publication_mutation::{
+    CreatePublicationRequest, CreatePublicationRequestBody, PushActionCreatePublication,
+    PushCreatePublication,
+};
+use crate::models::publication::delete_publication_mutation::{
+    DeletePublicationRequest, DeletePublicationRequestBody, PushActionDeletePublication,
+    PushDeletePublication, Variables as DeleteVariables,
+};
+use crate::models::publication::update_publication_mutation::{
+    PushActionUpdatePublication, PushUpdatePublication, UpdatePublicationRequest,
+    UpdatePublicationRequestBody,
+};
+use crate::models::publication::{Dimension, Units};
+use crate::models::utils::{
+    Isbn, Publication, PublicationType, PublicationTypeValues, ThothError, WorkType,
+};
+use crate::models::validation::validate_publication_details;
+use crate::models::{EnumValues, MutationResult};
+use crate::service::unsaved::UnsavedChangesGuard;
+use crate::string::{
+    ADD_PUBLICATION, CHAPTER_PUBLICATION_INFO, FIELD_DEPTH, FIELD_HEIGHT, FIELD_ISBN,
+    FIELD_PUBLICATION_TYPE, FIELD_WEIGHT, FIELD_WIDTH, PUBLICATIONS_INFO, REMOVE_BUTTON,
+    SAVE_BUTTON, SECTION_PUBLICATIONS,
+};
+
+pub struct PublicationsFormComponent {
+    // Local copies of the publications, edited until the user saves each of them
+    drafts: Vec<PublicationDraft>,
+    push_publication: PushCreatePublication,
+    push_update: PushUpdatePublication,
+    delete_publication: PushDeletePublication,
+    error: Option<String>,
+    unsaved: Option<UnsavedChangesGuard>,
+}
+
+#[derive(Clone, Default, PartialEq)]
+struct PublicationDraft {
+    publication: Publication,
+    // Track the user-entered ISBN string, which may not be validly formatted
+    isbn: String,
+    isbn_warning: String,
+}
+
+impl From<&Publication> for PublicationDraft {
+    fn from(publication: &Publication) -> Self {
+        PublicationDraft {
+            publication: publication.clone(),
+            isbn: publication
+                .isbn
+                .as_ref()
+                .map(|isbn| isbn.to_string())
+                .unwrap_or_default(),
+            isbn_warning: Default::default(),
+        }
+    }
+}
+
+impl PublicationDraft {
+    fn is_new(&self) -> bool {
+        self.publication.publication_id.is_nil()
+    }
+}
+
+pub enum Msg {
+    AddPublication,
+    ChangePublicationType(usize, PublicationType),
+    ChangeIsbn(usize, String),
+    ChangeMeasure(usize, Dimension, Units, String),
+    SavePublication(usize),
+    SetPublicationPushState(PushActionCreatePublication),
+    SetPublicationUpdateState(PushActionUpdatePublication),
+    DeletePublication(usize),
+    SetPublicationDeleteState(PushActionDeletePublication),
+}
+
+#[derive(PartialEq, Properties)]
+pub struct Props {
+    pub publications: Option<Vec<Publication>>,
+    pub publication_types: Vec<PublicationTypeValues>,
+    pub work_id: Uuid,
+    // Work type as stored in the database, which determines whether ISBNs are allowed
+    pub work_type: WorkType,
+    pub update_publications: Callback<Option<Vec<Publication>>>,
+}
+
+impl PublicationsFormComponent {
+    fn drafts(publications: &Option<Vec<Publication>>) -> Vec<PublicationDraft> {
+        publications
+            .iter()
+            .flatten()
+            .map(PublicationDraft::from)
+            .collect()
+    }
+
+    /// Whether any publication has been added or edited without being saved
+    fn is_dirty(&self, ctx: &Context<Self>) -> bool {
+        let saved = Self::drafts(&ctx.props().publications);
+        self.drafts.iter().any(|d| {
+            d.is_new()
+                || !saved.iter().any(|s| {
+                    s.publication.publication_id == d.publication.publication_id
+                        && s.publication == d.publication
+                        && s.isbn == d.isbn
+                })
+        })
+    }
+
+    fn track_unsaved_changes(&mut self, ctx: &Context<Self>) {
+        let is_dirty = self.is_dirty(ctx);
+        if is_dirty != self.unsaved.is_some() {
+            self.unsaved = is_dirty.then(UnsavedChangesGuard::new);
+        }
+    }
+
+    fn emit(&self, ctx: &Context<Self>) {
+        let publications = self
+            .drafts
+            .iter()
+            .filter(|d| !d.is_new())
+            .map(|d| d.publication.clone())
+            .collect();
+        ctx.props().update_publications.emit(Some(publications));
+    }
+
+    /// Replace the draft of a saved publication, keeping the prices and locations which are not
+    /// returned by publication mutations
+    fn store(&mut self, publication: Publication) {
+        let index = self
+            .drafts
+            .iter()
+            .position(|d| d.publication.publication_id == publication.publication_id)
+            .or_else(|| self.drafts.iter().position(|d| d.is_new()));
+        if let Some(i) = index {
+            let draft = &mut self.drafts[i];
+            let prices = draft.publication.prices.take();
+            let locations = draft.publication.locations.take();
+            *draft = PublicationDraft::from(&Publication {
+                prices,
+                locations,
+                ..publication
+            });
+        }
+    }
+
+    #[allow(clippy::unnecessary_operation)]
+    fn render_measure(
+        &self,
+        ctx: &Context<Self>,
+        index: usize,
+        publication: &Publication,
+        dimension: Dimension,
+    ) -> Html {
+        let (label, metric, imperial) = match dimension {
+            Dimension::Width => (FIELD_WIDTH, publication.width_mm, publication.width_in),
+            Dimension::Height => (FIELD_HEIGHT, publication.height_mm, publication.height_in),
+            Dimension::Depth => (FIELD_DEPTH, publication.depth_mm, publication.depth_in),
+            Dimension::Weight => (FIELD_WEIGHT, publication.weight_g, publication.weight_oz),
+        };
+        let (metric_unit, imperial_unit) = match dimension {
+            Dimension::Weight => ("g", "oz"),
+            _ => ("mm", "in"),
+        };
+        html! {
+            <div class="columns">
+                <div class="column">
+                    <FormFloatInput
+                        label={ format!("{} ({})", label, metric_unit) }
+                        value={ metric }
+                        step={ "0.1".to_string() }
+                        oninput={ ctx.link().callback(move |e: InputEvent| {
+                            Msg::ChangeMeasure(index, dimension, Units::Metric, e.to_value())
+                        }) }
+                    />
+                </div>
+                <div class="column">
+                    <FormFloatInput
+                        label={ format!("{} ({})", label, imperial_unit) }
+                        value={ imperial }
+                        step={ "0.01".to_string() }
+                        oninput={ ctx.link().callback(move |e: InputEvent| {
+                            Msg::ChangeMeasure(index, dimension, Units::Imperial, e.to_value())
+                        }) }
+                    />
+                </div>
+            </div>
+        }
+    }
+
+    #[allow(clippy::unnecessary_operation)]
+    fn render_publication(&self, ctx: &Context<Self>, index: usize, d: &PublicationDraft) -> Html {
+        let is_chapter = ctx.props().work_type == WorkType::BookChapter;
+        let publication = &d.publication;
+        html! {
+            <div class="box mb-2">
+                <FormPublicationTypeSelect
+                    label={ FIELD_PUBLICATION_TYPE.as_str() }
+                    value={ publication.publication_type.clone() }
+                    data={ PublicationTypeValues::to_options(&ctx.props().publication_types) }
+                    onchange={ ctx.link().callback(move |t| Msg::ChangePublicationType(index, t)) }
+                    required = true
+                />
+                {
+                    if is_chapter {
+                        html! { <p class="help mb-2">{ CHAPTER_PUBLICATION_INFO }</p> }
+                    } else {
+                        html! {
+                            <FormTextInputExtended
+                                label={ FIELD_ISBN.as_str() }
+                                value={ d.isbn.clone() }
+                                tooltip={ d.isbn_warning.clone() }
+                                oninput={ ctx.link().callback(move |e: InputEvent| Msg::ChangeIsbn(index, e.to_value())) }
+                            />
+                        }
+                    }
+                }
+                {
+                    if publication.publication_type.is_physical() && !is_chapter {
+                        html! {
+                            <>
+                                { self.render_measure(ctx, index, publication, Dimension::Width) }
+                                { self.render_measure(ctx, index, publication, Dimension::Height) }
+                                { self.render_measure(ctx, index, publication, Dimension::Depth) }
+                                { self.render_measure(ctx, index, publication, Dimension::Weight) }
+                            </>
+                        }
+                    } else {
+                        html! {}
+                    }
+                }
+                <div class="field is-grouped">
+                    <div class="control">
+                        <button
+                            class="button is-success is-small"
+                            type="button"
+                            onclick={ ctx.link().callback(move |_| Msg::SavePublication(index)) }
+                        >
+                            { SAVE_BUTTON }
+                        </button>
+                    </div>
+                    <div class="control">
+                        <button
+                            class="button is-danger is-outlined is-small"
+                            type="button"
+                            onclick={ ctx.link().callback(move |_| Msg::DeletePublication(index)) }
+                        >
+                            { REMOVE_BUTTON }
+                        </button>
+                    </div>
+                </div>
+            </div>
+        }
+    }
+}
+
+impl Component for PublicationsFormComponent {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        PublicationsFormComponent {
+            drafts: Self::drafts(&ctx.props().publications),
+            push_publication: Default::default(),
+            push_update: Default::default(),
+            delete_publication: Default::default(),
+            error: None,
+            unsaved: None,
+        }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        let should_render = match msg {
+            Msg::AddPublication => {
+                // Only one unsaved publication at a time, so that its result can be matched
+                if self.drafts.iter().any(|d| d.is_new()) {
+                    return false;
+                }
+                self.drafts.push(PublicationDraft {
+                    publication: Publication {
+                        work_id: ctx.props().work_id,
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                });
+                true
+            }
+            Msg::ChangePublicationType(index, publication_type) => {
+                match self.drafts.get_mut(index) {
+                    Some(d) => {
+                        let changed = d.publication.publication_type.neq_assign(publication_type);
+                        // Dimensions only apply to physical publications
+                        if changed && !d.publication.publication_type.is_physical() {
+                            d.publication.clear_dimensions();
+                        }
+                        changed
+                    }
+                    None => false,
+                }
+            }
+            Msg::ChangeIsbn(index, value) => match self.drafts.get_mut(index) {
+                Some(d) => {
+                    let changed = d.isbn.neq_assign(value.trim().to_owned());
+                    if changed {
+                        // If ISBN is not correctly formatted, display a warning
+                        // and block saving until it is corrected.
+                        d.isbn_warning.clear();
+                        match Isbn::from_str(&d.isbn) {
+                            Err(e) => {
+                                match e {
+                                    // If no ISBN was provided, no warning is required.
+                                    ThothError::IsbnEmptyError => {}
+                                    _ => d.isbn_warning = e.to_string(),
+                                }
+                                d.publication.isbn = None;
+                            }
+                            Ok(isbn) => d.publication.isbn = Some(isbn),
+                        }
+                    }
+                    changed
+                }
+                None => false,
+            },
+            Msg::ChangeMeasure(index, dimension, units, value) => {
+                match self.drafts.get_mut(index) {
+                    Some(d) => {
+                        d.publication
+                            .set_dimension(dimension, units, value.to_opt_float());
+                        true
+                    }
+                    None => false,
+                }
+            }
+            Msg::SavePublication(index) => {
+                let draft = match self.drafts.get(index) {
+                    Some(draft) => draft,
+                    None => return false,
+                };
+                if !draft.isbn_warning.is_empty() {
+                    self.error = Some(draft.isbn_warning.clone());
+                    return true;
+                }
+                let errors =
+                    validate_publication_details(&ctx.props().work_type, &draft.publication);
+                if !errors.is_empty() {
+                    self.error = Some(
+                        errors
+                            .iter()
+                            .map(|e| e.to_string())
+                            .collect::<Vec<String>>()
+                            .join(" "),
+                    );
+                    return true;
+                }
+                if draft.is_new() {
+                    let body = CreatePublicationRequestBody {
+                        variables: (&draft.publication).into(),
+                        ..Default::default()
+                    };
+                    self.push_publication = Fetch::new(CreatePublicationRequest { body });
+                    ctx.link()
+                        .send_future(self.push_publication.fetch(Msg::SetPublicationPushState));
+                    ctx.link()
+                        .send_message(Msg::SetPublicationPushState(FetchAction::Fetching));
+                } else {
+                    let body = UpdatePublicationRequestBody {
+                        variables: (&draft.publication).into(),
+                        ..Default::default()
+                    };
+                    self.push_update = Fetch::new(UpdatePublicationRequest { body });
+                    ctx.link()
+                        .send_future(self.push_update.fetch(Msg::SetPublicationUpdateState));
+                    ctx.link()
+                        .send_message(Msg::SetPublicationUpdateState(FetchAction::Fetching));
+                }
+                false
+            }
+            Msg::SetPublicationPushState(fetch_state) => {
+                self.push_publication.apply(fetch_state);
+                if let FetchState::Failed(_, err) = self.push_publication.as_ref().state() {
+                    expire_on_invalid_token(&err);
+                }
+                match self.push_publication.result() {
+                    None => false,
+                    Some(Ok(publication)) => {
+                        self.store(publication);
+                        self.error = None;
+                        self.emit(ctx);
+                        true
+                    }
+                    Some(Err(e)) => {
+                        self.error = Some(e.to_string());
+                        true
+                    }
+                }
+            }
+            Msg::SetPublicationUpdateState(fetch_state) => {
+                self.push_update.apply(fetch_state);
+                if let FetchState::Failed(_, err) = self.push_update.as_ref().state() {
+                    expire_on_invalid_token(&err);
+                }
+                match self.push_update.result() {
+                    None => false,
+                    Some(Ok(publication)) => {
+                        self.store(publication);
+                        self.error = None;
+                        self.emit(ctx);
+                        true
+                    }
+                    Some(Err(e)) => {
+                        self.error = Some(e.to_string());
+                        true
+                    }
+                }
+            }
+            Msg::DeletePublication(index) => {
+                let draft = match self.drafts.get(index) {
+                    Some(draft) => draft,
+                    None => return false,
+                };
+                // Unsaved publications only need discarding locally
+                if draft.is_new() {
+                    self.drafts.remove(index);
+                    return true;
+                }
+                let body = DeletePublicationRequestBody {
+                    variables: DeleteVariables {
+                        publication_id: draft.publication.publication_id,
+                    },
+                    ..Default::default()
+                };
+                self.delete_publication = Fetch::new(DeletePublicationRequest { body });
+                ctx.link().send_future(
+                    self.delete_publication
+                        .fetch(Msg::SetPublicationDeleteState),
+                );
+                ctx.link()
+                    .send_message(Msg::SetPublicationDeleteState(FetchAction::Fetching));
+                false
+            }
+            Msg::SetPublicationDeleteState(fetch_state) => {
+                self.delete_publication.apply(fetch_state);
+                if let FetchState::Failed(_, err) = self.delete_publication.as_ref().state() {
+                    expire_on_invalid_token(&err);
+                }
+                match self.delete_publication.result() {
+                    None => false,
+                    Some(Ok(deleted)) => {
+                        self.drafts
+                            .retain(|d| d.publication.publication_id != deleted.publication_id);
+                        self.error = None;
+                        self.emit(ctx);
+                        true
+                    }
+                    Some(Err(e)) => {
+                        self.error = Some(e.to_string());
+                        true
+                    }
+                }
+            }
+        };
+        self.track_unsaved_changes(ctx);
+        should_render
+    }
+
+    fn changed(&mut self, ctx: &Context<Self>) -> bool {
+        // Keep any unsaved publication the user is still filling in
+        let unsaved: Vec<PublicationDraft> =
+            self.drafts.iter().filter(|d| d.is_new()).cloned().collect();
+        self.drafts = Self::drafts(&ctx.props().publications);
+        self.drafts.extend(unsaved);
+        self.track_unsaved_changes(ctx);
+        true
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        html! {
+            <div id="edit-publications" class="my-4">
+                <div class="py-4 font-semibold text-header">{ SECTION_PUBLICATIONS }</div>
+                <p class="help mb-2">{ PUBLICATIONS_INFO }</p>
+                {
+                    match &self.error {
+                        Some(error) => html! {
+                            <div class="notification is-danger is-light py-2">{ error }</div>
+                        },
+                        None => html! {},
+                    }
+                }
+                {
+                    for self
+                        .drafts
+                        .iter()
+                        .enumerate()
+                        .map(|(i, d)| self.render_publication(ctx, i, d))
+                }
+                <button class="button is-small" type="button" onclick={ ctx.link().callback(|_| Msg::AddPublication) }>
+                    { ADD_PUBLICATION }
+                </button>
+            </div>
+        }
+    }
+}
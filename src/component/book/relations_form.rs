@@ -0,0 +1,730 @@
+use gloo_timers::callback::Timeout;
+use std::str::FromStr;
+use uuid::Uuid;
+use yew::html;
+use yew::prelude::*;
+use yewtil::fetch::{Fetch, FetchAction, FetchState};
+use yewtil::NeqAssign;
+
+use crate::agent::session::expire_on_invalid_token;
+use crate::component::utils::{FormInput, FormRelationTypeSelect};
+use crate::component::{Reorder, ReorderStep, ToElementValue, ToOption};
+use crate::config::config;
+use crate::i18n::Localized;
+use crate::models::relation::create_relation_mutation::{
+    CreateRelationRequest, CreateRelationRequestBody, PushActionCreateRelation, PushCreateRelation,
+    Variables as CreateVariables,
+};
+use crate::models::relation::delete_relation_mutation::{
+    DeleteRelationRequest, DeleteRelationRequestBody, PushActionDeleteRelation, PushDeleteRelation,
+    Variables as DeleteVariables,
+};
+use crate::models::relation::inverse_relations_query::{
+    FetchActionInverseRelations, FetchInverseRelations, InverseRelationsRequest,
+    InverseRelationsRequestBody, Variables as InverseRelationsVariables,
+};
+use crate::models::relation::relatable_works_query::{
+    FetchActionRelatableWorks, FetchRelatableWorks, RelatableWork, RelatableWorksRequest,
+    RelatableWorksRequestBody, Variables as RelatableWorksVariables,
+};
+use crate::models::relation::update_relation_mutation::{
+    PushActionUpdateRelation, PushUpdateRelation, UpdateRelationRequest, UpdateRelationRequestBody,
+    Variables as UpdateVariables,
+};
+use crate::models::utils::{RelationType, RelationTypeValues, WorkRelationWithRelatedWork};
+use crate::models::{EnumValues, MutationResult};
+use crate::string::{
+    ADD_RELATION, CANCEL_BUTTON, FIELD_RELATED_WORK, FIELD_RELATION_TYPE, MOVE_DOWN_BUTTON,
+    MOVE_UP_BUTTON, RELATIONS_INFO, REMOVE_BUTTON, SEARCH_WORKS_TO_RELATE, SECTION_RELATIONS,
+    SELECT_WORK,
+};
+
+pub struct RelationsFormComponent {
+    relations: Vec<WorkRelationWithRelatedWork>,
+    show_add_form: bool,
+    work_search: String,
+    debounce_timeout: Option<Timeout>,
+    fetch_works: FetchRelatableWorks,
+    related_work: Option<RelatableWork>,
+    new_relation_type: RelationType,
+    push_relation: PushCreateRelation,
+    // Inverse relation to create on the related work once its counterpart has been saved
+    pending_inverse: Option<CreateVariables>,
+    push_inverse: PushCreateRelation,
+    delete_relation: PushDeleteRelation,
+    // Change to make to the inverse relation on the related work once its counterpart is saved
+    inverse_change: Option<InverseChange>,
+    fetch_inverse: FetchInverseRelations,
+    delete_inverse: PushDeleteRelation,
+    reorder: Option<Reorder<WorkRelationWithRelatedWork>>,
+    error: Option<String>,
+}
+
+struct InverseChange {
+    work_relation_id: Uuid,
+    related_work_id: Uuid,
+    // Type of the relation before the change, whose inverse is looked up on the related work
+    relation_type: RelationType,
+    // New type of the relation, or `None` if it is deleted
+    new_relation_type: Option<RelationType>,
+}
+
+pub enum Msg {
+    ToggleAddForm,
+    SearchWork(String),
+    GetWorks,
+    SetWorksFetchState(FetchActionRelatableWorks),
+    ChangeRelatedWork(String),
+    ChangeNewRelationType(RelationType),
+    CreateRelation,
+    SetRelationPushState(PushActionCreateRelation),
+    SetInversePushState(PushActionCreateRelation),
+    DeleteRelation(Uuid),
+    SetRelationDeleteState(PushActionDeleteRelation),
+    ChangeRelationType(Uuid, RelationType),
+    SetRelationUpdateState(PushActionUpdateRelation),
+    GetInverseRelations,
+    SetInverseRelationsFetchState(FetchActionInverseRelations),
+    SetInverseDeleteState(PushActionDeleteRelation),
+    SetInverseUpdateState(PushActionUpdateRelation),
+    Move(usize, usize),
+    SetTemporaryOrdinalState(PushActionUpdateRelation),
+    SetFinalOrdinalState(PushActionUpdateRelation),
+}
+
+#[derive(PartialEq, Properties)]
+pub struct Props {
+    pub relations: Option<Vec<WorkRelationWithRelatedWork>>,
+    pub relation_types: Vec<RelationTypeValues>,
+    pub work_id: Uuid,
+    pub update_relations: Callback<Option<Vec<WorkRelationWithRelatedWork>>>,
+}
+
+impl RelationsFormComponent {
+    fn sorted(
+        relations: &Option<Vec<WorkRelationWithRelatedWork>>,
+    ) -> Vec<WorkRelationWithRelatedWork> {
+        let mut relations = relations.clone().unwrap_or_default();
+        relations.sort_by_key(|r| r.relation_ordinal);
+        relations
+    }
+
+    fn next_ordinal(&self) -> i32 {
+        self.relations
+            .iter()
+            .map(|r| r.relation_ordinal)
+            .max()
+            .unwrap_or_default()
+            + 1
+    }
+
+    fn emit(&mut self, ctx: &Context<Self>) {
+        self.relations.sort_by_key(|r| r.relation_ordinal);
+        ctx.props()
+            .update_relations
+            .emit(Some(self.relations.clone()));
+    }
+
+    fn push_update(
+        ctx: &Context<Self>,
+        variables: UpdateVariables,
+        to_msg: fn(PushActionUpdateRelation) -> Msg,
+    ) {
+        let body = UpdateRelationRequestBody {
+            variables,
+            ..Default::default()
+        };
+        let push: PushUpdateRelation = Fetch::new(UpdateRelationRequest { body });
+        ctx.link().send_future(push.fetch(to_msg));
+    }
+
+    /// Read the outcome of a request whose state is not otherwise kept by the component
+    fn update_result(
+        action: PushActionUpdateRelation,
+    ) -> Option<Result<WorkRelationWithRelatedWork, String>> {
+        let mut push = PushUpdateRelation::default();
+        push.apply(action);
+        if let FetchState::Failed(_, err) = push.as_ref().state() {
+            expire_on_invalid_token(&err);
+        }
+        push.result().map(|r| r.map_err(|e| e.to_string()))
+    }
+
+    /// Save whatever the reordering in progress needs saved next
+    fn reorder_step(
+        &mut self,
+        ctx: &Context<Self>,
+        step: ReorderStep<WorkRelationWithRelatedWork>,
+    ) -> bool {
+        match step {
+            ReorderStep::Park(parked) => {
+                Self::push_update(ctx, (&parked).into(), Msg::SetTemporaryOrdinalState);
+                false
+            }
+            ReorderStep::Finish(moved) => {
+                for r in moved.iter() {
+                    Self::push_update(ctx, r.into(), Msg::SetFinalOrdinalState);
+                }
+                false
+            }
+            ReorderStep::RollBack(parked, e) => {
+                self.reorder = None;
+                self.error = Some(e);
+                self.relations = Self::sorted(&ctx.props().relations);
+                for r in parked.iter() {
+                    Self::push_update(ctx, r.into(), Msg::SetRelationUpdateState);
+                }
+                true
+            }
+            ReorderStep::Wait => false,
+            ReorderStep::Done => {
+                self.reorder = None;
+                self.error = None;
+                self.emit(ctx);
+                true
+            }
+        }
+    }
+
+    #[allow(clippy::unnecessary_operation)]
+    fn render_relation(
+        &self,
+        ctx: &Context<Self>,
+        index: usize,
+        r: &WorkRelationWithRelatedWork,
+    ) -> Html {
+        let work_relation_id = r.work_relation_id;
+        let is_first = index == 0;
+        let is_last = index + 1 == self.relations.len();
+        html! {
+            <div class="box mb-2">
+                <div class="columns is-vcentered">
+                    <div class="column is-narrow">
+                        <div class="buttons has-addons are-small">
+                            <button
+                                class="button"
+                                type="button"
+                                title={ MOVE_UP_BUTTON.as_str() }
+                                disabled={ is_first || self.reorder.is_some() }
+                                onclick={ ctx.link().callback(move |_| Msg::Move(index, index.saturating_sub(1))) }
+                            >
+                                <span class="icon"><i class="fas fa-arrow-up"></i></span>
+                            </button>
+                            <button
+                                class="button"
+                                type="button"
+                                title={ MOVE_DOWN_BUTTON.as_str() }
+                                disabled={ is_last || self.reorder.is_some() }
+                                onclick={ ctx.link().callback(move |_| Msg::Move(index, index + 1)) }
+                            >
+                                <span class="icon"><i class="fas fa-arrow-down"></i></span>
+                            </button>
+                        </div>
+                    </div>
+                    <div class="column">
+                        <FormRelationTypeSelect
+                            label={ FIELD_RELATION_TYPE.as_str() }
+                            value={ r.relation_type }
+                            data={ RelationTypeValues::to_options(&ctx.props().relation_types) }
+                            onchange={ ctx.link().callback(move |t| Msg::ChangeRelationType(work_relation_id, t)) }
+                            required = true
+                        />
+                    </div>
+                    <div class="column is-half">
+                        <p class="has-text-weight-semibold">{ &r.related_work.full_title }</p>
+                        <p class="is-size-7">{ format!("{} · {}", r.related_work.work_type.localized(), r.related_work.work_status.localized()) }</p>
+                    </div>
+                    <div class="column is-narrow">
+                        <button
+                            class="button is-danger is-outlined is-small"
+                            type="button"
+                            onclick={ ctx.link().callback(move |_| Msg::DeleteRelation(work_relation_id)) }
+                        >
+                            { REMOVE_BUTTON }
+                        </button>
+                    </div>
+                </div>
+            </div>
+        }
+    }
+
+    #[allow(clippy::unnecessary_operation)]
+    fn render_add_form(&self, ctx: &Context<Self>) -> Html {
+        let works = match self.fetch_works.as_ref().state() {
+            FetchState::Fetched(body) => body.data.works.clone(),
+            _ => Default::default(),
+        };
+        let selected = self.related_work.as_ref().map(|w| w.work.work_id);
+        let work_id = ctx.props().work_id;
+        html! {
+            <div class="box">
+                <FormInput
+                    label={ SEARCH_WORKS_TO_RELATE.as_str() }
+                    value={ self.work_search.clone() }
+                    input_type="search"
+                    oninput={ ctx.link().callback(|e: InputEvent| Msg::SearchWork(e.to_value())) }
+                />
+                <div class="field">
+                    <label class="label">{ FIELD_RELATED_WORK }</label>
+                    <div class="control is-expanded">
+                        <div class="select is-fullwidth">
+                        <select
+                            required=true
+                            onchange={ ctx.link().callback(|e: Event| Msg::ChangeRelatedWork(e.to_value())) }
+                        >
+                            <option value="" selected={ selected.is_none() }>{ SELECT_WORK }</option>
+                            {
+                                // A work cannot be related to itself
+                                for works.iter().filter(|w| w.work.work_id != work_id).map(|w| html! {
+                                    <option value={ w.work.work_id.to_string() } selected={ selected == Some(w.work.work_id) }>
+                                        { &w.work.full_title }
+                                    </option>
+                                })
+                            }
+                        </select>
+                        </div>
+                    </div>
+                </div>
+                <FormRelationTypeSelect
+                    label={ FIELD_RELATION_TYPE.as_str() }
+                    value={ self.new_relation_type }
+                    data={ RelationTypeValues::to_options(&ctx.props().relation_types) }
+                    onchange={ ctx.link().callback(Msg::ChangeNewRelationType) }
+                    required = true
+                />
+                <div class="field is-grouped">
+                    <div class="control">
+                        <button
+                            class="button is-success"
+                            type="button"
+                            disabled={ self.related_work.is_none() }
+                            onclick={ ctx.link().callback(|_| Msg::CreateRelation) }
+                        >
+                            { ADD_RELATION }
+                        </button>
+                    </div>
+                    <div class="control">
+                        <button class="button is-light" type="button" onclick={ ctx.link().callback(|_| Msg::ToggleAddForm) }>
+                            { CANCEL_BUTTON }
+                        </button>
+                    </div>
+                </div>
+            </div>
+        }
+    }
+}
+
+impl Component for RelationsFormComponent {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        RelationsFormComponent {
+            relations: Self::sorted(&ctx.props().relations),
+            show_add_form: false,
+            work_search: Default::default(),
+            debounce_timeout: None,
+            fetch_works: Default::default(),
+            related_work: None,
+            new_relation_type: Default::default(),
+            push_relation: Default::default(),
+            pending_inverse: None,
+            push_inverse: Default::default(),
+            delete_relation: Default::default(),
+            inverse_change: None,
+            fetch_inverse: Default::default(),
+            delete_inverse: Default::default(),
+            reorder: None,
+            error: None,
+        }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::ToggleAddForm => {
+                self.show_add_form = !self.show_add_form;
+                if self.show_add_form {
+                    self.related_work = None;
+                    self.new_relation_type = Default::default();
+                    ctx.link().send_message(Msg::GetWorks);
+                }
+                true
+            }
+            Msg::SearchWork(query) => {
+                self.work_search = query;
+                // cancel previous timeout
+                self.debounce_timeout = self.debounce_timeout.take().and_then(|timeout| {
+                    timeout.cancel();
+                    None
+                });
+                // start new timeout
+                let link = ctx.link().clone();
+                let timeout = Timeout::new(config().debounce_timeout, move || {
+                    link.send_message(Msg::GetWorks);
+                });
+                self.debounce_timeout = Some(timeout);
+                true
+            }
+            Msg::GetWorks => {
+                let body = RelatableWorksRequestBody {
+                    variables: RelatableWorksVariables {
+                        limit: Some(50),
+                        filter: self.work_search.clone().to_opt_string(),
+                    },
+                    ..Default::default()
+                };
+                self.fetch_works = Fetch::new(RelatableWorksRequest { body });
+                ctx.link()
+                    .send_future(self.fetch_works.fetch(Msg::SetWorksFetchState));
+                ctx.link()
+                    .send_message(Msg::SetWorksFetchState(FetchAction::Fetching));
+                false
+            }
+            Msg::SetWorksFetchState(fetch_state) => {
+                self.fetch_works.apply(fetch_state);
+                true
+            }
+            Msg::ChangeRelatedWork(work_id) => {
+                self.related_work = match self.fetch_works.as_ref().state() {
+                    FetchState::Fetched(body) => Uuid::from_str(&work_id).ok().and_then(|id| {
+                        body.data
+                            .works
+                            .iter()
+                            .find(|w| w.work.work_id == id)
+                            .cloned()
+                    }),
+                    _ => None,
+                };
+                true
+            }
+            Msg::ChangeNewRelationType(relation_type) => {
+                self.new_relation_type.neq_assign(relation_type)
+            }
+            Msg::CreateRelation => {
+                let related_work = match &self.related_work {
+                    Some(related_work) => related_work,
+                    None => return false,
+                };
+                let work_id = ctx.props().work_id;
+                self.pending_inverse = Some(CreateVariables {
+                    relator_work_id: related_work.work.work_id,
+                    related_work_id: work_id,
+                    relation_type: self.new_relation_type.inverse(),
+                    relation_ordinal: related_work.next_relation_ordinal(),
+                });
+                let body = CreateRelationRequestBody {
+                    variables: CreateVariables {
+                        relator_work_id: work_id,
+                        related_work_id: related_work.work.work_id,
+                        relation_type: self.new_relation_type,
+                        relation_ordinal: self.next_ordinal(),
+                    },
+                    ..Default::default()
+                };
+                self.push_relation = Fetch::new(CreateRelationRequest { body });
+                ctx.link()
+                    .send_future(self.push_relation.fetch(Msg::SetRelationPushState));
+                ctx.link()
+                    .send_message(Msg::SetRelationPushState(FetchAction::Fetching));
+                false
+            }
+            Msg::SetRelationPushState(fetch_state) => {
+                self.push_relation.apply(fetch_state);
+                if let FetchState::Failed(_, err) = self.push_relation.as_ref().state() {
+                    expire_on_invalid_token(&err);
+                }
+                match self.push_relation.result() {
+                    None => false,
+                    Some(Ok(relation)) => {
+                        self.relations.push(relation);
+                        self.show_add_form = false;
+                        self.error = None;
+                        self.emit(ctx);
+                        // Record the same link on the related work, so it can be navigated both ways
+                        if let Some(variables) = self.pending_inverse.take() {
+                            let body = CreateRelationRequestBody {
+                                variables,
+                                ..Default::default()
+                            };
+                            self.push_inverse = Fetch::new(CreateRelationRequest { body });
+                            ctx.link()
+                                .send_future(self.push_inverse.fetch(Msg::SetInversePushState));
+                        }
+                        true
+                    }
+                    Some(Err(e)) => {
+                        self.pending_inverse = None;
+                        self.error = Some(e.to_string());
+                        true
+                    }
+                }
+            }
+            Msg::SetInversePushState(fetch_state) => {
+                self.push_inverse.apply(fetch_state);
+                match self.push_inverse.result() {
+                    Some(Err(e)) => {
+                        self.error = Some(e.to_string());
+                        true
+                    }
+                    _ => false,
+                }
+            }
+            Msg::DeleteRelation(work_relation_id) => {
+                self.inverse_change = self
+                    .relations
+                    .iter()
+                    .find(|r| r.work_relation_id == work_relation_id)
+                    .map(|r| InverseChange {
+                        work_relation_id,
+                        related_work_id: r.related_work_id,
+                        relation_type: r.relation_type,
+                        new_relation_type: None,
+                    });
+                let body = DeleteRelationRequestBody {
+                    variables: DeleteVariables { work_relation_id },
+                    ..Default::default()
+                };
+                self.delete_relation = Fetch::new(DeleteRelationRequest { body });
+                ctx.link()
+                    .send_future(self.delete_relation.fetch(Msg::SetRelationDeleteState));
+                ctx.link()
+                    .send_message(Msg::SetRelationDeleteState(FetchAction::Fetching));
+                false
+            }
+            Msg::SetRelationDeleteState(fetch_state) => {
+                self.delete_relation.apply(fetch_state);
+                if let FetchState::Failed(_, err) = self.delete_relation.as_ref().state() {
+                    expire_on_invalid_token(&err);
+                }
+                match self.delete_relation.result() {
+                    None => false,
+                    Some(Ok(deleted)) => {
+                        self.relations
+                            .retain(|r| r.work_relation_id != deleted.work_relation_id);
+                        self.error = None;
+                        self.emit(ctx);
+                        ctx.link().send_message(Msg::GetInverseRelations);
+                        true
+                    }
+                    Some(Err(e)) => {
+                        self.inverse_change = None;
+                        self.error = Some(e.to_string());
+                        true
+                    }
+                }
+            }
+            Msg::ChangeRelationType(work_relation_id, relation_type) => {
+                if let Some(r) = self
+                    .relations
+                    .iter_mut()
+                    .find(|r| r.work_relation_id == work_relation_id)
+                {
+                    let previous = r.relation_type;
+                    if r.relation_type.neq_assign(relation_type) {
+                        self.inverse_change = Some(InverseChange {
+                            work_relation_id,
+                            related_work_id: r.related_work_id,
+                            relation_type: previous,
+                            new_relation_type: Some(relation_type),
+                        });
+                        Self::push_update(ctx, (&*r).into(), Msg::SetRelationUpdateState);
+                    }
+                }
+                false
+            }
+            Msg::SetRelationUpdateState(action) => match Self::update_result(action) {
+                None => false,
+                Some(Ok(relation)) => {
+                    let retyped = self
+                        .inverse_change
+                        .as_ref()
+                        .is_some_and(|c| c.work_relation_id == relation.work_relation_id);
+                    if let Some(r) = self
+                        .relations
+                        .iter_mut()
+                        .find(|r| r.work_relation_id == relation.work_relation_id)
+                    {
+                        *r = relation;
+                    }
+                    self.error = None;
+                    self.emit(ctx);
+                    if retyped {
+                        ctx.link().send_message(Msg::GetInverseRelations);
+                    }
+                    true
+                }
+                Some(Err(e)) => {
+                    self.inverse_change = None;
+                    self.error = Some(e);
+                    true
+                }
+            },
+            Msg::GetInverseRelations => {
+                let related_work_id = match &self.inverse_change {
+                    Some(change) => change.related_work_id,
+                    None => return false,
+                };
+                let body = InverseRelationsRequestBody {
+                    variables: InverseRelationsVariables {
+                        work_id: related_work_id,
+                    },
+                    ..Default::default()
+                };
+                self.fetch_inverse = Fetch::new(InverseRelationsRequest { body });
+                ctx.link()
+                    .send_future(self.fetch_inverse.fetch(Msg::SetInverseRelationsFetchState));
+                false
+            }
+            Msg::SetInverseRelationsFetchState(fetch_state) => {
+                self.fetch_inverse.apply(fetch_state);
+                let body = match self.fetch_inverse.as_ref().state() {
+                    FetchState::Fetched(body) => body,
+                    FetchState::Failed(_, err) => {
+                        expire_on_invalid_token(&err);
+                        self.inverse_change = None;
+                        self.error = Some(err.to_string());
+                        return true;
+                    }
+                    _ => return false,
+                };
+                let change = match self.inverse_change.take() {
+                    Some(change) => change,
+                    None => return false,
+                };
+                // Relations created before inverses were recorded may have none to update
+                let inverse = match body
+                    .data
+                    .work
+                    .inverse_of(ctx.props().work_id, change.relation_type)
+                {
+                    Some(inverse) => inverse.clone(),
+                    None => return false,
+                };
+                match change.new_relation_type {
+                    Some(relation_type) => {
+                        let variables = UpdateVariables {
+                            relation_type: relation_type.inverse(),
+                            ..inverse
+                        };
+                        Self::push_update(ctx, variables, Msg::SetInverseUpdateState);
+                    }
+                    None => {
+                        let body = DeleteRelationRequestBody {
+                            variables: DeleteVariables {
+                                work_relation_id: inverse.work_relation_id,
+                            },
+                            ..Default::default()
+                        };
+                        self.delete_inverse = Fetch::new(DeleteRelationRequest { body });
+                        ctx.link()
+                            .send_future(self.delete_inverse.fetch(Msg::SetInverseDeleteState));
+                    }
+                }
+                false
+            }
+            Msg::SetInverseDeleteState(fetch_state) => {
+                self.delete_inverse.apply(fetch_state);
+                match self.delete_inverse.result() {
+                    Some(Err(e)) => {
+                        self.error = Some(e.to_string());
+                        true
+                    }
+                    _ => false,
+                }
+            }
+            Msg::SetInverseUpdateState(action) => match Self::update_result(action) {
+                Some(Err(e)) => {
+                    self.error = Some(e);
+                    true
+                }
+                _ => false,
+            },
+            Msg::Move(source, target) => {
+                if self.reorder.is_some() || target >= self.relations.len() {
+                    return false;
+                }
+                let mut relations = self.relations.clone();
+                if let Some((reorder, parked)) = Reorder::start(
+                    &mut relations,
+                    source,
+                    target,
+                    |r| r.work_relation_id,
+                    |r| &mut r.relation_ordinal,
+                ) {
+                    Self::push_update(ctx, (&parked).into(), Msg::SetTemporaryOrdinalState);
+                    self.reorder = Some(reorder);
+                    self.relations = relations;
+                }
+                true
+            }
+            Msg::SetTemporaryOrdinalState(action) => {
+                let step = match (Self::update_result(action), self.reorder.as_mut()) {
+                    (Some(result), Some(reorder)) => reorder.parked(result.map(|_| ())),
+                    _ => return false,
+                };
+                self.reorder_step(ctx, step)
+            }
+            Msg::SetFinalOrdinalState(action) => {
+                let step = match (Self::update_result(action), self.reorder.as_mut()) {
+                    (Some(result), Some(reorder)) => {
+                        if let Ok(relation) = &result {
+                            if let Some(r) = self
+                                .relations
+                                .iter_mut()
+                                .find(|r| r.work_relation_id == relation.work_relation_id)
+                            {
+                                *r = relation.clone();
+                            }
+                        }
+                        reorder.finished(result.map(|r| r.work_relation_id))
+                    }
+                    _ => return false,
+                };
+                self.reorder_step(ctx, step)
+            }
+        }
+    }
+
+    fn changed(&mut self, ctx: &Context<Self>) -> bool {
+        // Keep any reordering in progress rather than jumping back to the previous order
+        if self.reorder.is_none() {
+            self.relations = Self::sorted(&ctx.props().relations);
+        }
+        true
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        html! {
+            <div id="edit-relations" class="my-4">
+                <div class="py-4 font-semibold text-header">{ SECTION_RELATIONS }</div>
+                <p class="help mb-2">{ RELATIONS_INFO }</p>
+                {
+                    match &self.error {
+                        Some(error) => html! {
+                            <div class="notification is-danger is-light py-2">{ error }</div>
+                        },
+                        None => html! {},
+                    }
+                }
+                {
+                    for self
+                        .relations
+                        .iter()
+                        .enumerate()
+                        .map(|(i, r)| self.render_relation(ctx, i, r))
+                }
+                {
+                    if self.show_add_form {
+                        self.render_add_form(ctx)
+                    } else {
+                        html! {
+                            <button class="button is-small" type="button" onclick={ ctx.link().callback(|_| Msg::ToggleAddForm) }>
+                                { ADD_RELATION }
+                            </button>
+                        }
+                    }
+                }
+            </div>
+        }
+    }
+}
@@ -0,0 +1,522 @@
+use uuid::Uuid;
+use yew::html;
+use yew::prelude::*;
+use yewtil::fetch::{Fetch, FetchAction, FetchState};
+
+use crate::agent::session::expire_on_invalid_token;
+use crate::component::utils::FormSubjectTypeSelect;
+use crate::component::{reorder, ToElementValue};
+use crate::models::subject::create_subject_mutation::{
+    CreateSubjectRequest, CreateSubjectRequestBody, PushActionCreateSubject, PushCreateSubject,
+    Variables as CreateVariables,
+};
+use crate::models::subject::delete_subject_mutation::{
+    DeleteSubjectRequest, DeleteSubjectRequestBody, PushActionDeleteSubject, PushDeleteSubject,
+    Variables as DeleteVariables,
+};
+use crate::models::subject::update_subject_mutation::{
+    PushActionUpdateSubject, PushUpdateSubject, UpdateSubjectRequest, UpdateSubjectRequestBody,
+};
+use crate::models::utils::{Subject, SubjectType, SubjectTypeValues};
+use crate::models::validation::validate_subject_code;
+use crate::models::{Dropdown, EnumValues, MutationResult};
+use crate::string::{
+    ADD_KEYWORDS, ADD_SUBJECT, FIELD_KEYWORDS, FIELD_SUBJECT_CODE, FIELD_SUBJECT_TYPE,
+    KEYWORDS_INFO, MOVE_DOWN_BUTTON, MOVE_UP_BUTTON, REMOVE_BUTTON, SECTION_SUBJECTS,
+    SUBJECTS_INFO, SUBJECT_CODE_SEARCH,
+};
+
+// Maximum number of code list entries offered while typing a subject code
+const MAX_SUGGESTIONS: usize = 10;
+
+pub struct SubjectsFormComponent {
+    subjects: Vec<Subject>,
+    new_subject_type: SubjectType,
+    new_subject_code: String,
+    show_suggestions: bool,
+    keyword_input: String,
+    push_subject: PushCreateSubject,
+    delete_subject: PushDeleteSubject,
+    error: Option<String>,
+}
+
+pub enum Msg {
+    ChangeNewSubjectType(SubjectType),
+    ChangeNewSubjectCode(String),
+    ToggleSuggestions(bool),
+    SelectSuggestion(String),
+    CreateSubject,
+    ChangeKeywordInput(String),
+    AddKeywords,
+    CreateSubjects(SubjectType, Vec<String>),
+    SetSubjectPushState(PushActionCreateSubject),
+    DeleteSubject(Uuid),
+    SetSubjectDeleteState(PushActionDeleteSubject),
+    Move(SubjectType, usize, usize),
+    SetSubjectUpdateState(PushActionUpdateSubject),
+}
+
+#[derive(PartialEq, Properties)]
+pub struct Props {
+    pub subjects: Option<Vec<Subject>>,
+    pub subject_types: Vec<SubjectTypeValues>,
+    pub work_id: Uuid,
+    pub update_subjects: Callback<Option<Vec<Subject>>>,
+}
+
+impl SubjectsFormComponent {
+    fn sorted(subjects: &Option<Vec<Subject>>) -> Vec<Subject> {
+        let mut subjects = subjects.clone().unwrap_or_default();
+        subjects.sort_by(|a, b| {
+            (&a.subject_type, a.subject_ordinal).cmp(&(&b.subject_type, b.subject_ordinal))
+        });
+        subjects
+    }
+
+    /// Subjects of a single scheme, in ordinal order
+    fn of_type(&self, subject_type: &SubjectType) -> Vec<Subject> {
+        self.subjects
+            .iter()
+            .filter(|s| &s.subject_type == subject_type)
+            .cloned()
+            .collect()
+    }
+
+    fn next_ordinal(&self, subject_type: &SubjectType) -> i32 {
+        self.subjects
+            .iter()
+            .filter(|s| &s.subject_type == subject_type)
+            .map(|s| s.subject_ordinal)
+            .max()
+            .unwrap_or_default()
+            + 1
+    }
+
+    fn emit(&mut self, ctx: &Context<Self>) {
+        self.subjects = Self::sorted(&Some(self.subjects.clone()));
+        ctx.props()
+            .update_subjects
+            .emit(Some(self.subjects.clone()));
+    }
+
+    fn render_subject(
+        &self,
+        ctx: &Context<Self>,
+        index: usize,
+        count: usize,
+        subject: &Subject,
+    ) -> Html {
+        let subject_id = subject.subject_id;
+        let subject_type = subject.subject_type.clone();
+        let heading = subject
+            .subject_type
+            .find_code(&subject.subject_code)
+            .map(|entry| entry.heading)
+            .unwrap_or_default();
+        html! {
+            <div class="box mb-2 py-2">
+                <div class="columns is-vcentered">
+                    <div class="column is-narrow">
+                        <div class="buttons has-addons are-small">
+                            <button
+                                class="button"
+                                type="button"
+                                title={ MOVE_UP_BUTTON.as_str() }
+                                disabled={ index == 0 }
+                                onclick={ ctx.link().callback({
+                                    let subject_type = subject_type.clone();
+                                    move |_| Msg::Move(subject_type.clone(), index, index.saturating_sub(1))
+                                }) }
+                            >
+                                <span class="icon"><i class="fas fa-arrow-up"></i></span>
+                            </button>
+                            <button
+                                class="button"
+                                type="button"
+                                title={ MOVE_DOWN_BUTTON.as_str() }
+                                disabled={ index + 1 == count }
+                                onclick={ ctx.link().callback(move |_| Msg::Move(subject_type.clone(), index, index + 1)) }
+                            >
+                                <span class="icon"><i class="fas fa-arrow-down"></i></span>
+                            </button>
+                        </div>
+                    </div>
+                    <div class="column is-narrow">
+                        <span class="tag is-info is-light">{ &subject.subject_type }</span>
+                    </div>
+                    <div class="column">
+                        <span class="has-text-weight-semibold">{ &subject.subject_code }</span>
+                        <span class="is-size-7 ml-2">{ heading }</span>
+                    </div>
+                    <div class="column is-narrow">
+                        <button
+                            class="button is-danger is-outlined is-small"
+                            type="button"
+                            onclick={ ctx.link().callback(move |_| Msg::DeleteSubject(subject_id)) }
+                        >
+                            { REMOVE_BUTTON }
+                        </button>
+                    </div>
+                </div>
+            </div>
+        }
+    }
+
+    #[allow(clippy::unnecessary_operation)]
+    fn render_add_form(&self, ctx: &Context<Self>) -> Html {
+        let suggestions = self
+            .new_subject_type
+            .suggest_codes(&self.new_subject_code, MAX_SUGGESTIONS);
+        let dropdown_status = if self.show_suggestions && !suggestions.is_empty() {
+            "dropdown is-active"
+        } else {
+            "dropdown"
+        };
+        html! {
+            <div class="box">
+                <FormSubjectTypeSelect
+                    label={ FIELD_SUBJECT_TYPE.as_str() }
+                    value={ self.new_subject_type.clone() }
+                    data={ SubjectTypeValues::to_options(&ctx.props().subject_types) }
+                    // Keywords are entered as tags below
+                    deactivate={ vec![SubjectType::Keyword] }
+                    onchange={ ctx.link().callback(Msg::ChangeNewSubjectType) }
+                    required = true
+                />
+                <div class="field">
+                    <label class="label">{ FIELD_SUBJECT_CODE }</label>
+                    <div class={ dropdown_status } style="width: 100%">
+                        <div class="dropdown-trigger" style="width: 100%">
+                            <div class="control">
+                                <input
+                                    class="input"
+                                    type="search"
+                                    placeholder={ SUBJECT_CODE_SEARCH.as_str() }
+                                    value={ self.new_subject_code.clone() }
+                                    oninput={ ctx.link().callback(|e: InputEvent| Msg::ChangeNewSubjectCode(e.to_value())) }
+                                    onfocus={ ctx.link().callback(|_| Msg::ToggleSuggestions(true)) }
+                                    onblur={ ctx.link().callback(|_| Msg::ToggleSuggestions(false)) }
+                                />
+                            </div>
+                        </div>
+                        <div class="dropdown-menu" role="menu">
+                            <div class="dropdown-content">
+                                {
+                                    for suggestions.into_iter().map(|entry| {
+                                        let code = entry.code.to_string();
+                                        entry.as_dropdown_item(
+                                            ctx.link().callback(move |_| Msg::SelectSuggestion(code.clone())),
+                                        )
+                                    })
+                                }
+                            </div>
+                        </div>
+                    </div>
+                </div>
+                <div class="control">
+                    <button
+                        class="button is-success"
+                        type="button"
+                        disabled={ self.new_subject_code.trim().is_empty() }
+                        onclick={ ctx.link().callback(|_| Msg::CreateSubject) }
+                    >
+                        { ADD_SUBJECT }
+                    </button>
+                </div>
+            </div>
+        }
+    }
+
+    fn render_keywords(&self, ctx: &Context<Self>) -> Html {
+        let keywords = self.of_type(&SubjectType::Keyword);
+        html! {
+            <div class="box">
+                <label class="label">{ FIELD_KEYWORDS }</label>
+                <div class="tags">
+                    {
+                        for keywords.iter().map(|k| {
+                            let subject_id = k.subject_id;
+                            html! {
+                                <span class="tag is-medium">
+                                    { &k.subject_code }
+                                    <button
+                                        class="delete is-small"
+                                        type="button"
+                                        title={ REMOVE_BUTTON.as_str() }
+                                        onclick={ ctx.link().callback(move |_| Msg::DeleteSubject(subject_id)) }
+                                    />
+                                </span>
+                            }
+                        })
+                    }
+                </div>
+                // Enter submits the form, adding whatever has been typed as keywords
+                <form onsubmit={ ctx.link().callback(|e: FocusEvent| {
+                    e.prevent_default();
+                    Msg::AddKeywords
+                }) }>
+                    <div class="field has-addons">
+                        <div class="control is-expanded">
+                            <input
+                                class="input"
+                                type="text"
+                                value={ self.keyword_input.clone() }
+                                oninput={ ctx.link().callback(|e: InputEvent| Msg::ChangeKeywordInput(e.to_value())) }
+                            />
+                        </div>
+                        <div class="control">
+                            <button class="button" type="submit">{ ADD_KEYWORDS }</button>
+                        </div>
+                    </div>
+                    <p class="help">{ KEYWORDS_INFO }</p>
+                </form>
+            </div>
+        }
+    }
+}
+
+impl Component for SubjectsFormComponent {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        SubjectsFormComponent {
+            subjects: Self::sorted(&ctx.props().subjects),
+            new_subject_type: SubjectType::Thema,
+            new_subject_code: Default::default(),
+            show_suggestions: false,
+            keyword_input: Default::default(),
+            push_subject: Default::default(),
+            delete_subject: Default::default(),
+            error: None,
+        }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::ChangeNewSubjectType(subject_type) => {
+                self.new_subject_type = subject_type;
+                true
+            }
+            Msg::ChangeNewSubjectCode(code) => {
+                self.new_subject_code = code;
+                self.show_suggestions = true;
+                true
+            }
+            Msg::ToggleSuggestions(show) => {
+                self.show_suggestions = show;
+                true
+            }
+            Msg::SelectSuggestion(code) => {
+                self.new_subject_code = code;
+                self.show_suggestions = false;
+                true
+            }
+            Msg::CreateSubject => {
+                let code = self.new_subject_code.trim().to_string();
+                // Prefer the code's canonical capitalisation, as listed in the code list
+                let code = self
+                    .new_subject_type
+                    .find_code(&code)
+                    .map(|entry| entry.code.to_string())
+                    .unwrap_or(code);
+                ctx.link().send_message(Msg::CreateSubjects(
+                    self.new_subject_type.clone(),
+                    vec![code],
+                ));
+                false
+            }
+            Msg::ChangeKeywordInput(value) => {
+                // Typing a separator completes the keyword(s) entered so far
+                if value.ends_with(',') || value.ends_with(';') {
+                    ctx.link().send_message(Msg::AddKeywords);
+                }
+                self.keyword_input = value;
+                true
+            }
+            Msg::AddKeywords => {
+                let existing = self.of_type(&SubjectType::Keyword);
+                let mut keywords: Vec<String> = vec![];
+                for keyword in self.keyword_input.split([',', ';']).map(str::trim) {
+                    let duplicate = keywords.iter().any(|k| k.eq_ignore_ascii_case(keyword))
+                        || existing
+                            .iter()
+                            .any(|s| s.subject_code.eq_ignore_ascii_case(keyword));
+                    if !keyword.is_empty() && !duplicate {
+                        keywords.push(keyword.to_string());
+                    }
+                }
+                self.keyword_input.clear();
+                ctx.link()
+                    .send_message(Msg::CreateSubjects(SubjectType::Keyword, keywords));
+                true
+            }
+            Msg::CreateSubjects(subject_type, codes) => {
+                if let Some(Err(e)) = codes
+                    .iter()
+                    .map(|code| validate_subject_code(&subject_type, code))
+                    .find(|result| result.is_err())
+                {
+                    self.error = Some(e.to_string());
+                    return true;
+                }
+                let first_ordinal = self.next_ordinal(&subject_type);
+                for (ordinal, subject_code) in (first_ordinal..).zip(codes) {
+                    let body = CreateSubjectRequestBody {
+                        variables: CreateVariables {
+                            work_id: ctx.props().work_id,
+                            subject_type: subject_type.clone(),
+                            subject_code,
+                            subject_ordinal: ordinal,
+                        },
+                        ..Default::default()
+                    };
+                    let push: PushCreateSubject = Fetch::new(CreateSubjectRequest { body });
+                    ctx.link().send_future(push.fetch(Msg::SetSubjectPushState));
+                }
+                false
+            }
+            Msg::SetSubjectPushState(fetch_state) => {
+                self.push_subject.apply(fetch_state);
+                if let FetchState::Failed(_, err) = self.push_subject.as_ref().state() {
+                    expire_on_invalid_token(&err);
+                }
+                match self.push_subject.result() {
+                    None => false,
+                    Some(Ok(subject)) => {
+                        if subject.subject_type != SubjectType::Keyword {
+                            self.new_subject_code.clear();
+                        }
+                        self.subjects.push(subject);
+                        self.error = None;
+                        self.emit(ctx);
+                        true
+                    }
+                    Some(Err(e)) => {
+                        self.error = Some(e.to_string());
+                        true
+                    }
+                }
+            }
+            Msg::DeleteSubject(subject_id) => {
+                let body = DeleteSubjectRequestBody {
+                    variables: DeleteVariables { subject_id },
+                    ..Default::default()
+                };
+                self.delete_subject = Fetch::new(DeleteSubjectRequest { body });
+                ctx.link()
+                    .send_future(self.delete_subject.fetch(Msg::SetSubjectDeleteState));
+                ctx.link()
+                    .send_message(Msg::SetSubjectDeleteState(FetchAction::Fetching));
+                false
+            }
+            Msg::SetSubjectDeleteState(fetch_state) => {
+                self.delete_subject.apply(fetch_state);
+                if let FetchState::Failed(_, err) = self.delete_subject.as_ref().state() {
+                    expire_on_invalid_token(&err);
+                }
+                match self.delete_subject.result() {
+                    None => false,
+                    Some(Ok(deleted)) => {
+                        self.subjects.retain(|s| s.subject_id != deleted.subject_id);
+                        self.error = None;
+                        self.emit(ctx);
+                        true
+                    }
+                    Some(Err(e)) => {
+                        self.error = Some(e.to_string());
+                        true
+                    }
+                }
+            }
+            Msg::Move(subject_type, source, target) => {
+                let mut subjects = self.of_type(&subject_type);
+                if target >= subjects.len() {
+                    return false;
+                }
+                // Unlike contributions, subject ordinals need not be unique,
+                // so moved subjects can be saved at their new position straight away
+                let reordered = reorder(&mut subjects, source, target, |s| &mut s.subject_ordinal);
+                for subject in reordered.iter() {
+                    let body = UpdateSubjectRequestBody {
+                        variables: subject.into(),
+                        ..Default::default()
+                    };
+                    let push: PushUpdateSubject = Fetch::new(UpdateSubjectRequest { body });
+                    ctx.link()
+                        .send_future(push.fetch(Msg::SetSubjectUpdateState));
+                }
+                self.subjects.retain(|s| s.subject_type != subject_type);
+                self.subjects.extend(subjects);
+                self.subjects = Self::sorted(&Some(self.subjects.clone()));
+                true
+            }
+            Msg::SetSubjectUpdateState(action) => {
+                let mut push = PushUpdateSubject::default();
+                push.apply(action);
+                if let FetchState::Failed(_, err) = push.as_ref().state() {
+                    expire_on_invalid_token(&err);
+                }
+                match push.result() {
+                    None => false,
+                    Some(Ok(subject)) => {
+                        if let Some(s) = self
+                            .subjects
+                            .iter_mut()
+                            .find(|s| s.subject_id == subject.subject_id)
+                        {
+                            *s = subject;
+                        }
+                        self.error = None;
+                        self.emit(ctx);
+                        true
+                    }
+                    Some(Err(e)) => {
+                        self.error = Some(e.to_string());
+                        true
+                    }
+                }
+            }
+        }
+    }
+
+    fn changed(&mut self, ctx: &Context<Self>) -> bool {
+        self.subjects = Self::sorted(&ctx.props().subjects);
+        true
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let coded_types: Vec<SubjectType> =
+            SubjectTypeValues::to_options(&ctx.props().subject_types)
+                .into_iter()
+                .filter(|t| *t != SubjectType::Keyword)
+                .collect();
+        html! {
+            <div id="edit-subjects" class="my-4">
+                <div class="py-4 font-semibold text-header">{ SECTION_SUBJECTS }</div>
+                <p class="help mb-2">{ SUBJECTS_INFO }</p>
+                {
+                    match &self.error {
+                        Some(error) => html! {
+                            <div class="notification is-danger is-light py-2">{ error }</div>
+                        },
+                        None => html! {},
+                    }
+                }
+                {
+                    for coded_types.iter().map(|subject_type| {
+                        let subjects = self.of_type(subject_type);
+                        html! {
+                            for subjects
+                                .iter()
+                                .enumerate()
+                                .map(|(i, s)| self.render_subject(ctx, i, subjects.len(), s))
+                        }
+                    })
+                }
+                { self.render_add_form(ctx) }
+                { self.render_keywords(ctx) }
+            </div>
+        }
+    }
+}
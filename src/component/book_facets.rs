@@ -0,0 +1,475 @@
+use yew::html;
+use yew::prelude::*;
+use yewtil::fetch::{Fetch, FetchAction, FetchState};
+
+use crate::agent::session::expire_on_invalid_token;
+use crate::component::ToElementValue;
+use crate::i18n::format::format_integer;
+use crate::i18n::{Localized, Message};
+use crate::models::book::book_facet_records_query::{
+    BookFacetRecord, BookFacetRecordsRequest, BookFacetRecordsRequestBody,
+    FetchActionBookFacetRecords, FetchBookFacetRecords, Variables as RecordsVariables,
+};
+use crate::models::book::book_facets_query::{
+    BookFacetsRequest, BookFacetsRequestBody, FetchActionBookFacets, FetchBookFacets, Variables,
+};
+use crate::models::book::books_filter::{BookFilter, LocalFacet};
+use crate::models::book::books_query::BOOK_TYPES;
+use crate::models::search::query::WORK_STATUSES;
+use crate::models::utils::{LanguageCode, SubjectType};
+use crate::models::FacetValues;
+use crate::string::{
+    CLEAR_FILTERS_BUTTON, FACET_COUNTS_TRUNCATED, FACET_LANGUAGE, FACET_OPEN_LICENSE,
+    FACET_PUBLICATION_YEAR, FACET_SCOPE, FACET_SUBJECT_SCHEME, FACET_WORK_STATUS, FACET_WORK_TYPE,
+    REMOVE_BUTTON, YEAR_FROM_PLACEHOLDER, YEAR_TO_PLACEHOLDER,
+};
+
+/// Books requested at a time to count the facets evaluated on the client
+const RECORDS_PAGE_SIZE: i32 = 500;
+/// Books counted at most for the facets evaluated on the client
+const RECORDS_LIMIT: i32 = 5000;
+
+/// Facets of the books catalogue, with the number of books for each value. Types and statuses are
+/// counted by the API; the other facets are counted across the books' facet values, which are
+/// requested a page at a time.
+pub struct BookFacetsComponent {
+    fetch_counts: FetchBookFacets,
+    // Selections the counts were requested for, so that they are only requested again on change
+    counted: Option<Variables>,
+    fetch_records: FetchBookFacetRecords,
+    records: Vec<BookFacetRecord>,
+    // Selections the records were requested for, and whether there are more than `RECORDS_LIMIT`
+    scanned: Option<Variables>,
+    records_truncated: bool,
+    // Incremented for every scan, so that pages of earlier ones are discarded
+    records_generation: usize,
+}
+
+pub enum Msg {
+    GetCounts,
+    SetCountsFetchState(FetchActionBookFacets),
+    GetRecords(i32),
+    SetRecordsFetchState(usize, FetchActionBookFacetRecords),
+}
+
+#[derive(PartialEq, Properties)]
+pub struct Props {
+    pub filter: BookFilter,
+    // Variables of the list query, whose selections the counts are based on
+    pub variables: crate::models::utils::Variables,
+    pub onchange: Callback<BookFilter>,
+}
+
+impl BookFacetsComponent {
+    fn view_facet<T>(
+        title: Message,
+        counts: Vec<(T, i32)>,
+        selected: &[T],
+        label: impl Fn(&T) -> String,
+        toggle: impl Fn(T) -> BookFilter,
+        onchange: &Callback<BookFilter>,
+    ) -> Html
+    where
+        T: PartialEq + Clone + 'static,
+    {
+        // Selected values are always listed, so that they can be unselected
+        let counts: Vec<(T, i32)> = counts
+            .into_iter()
+            .filter(|(value, count)| *count > 0 || selected.contains(value))
+            .collect();
+        if counts.is_empty() {
+            return html! {};
+        }
+        html! {
+            <fieldset class="py-2">
+                <legend class="font-semibold text-header py-1">{ title }</legend>
+                {
+                    for counts.into_iter().map(|(value, count)| {
+                        let checked = selected.contains(&value);
+                        let text = format!("{} ({})", label(&value), format_integer(count));
+                        let next = toggle(value);
+                        html! {
+                            <label class="checkbox block">
+                                <input
+                                    type="checkbox"
+                                    class="mr-2"
+                                    checked={ checked }
+                                    onchange={ onchange.reform(move |_: Event| next.clone()) }
+                                />
+                                { text }
+                            </label>
+                        }
+                    })
+                }
+            </fieldset>
+        }
+    }
+
+    /// Books counted for the facets evaluated on the client which match the selections of every
+    /// other such facet, and the scope
+    fn counted_records<'a>(
+        &'a self,
+        filter: &'a BookFilter,
+        facet: LocalFacet,
+    ) -> impl Iterator<Item = &'a BookFacetRecord> {
+        self.records.iter().filter(move |record| {
+            filter.matches_facets(*record, Some(facet))
+                && filter
+                    .scope
+                    .as_ref()
+                    .is_none_or(|scope| scope.work_ids.contains(&record.work_id))
+        })
+    }
+
+    /// Count the books having each value of `facet`, selected values first
+    fn value_counts<T: PartialEq + Clone>(
+        &self,
+        filter: &BookFilter,
+        facet: LocalFacet,
+        selected: &[T],
+        values: impl Fn(&BookFacetRecord) -> Vec<T>,
+    ) -> Vec<(T, i32)> {
+        let mut counts: Vec<(T, i32)> = selected.iter().map(|v| (v.clone(), 0)).collect();
+        for record in self.counted_records(filter, facet) {
+            let values = values(record);
+            // A book is counted once for each of its values
+            for (i, value) in values.iter().enumerate() {
+                if values[..i].contains(value) {
+                    continue;
+                }
+                match counts.iter_mut().find(|(v, _)| v == value) {
+                    Some((_, count)) => *count += 1,
+                    None => counts.push((value.clone(), 1)),
+                }
+            }
+        }
+        counts
+    }
+
+    /// Whether only open access books are selected, with the number of them
+    fn view_open_license_facet(
+        &self,
+        filter: &BookFilter,
+        onchange: &Callback<BookFilter>,
+    ) -> Html {
+        let count = self
+            .counted_records(filter, LocalFacet::OpenLicense)
+            .filter(|record| record.has_open_license())
+            .count();
+        let toggled = BookFilter {
+            open_license_only: !filter.open_license_only,
+            ..filter.clone()
+        };
+        html! {
+            <label class="checkbox block py-2">
+                <input
+                    type="checkbox"
+                    class="mr-2"
+                    checked={ filter.open_license_only }
+                    onchange={ onchange.reform(move |_: Event| toggled.clone()) }
+                />
+                { format!("{} ({})", FACET_OPEN_LICENSE, format_integer(count)) }
+            </label>
+        }
+    }
+
+    fn language_counts(&self, filter: &BookFilter) -> Vec<(LanguageCode, i32)> {
+        let mut counts = self.value_counts(
+            filter,
+            LocalFacet::Language,
+            &filter.languages,
+            FacetValues::language_codes,
+        );
+        counts[filter.languages.len()..].sort_by_key(|(code, _)| code.localized_name());
+        counts
+    }
+
+    fn subject_type_counts(&self, filter: &BookFilter) -> Vec<(SubjectType, i32)> {
+        let mut counts = self.value_counts(
+            filter,
+            LocalFacet::SubjectScheme,
+            &filter.subject_types,
+            FacetValues::subject_types,
+        );
+        counts[filter.subject_types.len()..].sort_by(|(a, _), (b, _)| a.cmp(b));
+        counts
+    }
+
+    /// The range of years to pick from, with the number of books published in the selected one
+    fn view_year_facet(&self, filter: &BookFilter, onchange: &Callback<BookFilter>) -> Html {
+        let years: Vec<i32> = self
+            .counted_records(filter, LocalFacet::PublicationYear)
+            .filter_map(|record| record.publication_year())
+            .collect();
+        let count = self
+            .counted_records(filter, LocalFacet::PublicationYear)
+            .filter(|record| filter.matches_facets(*record, None))
+            .count();
+        // The years of the books found are suggested as the bounds
+        let earliest = years.iter().min().map(|y| y.to_string());
+        let latest = years.iter().max().map(|y| y.to_string());
+        let current = filter.clone();
+        let set_year_from = onchange.reform(move |e: InputEvent| BookFilter {
+            year_from: e.to_value().parse().ok(),
+            ..current.clone()
+        });
+        let current = filter.clone();
+        let set_year_to = onchange.reform(move |e: InputEvent| BookFilter {
+            year_to: e.to_value().parse().ok(),
+            ..current.clone()
+        });
+        html! {
+            <fieldset class="py-2">
+                <legend class="font-semibold text-header py-1">
+                    { format!("{} ({})", FACET_PUBLICATION_YEAR, format_integer(count)) }
+                </legend>
+                <div class="flex flex-row gap-2">
+                    <input
+                        class="input is-small"
+                        type="number"
+                        aria-label={ YEAR_FROM_PLACEHOLDER.as_str() }
+                        placeholder={ earliest.unwrap_or_else(|| YEAR_FROM_PLACEHOLDER.to_string()) }
+                        value={ filter.year_from.map(|y| y.to_string()).unwrap_or_default() }
+                        oninput={ set_year_from }
+                    />
+                    <input
+                        class="input is-small"
+                        type="number"
+                        aria-label={ YEAR_TO_PLACEHOLDER.as_str() }
+                        placeholder={ latest.unwrap_or_else(|| YEAR_TO_PLACEHOLDER.to_string()) }
+                        value={ filter.year_to.map(|y| y.to_string()).unwrap_or_default() }
+                        oninput={ set_year_to }
+                    />
+                </div>
+            </fieldset>
+        }
+    }
+}
+
+impl Component for BookFacetsComponent {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        ctx.link().send_message(Msg::GetCounts);
+        BookFacetsComponent {
+            fetch_counts: Default::default(),
+            counted: None,
+            fetch_records: Default::default(),
+            records: vec![],
+            scanned: None,
+            records_truncated: false,
+            records_generation: 0,
+        }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::GetCounts => {
+                let variables = Variables::from(&ctx.props().variables);
+                if self.scanned.as_ref() != Some(&variables) {
+                    self.scanned = Some(variables.clone());
+                    self.records.clear();
+                    self.records_truncated = false;
+                    self.records_generation += 1;
+                    ctx.link().send_message(Msg::GetRecords(0));
+                }
+                if self.counted.as_ref() == Some(&variables) {
+                    return false;
+                }
+                self.counted = Some(variables.clone());
+                let body = BookFacetsRequestBody {
+                    variables,
+                    ..Default::default()
+                };
+                self.fetch_counts = Fetch::new(BookFacetsRequest { body });
+                ctx.link()
+                    .send_future(self.fetch_counts.fetch(Msg::SetCountsFetchState));
+                ctx.link()
+                    .send_message(Msg::SetCountsFetchState(FetchAction::Fetching));
+                false
+            }
+            Msg::SetCountsFetchState(fetch_state) => {
+                self.fetch_counts.apply(fetch_state);
+                match self.fetch_counts.as_ref().state() {
+                    FetchState::Fetched(_) => true,
+                    FetchState::Failed(_, err) => {
+                        expire_on_invalid_token(&err);
+                        // Counts are left out, and requested again with the next selection
+                        self.counted = None;
+                        true
+                    }
+                    FetchState::NotFetching(_) | FetchState::Fetching(_) => false,
+                }
+            }
+            Msg::GetRecords(offset) => {
+                let body = BookFacetRecordsRequestBody {
+                    variables: RecordsVariables {
+                        limit: Some(RECORDS_PAGE_SIZE),
+                        offset: Some(offset),
+                        selections: self.scanned.clone().unwrap_or_default(),
+                    },
+                    ..Default::default()
+                };
+                self.fetch_records = Fetch::new(BookFacetRecordsRequest { body });
+                let generation = self.records_generation;
+                ctx.link().send_future(
+                    self.fetch_records
+                        .fetch(move |state| Msg::SetRecordsFetchState(generation, state)),
+                );
+                false
+            }
+            Msg::SetRecordsFetchState(generation, fetch_state) => {
+                if generation != self.records_generation {
+                    return false;
+                }
+                self.fetch_records.apply(fetch_state);
+                match self.fetch_records.as_ref().state() {
+                    FetchState::Fetched(body) => {
+                        let page = body.data.books.clone();
+                        let is_last = page.len() < RECORDS_PAGE_SIZE as usize;
+                        self.records.extend(page);
+                        let offset = self.records.len() as i32;
+                        if !is_last && offset < body.data.book_count {
+                            match offset < RECORDS_LIMIT {
+                                true => ctx.link().send_message(Msg::GetRecords(offset)),
+                                false => self.records_truncated = true,
+                            }
+                        }
+                        true
+                    }
+                    FetchState::Failed(_, err) => {
+                        expire_on_invalid_token(&err);
+                        // Counts are left out, and requested again with the next selection
+                        self.scanned = None;
+                        self.records.clear();
+                        true
+                    }
+                    FetchState::NotFetching(_) | FetchState::Fetching(_) => false,
+                }
+            }
+        }
+    }
+
+    fn changed(&mut self, ctx: &Context<Self>) -> bool {
+        ctx.link().send_message(Msg::GetCounts);
+        true
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let filter = &ctx.props().filter;
+        let onchange = &ctx.props().onchange;
+        let counts = match self.fetch_counts.as_ref().state() {
+            FetchState::Fetched(body) => body.data.clone(),
+            _ => Default::default(),
+        };
+        let type_counts = BOOK_TYPES
+            .iter()
+            .map(|t| (t.clone(), counts.type_count(t)))
+            .collect();
+        let status_counts = WORK_STATUSES
+            .iter()
+            .map(|s| (s.clone(), counts.status_count(s)))
+            .collect();
+        let clear_scope = BookFilter {
+            scope: None,
+            ..filter.clone()
+        };
+        let cleared = filter.cleared();
+        html! {
+            <div class="flex flex-col gap-2">
+                {
+                    match &filter.search_error {
+                        Some(error) => html! {
+                            <p class="help is-danger">{ error }</p>
+                        },
+                        None => html! {},
+                    }
+                }
+                {
+                    match &filter.scope {
+                        Some(scope) => html! {
+                            <div class="tags has-addons py-2">
+                                <span class="tag is-info is-light">
+                                    { format!("{} {}", FACET_SCOPE, scope.label) }
+                                </span>
+                                <a
+                                    class="tag is-delete"
+                                    title={ REMOVE_BUTTON.as_str() }
+                                    onclick={ onchange.reform(move |_| clear_scope.clone()) }
+                                />
+                            </div>
+                        },
+                        None => html! {},
+                    }
+                }
+                {
+                    BookFacetsComponent::view_facet(
+                        FACET_WORK_TYPE,
+                        type_counts,
+                        &filter.work_types,
+                        |t| t.localized(),
+                        |t| filter.toggled(|f| &mut f.work_types, t),
+                        onchange,
+                    )
+                }
+                {
+                    BookFacetsComponent::view_facet(
+                        FACET_WORK_STATUS,
+                        status_counts,
+                        &filter.work_statuses,
+                        |s| s.localized(),
+                        |s| filter.toggled(|f| &mut f.work_statuses, s),
+                        onchange,
+                    )
+                }
+                { self.view_year_facet(filter, onchange) }
+                {
+                    BookFacetsComponent::view_facet(
+                        FACET_LANGUAGE,
+                        self.language_counts(filter),
+                        &filter.languages,
+                        |l| l.localized_name().to_string(),
+                        |l| filter.toggled(|f| &mut f.languages, l),
+                        onchange,
+                    )
+                }
+                {
+                    BookFacetsComponent::view_facet(
+                        FACET_SUBJECT_SCHEME,
+                        self.subject_type_counts(filter),
+                        &filter.subject_types,
+                        |s| s.localized(),
+                        |s| filter.toggled(|f| &mut f.subject_types, s),
+                        onchange,
+                    )
+                }
+                { self.view_open_license_facet(filter, onchange) }
+                {
+                    match self.records_truncated {
+                        true => html! {
+                            <p class="help">
+                                { FACET_COUNTS_TRUNCATED.fill(&[format_integer(RECORDS_LIMIT).as_str()]) }
+                            </p>
+                        },
+                        false => html! {},
+                    }
+                }
+                {
+                    if filter.is_empty() {
+                        html! {}
+                    } else {
+                        html! {
+                            <button
+                                class="button is-small"
+                                onclick={ onchange.reform(move |_| cleared.clone()) }
+                            >
+                                { CLEAR_FILTERS_BUTTON }
+                            </button>
+                        }
+                    }
+                }
+            </div>
+        }
+    }
+}
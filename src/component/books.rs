@@ -1,13 +1,19 @@
+use crate::i18n::Localized;
+use crate::models::book::books_filter::BookFilter;
 use crate::models::book::books_query::BooksRequest;
 use crate::models::book::books_query::BooksRequestBody;
 use crate::models::book::books_query::FetchActionBooks;
 use crate::models::book::books_query::FetchBooks;
 use crate::models::book::books_query::Variables;
+use crate::models::book::books_query::{
+    BOOKS_QUERY_FOOTER, BOOKS_QUERY_HEADER, WORKS_SCAN_QUERY_BODY,
+};
 use crate::models::utils::Direction::Asc;
 use crate::models::utils::Direction::Desc;
 use crate::models::utils::WorkField;
 use crate::models::utils::WorkOrderBy;
 use crate::models::utils::WorkWithRelations;
+use crate::string::{DETAIL_CONTRIBUTORS, DETAIL_PUBLISHER};
 
 use super::ToElementValue;
 
@@ -24,14 +30,16 @@ pagination_component! {
     SEARCH_WORKS,
     PAGINATION_COUNT_BOOKS,
     vec![
-        WorkField::WorkId.to_string(),
-        WorkField::FullTitle.to_string(),
-        WorkField::WorkType.to_string(),
-        "Contributors".to_string(),
-        WorkField::Doi.to_string(),
-        "Publisher".to_string(),
-        WorkField::UpdatedAt.to_string(),
+        WorkField::WorkId.localized(),
+        WorkField::FullTitle.localized(),
+        WorkField::WorkType.localized(),
+        DETAIL_CONTRIBUTORS.to_string(),
+        WorkField::Doi.localized(),
+        DETAIL_PUBLISHER.to_string(),
+        WorkField::UpdatedAt.localized(),
     ],
     WorkOrderBy,
     WorkField,
+    BookFilter,
+    format!("{BOOKS_QUERY_HEADER}{WORKS_SCAN_QUERY_BODY}{BOOKS_QUERY_FOOTER}"),
 }
@@ -0,0 +1,121 @@
+use yew::html;
+use yew::prelude::*;
+use yew_agent::{Dispatched, Dispatcher};
+use yew_router::history::History;
+use yew_router::prelude::RouterScopeExt;
+use yewtil::fetch::{Fetch, FetchAction, FetchState};
+
+use crate::agent::session::{SessionAgent, SessionRequest};
+use crate::component::utils::FormInput;
+use crate::component::ToElementValue;
+use crate::models::account::LoginCredentials;
+use crate::models::utils::ThothError;
+use crate::route::AppRoute;
+use crate::service::account::{FetchActionLogin, FetchLogin, LoginRequest};
+use crate::string::{EMAIL_LABEL, LOGIN_BUTTON, LOGIN_TITLE, PASSWORD_LABEL};
+
+pub struct LoginComponent {
+    credentials: LoginCredentials,
+    fetch_login: FetchLogin,
+    session: Dispatcher<SessionAgent>,
+}
+
+pub enum Msg {
+    ChangeEmail(String),
+    ChangePassword(String),
+    Login,
+    SetLoginFetchState(FetchActionLogin),
+}
+
+impl Component for LoginComponent {
+    type Message = Msg;
+    type Properties = ();
+
+    fn create(_ctx: &Context<Self>) -> Self {
+        LoginComponent {
+            credentials: Default::default(),
+            fetch_login: Default::default(),
+            session: SessionAgent::dispatcher(),
+        }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::ChangeEmail(email) => {
+                self.credentials.email = email;
+                false
+            }
+            Msg::ChangePassword(password) => {
+                self.credentials.password = password;
+                false
+            }
+            Msg::Login => {
+                let request = LoginRequest {
+                    body: self.credentials.clone(),
+                };
+                self.fetch_login = Fetch::new(request);
+                ctx.link()
+                    .send_future(self.fetch_login.fetch(Msg::SetLoginFetchState));
+                ctx.link()
+                    .send_message(Msg::SetLoginFetchState(FetchAction::Fetching));
+                false
+            }
+            Msg::SetLoginFetchState(fetch_state) => {
+                self.fetch_login.apply(fetch_state);
+                if let FetchState::Fetched(account) = self.fetch_login.as_ref().state() {
+                    self.session.send(SessionRequest::Login(account.clone()));
+                    self.credentials.password = Default::default();
+                    ctx.link().history().unwrap().push(AppRoute::Books);
+                }
+                true
+            }
+        }
+    }
+
+    #[allow(clippy::unnecessary_operation)]
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let is_fetching = matches!(self.fetch_login.as_ref().state(), FetchState::Fetching(_));
+        html! {
+            <div class="columns is-centered py-12">
+                <div class="column is-one-third">
+                    <form
+                        class="box"
+                        onsubmit={ ctx.link().callback(|e: FocusEvent| {
+                            e.prevent_default();
+                            Msg::Login
+                        }) }
+                    >
+                        <h1 class="title">{ LOGIN_TITLE }</h1>
+                        {
+                            match self.fetch_login.as_ref().state() {
+                                FetchState::Failed(_, err) => html! {
+                                    <div class="notification is-danger">
+                                        { ThothError::from(err.clone()).to_string() }
+                                    </div>
+                                },
+                                _ => html! {},
+                            }
+                        }
+                        <FormInput
+                            label={ EMAIL_LABEL.as_str() }
+                            value={ self.credentials.email.clone() }
+                            input_type="email"
+                            oninput={ ctx.link().callback(|e: InputEvent| Msg::ChangeEmail(e.to_value())) }
+                            required=true
+                        />
+                        <FormInput
+                            label={ PASSWORD_LABEL.as_str() }
+                            value={ self.credentials.password.clone() }
+                            input_type="password"
+                            oninput={ ctx.link().callback(|e: InputEvent| Msg::ChangePassword(e.to_value())) }
+                            required=true
+                        />
+                        <button class="button is-success" type="submit" disabled={ is_fetching }>
+                            { LOGIN_BUTTON }
+                        </button>
+                    </form>
+                </div>
+            </div>
+        }
+    }
+}
@@ -1,10 +1,24 @@
 #![allow(clippy::let_unit_value)]
+// Views rendering components with properties allow `clippy::unnecessary_operation`: Yew 0.19's
+// `html!` checks the properties given with statements such as `props.label;`, which clippy reports
+
+/// Page sizes offered by paginated lists, besides the configured default
+pub const PAGE_SIZES: [i32; 4] = [10, 20, 50, 100];
+/// Number of pages either side of the current one which are linked to directly
+pub const PAGE_LINK_RADIUS: i32 = 2;
+/// Records scanned by lists whose filter is partly evaluated on the client, out of which the
+/// matching ones are paginated
+pub const LOCAL_FILTER_LIMIT: i32 = 1000;
+/// Records fetched by each request of such a scan, which is displayed as its pages arrive
+pub const LOCAL_FILTER_PAGE_SIZE: i32 = 100;
 
 #[macro_export]
 macro_rules! pagination_helpers {
     ($component:ident, $pagination_text:ident, $search_text:ident) => {
+        use $crate::i18n::format::format_integer;
         use $crate::string::$pagination_text;
         use $crate::string::$search_text;
+        use $crate::string::PAGINATION_OF;
 
         impl $component {
             fn search_text(&self) -> String {
@@ -12,15 +26,32 @@ macro_rules! pagination_helpers {
             }
 
             fn display_count(&self) -> String {
-                let offset_display = match self.offset == 0 && self.result_count > 0 {
-                    true => 1,
+                // In infinite scroll mode every page up to the current one is displayed
+                let first = match self.infinite_scroll {
+                    true => 0,
                     false => self.offset,
                 };
+                let offset_display = match first == 0 && self.result_count > 0 {
+                    true => 1,
+                    false => first,
+                };
                 let limit_display = match (self.limit + self.offset) > self.result_count {
                     true => self.result_count,
                     false => self.limit + self.offset,
                 };
-                format!("{} {}–{} of {}", $pagination_text, offset_display, limit_display, self.result_count)
+                format!(
+                    "{} {}–{} {} {}",
+                    $pagination_text,
+                    format_integer(offset_display),
+                    format_integer(limit_display),
+                    PAGINATION_OF,
+                    format_integer(self.result_count)
+                )
+            }
+
+            fn truncation_warning(&self) -> String {
+                $crate::string::LOCAL_FILTER_TRUNCATED
+                    .fill(&[format_integer($crate::component::LOCAL_FILTER_LIMIT).as_str()])
             }
 
             fn is_previous_disabled(&self) -> bool {
@@ -31,6 +62,66 @@ macro_rules! pagination_helpers {
                 self.limit + self.offset >= self.result_count
             }
 
+            fn current_page(&self) -> i32 {
+                self.offset / self.page_size + 1
+            }
+
+            fn page_count(&self) -> i32 {
+                ((self.result_count + self.page_size - 1) / self.page_size).max(1)
+            }
+
+            /// Pages to link to: the first and last ones and those around the current page,
+            /// with `None` standing in for each gap
+            fn page_numbers(&self) -> Vec<Option<i32>> {
+                let current = self.current_page();
+                let last = self.page_count();
+                let mut pages = vec![];
+                for page in 1..=last {
+                    let is_near = (page - current).abs() <= $crate::component::PAGE_LINK_RADIUS;
+                    if page == 1 || page == last || is_near {
+                        pages.push(Some(page));
+                    } else if pages.last() != Some(&None) {
+                        pages.push(None);
+                    }
+                }
+                pages
+            }
+
+            fn page_sizes(&self) -> Vec<i32> {
+                let mut sizes = $crate::component::PAGE_SIZES.to_vec();
+                for size in [$crate::config::config().page_size, self.page_size] {
+                    if !sizes.contains(&size) {
+                        sizes.push(size);
+                    }
+                }
+                sizes.sort_unstable();
+                sizes
+            }
+
+            fn page_link(&self, ctx: &Context<Self>, page: Option<i32>) -> Html {
+                match page {
+                    Some(page) => {
+                        let class = match page == self.current_page() {
+                            true => "pagination-link is-current",
+                            false => "pagination-link",
+                        };
+                        html! {
+                            <li>
+                                <a
+                                    style="color: #8c3f8d;"
+                                    class={ class }
+                                    aria-label={ format!("{} {}", $crate::string::JUMP_TO_PAGE, page) }
+                                    onclick={ ctx.link().callback(move |_| Msg::GoToPage(page)) }
+                                >{ page }</a>
+                            </li>
+                        }
+                    }
+                    None => html! {
+                        <li><span class="pagination-ellipsis">{ "…" }</span></li>
+                    },
+                }
+            }
+
             #[allow(dead_code)]
             fn pagination_controls(&self, ctx: &Context<Self>) -> Html {
                 html! {
@@ -40,32 +131,117 @@ macro_rules! pagination_helpers {
                     //     ".table{ border-width: 15px; border-style: solid; border-color: blue;}  "
                     // }
                     <nav style=" display: flex;  justify-content: center;" class="pagination is-centered" role="navigation" aria-label="pagination">
-                        <a style="color: #8c3f8d;" class="pagination-previous"
-                            onclick={ ctx.link().callback(|_| Msg::PreviousPage) }
-                            disabled={ self.is_previous_disabled() }
-                        >{ $crate::string::PREVIOUS_PAGE_BUTTON }</a>
-                        <a style="color: #8c3f8d;" class="pagination-next"
-                            onclick={ ctx.link().callback(|_| Msg::NextPage) }
-                            disabled={ self.is_next_disabled() }
-                        >{ $crate::string::NEXT_PAGE_BUTTON }</a>
+                        {
+                            // Pages are appended as the list is scrolled instead
+                            if self.infinite_scroll {
+                                html! {}
+                            } else {
+                                html! {
+                                    <>
+                                        <a style="color: #8c3f8d;" class="pagination-previous"
+                                            onclick={ ctx.link().callback(|_| Msg::PreviousPage) }
+                                            disabled={ self.is_previous_disabled() }
+                                        >{ $crate::string::PREVIOUS_PAGE_BUTTON }</a>
+                                        <a style="color: #8c3f8d;" class="pagination-next"
+                                            onclick={ ctx.link().callback(|_| Msg::NextPage) }
+                                            disabled={ self.is_next_disabled() }
+                                        >{ $crate::string::NEXT_PAGE_BUTTON }</a>
+                                        <ul class="pagination-list">
+                                            { for self.page_numbers().into_iter().map(|page| self.page_link(ctx, page)) }
+                                        </ul>
+                                    </>
+                                }
+                            }
+                        }
                         <div class="pagination-list">
                             <div class="field" style="width: 80%">
-                                <p class="control is-expanded has-icons-left">
-                                    <input
-                                        class="input"
-                                        type="search"
-                                        value={ self.search_query.clone() }
-                                        placeholder={ self.search_text() }
-                                        oninput={ ctx.link().callback(|e: InputEvent| Msg::SearchQueryChanged(e.to_value())) }
-                                    />
-                                    <span class="icon is-left">
-                                        <i class="fas fa-search" aria-hidden="true"></i>
+                                <$crate::component::search_box::SearchBoxComponent
+                                    value={ self.search_query.clone() }
+                                    placeholder={ self.search_text() }
+                                    oninput={ ctx.link().callback(Msg::SearchQueryChanged) }
+                                    onselect={ ctx.link().callback(Msg::SelectSuggestion) }
+                                />
+                            </div>
+                            <button
+                                class="button is-text"
+                                type="button"
+                                onclick={ ctx.link().callback(|_| Msg::ToggleAdvancedSearch) }
+                            >
+                                { $crate::string::ADVANCED_SEARCH }
+                            </button>
+                        </div>
+                    </nav>
+                    {
+                        if self.show_advanced_search {
+                            self.filter.view_advanced_search(
+                                &self.search_query,
+                                ctx.link().callback(Msg::SearchQueryChanged),
+                            )
+                        } else {
+                            html! {}
+                        }
+                    }
+                    <div class="field is-grouped is-grouped-multiline" style="justify-content: center;">
+                        {
+                            if self.infinite_scroll {
+                                html! {}
+                            } else {
+                                html! {
+                                    <div class="control">
+                                        <div class="field has-addons">
+                                            <p class="control">
+                                                <span class="button is-static">{ $crate::string::JUMP_TO_PAGE }</span>
+                                            </p>
+                                            <p class="control">
+                                                <input
+                                                    class="input"
+                                                    type="number"
+                                                    min="1"
+                                                    max={ self.page_count().to_string() }
+                                                    value={ self.current_page().to_string() }
+                                                    onchange={ ctx.link().callback(|e: Event| {
+                                                        Msg::GoToPage(e.to_value().parse().unwrap_or(1))
+                                                    }) }
+                                                />
+                                            </p>
+                                        </div>
+                                    </div>
+                                }
+                            }
+                        }
+                        <div class="control">
+                            <div class="field has-addons">
+                                <p class="control">
+                                    <span class="button is-static">{ $crate::string::PAGE_SIZE_LABEL }</span>
+                                </p>
+                                <p class="control">
+                                    <span class="select">
+                                        <select onchange={ ctx.link().callback(|e: Event| {
+                                            Msg::SetPageSize(e.to_value().parse().unwrap_or_default())
+                                        }) }>
+                                            {
+                                                for self.page_sizes().into_iter().map(|size| html! {
+                                                    <option value={ size.to_string() } selected={ size == self.page_size }>
+                                                        { size }
+                                                    </option>
+                                                })
+                                            }
+                                        </select>
                                     </span>
                                 </p>
                             </div>
                         </div>
-                    </nav>
-
+                        <div class="control">
+                            <label class="checkbox" style="line-height: 2.5em;">
+                                <input
+                                    type="checkbox"
+                                    checked={ self.infinite_scroll }
+                                    onchange={ ctx.link().callback(|_| Msg::ToggleInfiniteScroll) }
+                                />
+                                { " " }{ $crate::string::INFINITE_SCROLL }
+                            </label>
+                        </div>
+                    </div>
                     </>
                 }
             }
@@ -90,16 +266,23 @@ macro_rules! pagination_component {
         $table_headers:expr,
         $order_struct:ty,
         $order_field:ty,
+        $filter_state:ty,
+        $scan_query:expr,
     ) => {
         use gloo_timers::callback::Timeout;
-        use std::str::FromStr;
+        use std::cell::RefCell;
+        use wasm_bindgen::closure::Closure;
+        use wasm_bindgen::JsCast;
+        use web_sys::{Element, IntersectionObserver, IntersectionObserverEntry};
         use yew::Callback;
         use yew::html;
         use yew::prelude::Component;
         use yew::prelude::Context;
+        use yew::prelude::Event;
         use yew::prelude::Html;
-        use yew::prelude::InputEvent;
         use yew::prelude::Properties;
+        use yew::NodeRef;
+        use yew_agent::Dispatched;
         use yew_router::history::History;
         use yew_router::prelude::Link;
         use yew_router::prelude::RouterScopeExt;
@@ -108,12 +291,17 @@ macro_rules! pagination_component {
         use yewtil::fetch::FetchState;
         use yewtil::NeqAssign;
 
+        use $crate::agent::entity_cache::{CacheEntities, EntityCacheAgent};
+        use $crate::agent::session::expire_on_invalid_token;
         use $crate::component::utils::Loader;
         use $crate::component::utils::Reloader;
         use $crate::route::AppRoute;
-        use $crate::models::{CreateRoute, EditRoute, MetadataTable};
+        use $crate::models::{CreateRoute, EditRoute, ListFilter, MetadataTable};
+        use $crate::models::search::Suggestion;
         use $crate::models::utils::ThothError;
-        use $crate::DEFAULT_DEBOUNCING_TIMEOUT;
+        use $crate::service::account::AccountService;
+        use $crate::string::NEW_BUTTON;
+        use $crate::config::config;
 
         pub struct $component {
             limit: i32,
@@ -123,22 +311,215 @@ macro_rules! pagination_component {
             search_query: String,
             debounce_timeout: Option<Timeout>,
             order: $order_struct,
+            filter: $filter_state,
             data: Vec<$entity>,
             table_headers: Vec<String>,
             result_count: i32,
+            // Variables of the latest query, which facet counts are based on
+            variables: $request_variables,
             fetch_data: $fetch_data,
+            // Incremented for every query, so that responses to earlier ones are discarded
+            fetch_generation: usize,
+            show_advanced_search: bool,
+            infinite_scroll: bool,
+            // Whether the pending query fetches the next page to append to `data`
+            appending: bool,
+            // Whether `data` holds every record the API returned, up to `LOCAL_FILTER_LIMIT`, for
+            // the filter to pick the pages from, whether more of them are still being fetched,
+            // and whether the API returned more than that
+            local_scan: bool,
+            scanning: bool,
+            scan_truncated: bool,
+            // Scroll position to return to once the restored list has loaded
+            restore_scroll: Option<f64>,
+            sentinel: NodeRef,
+            observer: Option<(IntersectionObserver, Closure<dyn FnMut(js_sys::Array, IntersectionObserver)>)>,
+            observed: Option<Element>,
             // Store props value locally in order to test whether it has been updated on props change
         }
 
+        /// The list as it was left when a record was opened, so that returning to it shows the same
+        /// results at the same position
+        struct SavedListState {
+            offset: i32,
+            page_size: i32,
+            search_query: String,
+            order: $order_struct,
+            filter: $filter_state,
+            infinite_scroll: bool,
+            scroll_y: f64,
+        }
+
+        thread_local! {
+            static SAVED_STATE: RefCell<Option<SavedListState>> = RefCell::new(None);
+        }
+
         pagination_helpers! {$component, $pagination_text, $search_text}
 
+        impl $component {
+            fn filtered_data(&self) -> impl Iterator<Item = &$entity> {
+                let (skip, take) = match (self.local_scan, self.infinite_scroll) {
+                    (false, _) => (0, usize::MAX),
+                    (true, true) => (0, (self.offset + self.limit) as usize),
+                    (true, false) => (self.offset as usize, self.limit as usize),
+                };
+                self.data
+                    .iter()
+                    .filter(move |r| self.filter.matches(r))
+                    .skip(skip)
+                    .take(take)
+            }
+
+            fn count_local_matches(&mut self) {
+                if self.local_scan {
+                    self.result_count =
+                        self.data.iter().filter(|r| self.filter.matches(r)).count() as i32;
+                }
+            }
+
+            /// Query the current page, or in infinite scroll mode either the next page to append
+            /// or every page displayed so far
+            fn paginate(&mut self, ctx: &Context<Self>, appending: bool) {
+                let filter = self.filter.apply_search(&self.search_query);
+                let local_scan = self.filter.filters_locally();
+                self.appending = appending && !local_scan;
+                let order = self.order.clone();
+                let (limit, offset) = match (local_scan, self.infinite_scroll && !appending) {
+                    (true, _) => ($crate::component::LOCAL_FILTER_PAGE_SIZE, 0),
+                    (false, true) => (self.offset + self.limit, 0),
+                    (false, false) => (self.limit, self.offset),
+                };
+                let mut variables = $request_variables {
+                    limit: Some(limit),
+                    offset: Some(offset),
+                    filter: Some(filter),
+                    order: Some(order),
+                    publishers: config().publisher_scope(),
+                    ..Default::default()
+                };
+                // Facet selections are sent along with the text filter
+                self.filter.update_variables(&mut variables);
+                // Other pages of a scan already loaded, or still loading, are picked from it
+                let is_fetched = matches!(self.fetch_data.as_ref().state(), FetchState::Fetched(_));
+                if local_scan
+                    && self.local_scan
+                    && (is_fetched || self.scanning)
+                    && variables == self.variables
+                {
+                    self.count_local_matches();
+                    return;
+                }
+                self.local_scan = local_scan;
+                self.scanning = local_scan;
+                self.scan_truncated = false;
+                self.variables = variables.clone();
+                self.fetch(ctx, variables);
+            }
+
+            /// Query the next page of a scan, to append to the records scanned so far
+            fn scan_next(&mut self, ctx: &Context<Self>) {
+                let scanned = self.data.len() as i32;
+                let mut variables = self.variables.clone();
+                variables.limit = Some(
+                    $crate::component::LOCAL_FILTER_PAGE_SIZE
+                        .min($crate::component::LOCAL_FILTER_LIMIT - scanned),
+                );
+                variables.offset = Some(scanned);
+                self.appending = true;
+                self.fetch(ctx, variables);
+            }
+
+            /// Query the records for `variables`, replacing the pending query if any
+            fn fetch(&mut self, ctx: &Context<Self>, variables: $request_variables) {
+                let body = match self.local_scan {
+                    // A scan only needs what the filter and the rows read from each record
+                    true => $request_body {
+                        query: $scan_query,
+                        variables,
+                    },
+                    false => $request_body {
+                        variables,
+                        ..Default::default()
+                    },
+                };
+                let request = $request { body };
+                self.fetch_data = Fetch::new(request);
+                ctx.link().send_message(Msg::GetData);
+            }
+
+            fn save_state(&self) {
+                let scroll_y = web_sys::window()
+                    .and_then(|window| window.scroll_y().ok())
+                    .unwrap_or_default();
+                let state = SavedListState {
+                    offset: self.offset,
+                    page_size: self.page_size,
+                    search_query: self.search_query.clone(),
+                    order: self.order.clone(),
+                    filter: self.filter.clone(),
+                    infinite_scroll: self.infinite_scroll,
+                    scroll_y,
+                };
+                SAVED_STATE.with(|saved| *saved.borrow_mut() = Some(state));
+            }
+
+            /// Watch the element following the last row, so that scrolling it into view loads the
+            /// next page. Observing an element afresh reports whether it is already in view.
+            fn observe_sentinel(&mut self, ctx: &Context<Self>) {
+                let sentinel = match self.infinite_scroll {
+                    true => self.sentinel.cast::<Element>(),
+                    false => None,
+                };
+                if sentinel == self.observed {
+                    return;
+                }
+                if self.observer.is_none() {
+                    let link = ctx.link().clone();
+                    let callback = Closure::wrap(Box::new(
+                        move |entries: js_sys::Array, _: IntersectionObserver| {
+                            let in_view = entries.iter().any(|entry| {
+                                entry
+                                    .unchecked_into::<IntersectionObserverEntry>()
+                                    .is_intersecting()
+                            });
+                            if in_view {
+                                link.send_message(Msg::LoadMore);
+                            }
+                        },
+                    )
+                        as Box<dyn FnMut(js_sys::Array, IntersectionObserver)>);
+                    match IntersectionObserver::new(callback.as_ref().unchecked_ref()) {
+                        Ok(observer) => self.observer = Some((observer, callback)),
+                        Err(e) => {
+                            log::warn!("Unable to observe list for infinite scroll: {:?}", e);
+                            return;
+                        }
+                    }
+                }
+                if let Some((observer, _)) = &self.observer {
+                    observer.disconnect();
+                    if let Some(element) = &sentinel {
+                        observer.observe(element);
+                    }
+                }
+                self.observed = sentinel;
+            }
+        }
+
         pub enum Msg {
-            SetFetchState($fetch_action),
+            SetFetchState(usize, $fetch_action),
             GetData,
             PaginateData,
             SearchQueryChanged(String),
+            SelectSuggestion(Suggestion),
+            ToggleAdvancedSearch,
+            FilterChanged($filter_state),
             NextPage,
             PreviousPage,
+            GoToPage(i32),
+            SetPageSize(i32),
+            ToggleInfiniteScroll,
+            LoadMore,
             ChangeRoute(AppRoute),
             SortColumn($order_field),
         }
@@ -153,7 +534,7 @@ macro_rules! pagination_component {
 
             fn create(ctx: &Context<Self>) -> Self {
                 let offset: i32 = Default::default();
-                let page_size: i32 = 20;
+                let page_size: i32 = config().page_size;
                 let limit: i32 = page_size;
                 let search_callback = ctx.link().callback(|_| Msg::PaginateData);
                 let search_query: String = Default::default();
@@ -164,9 +545,7 @@ macro_rules! pagination_component {
                 let table_headers = $table_headers;
                 // Store props value locally in order to test whether it has been updated on props change
 
-                ctx.link().send_message(Msg::PaginateData);
-
-                $component {
+                let mut component = $component {
                     limit,
                     offset,
                     page_size,
@@ -174,51 +553,115 @@ macro_rules! pagination_component {
                     search_query,
                     debounce_timeout: None,
                     order,
+                    filter: Default::default(),
                     data,
                     table_headers,
                     result_count,
+                    variables: Default::default(),
                     fetch_data,
+                    fetch_generation: 0,
+                    show_advanced_search: false,
+                    infinite_scroll: false,
+                    appending: false,
+                    local_scan: false,
+                    scanning: false,
+                    scan_truncated: false,
+                    restore_scroll: None,
+                    sentinel: NodeRef::default(),
+                    observer: None,
+                    observed: None,
+                };
+                if let Some(saved) = SAVED_STATE.with(|saved| saved.borrow_mut().take()) {
+                    component.offset = saved.offset;
+                    component.page_size = saved.page_size;
+                    component.limit = saved.page_size;
+                    component.search_query = saved.search_query;
+                    component.order = saved.order;
+                    component.filter = saved.filter;
+                    component.infinite_scroll = saved.infinite_scroll;
+                    component.restore_scroll = Some(saved.scroll_y);
                 }
+
+                ctx.link().send_message(Msg::PaginateData);
+
+                component
             }
 
             fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
                 match msg {
-                    Msg::SetFetchState(fetch_state) => {
+                    Msg::SetFetchState(generation, fetch_state) => {
+                        if generation != self.fetch_generation {
+                            return false;
+                        }
                         self.fetch_data.apply(fetch_state);
-                        self.data = match self.fetch_data.as_ref().state() {
-                            FetchState::Fetched(body) => body.data.$result.clone(),
-                            _ => Default::default(),
-                        };
-                        self.result_count = match self.fetch_data.as_ref().state() {
-                            FetchState::Fetched(body) => body.data.$result_count,
-                            _ => Default::default(),
-                        };
+                        match self.fetch_data.as_ref().state() {
+                            FetchState::Fetched(body) => {
+                                let page = body.data.$result.clone();
+                                if !page.is_empty() {
+                                    EntityCacheAgent::dispatcher()
+                                        .send(<$entity>::cache_request(page.clone()));
+                                }
+                                let received = page.len();
+                                let total = body.data.$result_count;
+                                match self.appending {
+                                    true => self.data.extend(page),
+                                    false => self.data = page,
+                                }
+                                self.result_count = total;
+                                self.count_local_matches();
+                                self.appending = false;
+                                if self.local_scan {
+                                    // Keep scanning until every record, or the limit, is loaded
+                                    let scanned = self.data.len() as i32;
+                                    let limit = $crate::component::LOCAL_FILTER_LIMIT;
+                                    self.scanning = received > 0 && scanned < total && scanned < limit;
+                                    self.scan_truncated = scanned >= limit && total > scanned;
+                                    if self.scanning {
+                                        self.scan_next(ctx);
+                                        return true;
+                                    }
+                                }
+                                // Observe the sentinel again, in case it is still in view
+                                self.observed = None;
+                            }
+                            FetchState::Failed(_, err) => {
+                                expire_on_invalid_token(&err);
+                                match self.appending && !self.scanning {
+                                    // Keep the pages already loaded, so that the next one can be retried
+                                    true => self.offset -= self.page_size,
+                                    // A scan is only ever matched as a whole
+                                    false => {
+                                        self.data = Default::default();
+                                        self.result_count = Default::default();
+                                    }
+                                }
+                                self.appending = false;
+                                self.scanning = false;
+                            }
+                            FetchState::Fetching(_) | FetchState::NotFetching(_) => {
+                                if !self.appending {
+                                    self.data = Default::default();
+                                    self.result_count = Default::default();
+                                }
+                            }
+                        }
                         true
                     }
                     Msg::GetData => {
+                        self.fetch_generation += 1;
+                        let generation = self.fetch_generation;
+                        ctx.link().send_future(
+                            self.fetch_data
+                                .fetch(move |state| Msg::SetFetchState(generation, state)),
+                        );
                         ctx.link()
-                            .send_future(self.fetch_data.fetch(Msg::SetFetchState));
-                        ctx.link()
-                            .send_message(Msg::SetFetchState(FetchAction::Fetching));
+                            .send_message(Msg::SetFetchState(generation, FetchAction::Fetching));
                         false
                     }
                     Msg::PaginateData => {
-                        let filter = self.search_query.clone();
-                        let order = self.order.clone();
-                        let body = $request_body {
-                            variables: $request_variables {
-                                limit: Some(self.limit),
-                                offset: Some(self.offset),
-                                filter: Some(filter),
-                                order: Some(order),
-                                publishers: None,
-                            },
-                            ..Default::default()
-                        };
-                        let request = $request { body };
-                        self.fetch_data = Fetch::new(request);
-                        ctx.link().send_message(Msg::GetData);
-                        false
+                        self.paginate(ctx, false);
+                        // A page of a scan already loaded is displayed without fetching
+                        self.local_scan
                     }
                     Msg::SearchQueryChanged(query) => {
                         self.offset = 0;
@@ -231,12 +674,49 @@ macro_rules! pagination_component {
                         });
                         // start new timeout
                         let search_callback = self.search_callback.clone();
-                        let timeout = Timeout::new(DEFAULT_DEBOUNCING_TIMEOUT, move || {
+                        let timeout = Timeout::new(config().debounce_timeout, move || {
                             search_callback.emit(());
                         });
                         self.debounce_timeout = Some(timeout);
                         false
                     }
+                    Msg::SelectSuggestion(suggestion) => {
+                        if let Some(route) = suggestion.route() {
+                            ctx.link().send_message(Msg::ChangeRoute(route));
+                        } else if let Some(filter) = self.filter.with_suggestion(&suggestion) {
+                            // The suggestion replaces the text it was found for
+                            if let Some(timeout) = self.debounce_timeout.take() {
+                                timeout.cancel();
+                            }
+                            self.search_query.clear();
+                            self.filter = filter;
+                            self.offset = 0;
+                            ctx.link().send_message(Msg::PaginateData);
+                        }
+                        true
+                    }
+                    Msg::ToggleAdvancedSearch => {
+                        self.show_advanced_search = !self.show_advanced_search;
+                        true
+                    }
+                    Msg::FilterChanged(filter) => {
+                        // Only go back to the API if the selections it evaluates have changed,
+                        // the remaining ones are applied to the data already loaded
+                        let mut previous: $request_variables = Default::default();
+                        self.filter.update_variables(&mut previous);
+                        let mut next: $request_variables = Default::default();
+                        filter.update_variables(&mut next);
+                        let local_scan = filter.filters_locally();
+                        self.filter = filter;
+                        if previous != next || local_scan != self.local_scan {
+                            self.offset = 0;
+                            ctx.link().send_message(Msg::PaginateData);
+                        } else if self.local_scan {
+                            self.offset = 0;
+                            self.count_local_matches();
+                        }
+                        true
+                    }
                     Msg::NextPage => {
                         if self.limit < self.result_count && !self.is_next_disabled() {
                             self.offset += self.page_size;
@@ -251,7 +731,44 @@ macro_rules! pagination_component {
                         }
                         false
                     }
+                    Msg::GoToPage(page) => {
+                        let offset = (page.clamp(1, self.page_count()) - 1) * self.page_size;
+                        if offset != self.offset {
+                            self.offset = offset;
+                            ctx.link().send_message(Msg::PaginateData);
+                        }
+                        // Reset the page number input if it was out of range
+                        true
+                    }
+                    Msg::SetPageSize(page_size) => {
+                        if page_size > 0 && page_size != self.page_size {
+                            // Stay on the page holding the first record currently displayed
+                            self.offset = self.offset / page_size * page_size;
+                            self.page_size = page_size;
+                            self.limit = page_size;
+                            ctx.link().send_message(Msg::PaginateData);
+                        }
+                        false
+                    }
+                    Msg::ToggleInfiniteScroll => {
+                        // Either mode starts again from the first page
+                        self.infinite_scroll = !self.infinite_scroll;
+                        self.offset = 0;
+                        ctx.link().send_message(Msg::PaginateData);
+                        true
+                    }
+                    Msg::LoadMore => {
+                        let is_fetching =
+                            matches!(self.fetch_data.as_ref().state(), FetchState::Fetching(_));
+                        if self.infinite_scroll && !is_fetching && !self.is_next_disabled() {
+                            self.offset += self.page_size;
+                            self.paginate(ctx, true);
+                            return self.local_scan;
+                        }
+                        false
+                    }
                     Msg::ChangeRoute(r) => {
+                        self.save_state();
                         ctx.link().history().unwrap().push(r);
                         false
                     }
@@ -276,8 +793,30 @@ macro_rules! pagination_component {
                 false
             }
 
+            fn rendered(&mut self, ctx: &Context<Self>, _first_render: bool) {
+                match self.fetch_data.as_ref().state() {
+                    FetchState::Fetched(_) => {
+                        if let Some(scroll_y) = self.restore_scroll.take() {
+                            if let Some(window) = web_sys::window() {
+                                window.scroll_to_with_x_and_y(0.0, scroll_y);
+                            }
+                        }
+                    }
+                    FetchState::Failed(_, _) => self.restore_scroll = None,
+                    _ => {}
+                }
+                self.observe_sentinel(ctx);
+            }
+
+            fn destroy(&mut self, _ctx: &Context<Self>) {
+                if let Some((observer, _)) = &self.observer {
+                    observer.disconnect();
+                }
+            }
+
             fn view(&self, ctx: &Context<Self>) -> Html {
                 let route = <$entity>::create_route();
+                let highlights = $crate::models::search::highlight_terms(&self.search_query);
                 html! {
                     <>
                         <nav class="table">
@@ -287,8 +826,33 @@ macro_rules! pagination_component {
                                     { self.display_count() }
                                     </span>
                                 </p>
+                                {
+                                    // Records past the scan are never matched, so say so
+                                    match self.scan_truncated {
+                                        true => html! {
+                                            <p class="level-item help is-warning">
+                                                { self.truncation_warning() }
+                                            </p>
+                                        },
+                                        false => html! {},
+                                    }
+                                }
                             </div>
                             <div class="level-right">
+                                {
+                                    // Only logged in users can create new records
+                                    if AccountService::new().is_loggedin() {
+                                        html! {
+                                            <p class="level-item">
+                                                <Link<AppRoute> classes="button is-success" to={ route }>
+                                                    { NEW_BUTTON }
+                                                </Link<AppRoute>>
+                                            </p>
+                                        }
+                                    } else {
+                                        html! {}
+                                    }
+                                }
                                 <p class="level-item">
                                     <a href="tlksoft.com" />
                                 </p>
@@ -300,21 +864,42 @@ macro_rules! pagination_component {
                                 FetchState::NotFetching(_) => {
                                     html! {<Reloader onclick={ ctx.link().callback(|_| Msg::GetData) }/>}
                                 },
-                                FetchState::Fetching(_) => html! {<Loader/>},
-                                FetchState::Fetched(_body) => html! {
-                                    <div class="flex flex-col gap-6">
-                                        {
-                                            for self.data.iter().map(|r| {
-                                                let route = r.edit_route().clone();
-                                                r.as_table_row(
-                                                ctx.link().callback(move |_| { Msg::ChangeRoute(route.clone()) }))
-                                            })
-                                        }
-                                    </div>
-                                },
-                                FetchState::Failed(_, err) => html! {
+                                FetchState::Fetching(_) if !self.appending => html! {<Loader/>},
+                                FetchState::Failed(_, err) if self.data.is_empty() => html! {
                                     { ThothError::from(err).to_string() }
                                 },
+                                state => html! {
+                                    <div class="flex flex-col md:flex-row gap-6">
+                                        <aside class="md:w-64 shrink-0">
+                                            { self.filter.view_facets(&self.variables, ctx.link().callback(Msg::FilterChanged)) }
+                                        </aside>
+                                        <div class="flex flex-col gap-6 w-full">
+                                            {
+                                                for self.filtered_data().map(|r| {
+                                                    let route = r.edit_route().clone();
+                                                    r.as_table_row(
+                                                    ctx.link().callback(move |_| { Msg::ChangeRoute(route.clone()) }),
+                                                    &highlights)
+                                                })
+                                            }
+                                            {
+                                                match state {
+                                                    FetchState::Fetching(_) => html! {<Loader/>},
+                                                    FetchState::Failed(_, err) => html! {
+                                                        <>
+                                                            { ThothError::from(err).to_string() }
+                                                            <Reloader onclick={ ctx.link().callback(|_| Msg::LoadMore) }/>
+                                                        </>
+                                                    },
+                                                    _ if self.infinite_scroll => html! {
+                                                        <div ref={ self.sentinel.clone() }></div>
+                                                    },
+                                                    _ => html! {},
+                                                }
+                                            }
+                                        </div>
+                                    </div>
+                                },
                             }
                         }
                     </>
@@ -324,6 +909,173 @@ macro_rules! pagination_component {
     };
 }
 
+/// Move the item at `source` to `target` and renumber the list from 1, returning the items
+/// whose ordinal changed so that editors can save the new positions through mutations
+pub fn reorder<T: Clone>(
+    items: &mut Vec<T>,
+    source: usize,
+    target: usize,
+    ordinal: impl Fn(&mut T) -> &mut i32,
+) -> Vec<T> {
+    let moved = items.remove(source);
+    items.insert(target.min(items.len()), moved);
+    items
+        .iter_mut()
+        .enumerate()
+        .filter_map(|(i, item)| {
+            let position = i as i32 + 1;
+            let current = ordinal(item);
+            if *current != position {
+                *current = position;
+                Some(item.clone())
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+/// Step of a reordering whose ordinals must stay unique while it is saved
+pub enum ReorderStep<T> {
+    /// Save this item on its temporary ordinal
+    Park(T),
+    /// Every moved item has been parked: save these on their final ordinals
+    Finish(Vec<T>),
+    /// Saving failed: save these items back on their previous ordinals
+    RollBack(Vec<T>, String),
+    /// Some items are still being saved on their final ordinals
+    Wait,
+    /// Every moved item is saved on its final ordinal
+    Done,
+}
+
+/// Reordering of a list whose ordinals are unique within a work. Every moved item is parked on
+/// an unused ordinal, one at a time, before being given its final position, so that no save
+/// collides with an ordinal still in use. Saving stops at the first error: items already given
+/// their final position are parked again, and every parked item is returned to its previous one.
+pub struct Reorder<T> {
+    previous: Vec<T>,
+    moved: Vec<T>,
+    parked: usize,
+    offset: i32,
+    id: fn(&T) -> uuid::Uuid,
+    ordinal: fn(&mut T) -> &mut i32,
+    // Final saves still awaited, once every item is parked
+    finishing: usize,
+    // Indices into `moved` of the items saved on their final ordinals
+    finished: Vec<usize>,
+    // The first error, once saving an item on its final ordinal failed
+    error: Option<String>,
+}
+
+impl<T: Clone> Reorder<T> {
+    /// Move the item at `source` to `target`, returning the reordering with its first item to
+    /// park, or `None` if no ordinal changed
+    pub fn start(
+        items: &mut Vec<T>,
+        source: usize,
+        target: usize,
+        id: fn(&T) -> uuid::Uuid,
+        ordinal: fn(&mut T) -> &mut i32,
+    ) -> Option<(Self, T)> {
+        let before = items.clone();
+        let offset = items
+            .iter_mut()
+            .map(|item| *ordinal(item))
+            .max()
+            .unwrap_or_default()
+            + 1;
+        let moved = reorder(items, source, target, ordinal);
+        let previous = moved
+            .iter()
+            .filter_map(|item| before.iter().find(|b| id(b) == id(item)).cloned())
+            .collect();
+        let reorder = Reorder {
+            previous,
+            moved,
+            parked: 0,
+            offset,
+            id,
+            ordinal,
+            finishing: 0,
+            finished: vec![],
+            error: None,
+        };
+        let first = reorder.to_park(0)?;
+        Some((reorder, first))
+    }
+
+    /// The moved item at `index` on its temporary ordinal
+    fn to_park(&self, index: usize) -> Option<T> {
+        let mut item = self.moved.get(index)?.clone();
+        let ordinal = (self.ordinal)(&mut item);
+        *ordinal += self.offset;
+        Some(item)
+    }
+
+    /// Previous versions of every moved item except those still on their final ordinals
+    fn to_roll_back(&self) -> Vec<T> {
+        let parked = match self.error {
+            Some(_) => self.previous.len(),
+            None => self.parked,
+        };
+        self.previous[..parked]
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| !self.finished.contains(i))
+            .map(|(_, item)| item.clone())
+            .collect()
+    }
+
+    /// Record the outcome of parking the current item and return what to save next
+    pub fn parked(&mut self, result: Result<(), String>) -> ReorderStep<T> {
+        if let Err(e) = result {
+            return ReorderStep::RollBack(self.to_roll_back(), e);
+        }
+        if let Some(error) = &self.error {
+            // Rolling back: the item just parked again is off its final ordinal
+            self.finished.pop();
+            return match self.finished.last() {
+                Some(&index) => ReorderStep::Park(self.to_park(index).unwrap()),
+                None => ReorderStep::RollBack(self.previous.clone(), error.clone()),
+            };
+        }
+        self.parked += 1;
+        match self.to_park(self.parked) {
+            Some(item) => ReorderStep::Park(item),
+            None => {
+                self.finishing = self.moved.len();
+                ReorderStep::Finish(self.moved.clone())
+            }
+        }
+    }
+
+    /// Record the outcome of saving one of the items on its final ordinal, given the ID of the
+    /// item if it was saved, and return what to save next once every item has been saved
+    pub fn finished(&mut self, result: Result<uuid::Uuid, String>) -> ReorderStep<T> {
+        self.finishing = self.finishing.saturating_sub(1);
+        match result {
+            Ok(id) => {
+                if let Some(index) = self.moved.iter().position(|item| (self.id)(item) == id) {
+                    self.finished.push(index);
+                }
+            }
+            Err(e) => {
+                self.error.get_or_insert(e);
+            }
+        }
+        if self.finishing > 0 {
+            return ReorderStep::Wait;
+        }
+        match (&self.error, self.finished.last()) {
+            (None, _) => ReorderStep::Done,
+            // Park the items already on their final ordinals again, freeing their previous ones
+            (Some(_), Some(&index)) => ReorderStep::Park(self.to_park(index).unwrap()),
+            (Some(error), None) => ReorderStep::RollBack(self.previous.clone(), error.clone()),
+        }
+    }
+}
+
 pub trait ToOption {
     fn to_opt_string(self) -> Option<String>;
     fn to_opt_float(self) -> Option<f64>;
@@ -356,10 +1108,7 @@ impl ToOption for String {
     }
 
     fn to_opt_date(self) -> Option<chrono::NaiveDate> {
-        match chrono::NaiveDate::parse_from_str(&self, "%Y-%m-%d") {
-            Ok(date) => Some(date),
-            Err(_) => None,
-        }
+        chrono::NaiveDate::parse_from_str(&self, "%Y-%m-%d").ok()
     }
 }
 
@@ -386,12 +1135,14 @@ impl ToElementValue for yew::InputEvent {
 impl ToElementValue for yew::Event {
     fn to_value(self) -> String {
         use wasm_bindgen::JsCast;
-        use web_sys::HtmlSelectElement;
+        use web_sys::{HtmlInputElement, HtmlSelectElement};
         let target = self.target().expect("Failed to get Event target");
         if target.has_type::<HtmlSelectElement>() {
             target.unchecked_into::<HtmlSelectElement>().value()
+        } else if target.has_type::<HtmlInputElement>() {
+            target.unchecked_into::<HtmlInputElement>().value()
         } else {
-            // We currently only expect to encounter Select elements from Events
+            // We currently only expect to encounter Select and Input elements from Events
             unimplemented!()
         }
     }
@@ -406,8 +1157,145 @@ impl ToElementValue for Option<chrono::NaiveDate> {
     }
 }
 
+pub mod advanced_search;
 pub mod book;
+pub mod book_facets;
 pub mod books;
+pub mod login;
 pub mod navbar;
 pub mod root;
+pub mod search_box;
 pub mod utils;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::utils::Contribution;
+    use uuid::Uuid;
+
+    fn contributions(count: i32) -> Vec<Contribution> {
+        (1..=count)
+            .map(|ordinal| Contribution {
+                contribution_id: Uuid::from_u128(ordinal as u128),
+                contribution_ordinal: ordinal,
+                ..Default::default()
+            })
+            .collect()
+    }
+
+    fn start(items: &mut Vec<Contribution>) -> (Reorder<Contribution>, Contribution) {
+        Reorder::start(
+            items,
+            0,
+            2,
+            |c| c.contribution_id,
+            |c| &mut c.contribution_ordinal,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn moved_items_are_parked_one_at_a_time_before_their_final_ordinal() {
+        let mut items = contributions(3);
+        let (mut reorder, first) = start(&mut items);
+        assert_eq!(first.contribution_ordinal, 4 + 1);
+        let second = match reorder.parked(Ok(())) {
+            ReorderStep::Park(item) => item,
+            _ => panic!("expected the next item to park"),
+        };
+        assert_eq!(second.contribution_ordinal, 4 + 2);
+        reorder.parked(Ok(()));
+        match reorder.parked(Ok(())) {
+            ReorderStep::Finish(moved) => {
+                let moved: Vec<(u128, i32)> = moved
+                    .iter()
+                    .map(|c| (c.contribution_id.as_u128(), c.contribution_ordinal))
+                    .collect();
+                assert_eq!(moved, vec![(2, 1), (3, 2), (1, 3)]);
+            }
+            _ => panic!("expected every item to be parked"),
+        }
+    }
+
+    #[test]
+    fn parking_stops_at_the_first_error_and_rolls_back() {
+        let mut items = contributions(3);
+        let before = items.clone();
+        let (mut reorder, _) = start(&mut items);
+        reorder.parked(Ok(()));
+        match reorder.parked(Err("conflict".to_string())) {
+            ReorderStep::RollBack(parked, e) => {
+                assert_eq!(parked, vec![before[1].clone()]);
+                assert_eq!(e, "conflict");
+            }
+            _ => panic!("expected a rollback"),
+        }
+    }
+
+    fn finish(reorder: &mut Reorder<Contribution>) -> Vec<Contribution> {
+        reorder.parked(Ok(()));
+        reorder.parked(Ok(()));
+        match reorder.parked(Ok(())) {
+            ReorderStep::Finish(moved) => moved,
+            _ => panic!("expected every item to be parked"),
+        }
+    }
+
+    #[test]
+    fn reordering_is_done_once_every_item_is_on_its_final_ordinal() {
+        let mut items = contributions(3);
+        let (mut reorder, _) = start(&mut items);
+        let moved = finish(&mut reorder);
+        assert!(matches!(
+            reorder.finished(Ok(moved[0].contribution_id)),
+            ReorderStep::Wait
+        ));
+        assert!(matches!(
+            reorder.finished(Ok(moved[2].contribution_id)),
+            ReorderStep::Wait
+        ));
+        assert!(matches!(
+            reorder.finished(Ok(moved[1].contribution_id)),
+            ReorderStep::Done
+        ));
+    }
+
+    #[test]
+    fn finishing_errors_park_saved_items_again_and_roll_back() {
+        let mut items = contributions(3);
+        let before = items.clone();
+        let (mut reorder, _) = start(&mut items);
+        let moved = finish(&mut reorder);
+        reorder.finished(Ok(moved[0].contribution_id));
+        reorder.finished(Err("conflict".to_string()));
+        // Items on their final ordinals are parked again, one at a time, before rolling back
+        let parked_again = |step: ReorderStep<Contribution>| match step {
+            ReorderStep::Park(c) => (c.contribution_id.as_u128(), c.contribution_ordinal),
+            _ => panic!("expected an item to park again"),
+        };
+        let first = parked_again(reorder.finished(Ok(moved[2].contribution_id)));
+        let second = parked_again(reorder.parked(Ok(())));
+        assert_eq!(vec![first, second], vec![(1, 4 + 3), (2, 4 + 1)]);
+        match reorder.parked(Ok(())) {
+            ReorderStep::RollBack(mut previous, e) => {
+                previous.sort_by_key(|c| c.contribution_ordinal);
+                assert_eq!(previous, before);
+                assert_eq!(e, "conflict");
+            }
+            _ => panic!("expected a rollback"),
+        }
+    }
+
+    #[test]
+    fn nothing_is_saved_if_no_ordinal_changes() {
+        let mut items = contributions(3);
+        assert!(Reorder::start(
+            &mut items,
+            1,
+            1,
+            |c| c.contribution_id,
+            |c| &mut c.contribution_ordinal,
+        )
+        .is_none());
+    }
+}
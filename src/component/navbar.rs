@@ -1,29 +1,113 @@
+use web_sys::HtmlSelectElement;
 use yew::html;
 use yew::prelude::*;
 use yew::virtual_dom::VNode;
+use yew_agent::{Bridge, Bridged};
+use yew_router::history::History;
+use yew_router::prelude::{Link, RouterScopeExt};
 
-pub struct NavbarComponent {}
+use crate::agent::session::{SessionAgent, SessionRequest, SessionResponse};
+use crate::component::ToElementValue;
+use crate::config::config;
+use crate::i18n::{self, Locale};
+use crate::models::account::AccountDetails;
+use crate::route::AppRoute;
+use crate::service::unsaved::UnsavedChangesGuard;
+use crate::string::{LANGUAGE_LABEL, LOGIN_BUTTON, LOGOUT_BUTTON, SESSION_EXPIRED, WEBSITE_BUTTON};
 
-pub enum Msg {}
+pub struct NavbarComponent {
+    account: Option<AccountDetails>,
+    session_expired: bool,
+    session: Box<dyn Bridge<SessionAgent>>,
+    locale_select: NodeRef,
+}
+
+pub enum Msg {
+    SessionChanged(SessionResponse),
+    Logout,
+    ChangeLocale(Locale),
+}
 
 #[derive(PartialEq, Properties)]
-pub struct Props {}
+pub struct Props {
+    pub onlocalechange: Callback<Locale>,
+}
 
 impl Component for NavbarComponent {
     type Message = Msg;
     type Properties = Props;
 
-    fn create(_ctx: &Context<Self>) -> Self {
-        NavbarComponent {}
+    fn create(ctx: &Context<Self>) -> Self {
+        let session = SessionAgent::bridge(ctx.link().callback(Msg::SessionChanged));
+        NavbarComponent {
+            account: None,
+            session_expired: false,
+            session,
+            locale_select: NodeRef::default(),
+        }
     }
 
-    fn view(&self, _ctx: &Context<Self>) -> VNode {
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::SessionChanged(response) => {
+                match response {
+                    SessionResponse::LoggedIn(account) => {
+                        self.account = Some(account);
+                        self.session_expired = false;
+                    }
+                    SessionResponse::LoggedOut => self.account = None,
+                    SessionResponse::Expired => {
+                        self.account = None;
+                        self.session_expired = true;
+                        ctx.link().history().unwrap().push(AppRoute::Login);
+                    }
+                }
+                true
+            }
+            Msg::Logout => {
+                if !UnsavedChangesGuard::confirm_discard() {
+                    return false;
+                }
+                self.session.send(SessionRequest::Logout);
+                false
+            }
+            Msg::ChangeLocale(locale) => {
+                // The whole app is rendered again in the new locale, which discards any edits
+                if UnsavedChangesGuard::confirm_discard() {
+                    ctx.props().onlocalechange.emit(locale);
+                } else if let Some(select) = self.locale_select.cast::<HtmlSelectElement>() {
+                    select.set_value(i18n::locale().code());
+                }
+                false
+            }
+        }
+    }
+
+    #[allow(clippy::unnecessary_operation)]
+    fn view(&self, ctx: &Context<Self>) -> VNode {
+        let branding = &config().branding;
         html! {
+            <>
             <nav class="navbar is-warning" role="navigation" aria-label="main navigation">
                 <div class="navbar-brand">
                     <a class="navbar-item" href="/">
-                        <img src="https://cdn.thoth.pub/thoth_logo.png" width="50" height="58" style="max-height: none" />
-                        <img src="https://cdn.thoth.pub/thoth_name.png" style="margin-left: 0.5em; margin-top: 0.5em" />
+                        <img
+                            src={ branding.logo_url.clone() }
+                            alt={ branding.name.clone() }
+                            width="50"
+                            height="58"
+                            style="max-height: none"
+                        />
+                        {
+                            match &branding.wordmark_url {
+                                Some(url) => html! {
+                                    <img src={ url.clone() } style="margin-left: 0.5em; margin-top: 0.5em" />
+                                },
+                                None => html! {
+                                    <span class="font-semibold" style="margin-left: 0.5em">{ &branding.name }</span>
+                                },
+                            }
+                        }
                     </a>
 
                     <a role="button" class="navbar-burger burger" aria-label="menu" aria-expanded="false" data-target="thothNavbar">
@@ -34,15 +118,62 @@ impl Component for NavbarComponent {
                 </div>
 
                 <div class="navbar-end">
+                    <div class="navbar-item">
+                        <div class="select is-small">
+                            <select
+                                ref={ self.locale_select.clone() }
+                                aria-label={ LANGUAGE_LABEL.as_str() }
+                                onchange={ ctx.link().callback(|e: Event| {
+                                    Msg::ChangeLocale(e.to_value().parse().unwrap_or_default())
+                                }) }
+                            >
+                                {
+                                    for Locale::ALL.iter().map(|locale| html! {
+                                        <option value={ locale.code() } selected={ *locale == i18n::locale() }>
+                                            { locale.name() }
+                                        </option>
+                                    })
+                                }
+                            </select>
+                        </div>
+                    </div>
                     <div class="navbar-item">
                         <div class="buttons">
                             <a class="button primary" href="https://github.com/thoth-pub/thoth/blob/master/CHANGELOG.md">
-                                {"Website"}
+                                { WEBSITE_BUTTON }
                             </a>
+                            {
+                                match &self.account {
+                                    Some(account) => html! {
+                                        <button
+                                            class="button is-light"
+                                            title={ account.email.clone() }
+                                            onclick={ ctx.link().callback(|_| Msg::Logout) }
+                                        >
+                                            { format!("{} ({})", LOGOUT_BUTTON, account.full_name()) }
+                                        </button>
+                                    },
+                                    None => html! {
+                                        <Link<AppRoute> classes="button is-light" to={ AppRoute::Login }>
+                                            { LOGIN_BUTTON }
+                                        </Link<AppRoute>>
+                                    },
+                                }
+                            }
                         </div>
                     </div>
                 </div>
             </nav>
+            {
+                if self.session_expired {
+                    html! {
+                        <div class="notification is-warning is-light mb-0">{ SESSION_EXPIRED }</div>
+                    }
+                } else {
+                    html! {}
+                }
+            }
+            </>
         }
     }
 }
@@ -1,40 +1,116 @@
-use std::process::id;
-
 use yew::html;
 use yew::prelude::*;
 use yew::virtual_dom::VNode;
-use yew_agent::Dispatched;
+use yew_agent::{Dispatched, Dispatcher};
 use yew_router::prelude::*;
 
+use crate::agent::entity_cache::EntityCacheAgent;
 use crate::component::navbar::NavbarComponent;
-use crate::route::AppRoute;
+use crate::component::utils::Loader;
+use crate::config::{self, Config};
+use crate::i18n::Locale;
+use crate::models::utils::ThothResult;
+use crate::route::{AppRoute, PRERENDERED_ID};
+use crate::service::locale::LocaleService;
+use crate::string::{CONFIG_ERROR, NOT_IMPLEMENTED, PAGE_NOT_FOUND};
 
 use super::book::book::BookDetailComponent;
+use super::book::new_book::NewBookComponent;
 use super::books::BooksComponent;
+use super::login::LoginComponent;
 
-pub struct RootComponent {}
+pub struct RootComponent {
+    // Agents live only while connected, so keep the entity cache alive across page changes
+    _entity_cache: Dispatcher<EntityCacheAgent>,
+    // Nothing is rendered until the runtime configuration is known, as it holds the API URLs
+    config_loaded: bool,
+    // Why the configuration could not be used, in which case the app is not started
+    config_error: Option<String>,
+    locale: Locale,
+}
 
-pub enum Msg {}
+pub enum Msg {
+    SetConfig(ThothResult<Option<Config>>),
+    SetLocale(Locale),
+}
 
 impl Component for RootComponent {
     type Message = Msg;
     type Properties = ();
 
-    fn create(_ctx: &Context<Self>) -> Self {
-        RootComponent {}
+    fn create(ctx: &Context<Self>) -> Self {
+        // A <meta> tag in index.html takes precedence over config.json
+        match Config::from_meta() {
+            Ok(None) => ctx
+                .link()
+                .send_future(async { Msg::SetConfig(Config::from_file().await) }),
+            result => ctx.link().send_message(Msg::SetConfig(result)),
+        }
+        RootComponent {
+            _entity_cache: EntityCacheAgent::dispatcher(),
+            config_loaded: false,
+            config_error: None,
+            locale: LocaleService::new().init(),
+        }
     }
 
-    fn update(&mut self, _ctx: &Context<Self>, _msg: Self::Message) -> bool {
-        true
+    fn update(&mut self, _ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::SetConfig(config) => {
+                // Without any runtime configuration the compile-time defaults apply
+                match config.and_then(|config| config::init(config.unwrap_or_default())) {
+                    Ok(()) => {
+                        if let Some(document) = web_sys::window().and_then(|w| w.document()) {
+                            document.set_title(&config::config().branding.name);
+                        }
+                        self.config_loaded = true;
+                    }
+                    Err(e) => {
+                        log::error!("Cannot start: {}", e);
+                        self.config_error = Some(e.to_string());
+                    }
+                }
+                true
+            }
+            Msg::SetLocale(locale) => {
+                LocaleService::new().set(locale);
+                self.locale = locale;
+                true
+            }
+        }
     }
 
+    fn rendered(&mut self, _ctx: &Context<Self>, _first_render: bool) {
+        if !self.config_loaded {
+            return;
+        }
+        if let Some(prerendered) = web_sys::window()
+            .and_then(|w| w.document())
+            .and_then(|d| d.get_element_by_id(PRERENDERED_ID))
+        {
+            prerendered.remove();
+        }
+    }
+
+    #[allow(clippy::unnecessary_operation)]
     fn view(&self, ctx: &Context<Self>) -> VNode {
-        let render = Switch::render(move |r| switch_app(r));
+        if let Some(error) = &self.config_error {
+            return html! {
+                <div class="section">
+                    <div class="notification is-danger">{ CONFIG_ERROR.fill(&[error]) }</div>
+                </div>
+            };
+        }
+        if !self.config_loaded {
+            return html! { <Loader/> };
+        }
+        let render = Switch::render(switch_app);
 
         html! {
-            <BrowserRouter>
+            // Keyed by locale, so that every component is created again with translated text
+            <BrowserRouter key={ self.locale.code() }>
                 <header>
-                    <NavbarComponent />
+                    <NavbarComponent onlocalechange={ ctx.link().callback(Msg::SetLocale) } />
                 </header>
                 <div class="flex flex-col justify-between min-h-screen text-gray-900 dark:text-gray-100 bg-ternary-50 dark:bg-dark">
                     <Switch<AppRoute> { render } />
@@ -70,11 +146,19 @@ fn switch_app(route: &AppRoute) -> Html {
                 <BookDetailComponent book_id = {*book_id}/>
              </div>
         },
+        AppRoute::NewBook => html! {
+             <div class="section py-12">
+                <NewBookComponent />
+             </div>
+        },
+        AppRoute::Login => html! {
+            <LoginComponent />
+        },
         AppRoute::Error => html! {
-            "Page not found"
+            { PAGE_NOT_FOUND }
         },
         AppRoute::None => html! {
-            "Not Implemented"
+            { NOT_IMPLEMENTED }
         },
     }
 }
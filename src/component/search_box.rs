@@ -0,0 +1,241 @@
+use gloo_timers::callback::Timeout;
+use yew::html;
+use yew::prelude::*;
+use yewtil::fetch::{Fetch, FetchAction, FetchState};
+
+use crate::agent::session::expire_on_invalid_token;
+use crate::component::ToElementValue;
+use crate::config::config;
+use crate::models::search::query::SearchQuery;
+use crate::models::search::suggestions_query::{
+    FetchActionSuggestions, FetchSuggestions, SuggestionsRequest, SuggestionsRequestBody, Variables,
+};
+use crate::models::search::Suggestion;
+use crate::models::Dropdown;
+
+// Number of records of each kind offered below the search box
+const SUGGESTIONS_PER_KIND: i32 = 5;
+// Shortest query for which suggestions are requested
+const MIN_QUERY_LENGTH: usize = 2;
+
+/// Free-text search input offering matching titles, contributors and series as the user types
+pub struct SearchBoxComponent {
+    suggestions: Vec<Suggestion>,
+    active: Option<usize>,
+    show_suggestions: bool,
+    fetch_suggestions: FetchSuggestions,
+    // Incremented on every keystroke, so that responses to earlier queries are discarded
+    generation: usize,
+    debounce_timeout: Option<Timeout>,
+}
+
+pub enum Msg {
+    ChangeQuery(String),
+    GetSuggestions(String),
+    SetSuggestionsFetchState(usize, FetchActionSuggestions),
+    ToggleSuggestions(bool),
+    MoveActive(isize),
+    SelectActive,
+    Select(usize),
+}
+
+#[derive(PartialEq, Properties)]
+pub struct Props {
+    pub value: String,
+    pub placeholder: String,
+    pub oninput: Callback<String>,
+    pub onselect: Callback<Suggestion>,
+}
+
+impl SearchBoxComponent {
+    fn cancel_pending(&mut self) {
+        self.generation += 1;
+        if let Some(timeout) = self.debounce_timeout.take() {
+            timeout.cancel();
+        }
+    }
+}
+
+impl Component for SearchBoxComponent {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(_ctx: &Context<Self>) -> Self {
+        SearchBoxComponent {
+            suggestions: vec![],
+            active: None,
+            show_suggestions: false,
+            fetch_suggestions: Default::default(),
+            generation: 0,
+            debounce_timeout: None,
+        }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::ChangeQuery(query) => {
+                self.cancel_pending();
+                self.active = None;
+                self.show_suggestions = true;
+                if query.trim().chars().count() < MIN_QUERY_LENGTH {
+                    self.suggestions.clear();
+                } else {
+                    let link = ctx.link().clone();
+                    let pending = query.clone();
+                    let timeout = Timeout::new(config().debounce_timeout, move || {
+                        link.send_message(Msg::GetSuggestions(pending));
+                    });
+                    self.debounce_timeout = Some(timeout);
+                }
+                ctx.props().oninput.emit(query);
+                true
+            }
+            Msg::GetSuggestions(query) => {
+                // Only the free text of an advanced search names a record
+                let text = match query.parse::<SearchQuery>() {
+                    Ok(search) => search.text_filter(),
+                    Err(_) => query.trim().to_string(),
+                };
+                if text.chars().count() < MIN_QUERY_LENGTH {
+                    self.suggestions.clear();
+                    return true;
+                }
+                let body = SuggestionsRequestBody {
+                    variables: Variables {
+                        limit: Some(SUGGESTIONS_PER_KIND),
+                        filter: Some(text),
+                        publishers: config().publisher_scope(),
+                    },
+                    ..Default::default()
+                };
+                self.fetch_suggestions = Fetch::new(SuggestionsRequest { body });
+                let generation = self.generation;
+                ctx.link().send_future(
+                    self.fetch_suggestions
+                        .fetch(move |state| Msg::SetSuggestionsFetchState(generation, state)),
+                );
+                ctx.link().send_message(Msg::SetSuggestionsFetchState(
+                    generation,
+                    FetchAction::Fetching,
+                ));
+                false
+            }
+            Msg::SetSuggestionsFetchState(generation, fetch_state) => {
+                // The query has changed since this request was sent
+                if generation != self.generation {
+                    return false;
+                }
+                self.fetch_suggestions.apply(fetch_state);
+                match self.fetch_suggestions.as_ref().state() {
+                    FetchState::Fetched(body) => {
+                        self.suggestions = body.data.suggestions(&ctx.props().value);
+                        self.active = None;
+                        true
+                    }
+                    FetchState::Failed(_, err) => {
+                        expire_on_invalid_token(&err);
+                        self.suggestions.clear();
+                        true
+                    }
+                    _ => false,
+                }
+            }
+            Msg::ToggleSuggestions(show) => {
+                self.show_suggestions = show;
+                if !show {
+                    self.active = None;
+                }
+                true
+            }
+            Msg::MoveActive(step) => {
+                if self.suggestions.is_empty() {
+                    return false;
+                }
+                let count = self.suggestions.len() as isize;
+                // Wrap around at either end, starting from the top or bottom if none is active
+                let next = match self.active {
+                    Some(index) => (index as isize + step).rem_euclid(count),
+                    None if step > 0 => 0,
+                    None => count - 1,
+                };
+                self.active = Some(next as usize);
+                self.show_suggestions = true;
+                true
+            }
+            Msg::SelectActive => match self.active {
+                Some(index) => {
+                    ctx.link().send_message(Msg::Select(index));
+                    false
+                }
+                None => {
+                    self.show_suggestions = false;
+                    true
+                }
+            },
+            Msg::Select(index) => {
+                if let Some(suggestion) = self.suggestions.get(index).cloned() {
+                    self.cancel_pending();
+                    self.suggestions.clear();
+                    self.active = None;
+                    self.show_suggestions = false;
+                    ctx.props().onselect.emit(suggestion);
+                }
+                true
+            }
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let dropdown_status = if self.show_suggestions && !self.suggestions.is_empty() {
+            "dropdown is-active"
+        } else {
+            "dropdown"
+        };
+        let onkeydown = ctx.link().batch_callback(|e: KeyboardEvent| {
+            let msg = match e.key().as_str() {
+                "ArrowDown" => Msg::MoveActive(1),
+                "ArrowUp" => Msg::MoveActive(-1),
+                "Enter" => Msg::SelectActive,
+                "Escape" => Msg::ToggleSuggestions(false),
+                _ => return None,
+            };
+            e.prevent_default();
+            Some(msg)
+        });
+        html! {
+            <div class={ dropdown_status } style="width: 100%">
+                <div class="dropdown-trigger" style="width: 100%">
+                    <p class="control is-expanded has-icons-left">
+                        <input
+                            class="input"
+                            type="search"
+                            role="combobox"
+                            aria-expanded={ (self.show_suggestions && !self.suggestions.is_empty()).to_string() }
+                            value={ ctx.props().value.clone() }
+                            placeholder={ ctx.props().placeholder.clone() }
+                            oninput={ ctx.link().callback(|e: InputEvent| Msg::ChangeQuery(e.to_value())) }
+                            onkeydown={ onkeydown }
+                            onfocus={ ctx.link().callback(|_| Msg::ToggleSuggestions(true)) }
+                            onblur={ ctx.link().callback(|_| Msg::ToggleSuggestions(false)) }
+                        />
+                        <span class="icon is-left">
+                            <i class="fas fa-search" aria-hidden="true"></i>
+                        </span>
+                    </p>
+                </div>
+                <div class="dropdown-menu" role="listbox" style="width: 100%">
+                    <div class="dropdown-content">
+                        {
+                            for self.suggestions.iter().enumerate().map(|(index, suggestion)| {
+                                suggestion.as_selectable_dropdown_item(
+                                    ctx.link().callback(move |_| Msg::Select(index)),
+                                    self.active == Some(index),
+                                )
+                            })
+                        }
+                    </div>
+                </div>
+            </div>
+        }
+    }
+}
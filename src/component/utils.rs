@@ -1,6 +1,8 @@
+use std::str::FromStr;
 use uuid::Uuid;
 use yew::function_component;
 use yew::html;
+use yew::virtual_dom::VNode;
 use yew::Callback;
 use yew::Event;
 use yew::FocusEvent;
@@ -8,30 +10,24 @@ use yew::InputEvent;
 use yew::MouseEvent;
 use yew::Properties;
 
+use crate::component::ToElementValue;
+use crate::i18n::Localized;
 use crate::models::utils::ContributionType;
-use crate::models::utils::ContributionTypeValues;
 use crate::models::utils::Contributor;
 use crate::models::utils::CountryCode;
-use crate::models::utils::CountryCodeValues;
 use crate::models::utils::CurrencyCode;
-use crate::models::utils::CurrencyCodeValues;
 use crate::models::utils::ImprintWithPublisher;
 use crate::models::utils::LanguageCode;
-use crate::models::utils::LanguageCodeValues;
 use crate::models::utils::LanguageRelation;
-use crate::models::utils::LanguageRelationValues;
 use crate::models::utils::LocationPlatform;
-use crate::models::utils::LocationPlatformValues;
 use crate::models::utils::PublicationType;
-use crate::models::utils::PublicationTypeValues;
 use crate::models::utils::Publisher;
 use crate::models::utils::RelationType;
-use crate::models::utils::RelationTypeValues;
 use crate::models::utils::SeriesType;
-use crate::models::utils::SeriesTypeValues;
 use crate::models::utils::SubjectType;
-use crate::models::utils::SubjectTypeValues;
-use crate::string::RELOAD_BUTTON;
+use crate::models::utils::WorkStatus;
+use crate::models::utils::WorkType;
+use crate::string::{NO, RELOAD_BUTTON, SELECT_CONTRIBUTOR, SELECT_IMPRINT, SELECT_PUBLISHER, YES};
 
 #[derive(PartialEq, Properties)]
 pub struct FormInputProps {
@@ -177,128 +173,39 @@ pub struct FormNumberInputProps {
     pub help_text: String,
 }
 
-// #[derive(PartialEq, Properties)]
-// pub struct FormWorkTypeSelectProps {
-//     pub label: String,
-//     pub data: Vec<WorkTypeValues>,
-//     // Subset of `data` list which should be deactivated, if any
-//     #[prop_or_default]
-//     pub deactivate: Vec<WorkType>,
-//     pub value: WorkType,
-//     pub onchange: Callback<Event>,
-//     #[prop_or(false)]
-//     pub required: bool,
-// }
-
-// #[derive(PartialEq, Properties)]
-// pub struct FormWorkStatusSelectProps {
-//     pub label: String,
-//     pub data: Vec<WorkStatusValues>,
-//     pub value: WorkStatus,
-//     pub onchange: Callback<Event>,
-//     #[prop_or(false)]
-//     pub required: bool,
-// }
-
-#[derive(PartialEq, Properties)]
-pub struct FormContributionTypeSelectProps {
-    pub label: String,
-    pub data: Vec<ContributionTypeValues>,
-    pub value: ContributionType,
-    pub onchange: Callback<Event>,
-    #[prop_or(false)]
-    pub required: bool,
-}
-
-#[derive(PartialEq, Properties)]
-pub struct FormPublicationTypeSelectProps {
-    pub label: String,
-    pub data: Vec<PublicationTypeValues>,
-    pub value: PublicationType,
-    pub onchange: Callback<Event>,
-    #[prop_or(false)]
-    pub required: bool,
-}
-
-#[derive(PartialEq, Properties)]
-pub struct FormSubjectTypeSelectProps {
-    pub label: String,
-    pub data: Vec<SubjectTypeValues>,
-    pub value: SubjectType,
-    pub onchange: Callback<Event>,
-    #[prop_or(false)]
-    pub required: bool,
-}
-
-#[derive(PartialEq, Properties)]
-pub struct FormSeriesTypeSelectProps {
-    pub label: String,
-    pub data: Vec<SeriesTypeValues>,
-    pub value: SeriesType,
-    pub onchange: Callback<Event>,
-    #[prop_or(false)]
-    pub required: bool,
-}
-
 #[derive(PartialEq, Properties)]
-pub struct FormLanguageCodeSelectProps {
+pub struct FormEnumSelectProps<T: PartialEq> {
     pub label: String,
-    pub data: Vec<LanguageCodeValues>,
-    pub value: LanguageCode,
-    pub onchange: Callback<Event>,
-    #[prop_or(false)]
-    pub required: bool,
-}
-
-#[derive(PartialEq, Properties)]
-pub struct FormLanguageRelationSelectProps {
-    pub label: String,
-    pub data: Vec<LanguageRelationValues>,
-    pub value: LanguageRelation,
-    pub onchange: Callback<Event>,
-    #[prop_or(false)]
-    pub required: bool,
-}
-
-#[derive(PartialEq, Properties)]
-pub struct FormCurrencyCodeSelectProps {
-    pub label: String,
-    pub data: Vec<CurrencyCodeValues>,
-    pub value: CurrencyCode,
-    pub onchange: Callback<Event>,
-    #[prop_or(false)]
-    pub required: bool,
-}
-
-#[derive(PartialEq, Properties)]
-pub struct FormLocationPlatformSelectProps {
-    pub label: String,
-    pub data: Vec<LocationPlatformValues>,
-    pub value: LocationPlatform,
-    pub onchange: Callback<Event>,
-    #[prop_or(false)]
-    pub required: bool,
-}
-
-#[derive(PartialEq, Properties)]
-pub struct FormCountryCodeSelectProps {
-    pub label: String,
-    pub data: Vec<CountryCodeValues>,
-    pub value: Option<CountryCode>,
-    pub onchange: Callback<Event>,
+    // Usually the values of the enum as returned by GraphQL `__type` introspection
+    pub data: Vec<T>,
+    // Subset of `data` list which should be deactivated, if any
+    #[prop_or_default]
+    pub deactivate: Vec<T>,
+    pub value: Option<T>,
+    pub onchange: Callback<T>,
+    // Emitted when the placeholder option is chosen, i.e. the value is unset
+    #[prop_or_default]
+    pub onclear: Callback<()>,
+    #[prop_or_default]
+    pub onblur: Callback<FocusEvent>,
+    #[prop_or_default]
+    pub placeholder: Option<String>,
     #[prop_or(false)]
     pub required: bool,
 }
 
-#[derive(PartialEq, Properties)]
-pub struct FormRelationTypeSelectProps {
-    pub label: String,
-    pub data: Vec<RelationTypeValues>,
-    pub value: RelationType,
-    pub onchange: Callback<Event>,
-    #[prop_or(false)]
-    pub required: bool,
-}
+pub type FormWorkTypeSelect = FormEnumSelect<WorkType>;
+pub type FormWorkStatusSelect = FormEnumSelect<WorkStatus>;
+pub type FormContributionTypeSelect = FormEnumSelect<ContributionType>;
+pub type FormPublicationTypeSelect = FormEnumSelect<PublicationType>;
+pub type FormSubjectTypeSelect = FormEnumSelect<SubjectType>;
+pub type FormSeriesTypeSelect = FormEnumSelect<SeriesType>;
+pub type FormLanguageCodeSelect = FormEnumSelect<LanguageCode>;
+pub type FormLanguageRelationSelect = FormEnumSelect<LanguageRelation>;
+pub type FormCurrencyCodeSelect = FormEnumSelect<CurrencyCode>;
+pub type FormLocationPlatformSelect = FormEnumSelect<LocationPlatform>;
+pub type FormCountryCodeSelect = FormEnumSelect<CountryCode>;
+pub type FormRelationTypeSelect = FormEnumSelect<RelationType>;
 
 #[derive(PartialEq, Properties)]
 pub struct FormBooleanSelectProps {
@@ -457,544 +364,261 @@ pub fn form_text_input_extended(props: &FormTextInputExtendedProps) -> VNode {
 
 #[function_component(FormTextInput)]
 pub fn form_text_input(props: &FormTextInputProps) -> VNode {
-    html! {
-        <FormInput
-            label={ props.label.clone() }
-            value={ props.value.clone().unwrap_or_default() }
-            input_type="text"
-            oninput={ props.oninput.clone() }
-            onblur={ props.onblur.clone() }
-            required={ props.required }
-            deactivated={ props.deactivated }
-            help_text={ props.help_text.clone() }
-        />
+    #[allow(clippy::unnecessary_operation)]
+    {
+        html! {
+            <FormInput
+                label={ props.label.clone() }
+                value={ props.value.clone().unwrap_or_default() }
+                input_type="text"
+                oninput={ props.oninput.clone() }
+                onblur={ props.onblur.clone() }
+                required={ props.required }
+                deactivated={ props.deactivated }
+                help_text={ props.help_text.clone() }
+            />
+        }
     }
 }
 
 #[function_component(FormUrlInput)]
 pub fn form_url_input(props: &FormUrlInputProps) -> VNode {
-    html! {
-        <FormInput
-            label={ props.label.clone() }
-            value={ props.value.clone().unwrap_or_default() }
-            input_type="url"
-            oninput={ props.oninput.clone() }
-            onblur={ props.onblur.clone() }
-            required={ props.required }
-            help_text={ props.help_text.clone() }
-        />
+    #[allow(clippy::unnecessary_operation)]
+    {
+        html! {
+            <FormInput
+                label={ props.label.clone() }
+                value={ props.value.clone().unwrap_or_default() }
+                input_type="url"
+                oninput={ props.oninput.clone() }
+                onblur={ props.onblur.clone() }
+                required={ props.required }
+                help_text={ props.help_text.clone() }
+            />
+        }
     }
 }
 
 #[function_component(FormDateInput)]
 pub fn form_date_input(props: &FormDateInputProps) -> VNode {
-    html! {
-        <FormInput
-            label={ props.label.clone() }
-            value={ props.value.clone().unwrap_or_default() }
-            input_type="date"
-            oninput={ props.oninput.clone() }
-            onblur={ props.onblur.clone() }
-            required={ props.required }
-            deactivated={ props.deactivated }
-            help_text={ props.help_text.clone() }
-        />
+    #[allow(clippy::unnecessary_operation)]
+    {
+        html! {
+            <FormInput
+                label={ props.label.clone() }
+                value={ props.value.clone().unwrap_or_default() }
+                input_type="date"
+                oninput={ props.oninput.clone() }
+                onblur={ props.onblur.clone() }
+                required={ props.required }
+                deactivated={ props.deactivated }
+                help_text={ props.help_text.clone() }
+            />
+        }
     }
 }
 
 #[function_component(FormNumberInput)]
 pub fn form_number_input(props: &FormNumberInputProps) -> VNode {
-    html! {
-        <FormInput
-            label={ props.label.clone() }
-            value={ props.value.unwrap_or(0).to_string() }
-            input_type="number"
-            oninput={ props.oninput.clone() }
-            onblur={ props.onblur.clone() }
-            required={ props.required }
-            min={ props.min.clone() }
-            deactivated={ props.deactivated }
-            help_text={ props.help_text.clone() }
-        />
+    #[allow(clippy::unnecessary_operation)]
+    {
+        html! {
+            <FormInput
+                label={ props.label.clone() }
+                value={ props.value.unwrap_or(0).to_string() }
+                input_type="number"
+                oninput={ props.oninput.clone() }
+                onblur={ props.onblur.clone() }
+                required={ props.required }
+                min={ props.min.clone() }
+                deactivated={ props.deactivated }
+                help_text={ props.help_text.clone() }
+            />
+        }
     }
 }
 
 #[function_component(FormFloatInput)]
 pub fn form_float_input(props: &FormFloatInputProps) -> VNode {
-    html! {
-        <FormInput
-            label={ props.label.clone() }
-            value={ props.value.unwrap_or(0.00).to_string() }
-            input_type="number"
-            oninput={ props.oninput.clone() }
-            onblur={ props.onblur.clone() }
-            required={ props.required }
-            step={ props.step.clone() }
-            min={ props.min.clone() }
-            deactivated={ props.deactivated }
-            help_text={ props.help_text.clone() }
-        />
+    #[allow(clippy::unnecessary_operation)]
+    {
+        html! {
+            <FormInput
+                label={ props.label.clone() }
+                value={ props.value.unwrap_or(0.00).to_string() }
+                input_type="number"
+                oninput={ props.oninput.clone() }
+                onblur={ props.onblur.clone() }
+                required={ props.required }
+                step={ props.step.clone() }
+                min={ props.min.clone() }
+                deactivated={ props.deactivated }
+                help_text={ props.help_text.clone() }
+            />
+        }
     }
 }
 
-// #[function_component(FormWorkTypeSelect)]
-// pub fn form_work_type_select(props: &FormWorkTypeSelectProps) -> VNode {
-//     html! {
-//         <div class="field">
-//             <label class="label">{ &props.label }</label>
-//             <div class="control is-expanded">
-//                 <div class="select is-fullwidth">
-//                 <select required={ props.required } onchange={ &props.onchange }>
-//                     { for props.data.iter().map(|i| props.render_worktype(i, &props.deactivate)) }
-//                 </select>
-//                 </div>
-//             </div>
-//         </div>
-//     }
-// }
-
-// #[function_component(FormWorkStatusSelect)]
-// pub fn form_work_status_select(props: &FormWorkStatusSelectProps) -> VNode {
-//     html! {
-//         <div class="field">
-//             <label class="label">{ &props.label }</label>
-//             <div class="control is-expanded">
-//                 <div class="select is-fullwidth">
-//                 <select required={ props.required } onchange={ &props.onchange }>
-//                     { for props.data.iter().map(|i| props.render_workstatus(i)) }
-//                 </select>
-//                 </div>
-//             </div>
-//         </div>
-//     }
-// }
-
-// #[function_component(FormContributionTypeSelect)]
-// pub fn form_contribution_type_select(props: &FormContributionTypeSelectProps) -> VNode {
-//     html! {
-//         <div class="field">
-//             <label class="label">{ &props.label }</label>
-//             <div class="control is-expanded">
-//                 <div class="select">
-//                 <select
-//                     required={ props.required }
-//                     onchange={ &props.onchange }
-//                 >
-//                     { for props.data.iter().map(|i| props.render_contributiontype(i)) }
-//                 </select>
-//                 </div>
-//             </div>
-//         </div>
-//     }
-// }
-
-// #[function_component(FormPublicationTypeSelect)]
-// pub fn form_publication_type_select(props: &FormPublicationTypeSelectProps) -> VNode {
-//     html! {
-//         <div class="field">
-//             <label class="label">{ &props.label }</label>
-//             <div class="control is-expanded">
-//                 <div class="select">
-//                 <select
-//                     required={ props.required }
-//                     onchange={ &props.onchange }
-//                 >
-//                     { for props.data.iter().map(|p| props.render_publicationtype(p)) }
-//                 </select>
-//                 </div>
-//             </div>
-//         </div>
-//     }
-// }
-
-// #[function_component(FormSubjectTypeSelect)]
-// pub fn form_subject_type_select(props: &FormSubjectTypeSelectProps) -> VNode {
-//     html! {
-//         <div class="field">
-//             <label class="label">{ &props.label }</label>
-//             <div class="control is-expanded">
-//                 <div class="select">
-//                 <select
-//                     required={ props.required }
-//                     onchange={ &props.onchange }
-//                 >
-//                     { for props.data.iter().map(|p| props.render_subjecttype(p)) }
-//                 </select>
-//                 </div>
-//             </div>
-//         </div>
-//     }
-// }
-
-// #[function_component(FormSeriesTypeSelect)]
-// pub fn form_series_type_select(props: &FormSeriesTypeSelectProps) -> VNode {
-//     html! {
-//         <div class="field">
-//             <label class="label">{ &props.label }</label>
-//             <div class="control is-expanded">
-//                 <div class="select">
-//                 <select
-//                     required={ props.required }
-//                     onchange={ &props.onchange }
-//                 >
-//                     { for props.data.iter().map(|s| props.render_seriestype(s)) }
-//                 </select>
-//                 </div>
-//             </div>
-//         </div>
-//     }
-// }
-
-// #[function_component(FormLanguageCodeSelect)]
-// pub fn form_language_code_select(props: &FormLanguageCodeSelectProps) -> VNode {
-//     html! {
-//         <div class="field">
-//             <label class="label">{ &props.label }</label>
-//             <div class="control is-expanded">
-//                 <div class="select">
-//                 <select
-//                     required={ props.required }
-//                     onchange={ &props.onchange }
-//                 >
-//                     { for props.data.iter().map(|l| props.render_languagecode(l)) }
-//                 </select>
-//                 </div>
-//             </div>
-//         </div>
-//     }
-// }
-
-// #[function_component(FormLanguageRelationSelect)]
-// pub fn form_language_relation_select(props: &FormLanguageRelationSelectProps) -> VNode {
-//     html! {
-//         <div class="field">
-//             <label class="label">{ &props.label }</label>
-//             <div class="control is-expanded">
-//                 <div class="select">
-//                 <select
-//                     required={ props.required }
-//                     onchange={ &props.onchange }
-//                 >
-//                     { for props.data.iter().map(|l| props.render_languagerelation(l)) }
-//                 </select>
-//                 </div>
-//             </div>
-//         </div>
-//     }
-// }
-
-// #[function_component(FormCurrencyCodeSelect)]
-// pub fn form_currency_code_select(props: &FormCurrencyCodeSelectProps) -> VNode {
-//     html! {
-//         <div class="field">
-//             <label class="label">{ &props.label }</label>
-//             <div class="control is-expanded">
-//                 <div class="select">
-//                 <select
-//                     required={ props.required }
-//                     onchange={ &props.onchange }
-//                 >
-//                     { for props.data.iter().map(|c| props.render_currencycode(c)) }
-//                 </select>
-//                 </div>
-//             </div>
-//         </div>
-//     }
-// }
-
-// #[function_component(FormLocationPlatformSelect)]
-// pub fn form_location_platform_select(props: &FormLocationPlatformSelectProps) -> VNode {
-//     html! {
-//         <div class="field">
-//             <label class="label">{ &props.label }</label>
-//             <div class="control is-expanded">
-//                 <div class="select">
-//                 <select
-//                     required={ props.required }
-//                     onchange={ &props.onchange }
-//                 >
-//                     { for props.data.iter().map(|l| props.render_locationplatform(l)) }
-//                 </select>
-//                 </div>
-//             </div>
-//         </div>
-//     }
-// }
-
-// #[function_component(FormCountryCodeSelect)]
-// pub fn form_country_code_select(props: &FormCountryCodeSelectProps) -> VNode {
-//     html! {
-//         <div class="field">
-//             <label class="label">{ &props.label }</label>
-//             <div class="control is-expanded">
-//                 <div class="select">
-//                 <select
-//                     required={ props.required }
-//                     onchange={ &props.onchange }
-//                 >
-//                     <option value="">{"Select Country"}</option>
-//                     { for props.data.iter().map(|c| props.render_countrycode(c)) }
-//                 </select>
-//                 </div>
-//             </div>
-//         </div>
-//     }
-// }
-
-// #[function_component(FormRelationTypeSelect)]
-// pub fn form_relation_type_select(props: &FormRelationTypeSelectProps) -> VNode {
-//     html! {
-//         <div class="field">
-//             <label class="label">{ &props.label }</label>
-//             <div class="control is-expanded">
-//                 <div class="select">
-//                 <select
-//                     required={ props.required }
-//                     onchange={ &props.onchange }
-//                 >
-//                     { for props.data.iter().map(|r| props.render_relationtype(r)) }
-//                 </select>
-//                 </div>
-//             </div>
-//         </div>
-//     }
-// }
-
-// #[function_component(FormBooleanSelect)]
-// pub fn form_boolean_select(props: &FormBooleanSelectProps) -> VNode {
-//     html! {
-//         <div class="field">
-//             <label class="label">{ &props.label }</label>
-//             <div class="control is-expanded">
-//                 <div class="select">
-//                 <select
-//                     required={ props.required }
-//                     onchange={ &props.onchange }
-//                     onblur={ props.onblur.clone() }
-//                 >
-//                     <option value={ true.to_string() } selected={ props.value }>
-//                         { YES }
-//                     </option>
-//                     <option value={ false.to_string() } selected={ !props.value }>
-//                         { NO }
-//                     </option>
-//                 </select>
-//                 </div>
-//             </div>
-//         </div>
-//     }
-// }
-
-// #[function_component(FormImprintSelect)]
-// pub fn form_imprint_select(props: &FormImprintSelectProps) -> VNode {
-//     html! {
-//         <div class="field">
-//             <label class="label">{ &props.label }</label>
-//             <div class="control is-expanded">
-//                 <div class="select is-fullwidth">
-//                 <select required={ props.required } onchange={ &props.onchange }>
-//                     <option value="">{"Select Imprint"}</option>
-//                     { for props.data.iter().map(|i| props.render_imprint(i)) }
-//                 </select>
-//                 </div>
-//             </div>
-//         </div>
-//     }
-// }
-
-// #[function_component(FormPublisherSelect)]
-// pub fn form_publisher_select(props: &FormPublisherSelectProps) -> VNode {
-//     html! {
-//         <div class="field">
-//             <label class="label">{ &props.label }</label>
-//             <div class="control is-expanded">
-//                 <div class="select is-fullwidth">
-//                 <select required={ props.required } onchange={ &props.onchange }>
-//                     <option value="">{"Select Publisher"}</option>
-//                     { for props.data.iter().map(|p| props.render_publisher(p)) }
-//                 </select>
-//                 </div>
-//             </div>
-//         </div>
-//     }
-// }
-
-// #[function_component(FormContributorSelect)]
-// pub fn form_contributor_select(props: &FormContributorSelectProps) -> VNode {
-//     html! {
-//         <div class="field">
-//             <label class="label">{ &props.label }</label>
-//             <div class="control is-expanded">
-//                 <div class="select is-fullwidth">
-//                 <select required={ props.required } onchange={ &props.onchange }>
-//                     <option value="" selected={props.value.is_nil()}>{"Select Contributor"}</option>
-//                     { for props.data.iter().map(|c| props.render_contributor(c)) }
-//                 </select>
-//                 </div>
-//             </div>
-//         </div>
-//     }
-// }
-
-// impl FormWorkTypeSelectProps {
-//     fn render_worktype(&self, w: &WorkTypeValues, deactivate: &[WorkType]) -> VNode {
-//         let deactivated = deactivate.contains(&w.name);
-//         let selected = w.name == self.value;
-//         html! {
-//             <option value={w.name.to_string()} selected={selected} disabled={deactivated}>
-//                 {&w.name}
-//             </option>
-//         }
-//     }
-// }
-
-// impl FormWorkStatusSelectProps {
-//     fn render_workstatus(&self, w: &WorkStatusValues) -> VNode {
-//         html! {
-//             <option value={w.name.to_string()} selected={w.name == self.value}>
-//                 {&w.name}
-//             </option>
-//         }
-//     }
-// }
-
-// impl FormContributionTypeSelectProps {
-//     fn render_contributiontype(&self, c: &ContributionTypeValues) -> VNode {
-//         html! {
-//             <option value={c.name.to_string()} selected={c.name == self.value}>
-//                 {&c.name}
-//             </option>
-//         }
-//     }
-// }
-
-// impl FormPublicationTypeSelectProps {
-//     fn render_publicationtype(&self, p: &PublicationTypeValues) -> VNode {
-//         html! {
-//             <option value={p.name.to_string()} selected={p.name == self.value}>
-//                 {&p.name}
-//             </option>
-//         }
-//     }
-// }
-
-// impl FormSubjectTypeSelectProps {
-//     fn render_subjecttype(&self, s: &SubjectTypeValues) -> VNode {
-//         html! {
-//             <option value={s.name.to_string()} selected={s.name == self.value}>
-//                 {&s.name}
-//             </option>
-//         }
-//     }
-// }
-
-// impl FormSeriesTypeSelectProps {
-//     fn render_seriestype(&self, s: &SeriesTypeValues) -> VNode {
-//         html! {
-//             <option value={s.name.to_string()} selected={s.name == self.value}>
-//                 {&s.name}
-//             </option>
-//         }
-//     }
-// }
-
-// impl FormLanguageCodeSelectProps {
-//     fn render_languagecode(&self, l: &LanguageCodeValues) -> VNode {
-//         html! {
-//             <option value={l.name.to_string()} selected={l.name == self.value}>
-//                 {&l.name}
-//             </option>
-//         }
-//     }
-// }
+#[function_component(FormEnumSelect)]
+pub fn form_enum_select<T>(props: &FormEnumSelectProps<T>) -> VNode
+where
+    T: Clone + PartialEq + FromStr + Localized + 'static,
+{
+    let onchange = {
+        let onchange = props.onchange.clone();
+        let onclear = props.onclear.clone();
+        Callback::from(move |e: Event| match T::from_str(&e.to_value()) {
+            Ok(value) => onchange.emit(value),
+            Err(_) => onclear.emit(()),
+        })
+    };
+    html! {
+        <div class="field">
+            <label class="label">{ &props.label }</label>
+            <div class="control is-expanded">
+                <div class="select is-fullwidth">
+                <select
+                    required={ props.required }
+                    onchange={ onchange }
+                    onblur={ props.onblur.clone() }
+                >
+                    {
+                        match &props.placeholder {
+                            Some(placeholder) => html! {
+                                <option value="" selected={ props.value.is_none() }>
+                                    { placeholder }
+                                </option>
+                            },
+                            None => html! {},
+                        }
+                    }
+                    { for props.data.iter().map(|v| props.render_option(v)) }
+                </select>
+                </div>
+            </div>
+        </div>
+    }
+}
 
-// impl FormLanguageRelationSelectProps {
-//     fn render_languagerelation(&self, l: &LanguageRelationValues) -> VNode {
-//         html! {
-//             <option value={l.name.to_string()} selected={l.name == self.value}>
-//                 {&l.name}
-//             </option>
-//         }
-//     }
-// }
+#[function_component(FormBooleanSelect)]
+pub fn form_boolean_select(props: &FormBooleanSelectProps) -> VNode {
+    html! {
+        <div class="field">
+            <label class="label">{ &props.label }</label>
+            <div class="control is-expanded">
+                <div class="select">
+                <select
+                    required={ props.required }
+                    onchange={ props.onchange.clone() }
+                    onblur={ props.onblur.clone() }
+                >
+                    <option value={ true.to_string() } selected={ props.value }>
+                        { YES }
+                    </option>
+                    <option value={ false.to_string() } selected={ !props.value }>
+                        { NO }
+                    </option>
+                </select>
+                </div>
+            </div>
+        </div>
+    }
+}
 
-// impl FormCurrencyCodeSelectProps {
-//     fn render_currencycode(&self, c: &CurrencyCodeValues) -> VNode {
-//         html! {
-//             <option value={c.name.to_string()} selected={c.name == self.value}>
-//                 {&c.name}
-//             </option>
-//         }
-//     }
-// }
+#[function_component(FormImprintSelect)]
+pub fn form_imprint_select(props: &FormImprintSelectProps) -> VNode {
+    html! {
+        <div class="field">
+            <label class="label">{ &props.label }</label>
+            <div class="control is-expanded">
+                <div class="select is-fullwidth">
+                <select required={ props.required } onchange={ props.onchange.clone() }>
+                    <option value="" selected={ props.value.is_none() }>{ SELECT_IMPRINT }</option>
+                    { for props.data.iter().map(|i| props.render_imprint(i)) }
+                </select>
+                </div>
+            </div>
+        </div>
+    }
+}
 
-// impl FormLocationPlatformSelectProps {
-//     fn render_locationplatform(&self, l: &LocationPlatformValues) -> VNode {
-//         html! {
-//             <option value={l.name.to_string()} selected={l.name == self.value}>
-//                 {&l.name}
-//             </option>
-//         }
-//     }
-// }
+#[function_component(FormPublisherSelect)]
+pub fn form_publisher_select(props: &FormPublisherSelectProps) -> VNode {
+    html! {
+        <div class="field">
+            <label class="label">{ &props.label }</label>
+            <div class="control is-expanded">
+                <div class="select is-fullwidth">
+                <select required={ props.required } onchange={ props.onchange.clone() }>
+                    <option value="" selected={ props.value.is_none() }>{ SELECT_PUBLISHER }</option>
+                    { for props.data.iter().map(|p| props.render_publisher(p)) }
+                </select>
+                </div>
+            </div>
+        </div>
+    }
+}
 
-// impl FormCountryCodeSelectProps {
-//     fn render_countrycode(&self, c: &CountryCodeValues) -> VNode {
-//         if Some(c.name.clone()) == self.value {
-//             html! {
-//                 <option value={c.name.to_string()} selected={ true }>
-//                     {&c.name}
-//                 </option>
-//             }
-//         } else {
-//             html! {
-//                 <option value={c.name.to_string()}>{&c.name}</option>
-//             }
-//         }
-//     }
-// }
+#[function_component(FormContributorSelect)]
+pub fn form_contributor_select(props: &FormContributorSelectProps) -> VNode {
+    html! {
+        <div class="field">
+            <label class="label">{ &props.label }</label>
+            <div class="control is-expanded">
+                <div class="select is-fullwidth">
+                <select required={ props.required } onchange={ props.onchange.clone() }>
+                    <option value="" selected={ props.value.is_nil() }>{ SELECT_CONTRIBUTOR }</option>
+                    { for props.data.iter().map(|c| props.render_contributor(c)) }
+                </select>
+                </div>
+            </div>
+        </div>
+    }
+}
 
-// impl FormRelationTypeSelectProps {
-//     fn render_relationtype(&self, r: &RelationTypeValues) -> VNode {
-//         html! {
-//             <option value={r.name.to_string()} selected={r.name == self.value}>
-//                 {&r.name}
-//             </option>
-//         }
-//     }
-// }
+impl<T: Clone + PartialEq + Localized> FormEnumSelectProps<T> {
+    fn render_option(&self, v: &T) -> VNode {
+        let selected = self.value.as_ref() == Some(v);
+        let deactivated = self.deactivate.contains(v);
+        html! {
+            <option value={ v.to_string() } selected={ selected } disabled={ deactivated }>
+                { v.localized() }
+            </option>
+        }
+    }
+}
 
-// impl FormImprintSelectProps {
-//     fn render_imprint(&self, i: &ImprintWithPublisher) -> VNode {
-//         let value = &self.value.unwrap_or_default();
-//         html! {
-//             <option value={i.imprint_id.to_string()} selected={&i.imprint_id == value}>
-//                 {&i.imprint_name}
-//             </option>
-//         }
-//     }
-// }
+impl FormImprintSelectProps {
+    fn render_imprint(&self, i: &ImprintWithPublisher) -> VNode {
+        let value = &self.value.unwrap_or_default();
+        html! {
+            <option value={ i.imprint_id.to_string() } selected={ &i.imprint_id == value }>
+                { &i.imprint_name }
+            </option>
+        }
+    }
+}
 
-// impl FormPublisherSelectProps {
-//     fn render_publisher(&self, p: &Publisher) -> VNode {
-//         let value = &self.value.unwrap_or_default();
-//         html! {
-//             <option value={p.publisher_id.to_string()} selected={&p.publisher_id == value}>
-//                 {&p.publisher_name}
-//             </option>
-//         }
-//     }
-// }
+impl FormPublisherSelectProps {
+    fn render_publisher(&self, p: &Publisher) -> VNode {
+        let value = &self.value.unwrap_or_default();
+        html! {
+            <option value={ p.publisher_id.to_string() } selected={ &p.publisher_id == value }>
+                { &p.publisher_name }
+            </option>
+        }
+    }
+}
 
-// impl FormContributorSelectProps {
-//     fn render_contributor(&self, c: &Contributor) -> VNode {
-//         html! {
-//             <option value={c.contributor_id.to_string()} selected={c.contributor_id == self.value}>
-//                 {&c.to_string()}
-//             </option>
-//         }
-//     }
-// }
+impl FormContributorSelectProps {
+    fn render_contributor(&self, c: &Contributor) -> VNode {
+        html! {
+            <option value={ c.contributor_id.to_string() } selected={ c.contributor_id == self.value }>
+                { &c.full_name }
+            </option>
+        }
+    }
+}
 
 #[function_component(Loader)]
 pub fn loader() -> VNode {
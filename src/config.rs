@@ -0,0 +1,171 @@
+use serde::{Deserialize, Serialize};
+use std::sync::OnceLock;
+use uuid::Uuid;
+
+use crate::models::utils::{ThothError, ThothResult};
+
+/// Name of the `<meta>` tag whose `content` may hold the configuration as JSON
+#[cfg(any(target_arch = "wasm32", test))]
+const CONFIG_META_NAME: &str = "thoth-config";
+/// File requested from the directory `index.html` is served from, if no `<meta>` tag is present
+#[cfg(any(target_arch = "wasm32", test))]
+const CONFIG_FILE: &str = "config.json";
+
+// Values baked in at compile time, used for anything the deployment does not configure
+const DEFAULT_GRAPHQL_API: &str = match option_env!("THOTH_GRAPHQL_API") {
+    Some(url) => url,
+    None => "http://localhost:8000",
+};
+const DEFAULT_EXPORT_API: &str = match option_env!("THOTH_EXPORT_API") {
+    Some(url) => url,
+    None => "http://localhost:8181",
+};
+const DEFAULT_PAGE_SIZE: i32 = 20;
+/// Default number of milliseconds to wait before sending a search query
+const DEFAULT_DEBOUNCING_TIMEOUT: u32 = 500;
+
+static CONFIG: OnceLock<Config> = OnceLock::new();
+
+/// Per-deployment settings, read at startup so that one build can be served by any instance
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Config {
+    pub graphql_api: String,
+    // Must be a public facing URL, as it is linked to directly
+    pub export_api: String,
+    pub page_size: i32,
+    pub debounce_timeout: u32,
+    // Publishers whose records are listed and editable by default, or all of them if unset
+    pub publishers: Option<Vec<Uuid>>,
+    pub branding: Branding,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Branding {
+    pub name: String,
+    pub logo_url: String,
+    pub wordmark_url: Option<String>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            graphql_api: DEFAULT_GRAPHQL_API.to_string(),
+            export_api: DEFAULT_EXPORT_API.to_string(),
+            page_size: DEFAULT_PAGE_SIZE,
+            debounce_timeout: DEFAULT_DEBOUNCING_TIMEOUT,
+            publishers: None,
+            branding: Default::default(),
+        }
+    }
+}
+
+impl Default for Branding {
+    fn default() -> Self {
+        Branding {
+            name: "Thoth".to_string(),
+            logo_url: "https://cdn.thoth.pub/thoth_logo.png".to_string(),
+            wordmark_url: Some("https://cdn.thoth.pub/thoth_name.png".to_string()),
+        }
+    }
+}
+
+impl Config {
+    /// Publisher scope in the form expected by query variables
+    pub fn publisher_scope(&self) -> Option<Vec<String>> {
+        self.publishers
+            .as_ref()
+            .map(|publishers| publishers.iter().map(|p| p.to_string()).collect())
+    }
+
+    /// Check the settings which cannot be used as they are, e.g. API URLs which do not parse
+    pub fn validate(&self) -> ThothResult<()> {
+        for (name, value) in [
+            ("graphqlApi", &self.graphql_api),
+            ("exportApi", &self.export_api),
+        ] {
+            url::Url::parse(value).map_err(|e| {
+                ThothError::InternalError(format!("{name} is not a valid URL ({value}): {e}"))
+            })?;
+        }
+        if self.page_size < 1 {
+            return Err(ThothError::InternalError(format!(
+                "pageSize must be positive, not {}",
+                self.page_size
+            )));
+        }
+        Ok(())
+    }
+
+    /// Read the configuration embedded in the page, if any
+    #[cfg(any(target_arch = "wasm32", test))]
+    pub fn from_meta() -> ThothResult<Option<Config>> {
+        let content = web_sys::window()
+            .and_then(|window| window.document())
+            .and_then(|document| {
+                document
+                    .query_selector(&format!("meta[name=\"{CONFIG_META_NAME}\"]"))
+                    .ok()
+                    .flatten()
+            })
+            .and_then(|meta| meta.get_attribute("content"));
+        match content {
+            Some(content) => serde_json::from_str(&content).map(Some).map_err(|e| {
+                ThothError::InternalError(format!("Invalid {CONFIG_META_NAME} meta tag: {e}"))
+            }),
+            None => Ok(None),
+        }
+    }
+
+    /// Request `config.json` from alongside `index.html`, returning `None` if there is none
+    #[cfg(any(target_arch = "wasm32", test))]
+    pub async fn from_file() -> ThothResult<Option<Config>> {
+        // Resolve against the document base rather than the current route, which may be nested
+        let base = web_sys::window()
+            .and_then(|window| window.document())
+            .and_then(|document| document.base_uri().ok().flatten())
+            .ok_or_else(|| ThothError::InternalError("Document has no base URL".to_string()))?;
+        let url = url::Url::parse(&base)
+            .and_then(|base| base.join(CONFIG_FILE))
+            .map_err(|e| ThothError::InternalError(format!("Invalid base URL {base}: {e}")))?;
+        let error = |e: reqwest::Error| ThothError::InternalError(format!("{CONFIG_FILE}: {e}"));
+        let response = reqwest::get(url).await.map_err(error)?;
+        // Deployments relying on the compile-time defaults do not serve one
+        if response.status() == reqwest::StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+        let response = response.error_for_status().map_err(error)?;
+        response.json().await.map(Some).map_err(error)
+    }
+}
+
+/// Store the configuration for the rest of the session, failing if one was stored already
+pub fn init(config: Config) -> ThothResult<()> {
+    config.validate()?;
+    CONFIG.set(config).map_err(|_| {
+        ThothError::InternalError("The configuration has already been loaded".to_string())
+    })
+}
+
+/// The configuration loaded at startup. Reading it before `init` is a bug, as it would fix the
+/// compile-time defaults for the rest of the session, so it panics instead.
+pub fn config() -> &'static Config {
+    CONFIG
+        .get()
+        .expect("The configuration was read before being loaded with config::init")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn invalid_settings_are_rejected() {
+        assert!(Config::default().validate().is_ok());
+        let config: Config = serde_json::from_str(r#"{"graphqlApi": "api.example.org"}"#).unwrap();
+        assert!(config.validate().is_err());
+        let config: Config = serde_json::from_str(r#"{"pageSize": 0}"#).unwrap();
+        assert!(config.validate().is_err());
+    }
+}
@@ -0,0 +1,292 @@
+//! Native build step which writes a sitemap of the catalogue, and RSS and Atom feeds of new and
+//! forthcoming titles, for discovery services and newsletters
+
+use chrono::Utc;
+use std::fs;
+use std::path::PathBuf;
+use uuid::Uuid;
+
+use crate::catalogue::{self, Catalogue};
+use crate::config::config;
+use crate::i18n::{self, Locale};
+use crate::models::book::books_query::Variables;
+use crate::models::utils::{
+    Direction, ImprintWithPublisher, ThothError, ThothResult, WorkField, WorkOrderBy, WorkStatus,
+    WorkWithRelations,
+};
+use crate::string::{FEED_DESCRIPTION, FEED_TITLE};
+
+pub mod render;
+
+use render::Feed;
+
+// Entries in each feed unless otherwise requested
+const DEFAULT_LIMIT: usize = 50;
+
+// Statuses of the new and forthcoming titles which feeds list
+const FEED_STATUSES: [WorkStatus; 2] = [WorkStatus::Active, WorkStatus::Forthcoming];
+
+pub const USAGE: &str = "Usage: thoth-ui feeds --base-url URL [--dist DIR] [--config FILE] [--fixture FILE]
+                       [--name NAME] [--limit N] [--locale CODE]
+                       [--publisher ID]... [--imprint ID]... [--series ID]...
+
+  --base-url URL   Public URL the catalogue is served from
+  --dist DIR       Directory to write sitemap.xml, NAME.rss and NAME.atom to [default: pkg]
+  --config FILE    Runtime configuration, as served to the app [default: DIR/config.json]
+  --fixture FILE   JSON array of works to use instead of querying the GraphQL API
+  --name NAME      File name of the feeds, e.g. to keep feeds of several imprints [default: new-titles]
+  --limit N        Number of titles in each feed [default: 50]
+  --locale CODE    Language of the feeds' titles and descriptions, e.g. de or fr [default: en]
+  --publisher ID   Only include titles of the given publisher in the feeds
+  --imprint ID     Only include titles of the given imprint in the feeds
+  --series ID      Only include titles in the given series in the feeds";
+
+/// Records which a feed is restricted to. Each list left empty allows any value.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FeedFilter {
+    pub publishers: Vec<Uuid>,
+    pub imprints: Vec<Uuid>,
+    pub series: Vec<Uuid>,
+}
+
+impl FeedFilter {
+    /// Whether the work is new or forthcoming, and from one of the selected imprints or publishers.
+    /// Works as returned by the list query, which leaves out series, can be checked with this.
+    pub fn matches_listed(&self, work: &WorkWithRelations) -> bool {
+        let imprint = &work.imprint;
+        FEED_STATUSES.contains(&work.work_status)
+            && (self.publishers.is_empty()
+                || self.publishers.contains(&imprint.publisher.publisher_id))
+            && (self.imprints.is_empty() || self.imprints.contains(&imprint.imprint_id))
+    }
+
+    /// Publishers whose works the API is asked for: those selected, or else the publishers of
+    /// the selected imprints, or `None` if neither is restricted
+    pub fn publishers(&self, imprints: &[ImprintWithPublisher]) -> Option<Vec<String>> {
+        let publishers: Vec<Uuid> = match self.publishers.is_empty() {
+            false => self.publishers.clone(),
+            true => {
+                let mut publishers = vec![];
+                for imprint in imprints {
+                    let publisher_id = imprint.publisher.publisher_id;
+                    if self.imprints.contains(&imprint.imprint_id)
+                        && !publishers.contains(&publisher_id)
+                    {
+                        publishers.push(publisher_id);
+                    }
+                }
+                publishers
+            }
+        };
+        match self.publishers.is_empty() && self.imprints.is_empty() {
+            true => None,
+            false => Some(publishers.iter().map(|p| p.to_string()).collect()),
+        }
+    }
+
+    /// Whether the full record of a work matches every selection
+    pub fn matches(&self, work: &WorkWithRelations) -> bool {
+        self.matches_listed(work)
+            && (self.series.is_empty()
+                || work
+                    .issues
+                    .iter()
+                    .flatten()
+                    .any(|issue| self.series.contains(&issue.series_id)))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Options {
+    pub base_url: String,
+    pub dist: PathBuf,
+    pub config: Option<PathBuf>,
+    pub fixture: Option<PathBuf>,
+    pub name: String,
+    pub limit: usize,
+    pub locale: Locale,
+    pub filter: FeedFilter,
+}
+
+impl Options {
+    pub fn from_args(mut args: impl Iterator<Item = String>) -> ThothResult<Self> {
+        let mut base_url = None;
+        let mut options = Options {
+            base_url: String::new(),
+            dist: PathBuf::from("pkg"),
+            config: None,
+            fixture: None,
+            name: "new-titles".to_string(),
+            limit: DEFAULT_LIMIT,
+            locale: Locale::default(),
+            filter: Default::default(),
+        };
+        while let Some(arg) = args.next() {
+            let value = args
+                .next()
+                .ok_or_else(|| ThothError::InternalError(format!("{arg} requires a value")))?;
+            match arg.as_str() {
+                "--base-url" => base_url = Some(value.trim_end_matches('/').to_string()),
+                "--dist" => options.dist = PathBuf::from(value),
+                "--config" => options.config = Some(PathBuf::from(value)),
+                "--fixture" => options.fixture = Some(PathBuf::from(value)),
+                "--name" => options.name = value,
+                "--limit" => {
+                    options.limit = value.parse().map_err(|_| {
+                        ThothError::InternalError(format!("{value} is not a valid limit"))
+                    })?
+                }
+                "--locale" => options.locale = value.parse()?,
+                "--publisher" => options.filter.publishers.push(Uuid::parse_str(&value)?),
+                "--imprint" => options.filter.imprints.push(Uuid::parse_str(&value)?),
+                "--series" => options.filter.series.push(Uuid::parse_str(&value)?),
+                _ => {
+                    return Err(ThothError::InternalError(format!(
+                        "unrecognised option {arg}\n\n{USAGE}"
+                    )))
+                }
+            }
+        }
+        options.base_url = base_url.ok_or_else(|| {
+            ThothError::InternalError(format!("--base-url is required\n\n{USAGE}"))
+        })?;
+        Ok(options)
+    }
+}
+
+/// Write the sitemap and both feeds
+pub async fn run(options: Options) -> ThothResult<()> {
+    i18n::set_locale(options.locale);
+    catalogue::load_config(&options.dist, options.config.as_deref())?;
+    let catalogue = Catalogue::new(options.fixture.as_deref())?;
+    fs::create_dir_all(&options.dist)?;
+
+    // The sitemap covers the whole catalogue, whatever the feeds are restricted to
+    let recently_updated = catalogue
+        .list(Variables {
+            order: Some(WorkOrderBy {
+                field: WorkField::UpdatedAt,
+                direction: Direction::Desc,
+            }),
+            publishers: config().publisher_scope(),
+            ..Default::default()
+        })
+        .await?;
+    fs::write(
+        options.dist.join("sitemap.xml"),
+        render::sitemap(&options.base_url, &recently_updated)?,
+    )?;
+
+    // Forthcoming titles are dated in the future, so come first, followed by the latest published.
+    // Selected imprints are looked up to request the works of their publishers only.
+    let imprints = match options.filter.imprints.is_empty() {
+        true => vec![],
+        false => catalogue.imprints().await?,
+    };
+    let entries = catalogue
+        .works(
+            Variables {
+                order: Some(WorkOrderBy {
+                    field: WorkField::PublicationDate,
+                    direction: Direction::Desc,
+                }),
+                publishers: options
+                    .filter
+                    .publishers(&imprints)
+                    .or_else(|| config().publisher_scope()),
+                work_statuses: Some(FEED_STATUSES.to_vec()),
+                ..Default::default()
+            },
+            |work| options.filter.matches(work),
+            Some(options.limit),
+        )
+        .await?;
+
+    let site_name = &config().branding.name;
+    let feed = |extension: &str| {
+        let file = format!("{}.{}", options.name, extension);
+        let feed = Feed {
+            title: format!("{site_name}: {FEED_TITLE}"),
+            description: FEED_DESCRIPTION.to_string(),
+            base_url: options.base_url.clone(),
+            url: format!("{}/{}", options.base_url, file),
+            author: site_name.clone(),
+            updated: Utc::now(),
+        };
+        (options.dist.join(file), feed)
+    };
+    let (file, rss) = feed("rss");
+    fs::write(file, render::rss(&rss, &entries)?)?;
+    let (file, atom) = feed("atom");
+    fs::write(file, render::atom(&atom, &entries)?)?;
+    println!(
+        "Wrote a sitemap of {} works and feeds of {} titles into {}",
+        recently_updated.len(),
+        entries.len(),
+        options.dist.display()
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::Path;
+
+    fn catalogue() -> Catalogue {
+        let fixture = Path::new(env!("CARGO_MANIFEST_DIR")).join("fixtures/works.json");
+        Catalogue::new(Some(&fixture)).unwrap()
+    }
+
+    #[tokio::test]
+    async fn imprints_are_requested_through_their_publisher() {
+        let catalogue = catalogue();
+        let imprint_id = Uuid::parse_str("5b6e1f1a-0000-4000-8000-000000000701").unwrap();
+        let publisher_id = "5b6e1f1a-0000-4000-8000-000000000801".to_string();
+        let filter = FeedFilter {
+            imprints: vec![imprint_id, Uuid::nil()],
+            ..Default::default()
+        };
+        let imprints = catalogue.imprints().await.unwrap();
+        assert_eq!(filter.publishers(&imprints), Some(vec![publisher_id]));
+        // Unknown imprints leave no publisher to request works of
+        let filter = FeedFilter {
+            imprints: vec![Uuid::nil()],
+            ..Default::default()
+        };
+        assert_eq!(filter.publishers(&imprints), Some(vec![]));
+        assert_eq!(FeedFilter::default().publishers(&imprints), None);
+    }
+
+    #[tokio::test]
+    async fn feeds_list_new_and_forthcoming_titles_up_to_the_limit() {
+        let catalogue = catalogue();
+        let variables = |publishers: Option<Vec<String>>| Variables {
+            publishers,
+            work_statuses: Some(FEED_STATUSES.to_vec()),
+            ..Default::default()
+        };
+        let filter = FeedFilter::default();
+        let works = catalogue
+            .works(variables(None), |work| filter.matches(work), Some(1))
+            .await
+            .unwrap();
+        assert_eq!(works.len(), 1);
+        assert_eq!(works[0].title, "Trade & Empire");
+        let works = catalogue
+            .works(variables(Some(vec![])), |_| true, None)
+            .await
+            .unwrap();
+        assert!(works.is_empty());
+        // Titles outside the selected series are left out once their full record is read
+        let filter = FeedFilter {
+            series: vec![Uuid::nil()],
+            ..Default::default()
+        };
+        let works = catalogue
+            .works(variables(None), |work| filter.matches(work), None)
+            .await
+            .unwrap();
+        assert!(works.is_empty());
+    }
+}
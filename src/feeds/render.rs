@@ -0,0 +1,377 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use std::io::Write;
+use xml::writer::{EmitterConfig, EventWriter, XmlEvent};
+
+use crate::models::utils::{
+    Contribution, ThothError, ThothResult, WorkStatus, WorkWithRelations, DOI_DOMAIN,
+};
+use crate::prerender::page::path;
+use crate::route::AppRoute;
+
+const SITEMAP_NS: &str = "http://www.sitemaps.org/schemas/sitemap/0.9";
+const ATOM_NS: &str = "http://www.w3.org/2005/Atom";
+const DC_NS: &str = "http://purl.org/dc/elements/1.1/";
+const MEDIA_NS: &str = "http://search.yahoo.com/mrss/";
+
+/// Details of a feed as a whole, as opposed to its entries
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Feed {
+    pub title: String,
+    pub description: String,
+    // Public URL of the catalogue, which page links are relative to, without a trailing slash
+    pub base_url: String,
+    // Public URL the feed itself is served from
+    pub url: String,
+    pub author: String,
+    pub updated: DateTime<Utc>,
+}
+
+impl Feed {
+    fn link(&self, route: &AppRoute) -> String {
+        format!("{}{}", self.base_url, path(route))
+    }
+}
+
+fn document(
+    write: impl FnOnce(&mut EventWriter<&mut Vec<u8>>) -> ThothResult<()>,
+) -> ThothResult<String> {
+    let mut buffer = vec![];
+    {
+        let mut writer = EmitterConfig::new()
+            .perform_indent(true)
+            .create_writer(&mut buffer);
+        write(&mut writer)?;
+    }
+    String::from_utf8(buffer).map_err(|e| ThothError::InternalError(e.to_string()))
+}
+
+fn text_element<W: Write>(writer: &mut EventWriter<W>, name: &str, text: &str) -> ThothResult<()> {
+    writer.write(XmlEvent::start_element(name))?;
+    writer.write(XmlEvent::characters(text))?;
+    writer.write(XmlEvent::end_element())?;
+    Ok(())
+}
+
+fn empty_element<W: Write>(
+    writer: &mut EventWriter<W>,
+    name: &str,
+    attributes: &[(&str, &str)],
+) -> ThothResult<()> {
+    let mut element = XmlEvent::start_element(name);
+    for (attribute, value) in attributes {
+        element = element.attr(*attribute, value);
+    }
+    writer.write(element)?;
+    writer.write(XmlEvent::end_element())?;
+    Ok(())
+}
+
+/// Main contributors in order, as credited on the work
+fn contributors(work: &WorkWithRelations) -> Vec<&str> {
+    let mut main: Vec<&Contribution> = work
+        .contributions
+        .iter()
+        .flatten()
+        .filter(|c| c.main_contribution)
+        .collect();
+    main.sort_by_key(|c| c.contribution_ordinal);
+    main.iter().map(|c| c.full_name.as_str()).collect()
+}
+
+fn summary(work: &WorkWithRelations) -> Option<&str> {
+    work.short_abstract
+        .as_deref()
+        .or(work.long_abstract.as_deref())
+}
+
+fn doi_url(work: &WorkWithRelations) -> Option<String> {
+    work.doi.as_ref().map(|doi| format!("{DOI_DOMAIN}{doi}"))
+}
+
+fn publication_date(work: &WorkWithRelations) -> Option<DateTime<Utc>> {
+    let date = NaiveDate::parse_from_str(work.publication_date.as_deref()?, "%Y-%m-%d").ok()?;
+    Some(date.and_hms_opt(0, 0, 0)?.and_utc())
+}
+
+/// Sitemap of the books list and every work's page, each with the time its record last changed
+pub fn sitemap(base_url: &str, works: &[WorkWithRelations]) -> ThothResult<String> {
+    document(|writer| {
+        writer.write(XmlEvent::start_element("urlset").default_ns(SITEMAP_NS))?;
+        writer.write(XmlEvent::start_element("url"))?;
+        text_element(
+            writer,
+            "loc",
+            &format!("{base_url}{}", path(&AppRoute::Books)),
+        )?;
+        writer.write(XmlEvent::end_element())?;
+        for work in works {
+            let route = AppRoute::BookDetail {
+                book_id: work.work_id,
+            };
+            writer.write(XmlEvent::start_element("url"))?;
+            text_element(writer, "loc", &format!("{base_url}{}", path(&route)))?;
+            text_element(writer, "lastmod", &work.updated_at.to_rfc3339())?;
+            writer.write(XmlEvent::end_element())?;
+        }
+        writer.write(XmlEvent::end_element())?;
+        Ok(())
+    })
+}
+
+/// RSS 2.0 feed of `works`, using Dublin Core for contributors and DOIs, and Media RSS for covers
+pub fn rss(feed: &Feed, works: &[WorkWithRelations]) -> ThothResult<String> {
+    document(|writer| {
+        writer.write(
+            XmlEvent::start_element("rss")
+                .attr("version", "2.0")
+                .ns("atom", ATOM_NS)
+                .ns("dc", DC_NS)
+                .ns("media", MEDIA_NS),
+        )?;
+        writer.write(XmlEvent::start_element("channel"))?;
+        text_element(writer, "title", &feed.title)?;
+        text_element(writer, "link", &feed.link(&AppRoute::Books))?;
+        text_element(writer, "description", &feed.description)?;
+        text_element(writer, "lastBuildDate", &feed.updated.to_rfc2822())?;
+        empty_element(
+            writer,
+            "atom:link",
+            &[
+                ("href", feed.url.as_str()),
+                ("rel", "self"),
+                ("type", "application/rss+xml"),
+            ],
+        )?;
+        for work in works {
+            let link = feed.link(&AppRoute::BookDetail {
+                book_id: work.work_id,
+            });
+            writer.write(XmlEvent::start_element("item"))?;
+            text_element(writer, "title", &work.full_title)?;
+            text_element(writer, "link", &link)?;
+            writer.write(XmlEvent::start_element("guid").attr("isPermaLink", "true"))?;
+            writer.write(XmlEvent::characters(&link))?;
+            writer.write(XmlEvent::end_element())?;
+            if let Some(date) = publication_date(work) {
+                text_element(writer, "pubDate", &date.to_rfc2822())?;
+            }
+            if let Some(summary) = summary(work) {
+                text_element(writer, "description", summary)?;
+            }
+            for name in contributors(work) {
+                text_element(writer, "dc:creator", name)?;
+            }
+            if let Some(doi) = doi_url(work) {
+                text_element(writer, "dc:identifier", &doi)?;
+            }
+            if let Some(cover) = &work.cover_url {
+                empty_element(writer, "media:thumbnail", &[("url", cover.as_str())])?;
+            }
+            if work.work_status == WorkStatus::Forthcoming {
+                text_element(writer, "category", &work.work_status.to_string())?;
+            }
+            writer.write(XmlEvent::end_element())?;
+        }
+        writer.write(XmlEvent::end_element())?;
+        writer.write(XmlEvent::end_element())?;
+        Ok(())
+    })
+}
+
+/// Atom feed of `works`, linking each entry's DOI and cover alongside its page
+pub fn atom(feed: &Feed, works: &[WorkWithRelations]) -> ThothResult<String> {
+    document(|writer| {
+        writer.write(XmlEvent::start_element("feed").default_ns(ATOM_NS))?;
+        text_element(writer, "title", &feed.title)?;
+        text_element(writer, "subtitle", &feed.description)?;
+        text_element(writer, "id", &feed.url)?;
+        text_element(writer, "updated", &feed.updated.to_rfc3339())?;
+        empty_element(
+            writer,
+            "link",
+            &[("rel", "self"), ("href", feed.url.as_str())],
+        )?;
+        empty_element(
+            writer,
+            "link",
+            &[("href", feed.link(&AppRoute::Books).as_str())],
+        )?;
+        writer.write(XmlEvent::start_element("author"))?;
+        text_element(writer, "name", &feed.author)?;
+        writer.write(XmlEvent::end_element())?;
+        for work in works {
+            let link = feed.link(&AppRoute::BookDetail {
+                book_id: work.work_id,
+            });
+            writer.write(XmlEvent::start_element("entry"))?;
+            text_element(writer, "title", &work.full_title)?;
+            text_element(writer, "id", &format!("urn:uuid:{}", work.work_id))?;
+            empty_element(writer, "link", &[("href", link.as_str())])?;
+            if let Some(doi) = doi_url(work) {
+                empty_element(
+                    writer,
+                    "link",
+                    &[("rel", "related"), ("href", doi.as_str())],
+                )?;
+            }
+            if let Some(cover) = &work.cover_url {
+                empty_element(
+                    writer,
+                    "link",
+                    &[("rel", "enclosure"), ("href", cover.as_str())],
+                )?;
+            }
+            if let Some(date) = publication_date(work) {
+                text_element(writer, "published", &date.to_rfc3339())?;
+            }
+            text_element(writer, "updated", &work.updated_at.to_rfc3339())?;
+            for name in contributors(work) {
+                writer.write(XmlEvent::start_element("author"))?;
+                text_element(writer, "name", name)?;
+                writer.write(XmlEvent::end_element())?;
+            }
+            if let Some(summary) = summary(work) {
+                text_element(writer, "summary", summary)?;
+            }
+            if work.work_status == WorkStatus::Forthcoming {
+                empty_element(
+                    writer,
+                    "category",
+                    &[("term", work.work_status.to_string().as_str())],
+                )?;
+            }
+            writer.write(XmlEvent::end_element())?;
+        }
+        writer.write(XmlEvent::end_element())?;
+        Ok(())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn works() -> Vec<WorkWithRelations> {
+        serde_json::from_str(include_str!("../../fixtures/works.json")).unwrap()
+    }
+
+    fn feed() -> Feed {
+        Feed {
+            title: "Example Books: New titles".to_string(),
+            description: "Recent & forthcoming".to_string(),
+            base_url: "https://books.example.org".to_string(),
+            url: "https://books.example.org/new-titles.rss".to_string(),
+            author: "Example Books".to_string(),
+            updated: "2024-03-02T08:00:00Z".parse().unwrap(),
+        }
+    }
+
+    #[test]
+    fn sitemap_lists_every_page_with_its_last_change() {
+        assert_eq!(
+            sitemap("https://books.example.org", &works()).unwrap(),
+            r#"<?xml version="1.0" encoding="utf-8"?>
+<urlset xmlns="http://www.sitemaps.org/schemas/sitemap/0.9">
+  <url>
+    <loc>https://books.example.org/books</loc>
+  </url>
+  <url>
+    <loc>https://books.example.org/books/5b6e1f1a-0000-4000-8000-000000000001</loc>
+    <lastmod>2024-03-01T12:00:00Z</lastmod>
+  </url>
+  <url>
+    <loc>https://books.example.org/books/5b6e1f1a-0000-4000-8000-000000000002</loc>
+    <lastmod>2024-02-01T09:30:00Z</lastmod>
+  </url>
+</urlset>"#
+        );
+    }
+
+    #[test]
+    fn rss_items_carry_contributors_doi_and_cover() {
+        assert_eq!(
+            rss(&feed(), &works()).unwrap(),
+            r#"<?xml version="1.0" encoding="utf-8"?>
+<rss xmlns:atom="http://www.w3.org/2005/Atom" xmlns:dc="http://purl.org/dc/elements/1.1/" xmlns:media="http://search.yahoo.com/mrss/" version="2.0">
+  <channel>
+    <title>Example Books: New titles</title>
+    <link>https://books.example.org/books</link>
+    <description>Recent &amp; forthcoming</description>
+    <lastBuildDate>Sat, 2 Mar 2024 08:00:00 +0000</lastBuildDate>
+    <atom:link href="https://books.example.org/new-titles.rss" rel="self" type="application/rss+xml" />
+    <item>
+      <title>Trade &amp; Empire: A History of Europe</title>
+      <link>https://books.example.org/books/5b6e1f1a-0000-4000-8000-000000000001</link>
+      <guid isPermaLink="true">https://books.example.org/books/5b6e1f1a-0000-4000-8000-000000000001</guid>
+      <pubDate>Tue, 4 May 2021 00:00:00 +0000</pubDate>
+      <description>How trade   shaped &lt;Europe&gt;
+since 1500.</description>
+      <dc:creator>Jane Smith</dc:creator>
+      <dc:creator>José Núñez</dc:creator>
+      <dc:identifier>https://doi.org/10.11647/OBP.0001</dc:identifier>
+      <media:thumbnail url="https://books.example.org/covers/trade-and-empire.jpg" />
+    </item>
+    <item>
+      <title>Ports of Call</title>
+      <link>https://books.example.org/books/5b6e1f1a-0000-4000-8000-000000000002</link>
+      <guid isPermaLink="true">https://books.example.org/books/5b6e1f1a-0000-4000-8000-000000000002</guid>
+      <pubDate>Wed, 15 Jan 2031 00:00:00 +0000</pubDate>
+      <dc:creator>Min Lee</dc:creator>
+      <category>Forthcoming</category>
+    </item>
+  </channel>
+</rss>"#
+        );
+    }
+
+    #[test]
+    fn atom_entries_link_doi_and_cover() {
+        let feed = Feed {
+            url: "https://books.example.org/new-titles.atom".to_string(),
+            ..feed()
+        };
+        assert_eq!(
+            atom(&feed, &works()).unwrap(),
+            r#"<?xml version="1.0" encoding="utf-8"?>
+<feed xmlns="http://www.w3.org/2005/Atom">
+  <title>Example Books: New titles</title>
+  <subtitle>Recent &amp; forthcoming</subtitle>
+  <id>https://books.example.org/new-titles.atom</id>
+  <updated>2024-03-02T08:00:00+00:00</updated>
+  <link rel="self" href="https://books.example.org/new-titles.atom" />
+  <link href="https://books.example.org/books" />
+  <author>
+    <name>Example Books</name>
+  </author>
+  <entry>
+    <title>Trade &amp; Empire: A History of Europe</title>
+    <id>urn:uuid:5b6e1f1a-0000-4000-8000-000000000001</id>
+    <link href="https://books.example.org/books/5b6e1f1a-0000-4000-8000-000000000001" />
+    <link rel="related" href="https://doi.org/10.11647/OBP.0001" />
+    <link rel="enclosure" href="https://books.example.org/covers/trade-and-empire.jpg" />
+    <published>2021-05-04T00:00:00+00:00</published>
+    <updated>2024-03-01T12:00:00Z</updated>
+    <author>
+      <name>Jane Smith</name>
+    </author>
+    <author>
+      <name>José Núñez</name>
+    </author>
+    <summary>How trade   shaped &lt;Europe&gt;
+since 1500.</summary>
+  </entry>
+  <entry>
+    <title>Ports of Call</title>
+    <id>urn:uuid:5b6e1f1a-0000-4000-8000-000000000002</id>
+    <link href="https://books.example.org/books/5b6e1f1a-0000-4000-8000-000000000002" />
+    <published>2031-01-15T00:00:00+00:00</published>
+    <updated>2024-02-01T09:30:00Z</updated>
+    <author>
+      <name>Min Lee</name>
+    </author>
+    <category term="Forthcoming" />
+  </entry>
+</feed>"#
+        );
+    }
+}
@@ -0,0 +1,156 @@
+use chrono::{Datelike, NaiveDate};
+use std::fmt::Display;
+
+use super::{locale, Locale};
+
+const MONTHS_EN: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+const MONTHS_DE: [&str; 12] = [
+    "Januar",
+    "Februar",
+    "März",
+    "April",
+    "Mai",
+    "Juni",
+    "Juli",
+    "August",
+    "September",
+    "Oktober",
+    "November",
+    "Dezember",
+];
+const MONTHS_FR: [&str; 12] = [
+    "janvier",
+    "février",
+    "mars",
+    "avril",
+    "mai",
+    "juin",
+    "juillet",
+    "août",
+    "septembre",
+    "octobre",
+    "novembre",
+    "décembre",
+];
+const MONTHS_ES: [&str; 12] = [
+    "enero",
+    "febrero",
+    "marzo",
+    "abril",
+    "mayo",
+    "junio",
+    "julio",
+    "agosto",
+    "septiembre",
+    "octubre",
+    "noviembre",
+    "diciembre",
+];
+
+/// A date as written in the current locale, e.g. "3 March 2024" or "3. März 2024". Dates are
+/// stored as `YYYY-MM-DD`; anything else is returned unchanged.
+pub fn format_date(date: &str) -> String {
+    let parsed = match NaiveDate::parse_from_str(date, "%Y-%m-%d") {
+        Ok(parsed) => parsed,
+        Err(_) => return date.to_string(),
+    };
+    let (day, month, year) = (parsed.day(), parsed.month0() as usize, parsed.year());
+    match locale() {
+        Locale::En => format!("{} {} {}", day, MONTHS_EN[month], year),
+        Locale::De => format!("{}. {} {}", day, MONTHS_DE[month], year),
+        Locale::Fr => format!("{} {} {}", day, MONTHS_FR[month], year),
+        Locale::Es => format!("{} de {} de {}", day, MONTHS_ES[month], year),
+    }
+}
+
+/// A whole number with its digits grouped in thousands as in the current locale, e.g. "12,345"
+/// or "12.345"
+pub fn format_integer<T: Display>(value: T) -> String {
+    let separator = match locale() {
+        Locale::En => ",",
+        Locale::De | Locale::Es => ".",
+        // Narrow no-break space, so that a number is never split across lines
+        Locale::Fr => "\u{202f}",
+    };
+    let digits = value.to_string();
+    let (sign, digits) = match digits.strip_prefix('-') {
+        Some(digits) => ("-", digits),
+        None => ("", digits.as_str()),
+    };
+    let mut grouped = String::from(sign);
+    for (i, digit) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i) % 3 == 0 {
+            grouped.push_str(separator);
+        }
+        grouped.push(digit);
+    }
+    grouped
+}
+
+/// A number rounded to `places` decimals, with the decimal separator and digit grouping of the
+/// current locale, e.g. "1,234.50" or "1.234,50"
+pub fn format_decimal(value: f64, places: usize) -> String {
+    decimal(value, places, false)
+}
+
+/// A measurement, such as a dimension in millimetres, with only as many of up to two decimals as
+/// it needs, e.g. "234" or "480,5"
+pub fn format_measure(value: f64) -> String {
+    decimal(value, 2, true)
+}
+
+fn decimal(value: f64, places: usize, trim_zeros: bool) -> String {
+    let rounded = format!("{:.*}", places, value.abs());
+    let (whole, fraction) = rounded.split_once('.').unwrap_or((&rounded, ""));
+    let fraction = match trim_zeros {
+        true => fraction.trim_end_matches('0'),
+        false => fraction,
+    };
+    // No sign on values which round to zero
+    let sign = match value < 0.0 && rounded.contains(|c: char| ('1'..='9').contains(&c)) {
+        true => "-",
+        false => "",
+    };
+    let mut formatted = format!("{}{}", sign, format_integer(whole));
+    if !fraction.is_empty() {
+        formatted.push_str(match locale() {
+            Locale::En => ".",
+            Locale::De | Locale::Fr | Locale::Es => ",",
+        });
+        formatted.push_str(fraction);
+    }
+    formatted
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::i18n::set_locale;
+
+    #[test]
+    fn decimals_are_written_as_in_the_locale() {
+        assert_eq!(format_decimal(1234.5, 2), "1,234.50");
+        assert_eq!(format_decimal(-0.001, 2), "0.00");
+        assert_eq!(format_decimal(-12.345, 1), "-12.3");
+        assert_eq!(format_measure(156.0), "156");
+        assert_eq!(format_measure(480.5), "480.5");
+        set_locale(Locale::De);
+        assert_eq!(format_decimal(1234.5, 2), "1.234,50");
+        assert_eq!(format_measure(6.25), "6,25");
+        set_locale(Locale::Fr);
+        assert_eq!(format_decimal(1234.5, 2), "1\u{202f}234,50");
+    }
+}
@@ -0,0 +1,189 @@
+//! Translation of the interface. Text is written in English in `string.rs`, and each other locale
+//! has a catalogue in `locales/` mapping message keys to its own text. Anything a catalogue leaves
+//! out is shown in English, so that a translation can be added to gradually.
+
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::fmt::{self, Debug, Display};
+use std::str::FromStr;
+
+use crate::models::utils::{
+    ContributionType, CountryCode, CurrencyCode, LanguageCode, LanguageRelation, LocationPlatform,
+    PublicationType, RelationType, SeriesType, SubjectType, ThothError, WorkField, WorkStatus,
+    WorkType,
+};
+
+pub mod format;
+
+/// Languages the interface is available in
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Locale {
+    #[default]
+    En,
+    De,
+    Fr,
+    Es,
+}
+
+impl Locale {
+    pub const ALL: [Locale; 4] = [Locale::En, Locale::De, Locale::Fr, Locale::Es];
+
+    /// BCP 47 language code, as used in `<html lang>` and for the stored preference
+    pub fn code(&self) -> &'static str {
+        match self {
+            Locale::En => "en",
+            Locale::De => "de",
+            Locale::Fr => "fr",
+            Locale::Es => "es",
+        }
+    }
+
+    /// Name of the language in that language, as offered by the locale switcher
+    #[cfg(any(target_arch = "wasm32", test))]
+    pub fn name(&self) -> &'static str {
+        match self {
+            Locale::En => "English",
+            Locale::De => "Deutsch",
+            Locale::Fr => "Français",
+            Locale::Es => "Español",
+        }
+    }
+
+    /// The locale of a language tag such as `de` or `fr-BE`, if it is one the interface supports
+    pub fn from_code(code: &str) -> Option<Locale> {
+        let language = code.split(['-', '_']).next()?.to_lowercase();
+        Locale::ALL.into_iter().find(|l| l.code() == language)
+    }
+
+    fn catalogue(&self) -> &'static str {
+        match self {
+            Locale::En => "",
+            Locale::De => include_str!("../../locales/de.txt"),
+            Locale::Fr => include_str!("../../locales/fr.txt"),
+            Locale::Es => include_str!("../../locales/es.txt"),
+        }
+    }
+}
+
+impl FromStr for Locale {
+    type Err = ThothError;
+
+    fn from_str(code: &str) -> Result<Self, Self::Err> {
+        Locale::from_code(code)
+            .ok_or_else(|| ThothError::InternalError(format!("{code} is not a supported locale")))
+    }
+}
+
+thread_local! {
+    static LOCALE: Cell<Locale> = Cell::new(Locale::default());
+    // Messages of the current locale, keyed as in its catalogue
+    static MESSAGES: RefCell<HashMap<&'static str, &'static str>> = RefCell::new(HashMap::new());
+}
+
+/// Parse a catalogue: one `KEY = text` entry per line, ignoring blank lines and `#` comments
+fn parse(catalogue: &'static str) -> HashMap<&'static str, &'static str> {
+    catalogue
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .filter_map(|line| line.split_once('='))
+        .map(|(key, text)| (key.trim(), text.trim()))
+        .collect()
+}
+
+pub fn locale() -> Locale {
+    LOCALE.with(Cell::get)
+}
+
+/// Translate everything rendered from now on. Components already rendered keep their text until
+/// they are rendered again.
+pub fn set_locale(locale: Locale) {
+    LOCALE.with(|current| current.set(locale));
+    MESSAGES.with(|messages| *messages.borrow_mut() = parse(locale.catalogue()));
+}
+
+fn translate(key: &str) -> Option<&'static str> {
+    MESSAGES.with(|messages| messages.borrow().get(key).copied())
+}
+
+/// A piece of interface text, identified by its key in the locale catalogues
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Message {
+    key: &'static str,
+    english: &'static str,
+}
+
+impl Message {
+    pub const fn new(key: &'static str, english: &'static str) -> Self {
+        Message { key, english }
+    }
+
+    /// The text as written in `string.rs`, e.g. to recognise it in messages from the API
+    pub fn english(&self) -> &'static str {
+        self.english
+    }
+
+    /// The text in the current locale
+    pub fn as_str(&self) -> &'static str {
+        translate(self.key).unwrap_or(self.english)
+    }
+
+    /// The text with each `{}` in turn replaced by the next of `values`
+    pub fn fill(&self, values: &[&str]) -> String {
+        let mut parts = self.as_str().split("{}");
+        let mut filled = parts.next().unwrap_or_default().to_string();
+        for (i, part) in parts.enumerate() {
+            filled.push_str(values.get(i).copied().unwrap_or_default());
+            filled.push_str(part);
+        }
+        filled
+    }
+}
+
+impl Display for Message {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Enums whose values are shown to users. Catalogues translate a value under the name of its
+/// type and variant, e.g. `WorkType.EditedBook`; untranslated values are displayed as they are.
+pub trait Localized: Debug + Display {
+    const TYPE_KEY: &'static str;
+
+    /// The value's text in the current locale, if its catalogue has one
+    fn translation(&self) -> Option<&'static str> {
+        translate(&format!("{}.{:?}", Self::TYPE_KEY, self))
+    }
+
+    fn localized(&self) -> String {
+        match self.translation() {
+            Some(text) => text.to_string(),
+            None => self.to_string(),
+        }
+    }
+}
+
+macro_rules! localized {
+    ($($enum:ident),* $(,)?) => (
+        $(impl Localized for $enum {
+            const TYPE_KEY: &'static str = stringify!($enum);
+        })*
+    )
+}
+
+localized! {
+    ContributionType,
+    CountryCode,
+    CurrencyCode,
+    LanguageCode,
+    LanguageRelation,
+    LocationPlatform,
+    PublicationType,
+    RelationType,
+    SeriesType,
+    SubjectType,
+    WorkField,
+    WorkStatus,
+    WorkType,
+}
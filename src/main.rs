@@ -1,22 +1,60 @@
 #![recursion_limit = "2048"]
+// Test builds compile the app's modules natively to test them, without anything running the app
+#![cfg_attr(test, allow(dead_code))]
 
+#[cfg(not(target_arch = "wasm32"))]
 use std::env;
 
+#[cfg(any(target_arch = "wasm32", test))]
+mod agent;
+#[cfg(any(target_arch = "wasm32", test))]
 #[macro_use]
 mod component;
+#[cfg(not(target_arch = "wasm32"))]
+mod catalogue;
+mod config;
+#[cfg(not(target_arch = "wasm32"))]
+mod feeds;
+mod i18n;
 mod models;
+#[cfg(not(target_arch = "wasm32"))]
+mod prerender;
 mod route;
+#[cfg(any(target_arch = "wasm32", test))]
+mod service;
 mod string;
 
+#[cfg(target_arch = "wasm32")]
 use crate::component::root::RootComponent;
 
-pub const THOTH_GRAPHQL_API: &str = env!("THOTH_GRAPHQL_API");
-pub const THOTH_EXPORT_API: &str = env!("THOTH_EXPORT_API");
-/// Default number of milliseconds to wait before sending a search query
-const DEFAULT_DEBOUNCING_TIMEOUT: u32 = 500;
-
+#[cfg(target_arch = "wasm32")]
 pub fn main() {
     wasm_logger::init(wasm_logger::Config::default());
 
     yew::start_app::<RootComponent>();
 }
+
+/// Native builds run build steps for the app rather than the app itself
+#[cfg(not(target_arch = "wasm32"))]
+#[tokio::main]
+async fn main() {
+    let mut args = env::args().skip(1);
+    let result = match args.next().as_deref() {
+        Some("prerender") => match prerender::Options::from_args(args) {
+            Ok(options) => prerender::run(options).await,
+            Err(e) => Err(e),
+        },
+        Some("feeds") => match feeds::Options::from_args(args) {
+            Ok(options) => feeds::run(options).await,
+            Err(e) => Err(e),
+        },
+        _ => {
+            eprintln!("{}\n\n{}", prerender::USAGE, feeds::USAGE);
+            std::process::exit(2);
+        }
+    };
+    if let Err(e) = result {
+        eprintln!("{e}");
+        std::process::exit(1);
+    }
+}
@@ -0,0 +1,24 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct LoginCredentials {
+    pub email: String,
+    pub password: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountDetails {
+    pub account_id: Uuid,
+    pub name: String,
+    pub surname: String,
+    pub email: String,
+    pub token: Option<String>,
+}
+
+impl AccountDetails {
+    pub fn full_name(&self) -> String {
+        format!("{} {}", self.name, self.surname)
+    }
+}
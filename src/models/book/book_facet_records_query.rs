@@ -0,0 +1,101 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::models::utils::{LanguageCode, SubjectType};
+use crate::models::FacetValues;
+
+/// Just the values of the books which the facets evaluated on the client select by, so that they
+/// can be counted across every book of the list rather than those of the current page. Works are
+/// ordered by ID, which unlike titles is unique, so that no book is missed or repeated across pages.
+pub const BOOK_FACET_RECORDS_QUERY: &str = "
+    query BookFacetRecordsQuery($limit: Int, $offset: Int, $filter: String, $publishers: [Uuid!], $workTypes: [WorkType!] = [MONOGRAPH, EDITED_BOOK, TEXTBOOK, JOURNAL_ISSUE, BOOK_SET], $workStatuses: [WorkStatus!]) {
+        books: works(limit: $limit, offset: $offset, filter: $filter, publishers: $publishers, order: {field: WORK_ID, direction: ASC}, workTypes: $workTypes, workStatuses: $workStatuses) {
+            workId
+            publicationDate
+            license
+            languages {
+                languageCode
+            }
+            subjects {
+                subjectType
+            }
+        }
+        bookCount: workCount(filter: $filter, publishers: $publishers, workTypes: $workTypes, workStatuses: $workStatuses)
+    }
+";
+
+graphql_query_builder! {
+    BookFacetRecordsRequest,
+    BookFacetRecordsRequestBody,
+    Variables,
+    BOOK_FACET_RECORDS_QUERY.to_string(),
+    BookFacetRecordsResponseBody,
+    BookFacetRecordsResponseData,
+    FetchBookFacetRecords,
+    FetchActionBookFacetRecords
+}
+
+/// A page of the books list as requested with `selections`
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Variables {
+    pub limit: Option<i32>,
+    pub offset: Option<i32>,
+    #[serde(flatten)]
+    pub selections: crate::models::book::book_facets_query::Variables,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct BookFacetRecordsResponseData {
+    pub books: Vec<BookFacetRecord>,
+    pub book_count: i32,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct BookFacetRecord {
+    pub work_id: Uuid,
+    pub publication_date: Option<String>,
+    pub license: Option<String>,
+    pub languages: Option<Vec<BookFacetLanguage>>,
+    pub subjects: Option<Vec<BookFacetSubject>>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct BookFacetLanguage {
+    pub language_code: LanguageCode,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct BookFacetSubject {
+    pub subject_type: SubjectType,
+}
+
+impl FacetValues for BookFacetRecord {
+    fn language_codes(&self) -> Vec<LanguageCode> {
+        self.languages
+            .iter()
+            .flatten()
+            .map(|l| l.language_code.clone())
+            .collect()
+    }
+
+    fn publication_date(&self) -> Option<&str> {
+        self.publication_date.as_deref()
+    }
+
+    fn subject_types(&self) -> Vec<SubjectType> {
+        self.subjects
+            .iter()
+            .flatten()
+            .map(|s| s.subject_type.clone())
+            .collect()
+    }
+
+    fn license_url(&self) -> Option<&str> {
+        self.license.as_deref()
+    }
+}
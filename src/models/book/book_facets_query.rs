@@ -0,0 +1,111 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::models::book::books_query::BOOK_TYPES;
+use crate::models::search::query::WORK_STATUSES;
+use crate::models::utils::{WorkStatus, WorkType};
+
+/// Name of the value in the query and response, e.g. `EDITED_BOOK`
+fn enum_name<T: Serialize>(value: &T) -> String {
+    serde_json::to_value(value)
+        .ok()
+        .and_then(|value| value.as_str().map(str::to_string))
+        .unwrap_or_default()
+}
+
+fn type_alias(work_type: &WorkType) -> String {
+    format!("type_{}", enum_name(work_type))
+}
+
+fn status_alias(work_status: &WorkStatus) -> String {
+    format!("status_{}", enum_name(work_status))
+}
+
+/// Count the books of each type and of each status which the books list would show if that value
+/// were selected: types are counted among the selected statuses, and statuses among the selected
+/// types, so that selecting a value in one facet updates the counts of the other.
+pub fn book_facets_query() -> String {
+    let type_counts = BOOK_TYPES.iter().map(|work_type| {
+        format!(
+            "{}: workCount(filter: $filter, publishers: $publishers, workTypes: [{}], workStatuses: $workStatuses)",
+            type_alias(work_type),
+            enum_name(work_type)
+        )
+    });
+    let status_counts = WORK_STATUSES.iter().map(|work_status| {
+        format!(
+            "{}: workCount(filter: $filter, publishers: $publishers, workTypes: $workTypes, workStatuses: [{}])",
+            status_alias(work_status),
+            enum_name(work_status)
+        )
+    });
+    let book_types: Vec<String> = BOOK_TYPES.iter().map(enum_name).collect();
+    format!(
+        "
+    query BookFacetsQuery($filter: String, $publishers: [Uuid!], $workTypes: [WorkType!] = [{}], $workStatuses: [WorkStatus!]) {{
+        {}
+    }}
+",
+        book_types.join(", "),
+        type_counts
+            .chain(status_counts)
+            .collect::<Vec<String>>()
+            .join("\n        ")
+    )
+}
+
+graphql_query_builder! {
+    BookFacetsRequest,
+    BookFacetsRequestBody,
+    Variables,
+    book_facets_query(),
+    BookFacetsResponseBody,
+    BookFacetsResponseData,
+    FetchBookFacets,
+    FetchActionBookFacets
+}
+
+/// The selections of the books list, other than its paging and order
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Variables {
+    pub filter: Option<String>,
+    pub publishers: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub work_types: Option<Vec<WorkType>>,
+    pub work_statuses: Option<Vec<WorkStatus>>,
+}
+
+impl From<&crate::models::utils::Variables> for Variables {
+    fn from(variables: &crate::models::utils::Variables) -> Self {
+        Variables {
+            filter: variables.filter.clone(),
+            publishers: variables.publishers.clone(),
+            work_types: variables.work_types.clone(),
+            work_statuses: variables.work_statuses.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct BookFacetsResponseData {
+    // Counts keyed by their alias in the query
+    #[serde(flatten)]
+    pub counts: HashMap<String, i32>,
+}
+
+impl BookFacetsResponseData {
+    pub fn type_count(&self, work_type: &WorkType) -> i32 {
+        self.counts
+            .get(&type_alias(work_type))
+            .copied()
+            .unwrap_or_default()
+    }
+
+    pub fn status_count(&self, work_status: &WorkStatus) -> i32 {
+        self.counts
+            .get(&status_alias(work_status))
+            .copied()
+            .unwrap_or_default()
+    }
+}
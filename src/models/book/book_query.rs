@@ -1,13 +1,19 @@
+#[cfg(any(target_arch = "wasm32", test))]
 use serde::{Deserialize, Serialize};
+#[cfg(any(target_arch = "wasm32", test))]
 use uuid::Uuid;
 
-use crate::models::utils::{
-    Contributor, ImprintWithPublisher, WorkStatusDefinition, WorkTypeDefinition, WorkWithRelations,
-};
+#[cfg(any(target_arch = "wasm32", test))]
+use crate::models::utils::WorkWithRelations;
 
-pub const WORK_QUERY: &str = "
-    query WorkQuery($workId: Uuid!, $publishers: [Uuid!]) {
-        work(workId: $workId) {
+#[cfg(any(target_arch = "wasm32", test))]
+const WORK_QUERY_HEADER: &str = "
+    query WorkQuery($workId: Uuid!) {
+        work(workId: $workId) {";
+
+/// Every field of a work and its relations shown by the app, also requested for each work of the
+/// catalogue by the native build steps
+pub const WORK_QUERY_BODY: &str = "
             workId
             workType
             workStatus
@@ -101,6 +107,24 @@ pub const WORK_QUERY: &str = "
                 heightIn: height(units: IN)
                 depthMm: depth(units: MM)
                 depthIn: depth(units: IN)
+                prices {
+                    priceId
+                    publicationId
+                    currencyCode
+                    unitPrice
+                    createdAt
+                    updatedAt
+                }
+                locations {
+                    locationId
+                    publicationId
+                    landingPage
+                    fullTextUrl
+                    locationPlatform
+                    canonical
+                    createdAt
+                    updatedAt
+                }
             }
             languages {
                 languageId
@@ -204,55 +228,34 @@ pub const WORK_QUERY: &str = "
                 createdAt
                 updatedAt
             }
-        }
-        imprints(limit: 9999, publishers: $publishers) {
-            imprintId
-            imprintName
-            updatedAt
-            publisher {
-                publisherId
-                publisherName
-                publisherShortname
-                publisherUrl
-                createdAt
-                updatedAt
-            }
-        }
-        work_types: __type(name: \"WorkType\") {
-            enumValues {
-                name
-            }
-        }
-        work_statuses: __type(name: \"WorkStatus\") {
-            enumValues {
-                name
-            }
-        }
+        }";
+
+#[cfg(any(target_arch = "wasm32", test))]
+const WORK_QUERY_FOOTER: &str = "
     }
 ";
 
+#[cfg(any(target_arch = "wasm32", test))]
 graphql_query_builder! {
     WorkRequest,
     WorkRequestBody,
     Variables,
-    WORK_QUERY,
+    format!("{WORK_QUERY_HEADER}{WORK_QUERY_BODY}{WORK_QUERY_FOOTER}"),
     WorkResponseBody,
     WorkResponseData,
     FetchWork,
     FetchActionBook
 }
 
+#[cfg(any(target_arch = "wasm32", test))]
 #[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
 pub struct Variables {
     pub work_id: Option<Uuid>,
-    pub publishers: Option<Vec<String>>,
 }
 
+#[cfg(any(target_arch = "wasm32", test))]
 #[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
 pub struct WorkResponseData {
     pub work: Option<WorkWithRelations>,
-    pub imprints: Vec<ImprintWithPublisher>,
-    pub work_types: WorkTypeDefinition,
-    pub work_statuses: WorkStatusDefinition,
 }
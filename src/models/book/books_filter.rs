@@ -0,0 +1,375 @@
+use uuid::Uuid;
+use yew::html;
+use yew::prelude::Html;
+use yew::Callback;
+
+use crate::component::advanced_search::AdvancedSearchComponent;
+use crate::component::book_facets::BookFacetsComponent;
+use crate::models::book::books_query::BOOK_TYPES;
+use crate::models::search::query::{SearchQuery, WORK_STATUSES};
+use crate::models::search::Suggestion;
+use crate::models::utils::{
+    LanguageCode, SubjectType, Variables, WorkStatus, WorkType, WorkWithRelations,
+};
+use crate::models::{FacetValues, ListFilter};
+
+/// Facet selections for the books catalogue.
+///
+/// Types and statuses are evaluated by the API, as are the search query's longest free text term,
+/// types and statuses. The API has no arguments for the remaining facets, the remaining terms or
+/// the scope picked from the search suggestions: these are matched against the fetched results.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BookFilter {
+    pub work_types: Vec<WorkType>,
+    pub work_statuses: Vec<WorkStatus>,
+    pub year_from: Option<i32>,
+    pub year_to: Option<i32>,
+    pub languages: Vec<LanguageCode>,
+    pub subject_types: Vec<SubjectType>,
+    pub open_license_only: bool,
+    pub scope: Option<WorkScope>,
+    pub search: SearchQuery,
+    pub search_error: Option<String>,
+}
+
+/// Facets which are evaluated on the client
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LocalFacet {
+    PublicationYear,
+    Language,
+    SubjectScheme,
+    OpenLicense,
+}
+
+/// Works of a contributor or series picked from the search suggestions
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WorkScope {
+    pub label: String,
+    pub work_ids: Vec<Uuid>,
+}
+
+impl BookFilter {
+    pub fn toggled<T: PartialEq>(
+        &self,
+        select: impl Fn(&mut BookFilter) -> &mut Vec<T>,
+        value: T,
+    ) -> Self {
+        let mut filter = self.clone();
+        let values = select(&mut filter);
+        match values.iter().position(|v| v == &value) {
+            Some(index) => {
+                values.remove(index);
+            }
+            None => values.push(value),
+        }
+        filter
+    }
+
+    /// The search query is entered separately, and is kept when clearing the facets
+    pub fn cleared(&self) -> Self {
+        BookFilter {
+            search: self.search.clone(),
+            search_error: self.search_error.clone(),
+            ..Default::default()
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self == &self.cleared()
+    }
+
+    /// Whether a record has one of the values selected in each facet evaluated on the client,
+    /// other than `except`, whose values are being counted
+    pub fn matches_facets(&self, record: &impl FacetValues, except: Option<LocalFacet>) -> bool {
+        let year = record.publication_year();
+        let matches_year = self
+            .year_from
+            .is_none_or(|from| year.is_some_and(|y| y >= from))
+            && self.year_to.is_none_or(|to| year.is_some_and(|y| y <= to));
+        (except == Some(LocalFacet::PublicationYear) || matches_year)
+            && (except == Some(LocalFacet::Language)
+                || self.languages.is_empty()
+                || self.languages.iter().any(|l| record.has_language(l)))
+            && (except == Some(LocalFacet::SubjectScheme)
+                || self.subject_types.is_empty()
+                || record
+                    .subject_types()
+                    .iter()
+                    .any(|s| self.subject_types.contains(s)))
+            && (except == Some(LocalFacet::OpenLicense)
+                || !self.open_license_only
+                || record.has_open_license())
+    }
+
+    fn has_local_facets(&self) -> bool {
+        self.year_from.is_some()
+            || self.year_to.is_some()
+            || !self.languages.is_empty()
+            || !self.subject_types.is_empty()
+            || self.open_license_only
+    }
+
+    /// Types and statuses which results must have one of, as selected in the facets and
+    /// searched for, or `None` for either which is unrestricted
+    fn restrictions(&self) -> (Option<Vec<WorkType>>, Option<Vec<WorkStatus>>) {
+        (
+            combine(&self.work_types, self.search.work_types(&BOOK_TYPES)),
+            combine(
+                &self.work_statuses,
+                self.search.work_statuses(&WORK_STATUSES),
+            ),
+        )
+    }
+
+    /// Whether the facets and search query exclude each other, e.g. `status:active` while only
+    /// cancelled works are selected
+    fn is_unsatisfiable(&self) -> bool {
+        let (work_types, work_statuses) = self.restrictions();
+        work_types.is_some_and(|types| types.is_empty())
+            || work_statuses.is_some_and(|statuses| statuses.is_empty())
+    }
+}
+
+/// Values satisfying both a facet's selection and the search query, either of which may be
+/// unrestricted
+fn combine<T: Clone + PartialEq>(selected: &[T], searched: Option<Vec<T>>) -> Option<Vec<T>> {
+    match (selected.is_empty(), searched) {
+        (true, None) => None,
+        (false, None) => Some(selected.to_vec()),
+        (true, Some(searched)) => Some(searched),
+        (false, Some(searched)) => Some(
+            selected
+                .iter()
+                .filter(|v| searched.contains(v))
+                .cloned()
+                .collect(),
+        ),
+    }
+}
+
+impl ListFilter for BookFilter {
+    type Entity = WorkWithRelations;
+    type Variables = Variables;
+
+    fn update_variables(&self, variables: &mut Variables) {
+        let (work_types, work_statuses) = self.restrictions();
+        // An empty list would leave the API unrestricted: such a filter is evaluated by `matches`
+        variables.work_types = work_types.filter(|types| !types.is_empty());
+        variables.work_statuses = work_statuses.filter(|statuses| !statuses.is_empty());
+    }
+
+    fn matches(&self, work: &WorkWithRelations) -> bool {
+        !self.is_unsatisfiable()
+            && self.matches_facets(work, None)
+            && self
+                .scope
+                .as_ref()
+                .is_none_or(|scope| scope.work_ids.contains(&work.work_id))
+            && self.search.matches(work)
+    }
+
+    fn filters_locally(&self) -> bool {
+        self.scope.is_some()
+            || self.has_local_facets()
+            || self.search.has_local_terms()
+            || self.is_unsatisfiable()
+    }
+
+    #[allow(clippy::unnecessary_operation)]
+    fn view_facets(&self, variables: &Variables, onchange: Callback<BookFilter>) -> Html {
+        html! {
+            <BookFacetsComponent
+                filter={ self.clone() }
+                variables={ variables.clone() }
+                onchange={ onchange }
+            />
+        }
+    }
+
+    fn apply_search(&mut self, query: &str) -> String {
+        match query.parse::<SearchQuery>() {
+            Ok(search) => {
+                let text = search.text_filter();
+                self.search = search;
+                self.search_error = None;
+                text
+            }
+            Err(e) => {
+                self.search = Default::default();
+                self.search_error = Some(e.to_string());
+                query.to_string()
+            }
+        }
+    }
+
+    #[allow(clippy::unnecessary_operation)]
+    fn view_advanced_search(&self, query: &str, onsearch: Callback<String>) -> Html {
+        html! {
+            <AdvancedSearchComponent query={ query.to_string() } onsearch={ onsearch } />
+        }
+    }
+
+    fn with_suggestion(&self, suggestion: &Suggestion) -> Option<Self> {
+        let scope = WorkScope {
+            label: suggestion.label.clone(),
+            work_ids: suggestion.work_ids.clone(),
+        };
+        Some(BookFilter {
+            scope: Some(scope),
+            ..self.clone()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::utils::{Language, Subject};
+
+    fn filter(query: &str, work_statuses: Vec<WorkStatus>) -> BookFilter {
+        let mut filter = BookFilter {
+            work_statuses,
+            ..Default::default()
+        };
+        filter.apply_search(query);
+        filter
+    }
+
+    #[test]
+    fn searched_types_and_statuses_are_sent_with_the_facets() {
+        let mut variables = Variables::default();
+        filter(
+            "-type:textbook status:active status:forthcoming",
+            vec![WorkStatus::Forthcoming, WorkStatus::Cancelled],
+        )
+        .update_variables(&mut variables);
+        assert_eq!(
+            variables.work_types,
+            Some(vec![
+                WorkType::Monograph,
+                WorkType::EditedBook,
+                WorkType::JournalIssue,
+                WorkType::BookSet,
+            ])
+        );
+        assert_eq!(variables.work_statuses, Some(vec![WorkStatus::Forthcoming]));
+    }
+
+    #[test]
+    fn languages_are_matched_on_the_client() {
+        let filter = BookFilter {
+            languages: vec![LanguageCode::Fre, LanguageCode::Ger],
+            ..Default::default()
+        };
+        let mut variables = Variables::default();
+        filter.update_variables(&mut variables);
+        assert_eq!(variables, Variables::default());
+        assert!(filter.filters_locally());
+        let work = |code: LanguageCode| WorkWithRelations {
+            languages: Some(vec![Language {
+                language_code: code,
+                ..Default::default()
+            }]),
+            ..Default::default()
+        };
+        assert!(filter.matches(&work(LanguageCode::Ger)));
+        assert!(!filter.matches(&work(LanguageCode::Eng)));
+        assert!(filter.matches_facets(&work(LanguageCode::Eng), Some(LocalFacet::Language)));
+    }
+
+    #[test]
+    fn selected_types_and_statuses_are_sent_to_the_api() {
+        let filter = BookFilter {
+            work_types: vec![WorkType::Textbook],
+            work_statuses: vec![WorkStatus::Active, WorkStatus::Forthcoming],
+            ..Default::default()
+        };
+        let mut variables = Variables::default();
+        filter.update_variables(&mut variables);
+        assert_eq!(variables.work_types, Some(vec![WorkType::Textbook]));
+        assert_eq!(
+            variables.work_statuses,
+            Some(vec![WorkStatus::Active, WorkStatus::Forthcoming])
+        );
+        assert!(!filter.filters_locally());
+    }
+
+    #[test]
+    fn publication_years_are_matched_on_the_client() {
+        let filter = BookFilter {
+            year_from: Some(2015),
+            year_to: Some(2020),
+            ..Default::default()
+        };
+        let mut variables = Variables::default();
+        filter.update_variables(&mut variables);
+        assert_eq!(variables, Variables::default());
+        assert!(filter.filters_locally());
+        let work = |date: Option<&str>| WorkWithRelations {
+            publication_date: date.map(str::to_string),
+            ..Default::default()
+        };
+        assert!(filter.matches(&work(Some("2015-01-01"))));
+        assert!(filter.matches(&work(Some("2020-12-31"))));
+        assert!(!filter.matches(&work(Some("2021-03-01"))));
+        assert!(!filter.matches(&work(None)));
+        assert!(filter.matches_facets(&work(None), Some(LocalFacet::PublicationYear)));
+    }
+
+    #[test]
+    fn subject_schemes_are_matched_on_the_client() {
+        let filter = BookFilter {
+            subject_types: vec![SubjectType::Thema],
+            ..Default::default()
+        };
+        let mut variables = Variables::default();
+        filter.update_variables(&mut variables);
+        assert_eq!(variables, Variables::default());
+        assert!(filter.filters_locally());
+        let work = |subject_type: SubjectType| WorkWithRelations {
+            subjects: Some(vec![Subject {
+                subject_type,
+                ..Default::default()
+            }]),
+            ..Default::default()
+        };
+        assert!(filter.matches(&work(SubjectType::Thema)));
+        assert!(!filter.matches(&work(SubjectType::Bic)));
+        assert!(filter.matches_facets(&work(SubjectType::Bic), Some(LocalFacet::SubjectScheme)));
+    }
+
+    #[test]
+    fn open_licences_are_matched_on_the_client() {
+        let filter = BookFilter {
+            open_license_only: true,
+            ..Default::default()
+        };
+        let mut variables = Variables::default();
+        filter.update_variables(&mut variables);
+        assert_eq!(variables, Variables::default());
+        assert!(filter.filters_locally());
+        let work = |license: Option<&str>| WorkWithRelations {
+            license: license.map(str::to_string),
+            ..Default::default()
+        };
+        assert!(filter.matches(&work(Some(
+            "https://creativecommons.org/licenses/by-nc/4.0/"
+        ))));
+        assert!(!filter.matches(&work(Some("https://example.org/all-rights-reserved"))));
+        assert!(!filter.matches(&work(None)));
+        assert!(filter.matches_facets(&work(None), Some(LocalFacet::OpenLicense)));
+    }
+
+    #[test]
+    fn selections_excluding_each_other_match_nothing() {
+        let filter = filter("status:active", vec![WorkStatus::Cancelled]);
+        let mut variables = Variables::default();
+        filter.update_variables(&mut variables);
+        assert_eq!(variables.work_statuses, None);
+        assert!(filter.filters_locally());
+        assert!(!filter.matches(&WorkWithRelations {
+            work_status: WorkStatus::Active,
+            ..Default::default()
+        }));
+    }
+}
@@ -1,4 +1,4 @@
-use crate::models::utils::WorkWithRelations;
+use crate::models::utils::{WorkType, WorkWithRelations};
 use serde::Deserialize;
 use serde::Serialize;
 
@@ -12,12 +12,33 @@ pub const WORKS_QUERY_BODY: &str = "
             title
             landingPage
             doi
+            reference
+            shortAbstract
+            longAbstract
             coverUrl
             license
             place
             publicationDate
             withdrawnDate
             updatedAt
+            subjects {
+                subjectId
+                workId
+                subjectType
+                subjectCode
+                subjectOrdinal
+                createdAt
+                updatedAt
+            }
+            languages {
+                languageId
+                workId
+                languageCode
+                languageRelation
+                mainLanguage
+                createdAt
+                updatedAt
+            }
             contributions {
                 contributionId
                 workId
@@ -40,6 +61,14 @@ pub const WORKS_QUERY_BODY: &str = "
                     updatedAt
                 }
             }
+            publications {
+                publicationId
+                publicationType
+                workId
+                isbn
+                createdAt
+                updatedAt
+            }
             imprint {
                 imprintId
                 imprintName
@@ -55,15 +84,97 @@ pub const WORKS_QUERY_BODY: &str = "
             }
         }";
 
+/// Types of the works listed as books, i.e. every type but chapters. These are the default of the
+/// `$workTypes` variable below, so that the list is the same as that of the API's `books` query.
+pub const BOOK_TYPES: [WorkType; 5] = [
+    WorkType::Monograph,
+    WorkType::EditedBook,
+    WorkType::Textbook,
+    WorkType::JournalIssue,
+    WorkType::BookSet,
+];
+
+// Queried as `works` rather than `books`, which cannot be restricted to some work types
 pub const BOOKS_QUERY_HEADER: &str = "
-    query BooksQuery($limit: Int, $offset: Int, $filter: String, $publishers: [Uuid!], $order: WorkOrderBy) {
-        books(limit: $limit, offset: $offset, filter: $filter, publishers: $publishers, order: $order) {";
+    query BooksQuery($limit: Int, $offset: Int, $filter: String, $publishers: [Uuid!], $order: WorkOrderBy, $workTypes: [WorkType!] = [MONOGRAPH, EDITED_BOOK, TEXTBOOK, JOURNAL_ISSUE, BOOK_SET], $workStatuses: [WorkStatus!]) {
+        books: works(limit: $limit, offset: $offset, filter: $filter, publishers: $publishers, order: $order, workTypes: $workTypes, workStatuses: $workStatuses) {";
 
 pub const BOOKS_QUERY_FOOTER: &str = "
-        bookCount(filter: $filter, publishers: $publishers)
+        bookCount: workCount(filter: $filter, publishers: $publishers, workTypes: $workTypes, workStatuses: $workStatuses)
     }
 ";
 
+/// Fields of the works scanned by a list filtered on the client, i.e. those the filter and the
+/// list's rows read, leaving out each contribution's contributor, the place and withdrawal date
+#[cfg(any(target_arch = "wasm32", test))]
+pub const WORKS_SCAN_QUERY_BODY: &str = "
+            workId
+            workType
+            workStatus
+            fullTitle
+            title
+            landingPage
+            doi
+            reference
+            shortAbstract
+            longAbstract
+            coverUrl
+            license
+            publicationDate
+            updatedAt
+            subjects {
+                subjectId
+                workId
+                subjectType
+                subjectCode
+                subjectOrdinal
+                createdAt
+                updatedAt
+            }
+            languages {
+                languageId
+                workId
+                languageCode
+                languageRelation
+                mainLanguage
+                createdAt
+                updatedAt
+            }
+            contributions {
+                contributionId
+                workId
+                contributorId
+                contributionType
+                mainContribution
+                createdAt
+                updatedAt
+                lastName
+                fullName
+                contributionOrdinal
+            }
+            publications {
+                publicationId
+                publicationType
+                workId
+                isbn
+                createdAt
+                updatedAt
+            }
+            imprint {
+                imprintId
+                imprintName
+                updatedAt
+                publisher {
+                    publisherId
+                    publisherName
+                    publisherShortname
+                    publisherUrl
+                    createdAt
+                    updatedAt
+                }
+            }
+        }";
+
 graphql_query_builder! {
     BooksRequest,
     BooksRequestBody,
@@ -0,0 +1,27 @@
+use serde::{Deserialize, Serialize};
+
+use crate::models::book::book_query::WORK_QUERY_BODY;
+use crate::models::book::books_query::{BOOKS_QUERY_FOOTER, BOOKS_QUERY_HEADER};
+use crate::models::utils::WorkWithRelations;
+
+pub use crate::models::utils::Variables;
+
+// The books list, with each work's full record rather than its summary, so that the native build
+// steps need no further request per work
+graphql_query_builder! {
+    CatalogueRequest,
+    CatalogueRequestBody,
+    Variables,
+    format!("{BOOKS_QUERY_HEADER}{WORK_QUERY_BODY}{BOOKS_QUERY_FOOTER}"),
+    CatalogueResponseBody,
+    CatalogueResponseData,
+    FetchCatalogue,
+    FetchActionCatalogue
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct CatalogueResponseData {
+    pub books: Vec<WorkWithRelations>,
+    pub book_count: i32,
+}
@@ -0,0 +1,170 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::WORK_FIELDS;
+use crate::component::ToOption;
+use crate::models::utils::{Doi, Work, WorkStatus, WorkType, WorkWithRelations};
+
+const CREATE_WORK_MUTATION: &str = "
+    mutation CreateWork(
+        $workType: WorkType!,
+        $workStatus: WorkStatus!,
+        $fullTitle: String!,
+        $title: String!,
+        $subtitle: String,
+        $reference: String,
+        $edition: Int,
+        $doi: Doi,
+        $publicationDate: NaiveDate,
+        $withdrawnDate: NaiveDate,
+        $place: String,
+        $pageCount: Int,
+        $pageBreakdown: String,
+        $imageCount: Int,
+        $tableCount: Int,
+        $audioCount: Int,
+        $videoCount: Int,
+        $license: String,
+        $copyrightHolder: String,
+        $landingPage: String,
+        $lccn: String,
+        $oclc: String,
+        $shortAbstract: String,
+        $longAbstract: String,
+        $generalNote: String,
+        $bibliographyNote: String,
+        $toc: String,
+        $coverUrl: String,
+        $coverCaption: String,
+        $imprintId: Uuid!,
+        $firstPage: String,
+        $lastPage: String,
+        $pageInterval: String
+    ){
+        createWork(data: {
+            workType: $workType
+            workStatus: $workStatus
+            fullTitle: $fullTitle
+            title: $title
+            subtitle: $subtitle
+            reference: $reference
+            edition: $edition
+            doi: $doi
+            publicationDate: $publicationDate
+            withdrawnDate: $withdrawnDate
+            place: $place
+            pageCount: $pageCount
+            pageBreakdown: $pageBreakdown
+            imageCount: $imageCount
+            tableCount: $tableCount
+            audioCount: $audioCount
+            videoCount: $videoCount
+            license: $license
+            copyrightHolder: $copyrightHolder
+            landingPage: $landingPage
+            lccn: $lccn
+            oclc: $oclc
+            shortAbstract: $shortAbstract
+            longAbstract: $longAbstract
+            generalNote: $generalNote
+            bibliographyNote: $bibliographyNote
+            toc: $toc
+            coverUrl: $coverUrl
+            coverCaption: $coverCaption
+            imprintId: $imprintId
+            firstPage: $firstPage
+            lastPage: $lastPage
+            pageInterval: $pageInterval
+        }){";
+
+graphql_mutation_builder! {
+    CreateWorkRequest,
+    CreateWorkRequestBody,
+    Variables,
+    format!("{CREATE_WORK_MUTATION}{WORK_FIELDS}"),
+    CreateWorkResponseBody,
+    CreateWorkResponseData,
+    create_work,
+    Work,
+    PushCreateWork,
+    PushActionCreateWork
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Variables {
+    pub work_type: WorkType,
+    pub work_status: WorkStatus,
+    pub full_title: String,
+    pub title: String,
+    pub subtitle: Option<String>,
+    pub reference: Option<String>,
+    pub edition: Option<i32>,
+    pub doi: Option<Doi>,
+    pub publication_date: Option<NaiveDate>,
+    pub withdrawn_date: Option<NaiveDate>,
+    pub place: Option<String>,
+    pub page_count: Option<i32>,
+    pub page_breakdown: Option<String>,
+    pub image_count: Option<i32>,
+    pub table_count: Option<i32>,
+    pub audio_count: Option<i32>,
+    pub video_count: Option<i32>,
+    pub license: Option<String>,
+    pub copyright_holder: Option<String>,
+    pub landing_page: Option<String>,
+    pub lccn: Option<String>,
+    pub oclc: Option<String>,
+    pub short_abstract: Option<String>,
+    pub long_abstract: Option<String>,
+    pub general_note: Option<String>,
+    pub bibliography_note: Option<String>,
+    pub toc: Option<String>,
+    pub cover_url: Option<String>,
+    pub cover_caption: Option<String>,
+    pub imprint_id: Uuid,
+    pub first_page: Option<String>,
+    pub last_page: Option<String>,
+    pub page_interval: Option<String>,
+}
+
+impl From<&WorkWithRelations> for Variables {
+    fn from(work: &WorkWithRelations) -> Self {
+        Variables {
+            work_type: work.work_type.clone(),
+            work_status: work.work_status.clone(),
+            full_title: work.compile_fulltitle(),
+            title: work.title.clone(),
+            subtitle: work.subtitle.clone(),
+            reference: work.reference.clone(),
+            edition: work.edition,
+            doi: work.doi.clone(),
+            publication_date: work.publication_date.clone().and_then(|d| d.to_opt_date()),
+            withdrawn_date: work.withdrawn_date.clone().and_then(|d| d.to_opt_date()),
+            place: work.place.clone(),
+            page_count: work.page_count,
+            page_breakdown: work.page_breakdown.clone(),
+            image_count: work.image_count,
+            table_count: work.table_count,
+            audio_count: work.audio_count,
+            video_count: work.video_count,
+            license: work.license.clone(),
+            copyright_holder: work.copyright_holder.clone(),
+            landing_page: work.landing_page.clone(),
+            lccn: work.lccn.clone(),
+            oclc: work.oclc.clone(),
+            short_abstract: work.short_abstract.clone(),
+            long_abstract: work.long_abstract.clone(),
+            general_note: work.general_note.clone(),
+            bibliography_note: work.bibliography_note.clone(),
+            toc: work.toc.clone(),
+            cover_url: work.cover_url.clone(),
+            cover_caption: work.cover_caption.clone(),
+            imprint_id: work.imprint.imprint_id,
+            first_page: work.first_page.clone(),
+            last_page: work.last_page.clone(),
+            page_interval: work.page_interval.clone(),
+        }
+    }
+}
@@ -0,0 +1,32 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::WORK_FIELDS;
+use crate::models::utils::Work;
+
+// Fetch the work as currently stored, to check for edits made since the editor loaded it
+const CURRENT_WORK_QUERY: &str = "
+    query CurrentWorkQuery($workId: Uuid!) {
+        work(workId: $workId) {";
+
+graphql_query_builder! {
+    CurrentWorkRequest,
+    CurrentWorkRequestBody,
+    Variables,
+    format!("{CURRENT_WORK_QUERY}{WORK_FIELDS}"),
+    CurrentWorkResponseBody,
+    CurrentWorkResponseData,
+    FetchCurrentWork,
+    FetchActionCurrentWork
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Variables {
+    pub work_id: Uuid,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct CurrentWorkResponseData {
+    pub work: Option<Work>,
+}
@@ -0,0 +1,32 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::WORK_FIELDS;
+use crate::models::utils::Work;
+
+const DELETE_WORK_MUTATION: &str = "
+    mutation DeleteWork(
+        $workId: Uuid!
+    ){
+        deleteWork(
+            workId: $workId
+        ){";
+
+graphql_mutation_builder! {
+    DeleteWorkRequest,
+    DeleteWorkRequestBody,
+    Variables,
+    format!("{DELETE_WORK_MUTATION}{WORK_FIELDS}"),
+    DeleteWorkResponseBody,
+    DeleteWorkResponseData,
+    delete_work,
+    Work,
+    PushDeleteWork,
+    PushActionDeleteWork
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Variables {
+    pub work_id: Uuid,
+}
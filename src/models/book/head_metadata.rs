@@ -0,0 +1,481 @@
+use serde_json::{json, Value};
+
+use crate::models::utils::{
+    Contribution, ContributionType, PublicationType, SubjectType, WorkWithRelations, DOI_DOMAIN,
+};
+
+/// Marks elements added to the document head for the page currently shown
+pub const PAGE_ATTRIBUTE: &str = "data-page-metadata";
+
+// Longest description offered to search engines and link previews, in characters
+const DESCRIPTION_LENGTH: usize = 300;
+
+/// Attribute by which a `<meta>` tag is identified: citation and Twitter tags use `name`, while
+/// Open Graph tags use `property`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MetaKey {
+    Name(&'static str),
+    Property(&'static str),
+}
+
+impl MetaKey {
+    pub fn attribute(&self) -> (&'static str, &'static str) {
+        match *self {
+            MetaKey::Name(name) => ("name", name),
+            MetaKey::Property(property) => ("property", property),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MetaTag {
+    pub key: MetaKey,
+    pub content: String,
+}
+
+/// Everything describing a work in the document head: the page title, `<meta>` tags read by
+/// Google Scholar and social previews, and a Schema.org `Book` for structured data
+#[derive(Debug, Clone, PartialEq)]
+pub struct HeadMetadata {
+    pub title: String,
+    pub meta: Vec<MetaTag>,
+    pub json_ld: Value,
+}
+
+impl HeadMetadata {
+    /// Describe `work` as published by `site_name`. Empty values are left out rather than emitted
+    /// as empty tags or properties.
+    pub fn from_work(work: &WorkWithRelations, site_name: &str) -> Self {
+        let mut meta = vec![];
+        let mut tag = |key: MetaKey, content: &str| {
+            if !content.trim().is_empty() {
+                meta.push(MetaTag {
+                    key,
+                    content: content.to_string(),
+                });
+            }
+        };
+        let description = description(work);
+        let url = url(work);
+        let doi = work.doi.as_ref().map(|doi| doi.to_string());
+        let publisher = &work.imprint.publisher.publisher_name;
+        let isbns = isbns(work);
+        let keywords = keywords(work);
+        let language = language(work);
+
+        tag(
+            MetaKey::Name("description"),
+            description.as_deref().unwrap_or_default(),
+        );
+
+        tag(MetaKey::Name("citation_title"), &work.full_title);
+        for contribution in creators(work) {
+            tag(
+                MetaKey::Name("citation_author"),
+                &citation_name(contribution),
+            );
+        }
+        if let Some(date) = &work.publication_date {
+            tag(
+                MetaKey::Name("citation_publication_date"),
+                &date.replace('-', "/"),
+            );
+        }
+        tag(
+            MetaKey::Name("citation_doi"),
+            doi.as_deref().unwrap_or_default(),
+        );
+        for (_, isbn) in &isbns {
+            tag(MetaKey::Name("citation_isbn"), isbn);
+        }
+        tag(MetaKey::Name("citation_publisher"), publisher);
+        tag(
+            MetaKey::Name("citation_pdf_url"),
+            pdf_url(work).unwrap_or_default(),
+        );
+        tag(MetaKey::Name("citation_keywords"), &keywords.join("; "));
+        tag(
+            MetaKey::Name("citation_language"),
+            language.as_deref().unwrap_or_default(),
+        );
+
+        tag(MetaKey::Property("og:type"), "book");
+        tag(MetaKey::Property("og:site_name"), site_name);
+        tag(MetaKey::Property("og:title"), &work.full_title);
+        tag(
+            MetaKey::Property("og:description"),
+            description.as_deref().unwrap_or_default(),
+        );
+        tag(
+            MetaKey::Property("og:url"),
+            url.as_deref().unwrap_or_default(),
+        );
+        tag(
+            MetaKey::Property("og:image"),
+            work.cover_url.as_deref().unwrap_or_default(),
+        );
+        for (_, isbn) in &isbns {
+            tag(MetaKey::Property("book:isbn"), &isbn.replace('-', ""));
+        }
+        tag(
+            MetaKey::Property("book:release_date"),
+            work.publication_date.as_deref().unwrap_or_default(),
+        );
+        for keyword in &keywords {
+            tag(MetaKey::Property("book:tag"), keyword);
+        }
+        let card = match work.cover_url {
+            Some(_) => "summary_large_image",
+            None => "summary",
+        };
+        tag(MetaKey::Name("twitter:card"), card);
+
+        let person = |c: &Contribution| {
+            json!({
+                "@type": "Person",
+                "name": c.full_name,
+                "givenName": c.first_name,
+                "familyName": c.last_name,
+                "sameAs": c.contributor.orcid.as_ref().map(|orcid| orcid.to_string()),
+            })
+        };
+        let json_ld = json!({
+            "@context": "https://schema.org",
+            "@type": "Book",
+            "name": work.title,
+            "alternativeHeadline": work.subtitle,
+            "author": contributions(work, ContributionType::Author).map(person).collect::<Vec<_>>(),
+            "editor": contributions(work, ContributionType::Editor).map(person).collect::<Vec<_>>(),
+            "publisher": {
+                "@type": "Organization",
+                "name": publisher,
+                "url": work.imprint.publisher.publisher_url,
+            },
+            "datePublished": work.publication_date,
+            "description": description,
+            "url": url,
+            "sameAs": doi.as_ref().map(|doi| format!("{DOI_DOMAIN}{doi}")),
+            "identifier": doi.as_ref().map(|doi| json!({
+                "@type": "PropertyValue",
+                "propertyID": "DOI",
+                "value": doi,
+            })),
+            "isbn": isbns.iter().map(|(_, isbn)| isbn).collect::<Vec<_>>(),
+            "image": work.cover_url,
+            "license": work.license,
+            "inLanguage": language,
+            "keywords": keywords.join(", "),
+            "bookEdition": work.edition.map(|edition| edition.to_string()),
+            "numberOfPages": work.page_count,
+            "workExample": isbns.iter().map(|(publication_type, isbn)| json!({
+                "@type": "Book",
+                "isbn": isbn,
+                "bookFormat": book_format(publication_type),
+            })).collect::<Vec<_>>(),
+        });
+
+        HeadMetadata {
+            title: format!("{} - {}", work.full_title, site_name),
+            meta,
+            json_ld: compact(json_ld),
+        }
+    }
+}
+
+/// Short abstract, or failing that the long one, on a single line and cut at a word boundary
+fn description(work: &WorkWithRelations) -> Option<String> {
+    let text = work
+        .short_abstract
+        .as_ref()
+        .or(work.long_abstract.as_ref())?
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ");
+    if text.chars().count() <= DESCRIPTION_LENGTH {
+        return Some(text).filter(|t| !t.is_empty());
+    }
+    let cut: String = text.chars().take(DESCRIPTION_LENGTH).collect();
+    let cut = cut.rsplit_once(' ').map_or(cut.as_str(), |(head, _)| head);
+    Some(format!(
+        "{}…",
+        cut.trim_end_matches(|c: char| c.is_ascii_punctuation())
+    ))
+}
+
+/// Page under which the work is published, falling back to its DOI
+fn url(work: &WorkWithRelations) -> Option<String> {
+    work.landing_page
+        .clone()
+        .or_else(|| work.doi.as_ref().map(|doi| format!("{DOI_DOMAIN}{doi}")))
+}
+
+fn contributions(
+    work: &WorkWithRelations,
+    contribution_type: ContributionType,
+) -> impl Iterator<Item = &Contribution> {
+    let mut contributions: Vec<&Contribution> = work
+        .contributions
+        .iter()
+        .flatten()
+        .filter(|c| c.contribution_type == contribution_type)
+        .collect();
+    contributions.sort_by_key(|c| c.contribution_ordinal);
+    contributions.into_iter()
+}
+
+/// Authors of the work, or its editors in the case of edited volumes
+fn creators(work: &WorkWithRelations) -> Vec<&Contribution> {
+    let authors: Vec<&Contribution> = contributions(work, ContributionType::Author).collect();
+    match authors.is_empty() {
+        true => contributions(work, ContributionType::Editor).collect(),
+        false => authors,
+    }
+}
+
+/// Name in the "Last, First" form preferred by Google Scholar
+fn citation_name(contribution: &Contribution) -> String {
+    match &contribution.first_name {
+        Some(first_name) => format!("{}, {}", contribution.last_name, first_name),
+        None => contribution.full_name.clone(),
+    }
+}
+
+fn isbns(work: &WorkWithRelations) -> Vec<(&PublicationType, String)> {
+    work.publications
+        .iter()
+        .flatten()
+        .filter_map(|p| {
+            p.isbn
+                .as_ref()
+                .map(|isbn| (&p.publication_type, isbn.to_string()))
+        })
+        .collect()
+}
+
+/// Full text of the canonical PDF location, if the work is openly available as one
+fn pdf_url(work: &WorkWithRelations) -> Option<&str> {
+    work.publications
+        .iter()
+        .flatten()
+        .filter(|p| p.publication_type == PublicationType::Pdf)
+        .flat_map(|p| p.locations.iter().flatten())
+        .find(|l| l.canonical)
+        .and_then(|l| l.full_text_url.as_deref())
+}
+
+fn keywords(work: &WorkWithRelations) -> Vec<&str> {
+    let mut subjects: Vec<_> = work
+        .subjects
+        .iter()
+        .flatten()
+        .filter(|s| s.subject_type == SubjectType::Keyword)
+        .collect();
+    subjects.sort_by_key(|s| s.subject_ordinal);
+    subjects.iter().map(|s| s.subject_code.as_str()).collect()
+}
+
+/// ISO 639-2 code of the main language of the work
+fn language(work: &WorkWithRelations) -> Option<String> {
+    work.languages
+        .iter()
+        .flatten()
+        .find(|l| l.main_language)
+        .map(|l| l.language_code.to_string().to_lowercase())
+}
+
+fn book_format(publication_type: &PublicationType) -> &'static str {
+    match publication_type {
+        PublicationType::Paperback => "https://schema.org/Paperback",
+        PublicationType::Hardback => "https://schema.org/Hardcover",
+        _ => "https://schema.org/EBook",
+    }
+}
+
+/// Drop null, empty string and empty array properties, which structured data validators flag
+fn compact(value: Value) -> Value {
+    match value {
+        Value::Object(map) => Value::Object(
+            map.into_iter()
+                .map(|(key, value)| (key, compact(value)))
+                .filter(|(_, value)| match value {
+                    Value::Null => false,
+                    Value::String(s) => !s.is_empty(),
+                    Value::Array(a) => !a.is_empty(),
+                    _ => true,
+                })
+                .collect(),
+        ),
+        Value::Array(values) => Value::Array(values.into_iter().map(compact).collect()),
+        value => value,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn works() -> Vec<WorkWithRelations> {
+        serde_json::from_str(include_str!("../../../fixtures/works.json")).unwrap()
+    }
+
+    fn tags(metadata: &HeadMetadata) -> Vec<(&'static str, &str)> {
+        metadata
+            .meta
+            .iter()
+            .map(|tag| (tag.key.attribute().1, tag.content.as_str()))
+            .collect()
+    }
+
+    #[test]
+    fn work_is_described_by_citation_and_open_graph_tags() {
+        let metadata = HeadMetadata::from_work(&works()[0], "Example Books");
+        assert_eq!(
+            metadata.title,
+            "Trade & Empire: A History of Europe - Example Books"
+        );
+        let description = "How trade shaped <Europe> since 1500.";
+        assert_eq!(
+            tags(&metadata),
+            vec![
+                ("description", description),
+                ("citation_title", "Trade & Empire: A History of Europe"),
+                // Authors in their order on the work, without other contributors
+                ("citation_author", "Smith, Jane"),
+                ("citation_author", "Núñez, José"),
+                ("citation_publication_date", "2021/05/04"),
+                ("citation_doi", "10.11647/OBP.0001"),
+                ("citation_isbn", "978-1-80064-000-1"),
+                ("citation_isbn", "978-1-80064-001-8"),
+                ("citation_publisher", "Example Press"),
+                (
+                    "citation_pdf_url",
+                    "https://books.example.org/trade-and-empire.pdf"
+                ),
+                ("citation_keywords", "trade; empire"),
+                ("citation_language", "eng"),
+                ("og:type", "book"),
+                ("og:site_name", "Example Books"),
+                ("og:title", "Trade & Empire: A History of Europe"),
+                ("og:description", description),
+                ("og:url", "https://books.example.org/trade-and-empire"),
+                (
+                    "og:image",
+                    "https://books.example.org/covers/trade-and-empire.jpg"
+                ),
+                ("book:isbn", "9781800640001"),
+                ("book:isbn", "9781800640018"),
+                ("book:release_date", "2021-05-04"),
+                ("book:tag", "trade"),
+                ("book:tag", "empire"),
+                ("twitter:card", "summary_large_image"),
+            ]
+        );
+        assert_eq!(metadata.meta[0].key.attribute(), ("name", "description"));
+        assert_eq!(metadata.meta[12].key.attribute(), ("property", "og:type"));
+    }
+
+    #[test]
+    fn work_is_described_as_a_schema_org_book() {
+        let metadata = HeadMetadata::from_work(&works()[0], "Example Books");
+        assert_eq!(
+            metadata.json_ld,
+            json!({
+                "@context": "https://schema.org",
+                "@type": "Book",
+                "name": "Trade & Empire",
+                "alternativeHeadline": "A History of Europe",
+                "author": [
+                    {
+                        "@type": "Person",
+                        "name": "Jane Smith",
+                        "givenName": "Jane",
+                        "familyName": "Smith",
+                        "sameAs": "https://orcid.org/0000-0002-1825-0097",
+                    },
+                    {
+                        "@type": "Person",
+                        "name": "José Núñez",
+                        "givenName": "José",
+                        "familyName": "Núñez",
+                    },
+                ],
+                "publisher": {
+                    "@type": "Organization",
+                    "name": "Example Press",
+                    "url": "https://press.example.org",
+                },
+                "datePublished": "2021-05-04",
+                "description": "How trade shaped <Europe> since 1500.",
+                "url": "https://books.example.org/trade-and-empire",
+                "sameAs": "https://doi.org/10.11647/OBP.0001",
+                "identifier": {
+                    "@type": "PropertyValue",
+                    "propertyID": "DOI",
+                    "value": "10.11647/OBP.0001",
+                },
+                "isbn": ["978-1-80064-000-1", "978-1-80064-001-8"],
+                "image": "https://books.example.org/covers/trade-and-empire.jpg",
+                "license": "https://creativecommons.org/licenses/by/4.0/",
+                "inLanguage": "eng",
+                "keywords": "trade, empire",
+                "bookEdition": "2",
+                "numberOfPages": 320,
+                "workExample": [
+                    {
+                        "@type": "Book",
+                        "isbn": "978-1-80064-000-1",
+                        "bookFormat": "https://schema.org/Paperback",
+                    },
+                    {
+                        "@type": "Book",
+                        "isbn": "978-1-80064-001-8",
+                        "bookFormat": "https://schema.org/EBook",
+                    },
+                ],
+            })
+        );
+    }
+
+    #[test]
+    fn empty_fields_are_left_out() {
+        let metadata = HeadMetadata::from_work(&works()[1], "Example Books");
+        // Editors stand in for the authors of an edited volume, under their full name if they
+        // have no first name to put last
+        assert_eq!(
+            tags(&metadata),
+            vec![
+                ("citation_title", "Ports of Call"),
+                ("citation_author", "Min Lee"),
+                ("citation_publication_date", "2031/01/15"),
+                ("citation_publisher", "Example Press"),
+                ("og:type", "book"),
+                ("og:site_name", "Example Books"),
+                ("og:title", "Ports of Call"),
+                ("book:release_date", "2031-01-15"),
+                ("twitter:card", "summary"),
+            ]
+        );
+        assert_eq!(
+            metadata.json_ld,
+            json!({
+                "@context": "https://schema.org",
+                "@type": "Book",
+                "name": "Ports of Call",
+                "editor": [{ "@type": "Person", "name": "Min Lee", "familyName": "Lee" }],
+                "publisher": { "@type": "Organization", "name": "Example Press" },
+                "datePublished": "2031-01-15",
+            })
+        );
+    }
+
+    #[test]
+    fn long_descriptions_are_cut_at_a_word() {
+        let work = WorkWithRelations {
+            long_abstract: Some(format!("{} ending, here", "word ".repeat(60))),
+            ..Default::default()
+        };
+        let description = description(&work).unwrap();
+        assert!(description.chars().count() <= DESCRIPTION_LENGTH + 1);
+        assert!(description.ends_with("word…"));
+    }
+}
@@ -0,0 +1,43 @@
+use serde::{Deserialize, Serialize};
+
+use crate::models::utils::ImprintWithPublisher;
+
+/// Imprints and their publishers, to restrict requests for an imprint's works to its publisher
+pub const IMPRINTS_QUERY: &str = "
+    query ImprintsQuery($publishers: [Uuid!]) {
+        imprints(limit: 9999, publishers: $publishers) {
+            imprintId
+            imprintName
+            updatedAt
+            publisher {
+                publisherId
+                publisherName
+                publisherShortname
+                publisherUrl
+                createdAt
+                updatedAt
+            }
+        }
+    }
+";
+
+graphql_query_builder! {
+    ImprintsRequest,
+    ImprintsRequestBody,
+    Variables,
+    IMPRINTS_QUERY,
+    ImprintsResponseBody,
+    ImprintsResponseData,
+    FetchImprints,
+    FetchActionImprints
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Variables {
+    pub publishers: Option<Vec<String>>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ImprintsResponseData {
+    pub imprints: Vec<ImprintWithPublisher>,
+}
@@ -1,2 +1,354 @@
+#[cfg(any(target_arch = "wasm32", test))]
+pub mod book_facet_records_query;
+#[cfg(any(target_arch = "wasm32", test))]
+pub mod book_facets_query;
 pub mod book_query;
+#[cfg(any(target_arch = "wasm32", test))]
+pub mod books_filter;
 pub mod books_query;
+// Only requested by the native build steps
+#[cfg(not(target_arch = "wasm32"))]
+pub mod catalogue_query;
+#[cfg(any(target_arch = "wasm32", test))]
+pub mod create_work_mutation;
+#[cfg(any(target_arch = "wasm32", test))]
+pub mod current_work_query;
+#[cfg(any(target_arch = "wasm32", test))]
+pub mod delete_work_mutation;
+pub mod head_metadata;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod imprints_query;
+#[cfg(any(target_arch = "wasm32", test))]
+pub mod new_work_query;
+#[cfg(any(target_arch = "wasm32", test))]
+pub mod update_work_mutation;
+#[cfg(any(target_arch = "wasm32", test))]
+pub mod work_form_data_query;
+
+#[cfg(any(target_arch = "wasm32", test))]
+use std::fmt::Display;
+
+#[cfg(any(target_arch = "wasm32", test))]
+use crate::i18n::Message;
+#[cfg(any(target_arch = "wasm32", test))]
+use crate::models::utils::{ImprintWithPublisher, Work, WorkWithRelations};
+#[cfg(any(target_arch = "wasm32", test))]
+use crate::string::{
+    FIELD_AUDIO_COUNT, FIELD_BIBLIOGRAPHY_NOTE, FIELD_COPYRIGHT_HOLDER, FIELD_COVER_CAPTION,
+    FIELD_COVER_URL, FIELD_DOI, FIELD_EDITION, FIELD_FIRST_PAGE, FIELD_GENERAL_NOTE,
+    FIELD_IMAGE_COUNT, FIELD_IMPRINT, FIELD_LANDING_PAGE, FIELD_LAST_PAGE, FIELD_LCCN_NUMBER,
+    FIELD_LICENSE, FIELD_LONG_ABSTRACT, FIELD_OCLC, FIELD_PAGE_BREAKDOWN, FIELD_PAGE_COUNT,
+    FIELD_PLACE, FIELD_PUBLICATION_DATE, FIELD_REFERENCE, FIELD_SHORT_ABSTRACT, FIELD_SUBTITLE,
+    FIELD_TABLE_COUNT, FIELD_TITLE, FIELD_TOC, FIELD_VIDEO_COUNT, FIELD_WITHDRAWN_DATE,
+    FIELD_WORK_STATUS, FIELD_WORK_TYPE,
+};
+
+/// Fields returned by every work mutation, matching those of `Work`
+#[cfg(any(target_arch = "wasm32", test))]
+pub const WORK_FIELDS: &str = "
+            workId
+            workType
+            workStatus
+            fullTitle
+            title
+            subtitle
+            reference
+            edition
+            imprintId
+            doi
+            publicationDate
+            withdrawnDate
+            place
+            pageCount
+            pageBreakdown
+            imageCount
+            tableCount
+            audioCount
+            videoCount
+            license
+            copyrightHolder
+            landingPage
+            lccn
+            oclc
+            shortAbstract
+            longAbstract
+            generalNote
+            bibliographyNote
+            toc
+            coverUrl
+            coverCaption
+            createdAt
+            updatedAt
+            firstPage
+            lastPage
+            pageInterval
+            updatedAtWithRelations
+        }
+    }
+";
+
+/// A metadata field which someone else changed since the editor loaded the work
+#[cfg(any(target_arch = "wasm32", test))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldChange {
+    pub field: Message,
+    pub ours: String,
+    pub theirs: String,
+    // Whether the field was also edited locally, to a different value
+    pub conflicting: bool,
+}
+
+#[cfg(any(target_arch = "wasm32", test))]
+fn display<T: Display>(value: &Option<T>) -> String {
+    value.as_ref().map(|v| v.to_string()).unwrap_or_default()
+}
+
+/// Editable metadata of a work, as (label, value) pairs in the order of the edit form
+#[cfg(any(target_arch = "wasm32", test))]
+macro_rules! work_metadata {
+    ($work:expr, $imprint_id:expr) => {
+        vec![
+            (FIELD_WORK_TYPE, $work.work_type.to_string()),
+            (FIELD_WORK_STATUS, $work.work_status.to_string()),
+            (FIELD_IMPRINT, $imprint_id.to_string()),
+            (FIELD_TITLE, $work.title.clone()),
+            (FIELD_SUBTITLE, display(&$work.subtitle)),
+            (FIELD_REFERENCE, display(&$work.reference)),
+            (FIELD_EDITION, display(&$work.edition)),
+            (FIELD_DOI, display(&$work.doi)),
+            (FIELD_PUBLICATION_DATE, display(&$work.publication_date)),
+            (FIELD_WITHDRAWN_DATE, display(&$work.withdrawn_date)),
+            (FIELD_PLACE, display(&$work.place)),
+            (FIELD_PAGE_COUNT, display(&$work.page_count)),
+            (FIELD_PAGE_BREAKDOWN, display(&$work.page_breakdown)),
+            (FIELD_FIRST_PAGE, display(&$work.first_page)),
+            (FIELD_LAST_PAGE, display(&$work.last_page)),
+            (FIELD_IMAGE_COUNT, display(&$work.image_count)),
+            (FIELD_TABLE_COUNT, display(&$work.table_count)),
+            (FIELD_AUDIO_COUNT, display(&$work.audio_count)),
+            (FIELD_VIDEO_COUNT, display(&$work.video_count)),
+            (FIELD_LICENSE, display(&$work.license)),
+            (FIELD_COPYRIGHT_HOLDER, display(&$work.copyright_holder)),
+            (FIELD_LANDING_PAGE, display(&$work.landing_page)),
+            (FIELD_LCCN_NUMBER, display(&$work.lccn)),
+            (FIELD_OCLC, display(&$work.oclc)),
+            (FIELD_SHORT_ABSTRACT, display(&$work.short_abstract)),
+            (FIELD_LONG_ABSTRACT, display(&$work.long_abstract)),
+            (FIELD_GENERAL_NOTE, display(&$work.general_note)),
+            (FIELD_BIBLIOGRAPHY_NOTE, display(&$work.bibliography_note)),
+            (FIELD_TOC, display(&$work.toc)),
+            (FIELD_COVER_URL, display(&$work.cover_url)),
+            (FIELD_COVER_CAPTION, display(&$work.cover_caption)),
+        ]
+    };
+}
+
+#[cfg(any(target_arch = "wasm32", test))]
+impl WorkWithRelations {
+    fn metadata(&self) -> Vec<(Message, String)> {
+        work_metadata!(self, self.imprint.imprint_id)
+    }
+}
+
+#[cfg(any(target_arch = "wasm32", test))]
+impl Work {
+    fn metadata(&self) -> Vec<(Message, String)> {
+        work_metadata!(self, self.imprint_id)
+    }
+}
+
+/// Compare the work as it is now stored (`theirs`) with the version the editor loaded (`base`),
+/// returning every field changed in the meantime alongside the local value of that field
+#[cfg(any(target_arch = "wasm32", test))]
+pub fn changes_since(
+    base: &WorkWithRelations,
+    ours: &WorkWithRelations,
+    theirs: &Work,
+) -> Vec<FieldChange> {
+    base.metadata()
+        .into_iter()
+        .zip(ours.metadata())
+        .zip(theirs.metadata())
+        .filter(|(((_, base), _), (_, theirs))| base != theirs)
+        .map(|(((field, base), (_, ours)), (_, theirs))| FieldChange {
+            field,
+            conflicting: ours != base && ours != theirs,
+            ours,
+            theirs,
+        })
+        .collect()
+}
+
+/// Apply the edits made since the editor loaded the work (`base`) to the work as it is now stored
+/// (`theirs`), so that saving our version only overwrites the fields we changed ourselves.
+/// `imprints` supplies the details of an imprint someone else selected.
+#[cfg(any(target_arch = "wasm32", test))]
+pub fn merge_changes(
+    base: &WorkWithRelations,
+    ours: &WorkWithRelations,
+    theirs: &Work,
+    imprints: &[ImprintWithPublisher],
+) -> WorkWithRelations {
+    let mut merged = ours.clone();
+    macro_rules! theirs_unless_edited {
+        ($($field:ident),* $(,)?) => {
+            $(
+                if ours.$field == base.$field {
+                    merged.$field = theirs.$field.clone();
+                }
+            )*
+        };
+    }
+    theirs_unless_edited!(
+        work_type,
+        work_status,
+        title,
+        subtitle,
+        reference,
+        edition,
+        doi,
+        place,
+        page_count,
+        page_breakdown,
+        first_page,
+        last_page,
+        image_count,
+        table_count,
+        audio_count,
+        video_count,
+        license,
+        copyright_holder,
+        landing_page,
+        lccn,
+        oclc,
+        short_abstract,
+        long_abstract,
+        general_note,
+        bibliography_note,
+        toc,
+        cover_url,
+        cover_caption,
+    );
+    // The form holds dates as entered, whereas the stored work holds them parsed
+    if ours.publication_date == base.publication_date {
+        merged.publication_date = theirs.publication_date.map(|d| d.to_string());
+    }
+    if ours.withdrawn_date == base.withdrawn_date {
+        merged.withdrawn_date = theirs.withdrawn_date.map(|d| d.to_string());
+    }
+    if ours.imprint.imprint_id == base.imprint.imprint_id {
+        merged.imprint = imprints
+            .iter()
+            .find(|imprint| imprint.imprint_id == theirs.imprint_id)
+            .cloned()
+            .unwrap_or_else(|| ImprintWithPublisher {
+                imprint_id: theirs.imprint_id,
+                ..ours.imprint.clone()
+            });
+    }
+    merged.full_title = merged.compile_fulltitle();
+    merged.page_interval = merged.compile_page_interval();
+    merged
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+    use uuid::Uuid;
+
+    use super::*;
+
+    fn base() -> WorkWithRelations {
+        WorkWithRelations {
+            title: "Original title".to_string(),
+            edition: Some(1),
+            place: Some("Cambridge".to_string()),
+            ..Default::default()
+        }
+    }
+
+    fn stored(work: &WorkWithRelations) -> Work {
+        Work {
+            title: work.title.clone(),
+            edition: work.edition,
+            place: work.place.clone(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn changes_since_lists_fields_changed_by_others() {
+        let base = base();
+        let ours = WorkWithRelations {
+            title: "Our title".to_string(),
+            place: Some("London".to_string()),
+            ..base.clone()
+        };
+        let theirs = Work {
+            title: "Their title".to_string(),
+            edition: Some(2),
+            place: Some("London".to_string()),
+            ..stored(&base)
+        };
+        assert_eq!(
+            changes_since(&base, &ours, &theirs),
+            vec![
+                FieldChange {
+                    field: FIELD_TITLE,
+                    ours: "Our title".to_string(),
+                    theirs: "Their title".to_string(),
+                    conflicting: true,
+                },
+                FieldChange {
+                    field: FIELD_EDITION,
+                    ours: "1".to_string(),
+                    theirs: "2".to_string(),
+                    conflicting: false,
+                },
+                // Both sides made the same edit, which does not conflict
+                FieldChange {
+                    field: FIELD_PLACE,
+                    ours: "London".to_string(),
+                    theirs: "London".to_string(),
+                    conflicting: false,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn merge_changes_keeps_their_edits_of_fields_we_left_alone() {
+        let base = base();
+        let ours = WorkWithRelations {
+            title: "Our title".to_string(),
+            place: Some("London".to_string()),
+            ..base.clone()
+        };
+        let theirs = Work {
+            title: "Their title".to_string(),
+            edition: Some(2),
+            publication_date: NaiveDate::from_ymd_opt(2024, 5, 1),
+            imprint_id: Uuid::from_u128(1),
+            ..stored(&base)
+        };
+        let merged = merge_changes(&base, &ours, &theirs, &[]);
+        // Our edits win, whether or not they conflict
+        assert_eq!(merged.title, "Our title");
+        assert_eq!(merged.place.as_deref(), Some("London"));
+        assert_eq!(merged.full_title, "Our title");
+        // Their edits of the other fields are kept rather than reverted
+        assert_eq!(merged.edition, Some(2));
+        assert_eq!(merged.publication_date.as_deref(), Some("2024-05-01"));
+        assert_eq!(merged.imprint.imprint_id, Uuid::from_u128(1));
+    }
+
+    #[test]
+    fn changes_since_is_empty_if_only_other_fields_changed() {
+        let base = base();
+        let theirs = Work {
+            updated_at: serde_json::from_str("\"2024-01-01T00:00:00Z\"").unwrap(),
+            ..stored(&base)
+        };
+        assert!(changes_since(&base, &base, &theirs).is_empty());
+    }
+}
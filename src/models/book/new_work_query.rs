@@ -0,0 +1,90 @@
+use serde::{Deserialize, Serialize};
+
+use crate::models::utils::{
+    ContributionTypeDefinition, ImprintWithPublisher, LanguageCodeDefinition,
+    LanguageRelationDefinition, PublicationTypeDefinition, SubjectTypeDefinition,
+    WorkStatusDefinition, WorkTypeDefinition,
+};
+
+// Everything needed to fill in the select inputs of the new work wizard
+pub const NEW_WORK_QUERY: &str = "
+    query NewWorkQuery($publishers: [Uuid!]) {
+        imprints(limit: 9999, publishers: $publishers) {
+            imprintId
+            imprintName
+            updatedAt
+            publisher {
+                publisherId
+                publisherName
+                publisherShortname
+                publisherUrl
+                createdAt
+                updatedAt
+            }
+        }
+        work_types: __type(name: \"WorkType\") {
+            enumValues {
+                name
+            }
+        }
+        work_statuses: __type(name: \"WorkStatus\") {
+            enumValues {
+                name
+            }
+        }
+        contribution_types: __type(name: \"ContributionType\") {
+            enumValues {
+                name
+            }
+        }
+        publication_types: __type(name: \"PublicationType\") {
+            enumValues {
+                name
+            }
+        }
+        language_codes: __type(name: \"LanguageCode\") {
+            enumValues {
+                name
+            }
+        }
+        language_relations: __type(name: \"LanguageRelation\") {
+            enumValues {
+                name
+            }
+        }
+        subject_types: __type(name: \"SubjectType\") {
+            enumValues {
+                name
+            }
+        }
+    }
+";
+
+graphql_query_builder! {
+    NewWorkRequest,
+    NewWorkRequestBody,
+    Variables,
+    NEW_WORK_QUERY,
+    NewWorkResponseBody,
+    NewWorkResponseData,
+    FetchNewWork,
+    FetchActionNewWork
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Variables {
+    pub publishers: Option<Vec<String>>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct NewWorkResponseData {
+    pub imprints: Vec<ImprintWithPublisher>,
+    pub work_types: WorkTypeDefinition,
+    pub work_statuses: WorkStatusDefinition,
+    pub contribution_types: ContributionTypeDefinition,
+    pub publication_types: PublicationTypeDefinition,
+    pub language_codes: LanguageCodeDefinition,
+    pub language_relations: LanguageRelationDefinition,
+    pub subject_types: SubjectTypeDefinition,
+}
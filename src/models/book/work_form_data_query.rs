@@ -0,0 +1,84 @@
+use serde::{Deserialize, Serialize};
+
+use crate::models::utils::{
+    ContributionTypeDefinition, ImprintWithPublisher, PublicationTypeDefinition,
+    RelationTypeDefinition, SubjectTypeDefinition, WorkStatusDefinition, WorkTypeDefinition,
+};
+
+// Options for the select inputs of the work editor, which are the same for every work and
+// hence only requested once editing starts
+pub const WORK_FORM_DATA_QUERY: &str = "
+    query WorkFormDataQuery($publishers: [Uuid!]) {
+        imprints(limit: 9999, publishers: $publishers) {
+            imprintId
+            imprintName
+            updatedAt
+            publisher {
+                publisherId
+                publisherName
+                publisherShortname
+                publisherUrl
+                createdAt
+                updatedAt
+            }
+        }
+        work_types: __type(name: \"WorkType\") {
+            enumValues {
+                name
+            }
+        }
+        work_statuses: __type(name: \"WorkStatus\") {
+            enumValues {
+                name
+            }
+        }
+        contribution_types: __type(name: \"ContributionType\") {
+            enumValues {
+                name
+            }
+        }
+        relation_types: __type(name: \"RelationType\") {
+            enumValues {
+                name
+            }
+        }
+        publication_types: __type(name: \"PublicationType\") {
+            enumValues {
+                name
+            }
+        }
+        subject_types: __type(name: \"SubjectType\") {
+            enumValues {
+                name
+            }
+        }
+    }
+";
+
+graphql_query_builder! {
+    WorkFormDataRequest,
+    WorkFormDataRequestBody,
+    Variables,
+    WORK_FORM_DATA_QUERY,
+    WorkFormDataResponseBody,
+    WorkFormDataResponseData,
+    FetchWorkFormData,
+    FetchActionWorkFormData
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Variables {
+    pub publishers: Option<Vec<String>>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct WorkFormDataResponseData {
+    pub imprints: Vec<ImprintWithPublisher>,
+    pub work_types: WorkTypeDefinition,
+    pub work_statuses: WorkStatusDefinition,
+    pub contribution_types: ContributionTypeDefinition,
+    pub relation_types: RelationTypeDefinition,
+    pub publication_types: PublicationTypeDefinition,
+    pub subject_types: SubjectTypeDefinition,
+}
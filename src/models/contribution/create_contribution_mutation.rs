@@ -0,0 +1,56 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::CONTRIBUTION_FIELDS;
+use crate::models::utils::{Contribution, ContributionType};
+
+const CREATE_CONTRIBUTION_MUTATION: &str = "
+    mutation CreateContribution(
+        $workId: Uuid!,
+        $contributorId: Uuid!,
+        $contributionType: ContributionType!,
+        $mainContribution: Boolean!,
+        $biography: String,
+        $firstName: String,
+        $lastName: String!,
+        $fullName: String!,
+        $contributionOrdinal: Int!
+    ){
+        createContribution(data: {
+            workId: $workId
+            contributorId: $contributorId
+            contributionType: $contributionType
+            mainContribution: $mainContribution
+            biography: $biography
+            firstName: $firstName
+            lastName: $lastName
+            fullName: $fullName
+            contributionOrdinal: $contributionOrdinal
+        }){";
+
+graphql_mutation_builder! {
+    CreateContributionRequest,
+    CreateContributionRequestBody,
+    Variables,
+    format!("{CREATE_CONTRIBUTION_MUTATION}{CONTRIBUTION_FIELDS}"),
+    CreateContributionResponseBody,
+    CreateContributionResponseData,
+    create_contribution,
+    Contribution,
+    PushCreateContribution,
+    PushActionCreateContribution
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Variables {
+    pub work_id: Uuid,
+    pub contributor_id: Uuid,
+    pub contribution_type: ContributionType,
+    pub main_contribution: bool,
+    pub biography: Option<String>,
+    pub first_name: Option<String>,
+    pub last_name: String,
+    pub full_name: String,
+    pub contribution_ordinal: i32,
+}
@@ -0,0 +1,32 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::CONTRIBUTION_FIELDS;
+use crate::models::utils::Contribution;
+
+const DELETE_CONTRIBUTION_MUTATION: &str = "
+    mutation DeleteContribution(
+        $contributionId: Uuid!
+    ){
+        deleteContribution(
+            contributionId: $contributionId
+        ){";
+
+graphql_mutation_builder! {
+    DeleteContributionRequest,
+    DeleteContributionRequestBody,
+    Variables,
+    format!("{DELETE_CONTRIBUTION_MUTATION}{CONTRIBUTION_FIELDS}"),
+    DeleteContributionResponseBody,
+    DeleteContributionResponseData,
+    delete_contribution,
+    Contribution,
+    PushDeleteContribution,
+    PushActionDeleteContribution
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Variables {
+    pub contribution_id: Uuid,
+}
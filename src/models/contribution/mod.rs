@@ -0,0 +1,31 @@
+pub mod create_contribution_mutation;
+pub mod delete_contribution_mutation;
+pub mod update_contribution_mutation;
+
+/// Fields returned by every contribution mutation, matching those of `Contribution`
+pub const CONTRIBUTION_FIELDS: &str = "
+            contributionId
+            workId
+            contributorId
+            contributionType
+            mainContribution
+            biography
+            createdAt
+            updatedAt
+            firstName
+            lastName
+            fullName
+            contributionOrdinal
+            contributor {
+                contributorId
+                firstName
+                lastName
+                fullName
+                orcid
+                website
+                createdAt
+                updatedAt
+            }
+        }
+    }
+";
@@ -0,0 +1,76 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::CONTRIBUTION_FIELDS;
+use crate::models::utils::{Contribution, ContributionType};
+
+const UPDATE_CONTRIBUTION_MUTATION: &str = "
+    mutation UpdateContribution(
+        $contributionId: Uuid!,
+        $workId: Uuid!,
+        $contributorId: Uuid!,
+        $contributionType: ContributionType!,
+        $mainContribution: Boolean!,
+        $biography: String,
+        $firstName: String,
+        $lastName: String!,
+        $fullName: String!,
+        $contributionOrdinal: Int!
+    ){
+        updateContribution(data: {
+            contributionId: $contributionId
+            workId: $workId
+            contributorId: $contributorId
+            contributionType: $contributionType
+            mainContribution: $mainContribution
+            biography: $biography
+            firstName: $firstName
+            lastName: $lastName
+            fullName: $fullName
+            contributionOrdinal: $contributionOrdinal
+        }){";
+
+graphql_mutation_builder! {
+    UpdateContributionRequest,
+    UpdateContributionRequestBody,
+    Variables,
+    format!("{UPDATE_CONTRIBUTION_MUTATION}{CONTRIBUTION_FIELDS}"),
+    UpdateContributionResponseBody,
+    UpdateContributionResponseData,
+    update_contribution,
+    Contribution,
+    PushUpdateContribution,
+    PushActionUpdateContribution
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Variables {
+    pub contribution_id: Uuid,
+    pub work_id: Uuid,
+    pub contributor_id: Uuid,
+    pub contribution_type: ContributionType,
+    pub main_contribution: bool,
+    pub biography: Option<String>,
+    pub first_name: Option<String>,
+    pub last_name: String,
+    pub full_name: String,
+    pub contribution_ordinal: i32,
+}
+
+impl From<&Contribution> for Variables {
+    fn from(contribution: &Contribution) -> Self {
+        Variables {
+            contribution_id: contribution.contribution_id,
+            work_id: contribution.work_id,
+            contributor_id: contribution.contributor_id,
+            contribution_type: contribution.contribution_type,
+            main_contribution: contribution.main_contribution,
+            biography: contribution.biography.clone(),
+            first_name: contribution.first_name.clone(),
+            last_name: contribution.last_name.clone(),
+            full_name: contribution.full_name.clone(),
+            contribution_ordinal: contribution.contribution_ordinal,
+        }
+    }
+}
@@ -0,0 +1,41 @@
+use serde::{Deserialize, Serialize};
+
+use crate::models::utils::Contributor;
+
+pub const CONTRIBUTORS_QUERY: &str = "
+    query ContributorsQuery($limit: Int, $filter: String) {
+        contributors(limit: $limit, filter: $filter, order: {field: FULL_NAME, direction: ASC}) {
+            contributorId
+            firstName
+            lastName
+            fullName
+            orcid
+            website
+            createdAt
+            updatedAt
+        }
+    }
+";
+
+graphql_query_builder! {
+    ContributorsRequest,
+    ContributorsRequestBody,
+    Variables,
+    CONTRIBUTORS_QUERY,
+    ContributorsResponseBody,
+    ContributorsResponseData,
+    FetchContributors,
+    FetchActionContributors
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Variables {
+    pub limit: Option<i32>,
+    pub filter: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ContributorsResponseData {
+    pub contributors: Vec<Contributor>,
+}
@@ -0,0 +1 @@
+pub mod contributors_query;
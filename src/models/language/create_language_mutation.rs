@@ -0,0 +1,41 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::LANGUAGE_FIELDS;
+use crate::models::utils::{Language, LanguageCode, LanguageRelation};
+
+const CREATE_LANGUAGE_MUTATION: &str = "
+    mutation CreateLanguage(
+        $workId: Uuid!,
+        $languageCode: LanguageCode!,
+        $languageRelation: LanguageRelation!,
+        $mainLanguage: Boolean!
+    ){
+        createLanguage(data: {
+            workId: $workId
+            languageCode: $languageCode
+            languageRelation: $languageRelation
+            mainLanguage: $mainLanguage
+        }){";
+
+graphql_mutation_builder! {
+    CreateLanguageRequest,
+    CreateLanguageRequestBody,
+    Variables,
+    format!("{CREATE_LANGUAGE_MUTATION}{LANGUAGE_FIELDS}"),
+    CreateLanguageResponseBody,
+    CreateLanguageResponseData,
+    create_language,
+    Language,
+    PushCreateLanguage,
+    PushActionCreateLanguage
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Variables {
+    pub work_id: Uuid,
+    pub language_code: LanguageCode,
+    pub language_relation: LanguageRelation,
+    pub main_language: bool,
+}
@@ -0,0 +1,610 @@
+use super::utils::{Language, LanguageCode, LanguageRelation};
+use crate::i18n::Localized;
+use crate::string::{TRANSLATED_FROM, TRANSLATED_INTO};
+
+pub mod create_language_mutation;
+
+/// Fields returned by every language mutation, matching those of `Language`
+pub const LANGUAGE_FIELDS: &str = "
+            languageId
+            workId
+            languageCode
+            languageRelation
+            mainLanguage
+            createdAt
+            updatedAt
+        }
+    }
+";
+
+impl LanguageCode {
+    /// Get the English name of the language, as given in the ISO 639-2 code list.
+    pub fn english_name(&self) -> &'static str {
+        match self {
+            LanguageCode::Aar => "Afar",
+            LanguageCode::Abk => "Abkhazian",
+            LanguageCode::Ace => "Achinese",
+            LanguageCode::Ach => "Acoli",
+            LanguageCode::Ada => "Adangme",
+            LanguageCode::Ady => "Adyghe",
+            LanguageCode::Afa => "Afro-Asiatic languages",
+            LanguageCode::Afh => "Afrihili",
+            LanguageCode::Afr => "Afrikaans",
+            LanguageCode::Ain => "Ainu",
+            LanguageCode::Aka => "Akan",
+            LanguageCode::Akk => "Akkadian",
+            LanguageCode::Alb => "Albanian",
+            LanguageCode::Ale => "Aleut",
+            LanguageCode::Alg => "Algonquian languages",
+            LanguageCode::Alt => "Southern Altai",
+            LanguageCode::Amh => "Amharic",
+            LanguageCode::Ang => "Old English",
+            LanguageCode::Anp => "Angika",
+            LanguageCode::Apa => "Apache languages",
+            LanguageCode::Ara => "Arabic",
+            LanguageCode::Arc => "Aramaic",
+            LanguageCode::Arg => "Aragonese",
+            LanguageCode::Arm => "Armenian",
+            LanguageCode::Arn => "Mapudungun",
+            LanguageCode::Arp => "Arapaho",
+            LanguageCode::Art => "Artificial languages",
+            LanguageCode::Arw => "Arawak",
+            LanguageCode::Asm => "Assamese",
+            LanguageCode::Ast => "Asturian",
+            LanguageCode::Ath => "Athapascan languages",
+            LanguageCode::Aus => "Australian languages",
+            LanguageCode::Ava => "Avaric",
+            LanguageCode::Ave => "Avestan",
+            LanguageCode::Awa => "Awadhi",
+            LanguageCode::Aym => "Aymara",
+            LanguageCode::Aze => "Azerbaijani",
+            LanguageCode::Bad => "Banda languages",
+            LanguageCode::Bai => "Bamileke languages",
+            LanguageCode::Bak => "Bashkir",
+            LanguageCode::Bal => "Baluchi",
+            LanguageCode::Bam => "Bambara",
+            LanguageCode::Ban => "Balinese",
+            LanguageCode::Baq => "Basque",
+            LanguageCode::Bas => "Basa",
+            LanguageCode::Bat => "Baltic languages",
+            LanguageCode::Bej => "Beja",
+            LanguageCode::Bel => "Belarusian",
+            LanguageCode::Bem => "Bemba",
+            LanguageCode::Ben => "Bengali",
+            LanguageCode::Ber => "Berber languages",
+            LanguageCode::Bho => "Bhojpuri",
+            LanguageCode::Bih => "Bihari languages",
+            LanguageCode::Bik => "Bikol",
+            LanguageCode::Bin => "Bini",
+            LanguageCode::Bis => "Bislama",
+            LanguageCode::Bla => "Siksika",
+            LanguageCode::Bnt => "Bantu languages",
+            LanguageCode::Bos => "Bosnian",
+            LanguageCode::Bra => "Braj",
+            LanguageCode::Bre => "Breton",
+            LanguageCode::Btk => "Batak languages",
+            LanguageCode::Bua => "Buriat",
+            LanguageCode::Bug => "Buginese",
+            LanguageCode::Bul => "Bulgarian",
+            LanguageCode::Bur => "Burmese",
+            LanguageCode::Byn => "Blin",
+            LanguageCode::Cad => "Caddo",
+            LanguageCode::Cai => "Central American Indian languages",
+            LanguageCode::Car => "Galibi Carib",
+            LanguageCode::Cat => "Catalan",
+            LanguageCode::Cau => "Caucasian languages",
+            LanguageCode::Ceb => "Cebuano",
+            LanguageCode::Cel => "Celtic languages",
+            LanguageCode::Cha => "Chamorro",
+            LanguageCode::Chb => "Chibcha",
+            LanguageCode::Che => "Chechen",
+            LanguageCode::Chg => "Chagatai",
+            LanguageCode::Chi => "Chinese",
+            LanguageCode::Chk => "Chuukese",
+            LanguageCode::Chm => "Mari",
+            LanguageCode::Chn => "Chinook jargon",
+            LanguageCode::Cho => "Choctaw",
+            LanguageCode::Chp => "Chipewyan",
+            LanguageCode::Chr => "Cherokee",
+            LanguageCode::Chu => "Church Slavic",
+            LanguageCode::Chv => "Chuvash",
+            LanguageCode::Chy => "Cheyenne",
+            LanguageCode::Cmc => "Chamic languages",
+            LanguageCode::Cnr => "Montenegrin",
+            LanguageCode::Cop => "Coptic",
+            LanguageCode::Cor => "Cornish",
+            LanguageCode::Cos => "Corsican",
+            LanguageCode::Cpe => "Creoles and pidgins, English based",
+            LanguageCode::Cpf => "Creoles and pidgins, French-based",
+            LanguageCode::Cpp => "Creoles and pidgins, Portuguese-based",
+            LanguageCode::Cre => "Cree",
+            LanguageCode::Crh => "Crimean Tatar",
+            LanguageCode::Crp => "Creoles and pidgins",
+            LanguageCode::Csb => "Kashubian",
+            LanguageCode::Cus => "Cushitic languages",
+            LanguageCode::Cze => "Czech",
+            LanguageCode::Dak => "Dakota",
+            LanguageCode::Dan => "Danish",
+            LanguageCode::Dar => "Dargwa",
+            LanguageCode::Day => "Land Dayak languages",
+            LanguageCode::Del => "Delaware",
+            LanguageCode::Den => "Slave (Athapascan)",
+            LanguageCode::Dgr => "Dogrib",
+            LanguageCode::Din => "Dinka",
+            LanguageCode::Div => "Divehi",
+            LanguageCode::Doi => "Dogri",
+            LanguageCode::Dra => "Dravidian languages",
+            LanguageCode::Dsb => "Lower Sorbian",
+            LanguageCode::Dua => "Duala",
+            LanguageCode::Dum => "Middle Dutch",
+            LanguageCode::Dut => "Dutch",
+            LanguageCode::Dyu => "Dyula",
+            LanguageCode::Dzo => "Dzongkha",
+            LanguageCode::Efi => "Efik",
+            LanguageCode::Egy => "Ancient Egyptian",
+            LanguageCode::Eka => "Ekajuk",
+            LanguageCode::Elx => "Elamite",
+            LanguageCode::Eng => "English",
+            LanguageCode::Enm => "Middle English",
+            LanguageCode::Epo => "Esperanto",
+            LanguageCode::Est => "Estonian",
+            LanguageCode::Ewe => "Ewe",
+            LanguageCode::Ewo => "Ewondo",
+            LanguageCode::Fan => "Fang",
+            LanguageCode::Fao => "Faroese",
+            LanguageCode::Fat => "Fanti",
+            LanguageCode::Fij => "Fijian",
+            LanguageCode::Fil => "Filipino",
+            LanguageCode::Fin => "Finnish",
+            LanguageCode::Fiu => "Finno-Ugrian languages",
+            LanguageCode::Fon => "Fon",
+            LanguageCode::Fre => "French",
+            LanguageCode::Frm => "Middle French",
+            LanguageCode::Fro => "Old French",
+            LanguageCode::Frr => "Northern Frisian",
+            LanguageCode::Frs => "Eastern Frisian",
+            LanguageCode::Fry => "Western Frisian",
+            LanguageCode::Ful => "Fulah",
+            LanguageCode::Fur => "Friulian",
+            LanguageCode::Gaa => "Ga",
+            LanguageCode::Gay => "Gayo",
+            LanguageCode::Gba => "Gbaya",
+            LanguageCode::Gem => "Germanic languages",
+            LanguageCode::Geo => "Georgian",
+            LanguageCode::Ger => "German",
+            LanguageCode::Gez => "Geez",
+            LanguageCode::Gil => "Gilbertese",
+            LanguageCode::Gla => "Scottish Gaelic",
+            LanguageCode::Gle => "Irish",
+            LanguageCode::Glg => "Galician",
+            LanguageCode::Glv => "Manx",
+            LanguageCode::Gmh => "Middle High German",
+            LanguageCode::Goh => "Old High German",
+            LanguageCode::Gon => "Gondi",
+            LanguageCode::Gor => "Gorontalo",
+            LanguageCode::Got => "Gothic",
+            LanguageCode::Grb => "Grebo",
+            LanguageCode::Grc => "Ancient Greek",
+            LanguageCode::Gre => "Modern Greek",
+            LanguageCode::Grn => "Guarani",
+            LanguageCode::Gsw => "Swiss German",
+            LanguageCode::Guj => "Gujarati",
+            LanguageCode::Gwi => "Gwich'in",
+            LanguageCode::Hai => "Haida",
+            LanguageCode::Hat => "Haitian Creole",
+            LanguageCode::Hau => "Hausa",
+            LanguageCode::Haw => "Hawaiian",
+            LanguageCode::Heb => "Hebrew",
+            LanguageCode::Her => "Herero",
+            LanguageCode::Hil => "Hiligaynon",
+            LanguageCode::Him => "Himachali languages",
+            LanguageCode::Hin => "Hindi",
+            LanguageCode::Hit => "Hittite",
+            LanguageCode::Hmn => "Hmong",
+            LanguageCode::Hmo => "Hiri Motu",
+            LanguageCode::Hrv => "Croatian",
+            LanguageCode::Hsb => "Upper Sorbian",
+            LanguageCode::Hun => "Hungarian",
+            LanguageCode::Hup => "Hupa",
+            LanguageCode::Iba => "Iban",
+            LanguageCode::Ibo => "Igbo",
+            LanguageCode::Ice => "Icelandic",
+            LanguageCode::Ido => "Ido",
+            LanguageCode::Iii => "Sichuan Yi",
+            LanguageCode::Ijo => "Ijo languages",
+            LanguageCode::Iku => "Inuktitut",
+            LanguageCode::Ile => "Interlingue",
+            LanguageCode::Ilo => "Iloko",
+            LanguageCode::Ina => "Interlingua",
+            LanguageCode::Inc => "Indic languages",
+            LanguageCode::Ind => "Indonesian",
+            LanguageCode::Ine => "Indo-European languages",
+            LanguageCode::Inh => "Ingush",
+            LanguageCode::Ipk => "Inupiaq",
+            LanguageCode::Ira => "Iranian languages",
+            LanguageCode::Iro => "Iroquoian languages",
+            LanguageCode::Ita => "Italian",
+            LanguageCode::Jav => "Javanese",
+            LanguageCode::Jbo => "Lojban",
+            LanguageCode::Jpn => "Japanese",
+            LanguageCode::Jpr => "Judeo-Persian",
+            LanguageCode::Jrb => "Judeo-Arabic",
+            LanguageCode::Kaa => "Kara-Kalpak",
+            LanguageCode::Kab => "Kabyle",
+            LanguageCode::Kac => "Kachin",
+            LanguageCode::Kal => "Kalaallisut",
+            LanguageCode::Kam => "Kamba",
+            LanguageCode::Kan => "Kannada",
+            LanguageCode::Kar => "Karen languages",
+            LanguageCode::Kas => "Kashmiri",
+            LanguageCode::Kau => "Kanuri",
+            LanguageCode::Kaw => "Kawi",
+            LanguageCode::Kaz => "Kazakh",
+            LanguageCode::Kbd => "Kabardian",
+            LanguageCode::Kha => "Khasi",
+            LanguageCode::Khi => "Khoisan languages",
+            LanguageCode::Khm => "Central Khmer",
+            LanguageCode::Kho => "Khotanese",
+            LanguageCode::Kik => "Kikuyu",
+            LanguageCode::Kin => "Kinyarwanda",
+            LanguageCode::Kir => "Kirghiz",
+            LanguageCode::Kmb => "Kimbundu",
+            LanguageCode::Kok => "Konkani",
+            LanguageCode::Kom => "Komi",
+            LanguageCode::Kon => "Kongo",
+            LanguageCode::Kor => "Korean",
+            LanguageCode::Kos => "Kosraean",
+            LanguageCode::Kpe => "Kpelle",
+            LanguageCode::Krc => "Karachay-Balkar",
+            LanguageCode::Krl => "Karelian",
+            LanguageCode::Kro => "Kru languages",
+            LanguageCode::Kru => "Kurukh",
+            LanguageCode::Kua => "Kuanyama",
+            LanguageCode::Kum => "Kumyk",
+            LanguageCode::Kur => "Kurdish",
+            LanguageCode::Kut => "Kutenai",
+            LanguageCode::Lad => "Ladino",
+            LanguageCode::Lah => "Lahnda",
+            LanguageCode::Lam => "Lamba",
+            LanguageCode::Lao => "Lao",
+            LanguageCode::Lat => "Latin",
+            LanguageCode::Lav => "Latvian",
+            LanguageCode::Lez => "Lezghian",
+            LanguageCode::Lim => "Limburgish",
+            LanguageCode::Lin => "Lingala",
+            LanguageCode::Lit => "Lithuanian",
+            LanguageCode::Lol => "Mongo",
+            LanguageCode::Loz => "Lozi",
+            LanguageCode::Ltz => "Luxembourgish",
+            LanguageCode::Lua => "Luba-Lulua",
+            LanguageCode::Lub => "Luba-Katanga",
+            LanguageCode::Lug => "Ganda",
+            LanguageCode::Lui => "Luiseno",
+            LanguageCode::Lun => "Lunda",
+            LanguageCode::Luo => "Luo",
+            LanguageCode::Lus => "Lushai",
+            LanguageCode::Mac => "Macedonian",
+            LanguageCode::Mad => "Madurese",
+            LanguageCode::Mag => "Magahi",
+            LanguageCode::Mah => "Marshallese",
+            LanguageCode::Mai => "Maithili",
+            LanguageCode::Mak => "Makasar",
+            LanguageCode::Mal => "Malayalam",
+            LanguageCode::Man => "Mandingo",
+            LanguageCode::Mao => "Maori",
+            LanguageCode::Map => "Austronesian languages",
+            LanguageCode::Mar => "Marathi",
+            LanguageCode::Mas => "Masai",
+            LanguageCode::May => "Malay",
+            LanguageCode::Mdf => "Moksha",
+            LanguageCode::Mdr => "Mandar",
+            LanguageCode::Men => "Mende",
+            LanguageCode::Mga => "Middle Irish",
+            LanguageCode::Mic => "Mi'kmaq",
+            LanguageCode::Min => "Minangkabau",
+            LanguageCode::Mis => "Uncoded languages",
+            LanguageCode::Mkh => "Mon-Khmer languages",
+            LanguageCode::Mlg => "Malagasy",
+            LanguageCode::Mlt => "Maltese",
+            LanguageCode::Mnc => "Manchu",
+            LanguageCode::Mni => "Manipuri",
+            LanguageCode::Mno => "Manobo languages",
+            LanguageCode::Moh => "Mohawk",
+            LanguageCode::Mon => "Mongolian",
+            LanguageCode::Mos => "Mossi",
+            LanguageCode::Mul => "Multiple languages",
+            LanguageCode::Mun => "Munda languages",
+            LanguageCode::Mus => "Creek",
+            LanguageCode::Mwl => "Mirandese",
+            LanguageCode::Mwr => "Marwari",
+            LanguageCode::Myn => "Mayan languages",
+            LanguageCode::Myv => "Erzya",
+            LanguageCode::Nah => "Nahuatl languages",
+            LanguageCode::Nai => "North American Indian languages",
+            LanguageCode::Nap => "Neapolitan",
+            LanguageCode::Nau => "Nauru",
+            LanguageCode::Nav => "Navajo",
+            LanguageCode::Nbl => "South Ndebele",
+            LanguageCode::Nde => "North Ndebele",
+            LanguageCode::Ndo => "Ndonga",
+            LanguageCode::Nds => "Low German",
+            LanguageCode::Nep => "Nepali",
+            LanguageCode::New => "Newari",
+            LanguageCode::Nia => "Nias",
+            LanguageCode::Nic => "Niger-Kordofanian languages",
+            LanguageCode::Niu => "Niuean",
+            LanguageCode::Nno => "Norwegian Nynorsk",
+            LanguageCode::Nob => "Norwegian Bokmål",
+            LanguageCode::Nog => "Nogai",
+            LanguageCode::Non => "Old Norse",
+            LanguageCode::Nor => "Norwegian",
+            LanguageCode::Nqo => "N'Ko",
+            LanguageCode::Nso => "Pedi",
+            LanguageCode::Nub => "Nubian languages",
+            LanguageCode::Nwc => "Classical Newari",
+            LanguageCode::Nya => "Chichewa",
+            LanguageCode::Nym => "Nyamwezi",
+            LanguageCode::Nyn => "Nyankole",
+            LanguageCode::Nyo => "Nyoro",
+            LanguageCode::Nzi => "Nzima",
+            LanguageCode::Oci => "Occitan",
+            LanguageCode::Oji => "Ojibwa",
+            LanguageCode::Ori => "Oriya",
+            LanguageCode::Orm => "Oromo",
+            LanguageCode::Osa => "Osage",
+            LanguageCode::Oss => "Ossetian",
+            LanguageCode::Ota => "Ottoman Turkish",
+            LanguageCode::Oto => "Otomian languages",
+            LanguageCode::Paa => "Papuan languages",
+            LanguageCode::Pag => "Pangasinan",
+            LanguageCode::Pal => "Pahlavi",
+            LanguageCode::Pam => "Pampanga",
+            LanguageCode::Pan => "Punjabi",
+            LanguageCode::Pap => "Papiamento",
+            LanguageCode::Pau => "Palauan",
+            LanguageCode::Peo => "Old Persian",
+            LanguageCode::Per => "Persian",
+            LanguageCode::Phi => "Philippine languages",
+            LanguageCode::Phn => "Phoenician",
+            LanguageCode::Pli => "Pali",
+            LanguageCode::Pol => "Polish",
+            LanguageCode::Pon => "Pohnpeian",
+            LanguageCode::Por => "Portuguese",
+            LanguageCode::Pra => "Prakrit languages",
+            LanguageCode::Pro => "Old Provençal",
+            LanguageCode::Pus => "Pashto",
+            LanguageCode::Qaa => "Reserved for local use",
+            LanguageCode::Que => "Quechua",
+            LanguageCode::Raj => "Rajasthani",
+            LanguageCode::Rap => "Rapanui",
+            LanguageCode::Rar => "Rarotongan",
+            LanguageCode::Roa => "Romance languages",
+            LanguageCode::Roh => "Romansh",
+            LanguageCode::Rom => "Romany",
+            LanguageCode::Rum => "Romanian",
+            LanguageCode::Run => "Rundi",
+            LanguageCode::Rup => "Aromanian",
+            LanguageCode::Rus => "Russian",
+            LanguageCode::Sad => "Sandawe",
+            LanguageCode::Sag => "Sango",
+            LanguageCode::Sah => "Yakut",
+            LanguageCode::Sai => "South American Indian languages",
+            LanguageCode::Sal => "Salishan languages",
+            LanguageCode::Sam => "Samaritan Aramaic",
+            LanguageCode::San => "Sanskrit",
+            LanguageCode::Sas => "Sasak",
+            LanguageCode::Sat => "Santali",
+            LanguageCode::Scn => "Sicilian",
+            LanguageCode::Sco => "Scots",
+            LanguageCode::Sel => "Selkup",
+            LanguageCode::Sem => "Semitic languages",
+            LanguageCode::Sga => "Old Irish",
+            LanguageCode::Sgn => "Sign languages",
+            LanguageCode::Shn => "Shan",
+            LanguageCode::Sid => "Sidamo",
+            LanguageCode::Sin => "Sinhala",
+            LanguageCode::Sio => "Siouan languages",
+            LanguageCode::Sit => "Sino-Tibetan languages",
+            LanguageCode::Sla => "Slavic languages",
+            LanguageCode::Slo => "Slovak",
+            LanguageCode::Slv => "Slovenian",
+            LanguageCode::Sma => "Southern Sami",
+            LanguageCode::Sme => "Northern Sami",
+            LanguageCode::Smi => "Sami languages",
+            LanguageCode::Smj => "Lule Sami",
+            LanguageCode::Smn => "Inari Sami",
+            LanguageCode::Smo => "Samoan",
+            LanguageCode::Sms => "Skolt Sami",
+            LanguageCode::Sna => "Shona",
+            LanguageCode::Snd => "Sindhi",
+            LanguageCode::Snk => "Soninke",
+            LanguageCode::Sog => "Sogdian",
+            LanguageCode::Som => "Somali",
+            LanguageCode::Son => "Songhai languages",
+            LanguageCode::Sot => "Southern Sotho",
+            LanguageCode::Spa => "Spanish",
+            LanguageCode::Srd => "Sardinian",
+            LanguageCode::Srn => "Sranan Tongo",
+            LanguageCode::Srp => "Serbian",
+            LanguageCode::Srr => "Serer",
+            LanguageCode::Ssa => "Nilo-Saharan languages",
+            LanguageCode::Ssw => "Swati",
+            LanguageCode::Suk => "Sukuma",
+            LanguageCode::Sun => "Sundanese",
+            LanguageCode::Sus => "Susu",
+            LanguageCode::Sux => "Sumerian",
+            LanguageCode::Swa => "Swahili",
+            LanguageCode::Swe => "Swedish",
+            LanguageCode::Syc => "Classical Syriac",
+            LanguageCode::Syr => "Syriac",
+            LanguageCode::Tah => "Tahitian",
+            LanguageCode::Tai => "Tai languages",
+            LanguageCode::Tam => "Tamil",
+            LanguageCode::Tat => "Tatar",
+            LanguageCode::Tel => "Telugu",
+            LanguageCode::Tem => "Timne",
+            LanguageCode::Ter => "Tereno",
+            LanguageCode::Tet => "Tetum",
+            LanguageCode::Tgk => "Tajik",
+            LanguageCode::Tgl => "Tagalog",
+            LanguageCode::Tha => "Thai",
+            LanguageCode::Tib => "Tibetan",
+            LanguageCode::Tig => "Tigre",
+            LanguageCode::Tir => "Tigrinya",
+            LanguageCode::Tiv => "Tiv",
+            LanguageCode::Tkl => "Tokelau",
+            LanguageCode::Tlh => "Klingon",
+            LanguageCode::Tli => "Tlingit",
+            LanguageCode::Tmh => "Tamashek",
+            LanguageCode::Tog => "Tonga (Nyasa)",
+            LanguageCode::Ton => "Tonga (Tonga Islands)",
+            LanguageCode::Tpi => "Tok Pisin",
+            LanguageCode::Tsi => "Tsimshian",
+            LanguageCode::Tsn => "Tswana",
+            LanguageCode::Tso => "Tsonga",
+            LanguageCode::Tuk => "Turkmen",
+            LanguageCode::Tum => "Tumbuka",
+            LanguageCode::Tup => "Tupi languages",
+            LanguageCode::Tur => "Turkish",
+            LanguageCode::Tut => "Altaic languages",
+            LanguageCode::Tvl => "Tuvalu",
+            LanguageCode::Twi => "Twi",
+            LanguageCode::Tyv => "Tuvinian",
+            LanguageCode::Udm => "Udmurt",
+            LanguageCode::Uga => "Ugaritic",
+            LanguageCode::Uig => "Uighur",
+            LanguageCode::Ukr => "Ukrainian",
+            LanguageCode::Umb => "Umbundu",
+            LanguageCode::Und => "Undetermined",
+            LanguageCode::Urd => "Urdu",
+            LanguageCode::Uzb => "Uzbek",
+            LanguageCode::Vai => "Vai",
+            LanguageCode::Ven => "Venda",
+            LanguageCode::Vie => "Vietnamese",
+            LanguageCode::Vol => "Volapük",
+            LanguageCode::Vot => "Votic",
+            LanguageCode::Wak => "Wakashan languages",
+            LanguageCode::Wal => "Wolaitta",
+            LanguageCode::War => "Waray",
+            LanguageCode::Was => "Washo",
+            LanguageCode::Wel => "Welsh",
+            LanguageCode::Wen => "Sorbian languages",
+            LanguageCode::Wln => "Walloon",
+            LanguageCode::Wol => "Wolof",
+            LanguageCode::Xal => "Kalmyk",
+            LanguageCode::Xho => "Xhosa",
+            LanguageCode::Yao => "Yao",
+            LanguageCode::Yap => "Yapese",
+            LanguageCode::Yid => "Yiddish",
+            LanguageCode::Yor => "Yoruba",
+            LanguageCode::Ypk => "Yupik languages",
+            LanguageCode::Zap => "Zapotec",
+            LanguageCode::Zbl => "Blissymbols",
+            LanguageCode::Zen => "Zenaga",
+            LanguageCode::Zgh => "Standard Moroccan Tamazight",
+            LanguageCode::Zha => "Zhuang",
+            LanguageCode::Znd => "Zande languages",
+            LanguageCode::Zul => "Zulu",
+            LanguageCode::Zun => "Zuni",
+            LanguageCode::Zxx => "No linguistic content",
+            LanguageCode::Zza => "Zaza",
+        }
+    }
+
+    /// Get the name of the language in the language itself (autonym), where known.
+    pub fn native_name(&self) -> Option<&'static str> {
+        match self {
+            LanguageCode::Alb => Some("Shqip"),
+            LanguageCode::Ara => Some("العربية"),
+            LanguageCode::Arm => Some("Հայերեն"),
+            LanguageCode::Baq => Some("Euskara"),
+            LanguageCode::Bel => Some("Беларуская"),
+            LanguageCode::Ben => Some("বাংলা"),
+            LanguageCode::Bos => Some("Bosanski"),
+            LanguageCode::Bre => Some("Brezhoneg"),
+            LanguageCode::Bul => Some("Български"),
+            LanguageCode::Cat => Some("Català"),
+            LanguageCode::Chi => Some("中文"),
+            LanguageCode::Cze => Some("Čeština"),
+            LanguageCode::Dan => Some("Dansk"),
+            LanguageCode::Dut => Some("Nederlands"),
+            LanguageCode::Eng => Some("English"),
+            LanguageCode::Epo => Some("Esperanto"),
+            LanguageCode::Est => Some("Eesti"),
+            LanguageCode::Fin => Some("Suomi"),
+            LanguageCode::Fre => Some("Français"),
+            LanguageCode::Fry => Some("Frysk"),
+            LanguageCode::Geo => Some("ქართული"),
+            LanguageCode::Ger => Some("Deutsch"),
+            LanguageCode::Gla => Some("Gàidhlig"),
+            LanguageCode::Gle => Some("Gaeilge"),
+            LanguageCode::Glg => Some("Galego"),
+            LanguageCode::Gre => Some("Ελληνικά"),
+            LanguageCode::Heb => Some("עברית"),
+            LanguageCode::Hin => Some("हिन्दी"),
+            LanguageCode::Hrv => Some("Hrvatski"),
+            LanguageCode::Hun => Some("Magyar"),
+            LanguageCode::Ice => Some("Íslenska"),
+            LanguageCode::Ind => Some("Bahasa Indonesia"),
+            LanguageCode::Ita => Some("Italiano"),
+            LanguageCode::Jpn => Some("日本語"),
+            LanguageCode::Kor => Some("한국어"),
+            LanguageCode::Lat => Some("Latina"),
+            LanguageCode::Lav => Some("Latviešu"),
+            LanguageCode::Lit => Some("Lietuvių"),
+            LanguageCode::Ltz => Some("Lëtzebuergesch"),
+            LanguageCode::Mac => Some("Македонски"),
+            LanguageCode::Mlt => Some("Malti"),
+            LanguageCode::Nno => Some("Nynorsk"),
+            LanguageCode::Nob => Some("Norsk bokmål"),
+            LanguageCode::Nor => Some("Norsk"),
+            LanguageCode::Oci => Some("Occitan"),
+            LanguageCode::Per => Some("فارسی"),
+            LanguageCode::Pol => Some("Polski"),
+            LanguageCode::Por => Some("Português"),
+            LanguageCode::Roh => Some("Rumantsch"),
+            LanguageCode::Rum => Some("Română"),
+            LanguageCode::Rus => Some("Русский"),
+            LanguageCode::Slo => Some("Slovenčina"),
+            LanguageCode::Slv => Some("Slovenščina"),
+            LanguageCode::Spa => Some("Español"),
+            LanguageCode::Srp => Some("Српски"),
+            LanguageCode::Swe => Some("Svenska"),
+            LanguageCode::Tur => Some("Türkçe"),
+            LanguageCode::Ukr => Some("Українська"),
+            LanguageCode::Wel => Some("Cymraeg"),
+            LanguageCode::Yid => Some("ייִדיש"),
+            _ => None,
+        }
+    }
+
+    /// Name suitable for display in selection lists, e.g. "French (Français)".
+    ///
+    /// The native name is omitted if unknown or identical to the English name.
+    pub fn display_name(&self) -> String {
+        match self.native_name() {
+            Some(native) if native != self.english_name() => {
+                format!("{} ({})", self.english_name(), native)
+            }
+            _ => self.english_name().to_string(),
+        }
+    }
+
+    /// Name of the language in the current locale, or in English if its catalogue has none
+    pub fn localized_name(&self) -> &'static str {
+        self.translation().unwrap_or_else(|| self.english_name())
+    }
+}
+
+impl Language {
+    /// Describe the language and its relation to the work, e.g. "Translated from French".
+    /// Languages are named in English unless the current locale's catalogue names them.
+    pub fn description(&self) -> String {
+        let name = self.language_code.localized_name();
+        match self.language_relation {
+            LanguageRelation::Original => name.to_string(),
+            LanguageRelation::TranslatedFrom => TRANSLATED_FROM.fill(&[name]),
+            LanguageRelation::TranslatedInto => TRANSLATED_INTO.fill(&[name]),
+        }
+    }
+}
@@ -0,0 +1,303 @@
+use std::fmt;
+use std::str::FromStr;
+use yew::html;
+use yew::prelude::Html;
+
+use super::utils::{ThothError, ThothResult};
+
+pub const CC_DOMAIN: &str = "https://creativecommons.org/";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LicenseKind {
+    By,
+    BySa,
+    ByNc,
+    ByNd,
+    ByNcSa,
+    ByNcNd,
+    Cc0,
+    PublicDomain,
+}
+
+/// A recognised open licence, parsed from the licence URL stored against a work
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct License {
+    pub kind: LicenseKind,
+    pub version: Option<String>,
+    pub url: String,
+}
+
+impl LicenseKind {
+    pub fn short_name(&self) -> &'static str {
+        match self {
+            LicenseKind::By => "CC BY",
+            LicenseKind::BySa => "CC BY-SA",
+            LicenseKind::ByNc => "CC BY-NC",
+            LicenseKind::ByNd => "CC BY-ND",
+            LicenseKind::ByNcSa => "CC BY-NC-SA",
+            LicenseKind::ByNcNd => "CC BY-NC-ND",
+            LicenseKind::Cc0 => "CC0",
+            LicenseKind::PublicDomain => "Public Domain",
+        }
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            LicenseKind::By => "Creative Commons Attribution",
+            LicenseKind::BySa => "Creative Commons Attribution-ShareAlike",
+            LicenseKind::ByNc => "Creative Commons Attribution-NonCommercial",
+            LicenseKind::ByNd => "Creative Commons Attribution-NoDerivatives",
+            LicenseKind::ByNcSa => "Creative Commons Attribution-NonCommercial-ShareAlike",
+            LicenseKind::ByNcNd => "Creative Commons Attribution-NonCommercial-NoDerivatives",
+            LicenseKind::Cc0 => "Creative Commons Zero public domain dedication",
+            LicenseKind::PublicDomain => "Public Domain Mark: free of known copyright restrictions",
+        }
+    }
+
+    /// Short identifier as used in Creative Commons URLs, e.g. `by-nc-sa`
+    pub fn code(&self) -> &'static str {
+        match self {
+            LicenseKind::By => "by",
+            LicenseKind::BySa => "by-sa",
+            LicenseKind::ByNc => "by-nc",
+            LicenseKind::ByNd => "by-nd",
+            LicenseKind::ByNcSa => "by-nc-sa",
+            LicenseKind::ByNcNd => "by-nc-nd",
+            LicenseKind::Cc0 => "zero",
+            LicenseKind::PublicDomain => "mark",
+        }
+    }
+
+    fn path(&self) -> &'static str {
+        match self {
+            LicenseKind::Cc0 | LicenseKind::PublicDomain => "publicdomain",
+            _ => "licenses",
+        }
+    }
+}
+
+impl FromStr for LicenseKind {
+    type Err = ThothError;
+
+    fn from_str(input: &str) -> ThothResult<LicenseKind> {
+        match input.to_lowercase().as_str() {
+            "by" => Ok(LicenseKind::By),
+            "by-sa" => Ok(LicenseKind::BySa),
+            "by-nc" => Ok(LicenseKind::ByNc),
+            "by-nd" => Ok(LicenseKind::ByNd),
+            // the order of modifiers in "by-nc-sa" and "by-nc-nd" is occasionally swapped
+            "by-nc-sa" | "by-sa-nc" => Ok(LicenseKind::ByNcSa),
+            "by-nc-nd" | "by-nd-nc" => Ok(LicenseKind::ByNcNd),
+            "zero" | "cc0" => Ok(LicenseKind::Cc0),
+            "mark" | "pdm" => Ok(LicenseKind::PublicDomain),
+            _ => Err(ThothError::LicenseParseError(input.to_string())),
+        }
+    }
+}
+
+impl License {
+    /// All recognised licences permit free reading and redistribution
+    pub fn is_open_access(&self) -> bool {
+        true
+    }
+
+    pub fn allows_commercial_use(&self) -> bool {
+        !matches!(
+            self.kind,
+            LicenseKind::ByNc | LicenseKind::ByNcSa | LicenseKind::ByNcNd
+        )
+    }
+
+    pub fn allows_derivatives(&self) -> bool {
+        !matches!(self.kind, LicenseKind::ByNd | LicenseKind::ByNcNd)
+    }
+
+    pub fn tooltip(&self) -> String {
+        match &self.version {
+            Some(version) => format!("{} {}", self.kind.description(), version),
+            None => self.kind.description().to_string(),
+        }
+    }
+
+    pub fn as_badge(&self) -> Html {
+        html! {
+            <a
+                href={ self.url.clone() }
+                target="_blank"
+                rel="noopener noreferrer"
+                class="tag is-success has-tooltip-arrow has-tooltip-bottom"
+                data-tooltip={ self.tooltip() }
+            >
+                { self.to_string() }
+            </a>
+        }
+    }
+}
+
+impl fmt::Display for License {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.version {
+            Some(version) => write!(f, "{} {}", self.kind.short_name(), version),
+            None => write!(f, "{}", self.kind.short_name()),
+        }
+    }
+}
+
+impl FromStr for License {
+    type Err = ThothError;
+
+    fn from_str(input: &str) -> ThothResult<License> {
+        use lazy_static::lazy_static;
+        use regex::Regex;
+        lazy_static! {
+            static ref RE: Regex = Regex::new(
+            // Matches strings of format "[http[s]://][www.]creativecommons.org/licenses/<by[-...]>[/<version>]"
+            // and "[http[s]://][www.]creativecommons.org/publicdomain/<zero|mark>[/<version>][/]",
+            // optionally followed by a jurisdiction, legal code or deed, and captures the kind,
+            // under whichever of the two paths it belongs to, and the version
+            r"^(?i)(?:https?://)?(?:www\.)?creativecommons\.org/(?:licenses/(by(?:-[a-z]+)*)|publicdomain/(zero|mark))(?:/(\d+\.\d+))?(?:/[a-z]{2,3})?/?(?:legalcode(?:\.[a-z-]+)?|deed\.[a-z-]+)?$").unwrap();
+        }
+        let trimmed = input.trim();
+        if trimmed.is_empty() {
+            return Err(ThothError::LicenseEmptyError);
+        }
+        let matches = RE
+            .captures(trimmed)
+            .ok_or_else(|| ThothError::LicenseParseError(trimmed.to_string()))?;
+        let kind = matches
+            .get(1)
+            .or_else(|| matches.get(2))
+            .ok_or_else(|| ThothError::LicenseParseError(trimmed.to_string()))?
+            .as_str()
+            .parse::<LicenseKind>()
+            .map_err(|_| ThothError::LicenseParseError(trimmed.to_string()))?;
+        let version = matches.get(3).map(|v| v.as_str().to_string());
+        let url = match &version {
+            Some(version) => format!("{}{}/{}/{}/", CC_DOMAIN, kind.path(), kind.code(), version),
+            None => format!("{}{}/{}/", CC_DOMAIN, kind.path(), kind.code()),
+        };
+        Ok(License { kind, version, url })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parsed(input: &str) -> (LicenseKind, Option<String>, String) {
+        let license = License::from_str(input).unwrap();
+        (license.kind, license.version, license.url)
+    }
+
+    #[test]
+    fn licence_urls_are_parsed() {
+        assert_eq!(
+            parsed("https://creativecommons.org/licenses/by/4.0/"),
+            (
+                LicenseKind::By,
+                Some("4.0".to_string()),
+                "https://creativecommons.org/licenses/by/4.0/".to_string()
+            )
+        );
+        assert_eq!(
+            parsed("http://www.creativecommons.org/licenses/BY-NC-SA/3.0"),
+            (
+                LicenseKind::ByNcSa,
+                Some("3.0".to_string()),
+                "https://creativecommons.org/licenses/by-nc-sa/3.0/".to_string()
+            )
+        );
+        assert_eq!(
+            parsed("creativecommons.org/licenses/by-nd-nc/2.0/").0,
+            LicenseKind::ByNcNd
+        );
+        assert_eq!(
+            parsed("https://creativecommons.org/licenses/by-sa/3.0/de/"),
+            (
+                LicenseKind::BySa,
+                Some("3.0".to_string()),
+                "https://creativecommons.org/licenses/by-sa/3.0/".to_string()
+            )
+        );
+        assert_eq!(
+            parsed(" https://creativecommons.org/publicdomain/zero/1.0/ "),
+            (
+                LicenseKind::Cc0,
+                Some("1.0".to_string()),
+                "https://creativecommons.org/publicdomain/zero/1.0/".to_string()
+            )
+        );
+        assert_eq!(
+            parsed("https://creativecommons.org/publicdomain/mark/1.0"),
+            (
+                LicenseKind::PublicDomain,
+                Some("1.0".to_string()),
+                "https://creativecommons.org/publicdomain/mark/1.0/".to_string()
+            )
+        );
+        assert_eq!(
+            parsed("https://creativecommons.org/licenses/by-nc/"),
+            (
+                LicenseKind::ByNc,
+                None,
+                "https://creativecommons.org/licenses/by-nc/".to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn legal_code_and_deed_urls_are_parsed() {
+        for input in [
+            "https://creativecommons.org/licenses/by/4.0/legalcode",
+            "https://creativecommons.org/licenses/by/4.0/legalcode.de",
+            "https://creativecommons.org/licenses/by/4.0/deed.en",
+            "https://creativecommons.org/licenses/by/4.0/deed.zh-hans",
+            "https://creativecommons.org/licenses/by/3.0/fr/legalcode",
+        ] {
+            assert_eq!(parsed(input).0, LicenseKind::By, "{input}");
+        }
+        assert_eq!(
+            parsed("https://creativecommons.org/publicdomain/zero/1.0/legalcode").0,
+            LicenseKind::Cc0
+        );
+        assert_eq!(
+            parsed("https://creativecommons.org/publicdomain/mark/1.0/deed.fr").0,
+            LicenseKind::PublicDomain
+        );
+    }
+
+    #[test]
+    fn kinds_are_only_recognised_under_their_own_path() {
+        for input in [
+            "https://creativecommons.org/publicdomain/by/4.0/",
+            "https://creativecommons.org/publicdomain/by-nc/4.0/",
+            "https://creativecommons.org/licenses/zero/1.0/",
+            "https://creativecommons.org/licenses/mark/1.0/",
+        ] {
+            assert_eq!(
+                License::from_str(input),
+                Err(ThothError::LicenseParseError(input.to_string())),
+                "{input}"
+            );
+        }
+    }
+
+    #[test]
+    fn invalid_licence_urls_are_rejected() {
+        assert_eq!(License::from_str("  "), Err(ThothError::LicenseEmptyError));
+        for input in [
+            "https://example.org/licenses/by/4.0/",
+            "https://creativecommons.org/licenses/by-xx/4.0/",
+            "https://creativecommons.org/licenses/by/4.0/extra",
+            "https://creativecommons.org/licenses/by/four/",
+            "https://creativecommons.org/licenses/cc0/1.0/",
+            "All rights reserved",
+        ] {
+            assert_eq!(
+                License::from_str(input),
+                Err(ThothError::LicenseParseError(input.to_string())),
+                "{input}"
+            );
+        }
+    }
+}
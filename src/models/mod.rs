@@ -10,17 +10,17 @@ macro_rules! graphql_query_builder {
         $fetch:ident,
         $fetch_action:ident
     ) => {
-        use yewtil::fetch::Fetch;
-        use yewtil::fetch::FetchAction;
-        use yewtil::fetch::FetchRequest;
-        use yewtil::fetch::Json;
-        use yewtil::fetch::MethodBody;
-
-        use $crate::THOTH_GRAPHQL_API;
+        // Native builds only serialise request bodies and parse responses, requesting them
+        // without the app's fetch service
+        #[cfg(any(target_arch = "wasm32", test))]
+        use yewtil::fetch::{Fetch, FetchAction, FetchRequest, Json, MethodBody};
 
+        #[cfg(any(target_arch = "wasm32", test))]
         pub type $fetch = Fetch<$request, $response_body>;
+        #[cfg(any(target_arch = "wasm32", test))]
         pub type $fetch_action = FetchAction<$response_body>;
 
+        #[cfg(any(target_arch = "wasm32", test))]
         #[derive(Debug, Clone, Default)]
         pub struct $request {
             pub body: $request_body,
@@ -43,22 +43,23 @@ macro_rules! graphql_query_builder {
             pub data: $response_data,
         }
 
+        #[cfg(any(target_arch = "wasm32", test))]
         impl FetchRequest for $request {
             type RequestBody = $request_body;
             type ResponseBody = $response_body;
             type Format = Json;
 
             fn url(&self) -> String {
-                format!("{}/graphql", THOTH_GRAPHQL_API)
+                format!("{}/graphql", $crate::config::config().graphql_api)
             }
 
-            fn method(&self) -> MethodBody<Self::RequestBody> {
+            fn method(&self) -> MethodBody<'_, Self::RequestBody> {
                 MethodBody::Post(&self.body)
             }
 
             fn headers(&self) -> Vec<(String, String)> {
-                let json = ("Content-Type".into(), "application/json".into());
-                vec![json]
+                use $crate::service::account::AccountService;
+                AccountService::new().headers()
             }
 
             fn use_cors(&self) -> bool {
@@ -85,14 +86,83 @@ macro_rules! graphql_query_builder {
     };
 }
 
+#[macro_export]
+macro_rules! graphql_mutation_builder {
+    (
+        $request:ident,
+        $request_body:ident,
+        $variables: ty,
+        $mutation:expr,
+        $response_body:ident,
+        $response_data:ident,
+        $payload_field:ident,
+        $payload:ty,
+        $push:ident,
+        $push_action:ident
+    ) => {
+        $crate::graphql_query_builder! {
+            $request,
+            $request_body,
+            $variables,
+            $mutation,
+            $response_body,
+            $response_data,
+            $push,
+            $push_action
+        }
+
+        // Mutations return the affected entity under a field named after the mutation,
+        // or null alongside a list of errors if the mutation was rejected
+        #[allow(clippy::derive_partial_eq_without_eq)]
+        #[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+        #[serde(rename_all = "camelCase")]
+        pub struct $response_data {
+            pub $payload_field: Option<$payload>,
+        }
+
+        impl $crate::models::MutationResult for $push {
+            type Payload = $payload;
+
+            fn result(&self) -> Option<$crate::models::utils::ThothResult<$payload>> {
+                use yewtil::fetch::FetchState;
+                use $crate::models::utils::ThothError;
+
+                match self.as_ref().state() {
+                    FetchState::NotFetching(_) | FetchState::Fetching(_) => None,
+                    FetchState::Fetched(body) => Some(
+                        body.data
+                            .$payload_field
+                            .clone()
+                            .ok_or(ThothError::EntityNotFound),
+                    ),
+                    FetchState::Failed(_, err) => Some(Err(ThothError::from(err.clone()))),
+                }
+            }
+        }
+    };
+}
+
+#[cfg(any(target_arch = "wasm32", test))]
 use serde::{Deserialize, Serialize};
+#[cfg(any(target_arch = "wasm32", test))]
 use yew::html;
+#[cfg(any(target_arch = "wasm32", test))]
 use yew::prelude::Html;
+#[cfg(any(target_arch = "wasm32", test))]
 use yew::Callback;
+#[cfg(any(target_arch = "wasm32", test))]
 use yew::MouseEvent;
 
+#[cfg(any(target_arch = "wasm32", test))]
+use crate::models::license::License;
+#[cfg(any(target_arch = "wasm32", test))]
+use crate::models::search::Suggestion;
+#[cfg(any(target_arch = "wasm32", test))]
+use crate::models::utils::{LanguageCode, SubjectType, ThothResult};
+#[cfg(any(target_arch = "wasm32", test))]
 use crate::route::AppRoute;
 
+#[cfg(any(target_arch = "wasm32", test))]
 pub trait Dropdown {
     fn as_dropdown_item(&self, callback: Callback<MouseEvent>) -> Html
     where
@@ -103,12 +173,36 @@ pub trait Dropdown {
         // without disabling onblur so that onclick can take effect
         html! {
             <div onmousedown={ callback } class="dropdown-item">
-                { self }
+                { self.dropdown_content() }
+            </div>
+        }
+    }
+
+    /// An item which can be marked as active, e.g. while it is chosen using the keyboard
+    fn as_selectable_dropdown_item(&self, callback: Callback<MouseEvent>, is_active: bool) -> Html
+    where
+        Self: std::fmt::Display,
+    {
+        let class = match is_active {
+            true => "dropdown-item is-active",
+            false => "dropdown-item",
+        };
+        html! {
+            <div onmousedown={ callback } class={ class }>
+                { self.dropdown_content() }
             </div>
         }
     }
+
+    fn dropdown_content(&self) -> Html
+    where
+        Self: std::fmt::Display,
+    {
+        html! { { self } }
+    }
 }
 
+#[cfg(any(target_arch = "wasm32", test))]
 pub trait ListString {
     const BULLET_SEPARATOR: &'static str = " • ";
     const COMMA_SEPARATOR: &'static str = ", ";
@@ -120,18 +214,120 @@ pub trait ListString {
     fn separated_list_item(&self, is_small: bool, separator: &str) -> Html;
 }
 
+/// Typed outcome of a mutation request built with `graphql_mutation_builder!`
+#[cfg(any(target_arch = "wasm32", test))]
+pub trait MutationResult {
+    type Payload;
+
+    /// `None` until the request has completed, then either the affected entity or the reason
+    /// the API rejected the mutation
+    fn result(&self) -> Option<ThothResult<Self::Payload>>;
+}
+
+#[cfg(any(target_arch = "wasm32", test))]
 pub trait EditRoute {
     fn edit_route(&self) -> AppRoute;
 }
 
+#[cfg(any(target_arch = "wasm32", test))]
 pub trait CreateRoute {
     fn create_route() -> AppRoute;
 }
 
+#[cfg(any(target_arch = "wasm32", test))]
 pub trait MetadataTable {
-    fn as_table_row(&self, callback: Callback<MouseEvent>) -> Html;
+    /// Summary of a record in a list, with `highlights` marked wherever they appear
+    fn as_table_row(&self, callback: Callback<MouseEvent>, highlights: &[String]) -> Html;
+}
+
+/// Values of a GraphQL enum as listed by `__type` introspection, used to populate select inputs
+#[cfg(any(target_arch = "wasm32", test))]
+pub trait EnumValues: Sized {
+    type Value;
+
+    fn value(&self) -> Self::Value;
+
+    fn to_options(values: &[Self]) -> Vec<Self::Value> {
+        values.iter().map(Self::value).collect()
+    }
+}
+
+/// Values of a record which list facets evaluated on the client select by, so that the records
+/// fetched for a list and the lighter ones fetched to count its facets are filtered alike
+#[cfg(any(target_arch = "wasm32", test))]
+pub trait FacetValues {
+    /// Languages in which the record is available
+    fn language_codes(&self) -> Vec<LanguageCode>;
+
+    fn has_language(&self, code: &LanguageCode) -> bool {
+        self.language_codes().contains(code)
+    }
+
+    /// Publication date as returned by the API, e.g. `2021-05-01`
+    fn publication_date(&self) -> Option<&str>;
+
+    fn publication_year(&self) -> Option<i32> {
+        self.publication_date()
+            .and_then(|date| date.get(..4))
+            .and_then(|year| year.parse().ok())
+    }
+
+    /// Schemes of the record's subjects
+    fn subject_types(&self) -> Vec<SubjectType>;
+
+    /// The licence URL stored against the record
+    fn license_url(&self) -> Option<&str>;
+
+    /// Whether the record's licence is recognised as an open access one
+    fn has_open_license(&self) -> bool {
+        matches!(
+            self.license_url().map(str::parse::<License>),
+            Some(Ok(license)) if license.is_open_access()
+        )
+    }
+}
+
+/// Filter state carried by a paginated list, in addition to its free-text search
+#[cfg(any(target_arch = "wasm32", test))]
+pub trait ListFilter: Default + Clone + PartialEq {
+    type Entity;
+    type Variables;
+
+    /// Copy the selections which the API is able to evaluate into the request variables
+    fn update_variables(&self, variables: &mut Self::Variables);
+
+    /// Whether a fetched entity satisfies the selections evaluated on the client
+    fn matches(&self, entity: &Self::Entity) -> bool;
+
+    /// Whether `matches` may reject entities returned by the API. The list then fetches up to
+    /// `LOCAL_FILTER_LIMIT` records at once and pages through those matching, so that its pages
+    /// are full and its count is right.
+    fn filters_locally(&self) -> bool {
+        false
+    }
+
+    /// Render the facet controls for the list as requested with `variables`, emitting the updated
+    /// filter state on change
+    fn view_facets(&self, variables: &Self::Variables, onchange: Callback<Self>) -> Html;
+
+    /// Narrow the list down to a record picked from the search suggestions, if supported
+    fn with_suggestion(&self, _suggestion: &Suggestion) -> Option<Self> {
+        None
+    }
+
+    /// Interpret the free-text search, returning the text to send to the API as its `filter`.
+    /// Lists without an advanced search syntax send the query as it was typed.
+    fn apply_search(&mut self, query: &str) -> String {
+        query.to_string()
+    }
+
+    /// Render a form which builds a search query, if the list supports advanced searches
+    fn view_advanced_search(&self, _query: &str, _onsearch: Callback<String>) -> Html {
+        html! {}
+    }
 }
 
+#[cfg(any(target_arch = "wasm32", test))]
 #[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
 /// Structure representing a GraphQL type query, in combination with `GraphqlFieldDefinition`, e.g.
@@ -149,6 +345,7 @@ pub struct GraphqlFieldList {
     pub fields: Vec<GraphqlFieldDefinition>,
 }
 
+#[cfg(any(target_arch = "wasm32", test))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
 pub struct GraphqlFieldDefinition {
@@ -156,6 +353,7 @@ pub struct GraphqlFieldDefinition {
     pub description: Option<String>,
 }
 
+#[cfg(any(target_arch = "wasm32", test))]
 impl GraphqlFieldList {
     /// Get a GraphQL field's description.
     ///
@@ -168,5 +366,25 @@ impl GraphqlFieldList {
     }
 }
 
+#[cfg(any(target_arch = "wasm32", test))]
+pub mod account;
 pub mod book;
+#[cfg(any(target_arch = "wasm32", test))]
+pub mod contribution;
+#[cfg(any(target_arch = "wasm32", test))]
+pub mod contributor;
+#[cfg(any(target_arch = "wasm32", test))]
+pub mod language;
+#[cfg(any(target_arch = "wasm32", test))]
+pub mod license;
+#[cfg(any(target_arch = "wasm32", test))]
+pub mod publication;
+#[cfg(any(target_arch = "wasm32", test))]
+pub mod relation;
+#[cfg(any(target_arch = "wasm32", test))]
+pub mod search;
+#[cfg(any(target_arch = "wasm32", test))]
+pub mod subject;
 pub mod utils;
+#[cfg(any(target_arch = "wasm32", test))]
+pub mod validation;
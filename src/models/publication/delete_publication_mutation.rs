@@ -0,0 +1,32 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::PUBLICATION_FIELDS;
+use crate::models::utils::Publication;
+
+const DELETE_PUBLICATION_MUTATION: &str = "
+    mutation DeletePublication(
+        $publicationId: Uuid!
+    ){
+        deletePublication(
+            publicationId: $publicationId
+        ){";
+
+graphql_mutation_builder! {
+    DeletePublicationRequest,
+    DeletePublicationRequestBody,
+    Variables,
+    format!("{DELETE_PUBLICATION_MUTATION}{PUBLICATION_FIELDS}"),
+    DeletePublicationResponseBody,
+    DeletePublicationResponseData,
+    delete_publication,
+    Publication,
+    PushDeletePublication,
+    PushActionDeletePublication
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Variables {
+    pub publication_id: Uuid,
+}
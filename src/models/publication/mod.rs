@@ -0,0 +1,103 @@
+use crate::models::utils::Publication;
+
+pub mod create_publication_mutation;
+pub mod delete_publication_mutation;
+pub mod update_publication_mutation;
+
+/// Fields returned by every publication mutation, matching those of `Publication`
+pub const PUBLICATION_FIELDS: &str = "
+            publicationId
+            publicationType
+            workId
+            isbn
+            createdAt
+            updatedAt
+            weightG: weight(units: G)
+            weightOz: weight(units: OZ)
+            widthMm: width(units: MM)
+            widthIn: width(units: IN)
+            heightMm: height(units: MM)
+            heightIn: height(units: IN)
+            depthMm: depth(units: MM)
+            depthIn: depth(units: IN)
+        }
+    }
+";
+
+const MM_PER_IN: f64 = 25.4;
+const G_PER_OZ: f64 = 28.349523125;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Dimension {
+    Width,
+    Height,
+    Depth,
+    Weight,
+}
+
+/// Metric values are held in millimetres and grams, imperial ones in inches and ounces
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Units {
+    Metric,
+    Imperial,
+}
+
+fn round(value: f64, decimals: i32) -> f64 {
+    let factor = 10f64.powi(decimals);
+    (value * factor).round() / factor
+}
+
+impl Dimension {
+    /// Express a value given in `units` in the other unit of the pair
+    pub fn convert(&self, value: f64, units: Units) -> f64 {
+        match (self, units) {
+            (Dimension::Weight, Units::Metric) => round(value / G_PER_OZ, 4),
+            (Dimension::Weight, Units::Imperial) => round(value * G_PER_OZ, 1),
+            (_, Units::Metric) => round(value / MM_PER_IN, 2),
+            (_, Units::Imperial) => round(value * MM_PER_IN, 1),
+        }
+    }
+}
+
+impl Units {
+    fn other(&self) -> Units {
+        match self {
+            Units::Metric => Units::Imperial,
+            Units::Imperial => Units::Metric,
+        }
+    }
+}
+
+impl Publication {
+    fn dimension_mut(&mut self, dimension: Dimension, units: Units) -> &mut Option<f64> {
+        match (dimension, units) {
+            (Dimension::Width, Units::Metric) => &mut self.width_mm,
+            (Dimension::Width, Units::Imperial) => &mut self.width_in,
+            (Dimension::Height, Units::Metric) => &mut self.height_mm,
+            (Dimension::Height, Units::Imperial) => &mut self.height_in,
+            (Dimension::Depth, Units::Metric) => &mut self.depth_mm,
+            (Dimension::Depth, Units::Imperial) => &mut self.depth_in,
+            (Dimension::Weight, Units::Metric) => &mut self.weight_g,
+            (Dimension::Weight, Units::Imperial) => &mut self.weight_oz,
+        }
+    }
+
+    /// Set one value of a dimension, keeping the paired value in the other units in step,
+    /// as the API requires both values of a pair or neither
+    pub fn set_dimension(&mut self, dimension: Dimension, units: Units, value: Option<f64>) {
+        *self.dimension_mut(dimension, units) = value;
+        *self.dimension_mut(dimension, units.other()) = value.map(|v| dimension.convert(v, units));
+    }
+
+    /// Remove all dimensions, e.g. when the publication becomes a digital one
+    pub fn clear_dimensions(&mut self) {
+        for dimension in [
+            Dimension::Width,
+            Dimension::Height,
+            Dimension::Depth,
+            Dimension::Weight,
+        ] {
+            self.set_dimension(dimension, Units::Metric, None);
+        }
+    }
+}
@@ -0,0 +1,84 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::PUBLICATION_FIELDS;
+use crate::models::utils::{Isbn, Publication, PublicationType};
+
+const UPDATE_PUBLICATION_MUTATION: &str = "
+    mutation UpdatePublication(
+        $publicationId: Uuid!,
+        $publicationType: PublicationType!,
+        $workId: Uuid!,
+        $isbn: Isbn,
+        $widthMm: Float,
+        $widthIn: Float,
+        $heightMm: Float,
+        $heightIn: Float,
+        $depthMm: Float,
+        $depthIn: Float,
+        $weightG: Float,
+        $weightOz: Float
+    ){
+        updatePublication(data: {
+            publicationId: $publicationId
+            publicationType: $publicationType
+            workId: $workId
+            isbn: $isbn
+            widthMm: $widthMm
+            widthIn: $widthIn
+            heightMm: $heightMm
+            heightIn: $heightIn
+            depthMm: $depthMm
+            depthIn: $depthIn
+            weightG: $weightG
+            weightOz: $weightOz
+        }){";
+
+graphql_mutation_builder! {
+    UpdatePublicationRequest,
+    UpdatePublicationRequestBody,
+    Variables,
+    format!("{UPDATE_PUBLICATION_MUTATION}{PUBLICATION_FIELDS}"),
+    UpdatePublicationResponseBody,
+    UpdatePublicationResponseData,
+    update_publication,
+    Publication,
+    PushUpdatePublication,
+    PushActionUpdatePublication
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Variables {
+    pub publication_id: Uuid,
+    pub publication_type: PublicationType,
+    pub work_id: Uuid,
+    pub isbn: Option<Isbn>,
+    pub width_mm: Option<f64>,
+    pub width_in: Option<f64>,
+    pub height_mm: Option<f64>,
+    pub height_in: Option<f64>,
+    pub depth_mm: Option<f64>,
+    pub depth_in: Option<f64>,
+    pub weight_g: Option<f64>,
+    pub weight_oz: Option<f64>,
+}
+
+impl From<&Publication> for Variables {
+    fn from(publication: &Publication) -> Self {
+        Variables {
+            publication_id: publication.publication_id,
+            publication_type: publication.publication_type.clone(),
+            work_id: publication.work_id,
+            isbn: publication.isbn.clone(),
+            width_mm: publication.width_mm,
+            width_in: publication.width_in,
+            height_mm: publication.height_mm,
+            height_in: publication.height_in,
+            depth_mm: publication.depth_mm,
+            depth_in: publication.depth_in,
+            weight_g: publication.weight_g,
+            weight_oz: publication.weight_oz,
+        }
+    }
+}
@@ -0,0 +1,41 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::RELATION_FIELDS;
+use crate::models::utils::{RelationType, WorkRelationWithRelatedWork};
+
+const CREATE_RELATION_MUTATION: &str = "
+    mutation CreateWorkRelation(
+        $relatorWorkId: Uuid!,
+        $relatedWorkId: Uuid!,
+        $relationType: RelationType!,
+        $relationOrdinal: Int!
+    ){
+        createWorkRelation(data: {
+            relatorWorkId: $relatorWorkId
+            relatedWorkId: $relatedWorkId
+            relationType: $relationType
+            relationOrdinal: $relationOrdinal
+        }){";
+
+graphql_mutation_builder! {
+    CreateRelationRequest,
+    CreateRelationRequestBody,
+    Variables,
+    format!("{CREATE_RELATION_MUTATION}{RELATION_FIELDS}"),
+    CreateRelationResponseBody,
+    CreateRelationResponseData,
+    create_work_relation,
+    WorkRelationWithRelatedWork,
+    PushCreateRelation,
+    PushActionCreateRelation
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Variables {
+    pub relator_work_id: Uuid,
+    pub related_work_id: Uuid,
+    pub relation_type: RelationType,
+    pub relation_ordinal: i32,
+}
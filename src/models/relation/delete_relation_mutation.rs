@@ -0,0 +1,32 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::RELATION_FIELDS;
+use crate::models::utils::WorkRelationWithRelatedWork;
+
+const DELETE_RELATION_MUTATION: &str = "
+    mutation DeleteWorkRelation(
+        $workRelationId: Uuid!
+    ){
+        deleteWorkRelation(
+            workRelationId: $workRelationId
+        ){";
+
+graphql_mutation_builder! {
+    DeleteRelationRequest,
+    DeleteRelationRequestBody,
+    Variables,
+    format!("{DELETE_RELATION_MUTATION}{RELATION_FIELDS}"),
+    DeleteRelationResponseBody,
+    DeleteRelationResponseData,
+    delete_work_relation,
+    WorkRelationWithRelatedWork,
+    PushDeleteRelation,
+    PushActionDeleteRelation
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Variables {
+    pub work_relation_id: Uuid,
+}
@@ -0,0 +1,60 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::models::relation::update_relation_mutation::Variables as UpdateVariables;
+use crate::models::utils::RelationType;
+
+pub const INVERSE_RELATIONS_QUERY: &str = "
+    query InverseRelationsQuery($workId: Uuid!) {
+        work(workId: $workId) {
+            relations {
+                workRelationId
+                relatorWorkId
+                relatedWorkId
+                relationType
+                relationOrdinal
+            }
+        }
+    }
+";
+
+graphql_query_builder! {
+    InverseRelationsRequest,
+    InverseRelationsRequestBody,
+    Variables,
+    INVERSE_RELATIONS_QUERY,
+    InverseRelationsResponseBody,
+    InverseRelationsResponseData,
+    FetchInverseRelations,
+    FetchActionInverseRelations
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Variables {
+    pub work_id: Uuid,
+}
+
+/// The relations of a related work, among which the inverse of a relation is looked up
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct RelatedWorkRelations {
+    pub relations: Vec<UpdateVariables>,
+}
+
+impl RelatedWorkRelations {
+    /// The relation recording, on the related work, a link of the given type back to `work_id`
+    pub fn inverse_of(
+        &self,
+        work_id: Uuid,
+        relation_type: RelationType,
+    ) -> Option<&UpdateVariables> {
+        self.relations
+            .iter()
+            .find(|r| r.related_work_id == work_id && r.relation_type == relation_type.inverse())
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct InverseRelationsResponseData {
+    pub work: RelatedWorkRelations,
+}
@@ -0,0 +1,45 @@
+use crate::models::utils::RelationType;
+
+pub mod create_relation_mutation;
+pub mod delete_relation_mutation;
+pub mod inverse_relations_query;
+pub mod relatable_works_query;
+pub mod update_relation_mutation;
+
+/// Fields returned by every work relation mutation, matching those of `WorkRelationWithRelatedWork`
+pub const RELATION_FIELDS: &str = "
+            workRelationId
+            relatorWorkId
+            relatedWorkId
+            relationType
+            relationOrdinal
+            relatedWork {
+                workId
+                workType
+                workStatus
+                fullTitle
+                title
+                imprintId
+                createdAt
+                updatedAt
+                updatedAtWithRelations
+            }
+        }
+    }
+";
+
+impl RelationType {
+    /// The relation type describing the same link as seen from the related work
+    pub fn inverse(&self) -> RelationType {
+        match self {
+            RelationType::HasPart => RelationType::IsPartOf,
+            RelationType::IsPartOf => RelationType::HasPart,
+            RelationType::HasTranslation => RelationType::IsTranslationOf,
+            RelationType::IsTranslationOf => RelationType::HasTranslation,
+            RelationType::Replaces => RelationType::IsReplacedBy,
+            RelationType::IsReplacedBy => RelationType::Replaces,
+            RelationType::HasChild => RelationType::IsChildOf,
+            RelationType::IsChildOf => RelationType::HasChild,
+        }
+    }
+}
@@ -0,0 +1,71 @@
+use serde::{Deserialize, Serialize};
+
+use crate::models::utils::Work;
+
+pub const RELATABLE_WORKS_QUERY: &str = "
+    query RelatableWorksQuery($limit: Int, $filter: String) {
+        works(limit: $limit, filter: $filter, order: {field: FULL_TITLE, direction: ASC}) {
+            workId
+            workType
+            workStatus
+            fullTitle
+            title
+            imprintId
+            createdAt
+            updatedAt
+            updatedAtWithRelations
+            relations {
+                relationOrdinal
+            }
+        }
+    }
+";
+
+graphql_query_builder! {
+    RelatableWorksRequest,
+    RelatableWorksRequestBody,
+    Variables,
+    RELATABLE_WORKS_QUERY,
+    RelatableWorksResponseBody,
+    RelatableWorksResponseData,
+    FetchRelatableWorks,
+    FetchActionRelatableWorks
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Variables {
+    pub limit: Option<i32>,
+    pub filter: Option<String>,
+}
+
+/// A candidate target for a new relation, along with what is needed to add the inverse relation
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct RelatableWork {
+    #[serde(flatten)]
+    pub work: Work,
+    pub relations: Vec<RelationOrdinal>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct RelationOrdinal {
+    pub relation_ordinal: i32,
+}
+
+impl RelatableWork {
+    pub fn next_relation_ordinal(&self) -> i32 {
+        self.relations
+            .iter()
+            .map(|r| r.relation_ordinal)
+            .max()
+            .unwrap_or_default()
+            + 1
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct RelatableWorksResponseData {
+    pub works: Vec<RelatableWork>,
+}
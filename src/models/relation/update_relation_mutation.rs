@@ -0,0 +1,56 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::RELATION_FIELDS;
+use crate::models::utils::{RelationType, WorkRelationWithRelatedWork};
+
+const UPDATE_RELATION_MUTATION: &str = "
+    mutation UpdateWorkRelation(
+        $workRelationId: Uuid!,
+        $relatorWorkId: Uuid!,
+        $relatedWorkId: Uuid!,
+        $relationType: RelationType!,
+        $relationOrdinal: Int!
+    ){
+        updateWorkRelation(data: {
+            workRelationId: $workRelationId
+            relatorWorkId: $relatorWorkId
+            relatedWorkId: $relatedWorkId
+            relationType: $relationType
+            relationOrdinal: $relationOrdinal
+        }){";
+
+graphql_mutation_builder! {
+    UpdateRelationRequest,
+    UpdateRelationRequestBody,
+    Variables,
+    format!("{UPDATE_RELATION_MUTATION}{RELATION_FIELDS}"),
+    UpdateRelationResponseBody,
+    UpdateRelationResponseData,
+    update_work_relation,
+    WorkRelationWithRelatedWork,
+    PushUpdateRelation,
+    PushActionUpdateRelation
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Variables {
+    pub work_relation_id: Uuid,
+    pub relator_work_id: Uuid,
+    pub related_work_id: Uuid,
+    pub relation_type: RelationType,
+    pub relation_ordinal: i32,
+}
+
+impl From<&WorkRelationWithRelatedWork> for Variables {
+    fn from(relation: &WorkRelationWithRelatedWork) -> Self {
+        Variables {
+            work_relation_id: relation.work_relation_id,
+            relator_work_id: relation.relator_work_id,
+            related_work_id: relation.related_work_id,
+            relation_type: relation.relation_type,
+            relation_ordinal: relation.relation_ordinal,
+        }
+    }
+}
@@ -0,0 +1,151 @@
+use std::fmt;
+use uuid::Uuid;
+use yew::html;
+use yew::prelude::Html;
+
+use crate::models::search::query::SearchQuery;
+use crate::models::Dropdown;
+use crate::route::AppRoute;
+use crate::string::{SUGGESTION_CONTRIBUTOR, SUGGESTION_SERIES, SUGGESTION_WORK};
+
+pub mod query;
+pub mod suggestions_query;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SuggestionKind {
+    Work,
+    Contributor,
+    Series,
+}
+
+impl fmt::Display for SuggestionKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SuggestionKind::Work => write!(f, "{SUGGESTION_WORK}"),
+            SuggestionKind::Contributor => write!(f, "{SUGGESTION_CONTRIBUTOR}"),
+            SuggestionKind::Series => write!(f, "{SUGGESTION_SERIES}"),
+        }
+    }
+}
+
+/// A record offered while typing in a search box
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Suggestion {
+    pub kind: SuggestionKind,
+    pub id: Uuid,
+    pub label: String,
+    // Works the record is, or is linked to
+    pub work_ids: Vec<Uuid>,
+    // Terms of the search the record was found for, which are highlighted in the label
+    pub highlights: Vec<String>,
+}
+
+impl Suggestion {
+    /// Page to open on selection, for records which have one of their own
+    pub fn route(&self) -> Option<AppRoute> {
+        match self.kind {
+            SuggestionKind::Work => Some(AppRoute::BookDetail { book_id: self.id }),
+            SuggestionKind::Contributor | SuggestionKind::Series => None,
+        }
+    }
+}
+
+impl fmt::Display for Suggestion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.label)
+    }
+}
+
+impl Dropdown for Suggestion {
+    fn dropdown_content(&self) -> Html {
+        html! {
+            <>
+                <span class="tag is-light mr-2">{ &self.kind }</span>
+                { highlighted(&self.label, &self.highlights) }
+            </>
+        }
+    }
+}
+
+/// Text to highlight in records found for a search, e.g. `smith` for `author:smith`. Input which
+/// is not a valid search is highlighted word by word.
+pub fn highlight_terms(query: &str) -> Vec<String> {
+    match query.parse::<SearchQuery>() {
+        Ok(search) => search.highlight_terms(),
+        Err(_) => query.split_whitespace().map(str::to_string).collect(),
+    }
+}
+
+/// `text` with every match of `terms` marked
+pub fn highlighted(text: &str, terms: &[String]) -> Html {
+    html! {
+        for highlight_matches(text, terms).into_iter().map(|(text, is_match)| {
+            match is_match {
+                true => html! { <mark>{ text }</mark> },
+                false => html! { { text } },
+            }
+        })
+    }
+}
+
+/// Split `text` into consecutive segments, flagging those which match any of `terms`,
+/// ignoring case
+pub fn highlight_matches<'a>(text: &'a str, terms: &[String]) -> Vec<(&'a str, bool)> {
+    let terms: Vec<Vec<char>> = terms
+        .iter()
+        .filter(|term| !term.is_empty())
+        .map(|term| term.chars().collect())
+        .collect();
+    let chars: Vec<(usize, char)> = text.char_indices().collect();
+    let mut matched = vec![false; chars.len()];
+    for start in 0..chars.len() {
+        for term in &terms {
+            let is_match = term.len() <= chars.len() - start
+                && term
+                    .iter()
+                    .zip(&chars[start..])
+                    .all(|(t, (_, c))| t.to_lowercase().eq(c.to_lowercase()));
+            if is_match {
+                matched[start..start + term.len()].fill(true);
+            }
+        }
+    }
+    let mut segments = vec![];
+    let mut segment_start = 0;
+    for i in 1..=chars.len() {
+        if i == chars.len() || matched[i] != matched[segment_start] {
+            let end = chars.get(i).map_or(text.len(), |(byte, _)| *byte);
+            segments.push((&text[chars[segment_start].0..end], matched[segment_start]));
+            segment_start = i;
+        }
+    }
+    segments
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn marked(text: &str, query: &str) -> Vec<String> {
+        highlight_matches(text, &highlight_terms(query))
+            .into_iter()
+            .filter(|(_, is_match)| *is_match)
+            .map(|(text, _)| text.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn fielded_terms_are_highlighted_by_their_value() {
+        assert_eq!(marked("Jane Smith", "author:smith"), vec!["Smith"]);
+        assert_eq!(
+            marked("A History of Europe", "history type:monograph -europe"),
+            vec!["History"]
+        );
+        assert_eq!(
+            marked("Open access in history", "\"open access\""),
+            vec!["Open access"]
+        );
+        // Input which does not parse is still highlighted word by word
+        assert_eq!(marked("Open access", "open \"acc"), vec!["Open"]);
+    }
+}
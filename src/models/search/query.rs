@@ -0,0 +1,598 @@
+use std::fmt;
+use std::iter::Peekable;
+use std::str::{Chars, FromStr};
+
+use crate::models::license::LicenseKind;
+use crate::models::utils::{ThothError, ThothResult, WorkStatus, WorkType, WorkWithRelations};
+use crate::models::FacetValues;
+use crate::string::{
+    SEARCH_INVALID_YEAR, SEARCH_MISSING_VALUE, SEARCH_UNCLOSED_QUOTE, SEARCH_UNKNOWN_WORK_STATUS,
+    SEARCH_UNKNOWN_WORK_TYPE,
+};
+
+pub const WORK_TYPES: [WorkType; 6] = [
+    WorkType::Monograph,
+    WorkType::EditedBook,
+    WorkType::Textbook,
+    WorkType::BookChapter,
+    WorkType::JournalIssue,
+    WorkType::BookSet,
+];
+
+pub const WORK_STATUSES: [WorkStatus; 13] = [
+    WorkStatus::Active,
+    WorkStatus::Forthcoming,
+    WorkStatus::PostponedIndefinitely,
+    WorkStatus::Cancelled,
+    WorkStatus::OutOfStockIndefinitely,
+    WorkStatus::OutOfPrint,
+    WorkStatus::Inactive,
+    WorkStatus::NoLongerOurProduct,
+    WorkStatus::Remaindered,
+    WorkStatus::WithdrawnFromSale,
+    WorkStatus::Recalled,
+    WorkStatus::Unspecified,
+    WorkStatus::Unknown,
+];
+
+pub const LICENSE_KINDS: [LicenseKind; 8] = [
+    LicenseKind::By,
+    LicenseKind::BySa,
+    LicenseKind::ByNc,
+    LicenseKind::ByNd,
+    LicenseKind::ByNcSa,
+    LicenseKind::ByNcNd,
+    LicenseKind::Cc0,
+    LicenseKind::PublicDomain,
+];
+
+/// Prefixes which restrict a search term to one property of a work, e.g. `author:smith`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SearchField {
+    Author,
+    Isbn,
+    Subject,
+    Year,
+    Type,
+    Status,
+    License,
+}
+
+impl SearchField {
+    pub fn name(&self) -> &'static str {
+        match self {
+            SearchField::Author => "author",
+            SearchField::Isbn => "isbn",
+            SearchField::Subject => "subject",
+            SearchField::Year => "year",
+            SearchField::Type => "type",
+            SearchField::Status => "status",
+            SearchField::License => "license",
+        }
+    }
+
+    fn from_name(name: &str) -> Option<SearchField> {
+        match name.to_lowercase().as_str() {
+            "author" | "contributor" => Some(SearchField::Author),
+            "isbn" => Some(SearchField::Isbn),
+            "subject" => Some(SearchField::Subject),
+            "year" => Some(SearchField::Year),
+            "type" => Some(SearchField::Type),
+            "status" => Some(SearchField::Status),
+            "license" | "licence" => Some(SearchField::License),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TermValue {
+    /// Free text, which the API matches against titles, DOIs, references, abstracts and
+    /// landing pages. Words and quoted phrases are kept as they were entered.
+    Text(String),
+    Author(String),
+    /// Digits of a full or partial ISBN, without hyphens
+    Isbn(String),
+    Subject(String),
+    /// Publication years from and to, inclusive, either of which may be open
+    Year(Option<i32>, Option<i32>),
+    Type(WorkType),
+    Status(WorkStatus),
+    License(LicenseKind),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SearchTerm {
+    pub value: TermValue,
+    pub negated: bool,
+}
+
+/// A parsed search, e.g. `"open access" author:smith year:2019..2022 -type:textbook`.
+///
+/// Terms must all be satisfied, except that positive `type:`, `status:` and `license:` terms
+/// are alternatives to each other, so that `status:active status:forthcoming` finds either.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SearchQuery {
+    pub terms: Vec<SearchTerm>,
+}
+
+/// Convert user input such as `edited-book` or `out_of_print` into the title case names which
+/// the work enums are parsed from, e.g. `Edited Book`
+fn parse_title_case<T: FromStr>(value: &str) -> Option<T> {
+    let title = value
+        .split(|c: char| c == '-' || c == '_' || c.is_whitespace())
+        .filter(|word| !word.is_empty())
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first) => first
+                    .to_uppercase()
+                    .chain(chars.flat_map(|c| c.to_lowercase()))
+                    .collect(),
+                None => String::new(),
+            }
+        })
+        .collect::<Vec<String>>()
+        .join(" ");
+    T::from_str(&title).ok()
+}
+
+/// The inverse of `parse_title_case`, e.g. `Edited Book` becomes `edited-book`
+fn to_kebab_case(value: &impl fmt::Display) -> String {
+    value.to_string().to_lowercase().replace(' ', "-")
+}
+
+fn parse_year(value: &str) -> ThothResult<Option<i32>> {
+    match value.trim() {
+        "" => Ok(None),
+        year => year
+            .parse()
+            .map(Some)
+            .map_err(|_| ThothError::InvalidSearchQuery(SEARCH_INVALID_YEAR.fill(&[year]))),
+    }
+}
+
+fn parse_term(field: Option<SearchField>, value: String) -> ThothResult<TermValue> {
+    let field = match field {
+        Some(field) => field,
+        None => return Ok(TermValue::Text(value)),
+    };
+    if value.trim().is_empty() {
+        return Err(ThothError::InvalidSearchQuery(
+            SEARCH_MISSING_VALUE.fill(&[field.name()]),
+        ));
+    }
+    match field {
+        SearchField::Author => Ok(TermValue::Author(value)),
+        SearchField::Isbn => Ok(TermValue::Isbn(
+            value
+                .chars()
+                .filter(|c| c.is_ascii_digit() || *c == 'X' || *c == 'x')
+                .collect::<String>()
+                .to_uppercase(),
+        )),
+        SearchField::Subject => Ok(TermValue::Subject(value)),
+        SearchField::Year => {
+            let (from, to) = match value.split_once("..") {
+                Some((from, to)) => (parse_year(from)?, parse_year(to)?),
+                None => {
+                    let year = parse_year(&value)?;
+                    (year, year)
+                }
+            };
+            Ok(TermValue::Year(from, to))
+        }
+        SearchField::Type => parse_title_case(&value)
+            .map(TermValue::Type)
+            .ok_or_else(|| {
+                ThothError::InvalidSearchQuery(SEARCH_UNKNOWN_WORK_TYPE.fill(&[&value]))
+            }),
+        SearchField::Status => parse_title_case(&value)
+            .map(TermValue::Status)
+            .ok_or_else(|| {
+                ThothError::InvalidSearchQuery(SEARCH_UNKNOWN_WORK_STATUS.fill(&[&value]))
+            }),
+        SearchField::License => LicenseKind::from_str(&value).map(TermValue::License),
+    }
+}
+
+/// Read a quoted value, after its opening quote, up to and including the closing quote
+fn read_phrase(chars: &mut Peekable<Chars>) -> ThothResult<String> {
+    let mut phrase = String::new();
+    for c in chars.by_ref() {
+        if c == '"' {
+            return Ok(phrase);
+        }
+        phrase.push(c);
+    }
+    Err(ThothError::InvalidSearchQuery(
+        SEARCH_UNCLOSED_QUOTE.fill(&[&phrase]),
+    ))
+}
+
+impl FromStr for SearchQuery {
+    type Err = ThothError;
+
+    fn from_str(input: &str) -> ThothResult<SearchQuery> {
+        let mut terms = vec![];
+        let mut chars = input.chars().peekable();
+        loop {
+            while chars.next_if(|c| c.is_whitespace()).is_some() {}
+            if chars.peek().is_none() {
+                break;
+            }
+            let negated = chars.next_if_eq(&'-').is_some();
+            let mut word = String::new();
+            while let Some(c) = chars.next_if(|c| !c.is_whitespace() && *c != ':' && *c != '"') {
+                word.push(c);
+            }
+            // Colons within unrecognised prefixes, e.g. in titles, are kept as part of the text
+            let field = match chars.peek() == Some(&':') {
+                true => SearchField::from_name(&word),
+                false => None,
+            };
+            if field.is_some() {
+                chars.next();
+                word.clear();
+            }
+            if word.is_empty() && chars.next_if_eq(&'"').is_some() {
+                word = read_phrase(&mut chars)?;
+            } else {
+                while let Some(c) = chars.next_if(|c| !c.is_whitespace()) {
+                    word.push(c);
+                }
+            }
+            // A lone hyphen is not a negation of anything
+            if word.is_empty() && field.is_none() {
+                continue;
+            }
+            terms.push(SearchTerm {
+                value: parse_term(field, word)?,
+                negated,
+            });
+        }
+        Ok(SearchQuery { terms })
+    }
+}
+
+fn quoted(value: &str) -> String {
+    match value.contains(char::is_whitespace) || value.is_empty() {
+        true => format!("\"{value}\""),
+        false => value.to_string(),
+    }
+}
+
+impl fmt::Display for SearchTerm {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.negated {
+            write!(f, "-")?;
+        }
+        match &self.value {
+            TermValue::Text(text) => write!(f, "{}", quoted(text)),
+            TermValue::Author(author) => write!(f, "author:{}", quoted(author)),
+            TermValue::Isbn(isbn) => write!(f, "isbn:{isbn}"),
+            TermValue::Subject(subject) => write!(f, "subject:{}", quoted(subject)),
+            TermValue::Year(from, to) if from == to => {
+                write!(
+                    f,
+                    "year:{}",
+                    from.map(|y| y.to_string()).unwrap_or_default()
+                )
+            }
+            TermValue::Year(from, to) => write!(
+                f,
+                "year:{}..{}",
+                from.map(|y| y.to_string()).unwrap_or_default(),
+                to.map(|y| y.to_string()).unwrap_or_default()
+            ),
+            TermValue::Type(work_type) => write!(f, "type:{}", to_kebab_case(work_type)),
+            TermValue::Status(status) => write!(f, "status:{}", to_kebab_case(status)),
+            TermValue::License(kind) => write!(f, "license:{}", kind.code()),
+        }
+    }
+}
+
+impl fmt::Display for SearchQuery {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let terms: Vec<String> = self.terms.iter().map(|t| t.to_string()).collect();
+        write!(f, "{}", terms.join(" "))
+    }
+}
+
+fn contains_ignoring_case(haystack: &str, needle: &str) -> bool {
+    haystack.to_lowercase().contains(&needle.to_lowercase())
+}
+
+impl SearchTerm {
+    /// Whether the work has the property searched for, disregarding negation
+    fn matches(&self, work: &WorkWithRelations) -> bool {
+        match &self.value {
+            // The same properties the API's `filter` is matched against
+            TermValue::Text(text) => {
+                let properties = [
+                    Some(&work.full_title),
+                    work.reference.as_ref(),
+                    work.short_abstract.as_ref(),
+                    work.long_abstract.as_ref(),
+                    work.landing_page.as_ref(),
+                ];
+                properties
+                    .into_iter()
+                    .flatten()
+                    .any(|property| contains_ignoring_case(property, text))
+                    || work
+                        .doi
+                        .as_ref()
+                        .is_some_and(|doi| contains_ignoring_case(&doi.to_string(), text))
+            }
+            TermValue::Author(author) => work
+                .contributions
+                .iter()
+                .flatten()
+                .any(|c| contains_ignoring_case(&c.full_name, author)),
+            TermValue::Isbn(isbn) => work.publications.iter().flatten().any(|p| {
+                p.isbn
+                    .as_ref()
+                    .is_some_and(|i| i.to_string().replace('-', "").contains(isbn.as_str()))
+            }),
+            TermValue::Subject(subject) => work.subjects.iter().flatten().any(|s| {
+                contains_ignoring_case(&s.subject_code, subject)
+                    || s.subject_type
+                        .find_code(&s.subject_code)
+                        .is_some_and(|entry| contains_ignoring_case(entry.heading, subject))
+            }),
+            TermValue::Year(from, to) => work.publication_year().is_some_and(|year| {
+                from.is_none_or(|from| year >= from) && to.is_none_or(|to| year <= to)
+            }),
+            TermValue::Type(work_type) => &work.work_type == work_type,
+            TermValue::Status(status) => &work.work_status == status,
+            TermValue::License(kind) => {
+                matches!(work.parsed_license(), Some(Ok(license)) if &license.kind == kind)
+            }
+        }
+    }
+
+    /// Positive terms which are alternatives to others of the same kind
+    fn alternative_group(&self) -> Option<SearchField> {
+        match (&self.value, self.negated) {
+            (TermValue::Type(_), false) => Some(SearchField::Type),
+            (TermValue::Status(_), false) => Some(SearchField::Status),
+            (TermValue::License(_), false) => Some(SearchField::License),
+            _ => None,
+        }
+    }
+}
+
+impl SearchQuery {
+    /// Position of the included free text sent to the API as its `filter`. The API matches a
+    /// single substring, so only the longest, most selective, term is sent and any others are
+    /// matched once fetched.
+    fn api_text(&self) -> Option<usize> {
+        self.terms
+            .iter()
+            .enumerate()
+            .filter_map(|(i, term)| match (&term.value, term.negated) {
+                (TermValue::Text(text), false) => Some((i, text.chars().count())),
+                _ => None,
+            })
+            .max_by_key(|(i, length)| (*length, std::cmp::Reverse(*i)))
+            .map(|(i, _)| i)
+    }
+
+    /// Free text to send to the API as the `filter` variable
+    pub fn text_filter(&self) -> String {
+        match self.api_text().map(|i| &self.terms[i].value) {
+            Some(TermValue::Text(text)) => text.clone(),
+            _ => String::new(),
+        }
+    }
+
+    /// Values searched for which appear as they were entered in matching records, to highlight
+    pub fn highlight_terms(&self) -> Vec<String> {
+        self.terms
+            .iter()
+            .filter(|term| !term.negated)
+            .filter_map(|term| match &term.value {
+                TermValue::Text(text) | TermValue::Author(text) | TermValue::Subject(text) => {
+                    Some(text.clone())
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Values of `all` which results may have, given the terms picked out by `value`, or `None`
+    /// if there are no such terms. Included values are alternatives, and excluded ones are
+    /// removed from them, or from `all` if none is included.
+    fn allowed<T: Clone + PartialEq>(
+        &self,
+        all: &[T],
+        value: impl Fn(&TermValue) -> Option<&T>,
+    ) -> Option<Vec<T>> {
+        let (mut included, mut excluded) = (vec![], vec![]);
+        for term in &self.terms {
+            if let Some(value) = value(&term.value) {
+                match term.negated {
+                    true => excluded.push(value.clone()),
+                    false => included.push(value.clone()),
+                }
+            }
+        }
+        if included.is_empty() && excluded.is_empty() {
+            return None;
+        }
+        let candidates = match included.is_empty() {
+            true => all.to_vec(),
+            false => included,
+        };
+        Some(
+            candidates
+                .into_iter()
+                .filter(|v| all.contains(v) && !excluded.contains(v))
+                .collect(),
+        )
+    }
+
+    /// Types out of `all` which results may have, for the API to restrict results to
+    pub fn work_types(&self, all: &[WorkType]) -> Option<Vec<WorkType>> {
+        self.allowed(all, |value| match value {
+            TermValue::Type(work_type) => Some(work_type),
+            _ => None,
+        })
+    }
+
+    /// Statuses out of `all` which results may have, for the API to restrict results to
+    pub fn work_statuses(&self, all: &[WorkStatus]) -> Option<Vec<WorkStatus>> {
+        self.allowed(all, |value| match value {
+            TermValue::Status(status) => Some(status),
+            _ => None,
+        })
+    }
+
+    /// Whether any term is left for `matches` to evaluate once results are fetched, rather than
+    /// being sent to the API through `text_filter`, `work_types` and `work_statuses`
+    pub fn has_local_terms(&self) -> bool {
+        let api_text = self.api_text();
+        self.terms
+            .iter()
+            .enumerate()
+            .any(|(i, term)| match &term.value {
+                TermValue::Text(_) => Some(i) != api_text,
+                TermValue::Type(_) | TermValue::Status(_) => false,
+                _ => true,
+            })
+    }
+
+    /// Whether a fetched work satisfies every term other than the free text sent to the API
+    pub fn matches(&self, work: &WorkWithRelations) -> bool {
+        let api_text = self.api_text();
+        let required = self
+            .terms
+            .iter()
+            .enumerate()
+            .filter(|(i, term)| term.alternative_group().is_none() && Some(*i) != api_text)
+            .all(|(_, term)| term.matches(work) != term.negated);
+        let alternatives = [SearchField::Type, SearchField::Status, SearchField::License]
+            .iter()
+            .all(|field| {
+                let mut group = self
+                    .terms
+                    .iter()
+                    .filter(|term| term.alternative_group() == Some(*field))
+                    .peekable();
+                group.peek().is_none() || group.any(|term| term.matches(work))
+            });
+        required && alternatives
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::utils::Contribution;
+
+    fn parsed(input: &str) -> SearchQuery {
+        input.parse().unwrap()
+    }
+
+    fn work() -> WorkWithRelations {
+        WorkWithRelations {
+            work_type: WorkType::Monograph,
+            work_status: WorkStatus::Active,
+            full_title: "A History of Europe".to_string(),
+            short_abstract: Some("Trade and empire since 1500".to_string()),
+            publication_date: Some("2021-05-01".to_string()),
+            contributions: Some(vec![Contribution {
+                full_name: "Jane Smith".to_string(),
+                ..Default::default()
+            }]),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn queries_round_trip_through_display() {
+        for input in [
+            "history",
+            "\"open access\" author:\"de la cruz\" -subject:HIS",
+            "isbn:9781800640",
+            "year:2019 year:2019..2022 year:..2000 year:1990..",
+            "type:edited-book -status:out-of-print license:by-nc",
+        ] {
+            let query = parsed(input);
+            assert_eq!(query.to_string(), input);
+            assert_eq!(parsed(&query.to_string()), query);
+        }
+        // Prefixes and values are normalised
+        assert_eq!(
+            parsed("Contributor:smith ISBN:978-1-80064 type:Edited_Book").to_string(),
+            "author:smith isbn:978180064 type:edited-book"
+        );
+    }
+
+    #[test]
+    fn invalid_queries_are_rejected() {
+        for input in ["\"open access", "year:20x1", "type:pamphlet", "author:"] {
+            assert!(input.parse::<SearchQuery>().is_err(), "{input}");
+        }
+        // Unrecognised prefixes are free text
+        assert_eq!(
+            parsed("re:search").terms,
+            vec![SearchTerm {
+                value: TermValue::Text("re:search".to_string()),
+                negated: false,
+            }]
+        );
+    }
+
+    #[test]
+    fn only_the_longest_text_term_is_sent_to_the_api() {
+        let query = parsed("history europe -war");
+        assert_eq!(query.text_filter(), "history");
+        assert!(query.has_local_terms());
+        assert!(!parsed("history type:monograph").has_local_terms());
+        assert_eq!(parsed("author:smith").text_filter(), "");
+    }
+
+    #[test]
+    fn words_are_matched_separately_rather_than_as_a_phrase() {
+        // "history europe" is not a substring of the title, but each word is in it
+        assert!(parsed("history europe").matches(&work()));
+        assert!(parsed("europe trade").matches(&work()));
+        assert!(!parsed("history asia").matches(&work()));
+        assert!(!parsed("\"history europe\" asia").matches(&work()));
+        assert!(!parsed("history -empire").matches(&work()));
+    }
+
+    #[test]
+    fn fielded_terms_are_matched() {
+        assert!(parsed("author:smith year:2020..2022").matches(&work()));
+        assert!(!parsed("-author:smith").matches(&work()));
+        assert!(!parsed("year:..2020").matches(&work()));
+        assert!(parsed("type:textbook type:monograph").matches(&work()));
+        assert!(!parsed("type:monograph -status:active").matches(&work()));
+        assert!(!parsed("license:by").matches(&work()));
+    }
+
+    #[test]
+    fn types_and_statuses_are_compiled_into_api_restrictions() {
+        let all = [
+            WorkType::Monograph,
+            WorkType::EditedBook,
+            WorkType::Textbook,
+        ];
+        assert_eq!(parsed("history").work_types(&all), None);
+        assert_eq!(
+            parsed("type:textbook type:book-chapter").work_types(&all),
+            Some(vec![WorkType::Textbook])
+        );
+        assert_eq!(
+            parsed("-type:monograph").work_types(&all),
+            Some(vec![WorkType::EditedBook, WorkType::Textbook])
+        );
+        assert_eq!(
+            parsed("status:active -status:active").work_statuses(&WORK_STATUSES),
+            Some(vec![])
+        );
+    }
+}
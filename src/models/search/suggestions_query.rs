@@ -0,0 +1,111 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::models::search::{highlight_terms, Suggestion, SuggestionKind};
+
+pub const SUGGESTIONS_QUERY: &str = "
+    query SuggestionsQuery($limit: Int, $filter: String, $publishers: [Uuid!]) {
+        books(limit: $limit, filter: $filter, publishers: $publishers) {
+            workId
+            fullTitle
+        }
+        contributors(limit: $limit, filter: $filter, order: {field: FULL_NAME, direction: ASC}) {
+            contributorId
+            fullName
+            contributions {
+                workId
+            }
+        }
+        serieses(limit: $limit, filter: $filter, publishers: $publishers, order: {field: SERIES_NAME, direction: ASC}) {
+            seriesId
+            seriesName
+            issues {
+                workId
+            }
+        }
+    }
+";
+
+graphql_query_builder! {
+    SuggestionsRequest,
+    SuggestionsRequestBody,
+    Variables,
+    SUGGESTIONS_QUERY,
+    SuggestionsResponseBody,
+    SuggestionsResponseData,
+    FetchSuggestions,
+    FetchActionSuggestions
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Variables {
+    pub limit: Option<i32>,
+    pub filter: Option<String>,
+    pub publishers: Option<Vec<String>>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkLink {
+    pub work_id: Uuid,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct SuggestedWork {
+    pub work_id: Uuid,
+    pub full_title: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct SuggestedContributor {
+    pub contributor_id: Uuid,
+    pub full_name: String,
+    pub contributions: Vec<WorkLink>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct SuggestedSeries {
+    pub series_id: Uuid,
+    pub series_name: String,
+    pub issues: Vec<WorkLink>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct SuggestionsResponseData {
+    pub books: Vec<SuggestedWork>,
+    pub contributors: Vec<SuggestedContributor>,
+    pub serieses: Vec<SuggestedSeries>,
+}
+
+impl SuggestionsResponseData {
+    /// Titles, then contributors, then series, each remembering the query they were found for
+    pub fn suggestions(&self, query: &str) -> Vec<Suggestion> {
+        let highlights = highlight_terms(query);
+        let works = self.books.iter().map(|w| Suggestion {
+            kind: SuggestionKind::Work,
+            id: w.work_id,
+            label: w.full_title.clone(),
+            work_ids: vec![w.work_id],
+            highlights: highlights.clone(),
+        });
+        let contributors = self.contributors.iter().map(|c| Suggestion {
+            kind: SuggestionKind::Contributor,
+            id: c.contributor_id,
+            label: c.full_name.clone(),
+            work_ids: c.contributions.iter().map(|l| l.work_id).collect(),
+            highlights: highlights.clone(),
+        });
+        let series = self.serieses.iter().map(|s| Suggestion {
+            kind: SuggestionKind::Series,
+            id: s.series_id,
+            label: s.series_name.clone(),
+            work_ids: s.issues.iter().map(|l| l.work_id).collect(),
+            highlights: highlights.clone(),
+        });
+        works.chain(contributors).chain(series).collect()
+    }
+}
@@ -0,0 +1,41 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::SUBJECT_FIELDS;
+use crate::models::utils::{Subject, SubjectType};
+
+const CREATE_SUBJECT_MUTATION: &str = "
+    mutation CreateSubject(
+        $workId: Uuid!,
+        $subjectType: SubjectType!,
+        $subjectCode: String!,
+        $subjectOrdinal: Int!
+    ){
+        createSubject(data: {
+            workId: $workId
+            subjectType: $subjectType
+            subjectCode: $subjectCode
+            subjectOrdinal: $subjectOrdinal
+        }){";
+
+graphql_mutation_builder! {
+    CreateSubjectRequest,
+    CreateSubjectRequestBody,
+    Variables,
+    format!("{CREATE_SUBJECT_MUTATION}{SUBJECT_FIELDS}"),
+    CreateSubjectResponseBody,
+    CreateSubjectResponseData,
+    create_subject,
+    Subject,
+    PushCreateSubject,
+    PushActionCreateSubject
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Variables {
+    pub work_id: Uuid,
+    pub subject_type: SubjectType,
+    pub subject_code: String,
+    pub subject_ordinal: i32,
+}
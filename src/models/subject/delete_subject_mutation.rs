@@ -0,0 +1,32 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::SUBJECT_FIELDS;
+use crate::models::utils::Subject;
+
+const DELETE_SUBJECT_MUTATION: &str = "
+    mutation DeleteSubject(
+        $subjectId: Uuid!
+    ){
+        deleteSubject(
+            subjectId: $subjectId
+        ){";
+
+graphql_mutation_builder! {
+    DeleteSubjectRequest,
+    DeleteSubjectRequestBody,
+    Variables,
+    format!("{DELETE_SUBJECT_MUTATION}{SUBJECT_FIELDS}"),
+    DeleteSubjectResponseBody,
+    DeleteSubjectResponseData,
+    delete_subject,
+    Subject,
+    PushDeleteSubject,
+    PushActionDeleteSubject
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Variables {
+    pub subject_id: Uuid,
+}
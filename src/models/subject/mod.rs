@@ -0,0 +1,76 @@
+use std::fmt;
+
+use crate::models::utils::SubjectType;
+use crate::models::Dropdown;
+
+pub mod create_subject_mutation;
+pub mod delete_subject_mutation;
+pub mod update_subject_mutation;
+
+/// Fields returned by every subject mutation, matching those of `Subject`
+pub const SUBJECT_FIELDS: &str = "
+            subjectId
+            workId
+            subjectType
+            subjectCode
+            subjectOrdinal
+            createdAt
+            updatedAt
+        }
+    }
+";
+
+/// An entry of one of the bundled subject code lists
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SubjectCode {
+    pub code: &'static str,
+    pub heading: &'static str,
+}
+
+impl fmt::Display for SubjectCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} – {}", self.code, self.heading)
+    }
+}
+
+impl Dropdown for SubjectCode {}
+
+// `BIC`, `BISAC` and `THEMA`, generated by the build script from the lists in `codes/`
+include!(concat!(env!("OUT_DIR"), "/subject_codes.rs"));
+
+impl SubjectType {
+    /// Complete list of codes for subject schemes with a controlled vocabulary, used to suggest
+    /// and check codes, or `None` for schemes whose values are entered freely
+    pub fn code_list(&self) -> Option<&'static [SubjectCode]> {
+        match self {
+            SubjectType::Bic => Some(BIC),
+            SubjectType::Bisac => Some(BISAC),
+            SubjectType::Thema => Some(THEMA),
+            SubjectType::Lcc | SubjectType::Custom | SubjectType::Keyword => None,
+        }
+    }
+
+    /// Look up the entry for a code in this scheme's code list, if it has one
+    pub fn find_code(&self, code: &str) -> Option<&'static SubjectCode> {
+        self.code_list()?
+            .iter()
+            .find(|entry| entry.code.eq_ignore_ascii_case(code.trim()))
+    }
+
+    /// Entries whose code starts with, or whose heading contains, the given query
+    pub fn suggest_codes(&self, query: &str, limit: usize) -> Vec<&'static SubjectCode> {
+        let query = query.trim().to_lowercase();
+        if query.is_empty() {
+            return vec![];
+        }
+        self.code_list()
+            .unwrap_or_default()
+            .iter()
+            .filter(|entry| {
+                entry.code.to_lowercase().starts_with(&query)
+                    || entry.heading.to_lowercase().contains(&query)
+            })
+            .take(limit)
+            .collect()
+    }
+}
@@ -0,0 +1,56 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::SUBJECT_FIELDS;
+use crate::models::utils::{Subject, SubjectType};
+
+const UPDATE_SUBJECT_MUTATION: &str = "
+    mutation UpdateSubject(
+        $subjectId: Uuid!,
+        $workId: Uuid!,
+        $subjectType: SubjectType!,
+        $subjectCode: String!,
+        $subjectOrdinal: Int!
+    ){
+        updateSubject(data: {
+            subjectId: $subjectId
+            workId: $workId
+            subjectType: $subjectType
+            subjectCode: $subjectCode
+            subjectOrdinal: $subjectOrdinal
+        }){";
+
+graphql_mutation_builder! {
+    UpdateSubjectRequest,
+    UpdateSubjectRequestBody,
+    Variables,
+    format!("{UPDATE_SUBJECT_MUTATION}{SUBJECT_FIELDS}"),
+    UpdateSubjectResponseBody,
+    UpdateSubjectResponseData,
+    update_subject,
+    Subject,
+    PushUpdateSubject,
+    PushActionUpdateSubject
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Variables {
+    pub subject_id: Uuid,
+    pub work_id: Uuid,
+    pub subject_type: SubjectType,
+    pub subject_code: String,
+    pub subject_ordinal: i32,
+}
+
+impl From<&Subject> for Variables {
+    fn from(subject: &Subject) -> Self {
+        Variables {
+            subject_id: subject.subject_id,
+            work_id: subject.work_id,
+            subject_type: subject.subject_type.clone(),
+            subject_code: subject.subject_code.clone(),
+            subject_ordinal: subject.subject_ordinal,
+        }
+    }
+}
@@ -1,6 +1,8 @@
 use chrono::DateTime;
 use chrono::{NaiveDate, TimeZone, Utc};
-use juniper::{GraphQLScalarValue, ScalarValue, Value};
+#[cfg(any(target_arch = "wasm32", test))]
+use juniper::Value;
+use juniper::{GraphQLScalarValue, ScalarValue};
 use serde::{de, Deserialize, Serialize};
 use std::fmt::{self, Display};
 use std::str::FromStr;
@@ -8,14 +10,39 @@ use strum::Display;
 use strum::EnumString;
 use thiserror::Error;
 use uuid::Uuid;
+#[cfg(any(target_arch = "wasm32", test))]
 use yew::{html, Callback, Html, MouseEvent};
 
+#[cfg(any(target_arch = "wasm32", test))]
+use crate::models::search::highlighted;
+#[cfg(any(target_arch = "wasm32", test))]
 use crate::route::AppRoute;
-
-use super::{CreateRoute, EditRoute, MetadataTable};
+#[cfg(any(target_arch = "wasm32", test))]
+use crate::string::{COVER_ALT, VISIT_BOOK_PAGE};
+use crate::string::{
+    ERROR_API_UNREACHABLE, ERROR_CANONICAL_LOCATION, ERROR_CHAPTER_DIMENSION, ERROR_CHAPTER_ISBN,
+    ERROR_CSV, ERROR_DATABASE, ERROR_DEPTH_EMPTY, ERROR_DIMENSION_DIGITAL, ERROR_DOI_EMPTY,
+    ERROR_DOI_PARSE, ERROR_ENTITY_NOT_FOUND, ERROR_HEIGHT_EMPTY, ERROR_INCOMPLETE_METADATA_RECORD,
+    ERROR_INTERNAL, ERROR_INVALID_METADATA_SPECIFICATION, ERROR_INVALID_SUBJECT_CODE,
+    ERROR_INVALID_TOKEN, ERROR_INVALID_UUID, ERROR_ISBN_EMPTY, ERROR_ISBN_PARSE,
+    ERROR_ISSUE_IMPRINTS, ERROR_LICENSE_EMPTY, ERROR_LICENSE_PARSE, ERROR_LOCATION_URL, ERROR_MARC,
+    ERROR_NO_WITHDRAWN_DATE, ERROR_ORCID_EMPTY, ERROR_ORCID_PARSE, ERROR_PRICE_ZERO,
+    ERROR_ROR_EMPTY, ERROR_ROR_PARSE, ERROR_SUBJECT_CODE_EMPTY, ERROR_UNAUTHORISED,
+    ERROR_UNLISTED_SUBJECT_CODE, ERROR_WEIGHT_EMPTY, ERROR_WIDTH_EMPTY, ERROR_WITHDRAWN_DATE,
+    ERROR_WITHDRAWN_DATE_BEFORE_PUBLICATION,
+};
+
+#[cfg(any(target_arch = "wasm32", test))]
+use super::license::License;
+#[cfg(any(target_arch = "wasm32", test))]
+use super::MetadataTable;
+#[cfg(any(target_arch = "wasm32", test))]
+use super::{CreateRoute, EditRoute, EnumValues, FacetValues};
 
 pub const DOI_DOMAIN: &str = "https://doi.org/";
+#[cfg(any(target_arch = "wasm32", test))]
 pub const ORCID_DOMAIN: &str = "https://orcid.org/";
+#[cfg(any(target_arch = "wasm32", test))]
 pub const ROR_DOMAIN: &str = "https://ror.org/";
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq, EnumString, Display)]
@@ -123,6 +150,7 @@ pub struct WorkWithRelations {
     pub references: Option<Vec<Reference>>,
 }
 
+#[cfg(any(target_arch = "wasm32", test))]
 impl WorkWithRelations {
     pub fn compile_fulltitle(&self) -> String {
         if let Some(subtitle) = &self.subtitle.clone() {
@@ -140,6 +168,14 @@ impl WorkWithRelations {
         }
     }
 
+    /// Parse the work's licence URL, if any
+    pub fn parsed_license(&self) -> Option<ThothResult<License>> {
+        self.license
+            .as_ref()
+            .filter(|license| !license.trim().is_empty())
+            .map(|license| License::from_str(license))
+    }
+
     pub fn publisher(&self) -> String {
         if let Some(short_name) = &self.imprint.publisher.publisher_shortname.clone() {
             short_name.to_string()
@@ -149,12 +185,14 @@ impl WorkWithRelations {
     }
 }
 
+#[cfg(any(target_arch = "wasm32", test))]
 impl CreateRoute for WorkWithRelations {
     fn create_route() -> AppRoute {
-        AppRoute::None
+        AppRoute::NewBook
     }
 }
 
+#[cfg(any(target_arch = "wasm32", test))]
 impl EditRoute for WorkWithRelations {
     fn edit_route(&self) -> AppRoute {
         let work_id = self.work_id;
@@ -162,9 +200,9 @@ impl EditRoute for WorkWithRelations {
     }
 }
 
+#[cfg(any(target_arch = "wasm32", test))]
 impl MetadataTable for WorkWithRelations {
-    fn as_table_row(&self, callback: Callback<MouseEvent>) -> Html {
-        let doi = self.doi.as_ref().map(|s| s.to_string()).unwrap_or_default();
+    fn as_table_row(&self, callback: Callback<MouseEvent>, highlights: &[String]) -> Html {
         let book_id = format!("/books/{}", self.work_id.clone());
         let book_name = self.full_title.clone();
         let imprint_name = self.imprint.imprint_name.clone();
@@ -184,30 +222,71 @@ impl MetadataTable for WorkWithRelations {
             Some(a) => a.to_owned(),
             None => Default::default(),
         };
+        let license = match self.parsed_license() {
+            Some(Ok(license)) => license.as_badge(),
+            _ => html! {},
+        };
+        let languages = match self.languages.clone() {
+            Some(l) => l
+                .iter()
+                .map(|l| l.description())
+                .collect::<Vec<_>>()
+                .join(" • "),
+            None => Default::default(),
+        };
         html! {
             <section class="flex flex-col md:flex-row gap-6 md:gap-9 lg:gap-11 py-5 lg:py-10 px-5 bg-white dark:bg-gray-700 rounded-md shadow-lg max-w-full text-justify" onclick={ callback }>
-                <img alt="Book cover placeholder" sizes="25vw" class="object-cover h-48 cursor-pointer " role="link" tabindex="0" aria-label="Visit book page"
+                <img alt={ COVER_ALT.as_str() } sizes="25vw" class="object-cover h-48 cursor-pointer " role="link" tabindex="0" aria-label={ VISIT_BOOK_PAGE.as_str() }
                     src= {self.cover_url.clone()} />
                 <div>
-                    <div class="pb-0.5 text-header text-lg"> { imprint_name } </div>
+                    <div class="pb-0.5 text-header text-lg"> { imprint_name } { license } </div>
                     <h3 class="uppercase text-xl font-bold">
-                        <a href={ book_id }> { book_name } </a>
+                        <a href={ book_id }> { highlighted(&book_name, highlights) } </a>
                     </h3>
                     <ul class="text-l mt-2 mb-7 bullet-separated" role="list">
-                        <li><span class="inline-block">{ contributors }</span></li>
+                        <li><span class="inline-block">{ highlighted(&contributors, highlights) }</span></li>
+                        <li><span class="inline-block text-sm">{ languages }</span></li>
                     </ul>
                     <div></div>
-                    <div class="line-clamp-4">{ long_abstract }</div>
+                    <div class="line-clamp-4">{ highlighted(&long_abstract, highlights) }</div>
                 </div>
             </section>
         }
     }
 }
 
+#[cfg(any(target_arch = "wasm32", test))]
+impl FacetValues for WorkWithRelations {
+    fn language_codes(&self) -> Vec<LanguageCode> {
+        self.languages
+            .iter()
+            .flatten()
+            .map(|l| l.language_code.clone())
+            .collect()
+    }
+
+    fn publication_date(&self) -> Option<&str> {
+        self.publication_date.as_deref()
+    }
+
+    fn subject_types(&self) -> Vec<SubjectType> {
+        self.subjects
+            .iter()
+            .flatten()
+            .map(|s| s.subject_type.clone())
+            .collect()
+    }
+
+    fn license_url(&self) -> Option<&str> {
+        self.license.as_deref()
+    }
+}
+
 /// A specialised result type for returning Thoth data
 pub type ThothResult<T> = std::result::Result<T, ThothError>;
 
 impl Doi {
+    #[cfg(any(target_arch = "wasm32", test))]
     pub fn to_lowercase_string(&self) -> String {
         self.0.to_lowercase()
     }
@@ -248,6 +327,23 @@ impl FromStr for Doi {
     }
 }
 
+impl FromStr for Isbn {
+    type Err = ThothError;
+
+    fn from_str(input: &str) -> ThothResult<Isbn> {
+        use isbn2::Isbn13;
+        if input.is_empty() {
+            Err(ThothError::IsbnEmptyError)
+        } else {
+            // Store ISBNs in their hyphenated ISBN-13 form, as the API does
+            match input.parse::<Isbn13>().map(|parsed| parsed.hyphenate()) {
+                Ok(Ok(hyphenated)) => Ok(Isbn(hyphenated.to_string())),
+                _ => Err(ThothError::IsbnParseError(input.to_string())),
+            }
+        }
+    }
+}
+
 #[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct Publication {
@@ -265,6 +361,50 @@ pub struct Publication {
     pub depth_in: Option<f64>,
     pub weight_g: Option<f64>,
     pub weight_oz: Option<f64>,
+    pub prices: Option<Vec<Price>>,
+    pub locations: Option<Vec<Location>>,
+}
+
+impl Publication {
+    #[cfg(any(target_arch = "wasm32", test))]
+    pub fn has_dimensions(&self) -> bool {
+        [
+            self.width_mm,
+            self.width_in,
+            self.height_mm,
+            self.height_in,
+            self.depth_mm,
+            self.depth_in,
+            self.weight_g,
+            self.weight_oz,
+        ]
+        .iter()
+        .any(Option::is_some)
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Price {
+    pub price_id: Uuid,
+    pub publication_id: Uuid,
+    pub currency_code: CurrencyCode,
+    pub unit_price: f64,
+    pub created_at: Timestamp,
+    pub updated_at: Timestamp,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Location {
+    pub location_id: Uuid,
+    pub publication_id: Uuid,
+    pub landing_page: Option<String>,
+    pub full_text_url: Option<String>,
+    pub location_platform: LocationPlatform,
+    pub canonical: bool,
+    pub created_at: Timestamp,
+    pub updated_at: Timestamp,
 }
 
 #[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, Serialize, EnumString, Display)]
@@ -298,7 +438,15 @@ pub enum PublicationType {
     FictionBook,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+impl PublicationType {
+    /// Whether the publication is a physical (Paperback/Hardback) product
+    #[cfg(any(target_arch = "wasm32", test))]
+    pub fn is_physical(&self) -> bool {
+        matches!(self, PublicationType::Paperback | PublicationType::Hardback)
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
 pub struct Language {
     pub language_id: Uuid,
@@ -322,6 +470,7 @@ pub enum LanguageRelation {
     TranslatedInto,
 }
 
+#[cfg(any(target_arch = "wasm32", test))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
 pub struct SubjectTypeValues {
@@ -822,7 +971,7 @@ pub enum LanguageCode {
     Zza,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
 pub struct Subject {
     pub subject_id: Uuid,
@@ -875,6 +1024,7 @@ pub enum SubjectType {
     Keyword,
 }
 
+#[cfg(any(target_arch = "wasm32", test))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
 pub struct SeriesTypeValues {
@@ -1441,6 +1591,10 @@ pub struct Variables {
     pub filter: Option<String>,
     pub order: Option<WorkOrderBy>,
     pub publishers: Option<Vec<String>>,
+    // Left out rather than sent as null when unset, so that the query's default applies
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub work_types: Option<Vec<WorkType>>,
+    pub work_statuses: Option<Vec<WorkStatus>>,
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq, juniper::GraphQLEnum)]
@@ -1452,7 +1606,7 @@ pub enum Direction {
     Desc,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
 pub struct Timestamp(DateTime<Utc>);
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
@@ -1466,6 +1620,7 @@ pub struct ImprintWithPublisher {
     pub publisher: Publisher,
 }
 
+#[cfg(any(target_arch = "wasm32", test))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
 pub struct ContributionTypeValues {
@@ -1518,6 +1673,7 @@ pub enum RelationType {
     IsChildOf,
 }
 
+#[cfg(any(target_arch = "wasm32", test))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
 pub struct CountryCodeValues {
@@ -1583,7 +1739,7 @@ pub enum WorkType {
     BookSet,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
 pub struct Contribution {
     pub contribution_id: Uuid,
@@ -1598,6 +1754,8 @@ pub struct Contribution {
     pub last_name: String,
     pub full_name: String,
     pub contribution_ordinal: i32,
+    // Left out by queries which only need the name given in the contribution
+    #[serde(default)]
     pub contributor: Contributor,
 }
 
@@ -1631,6 +1789,7 @@ pub struct Reference {
     pub updated_at: Timestamp,
 }
 
+#[cfg(any(target_arch = "wasm32", test))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
 pub struct RelationTypeValues {
@@ -1693,12 +1852,14 @@ pub enum LocationPlatform {
     Other,
 }
 
+#[cfg(any(target_arch = "wasm32", test))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
 pub struct LanguageRelationValues {
     pub name: LanguageRelation,
 }
 
+#[cfg(any(target_arch = "wasm32", test))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
 pub struct CurrencyCodeValues {
@@ -2014,6 +2175,7 @@ pub enum CurrencyCode {
     Zwr,
 }
 
+#[cfg(any(target_arch = "wasm32", test))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
 pub struct LocationPlatformValues {
@@ -2033,6 +2195,7 @@ pub struct Contributor {
     pub updated_at: Timestamp,
 }
 
+#[cfg(any(target_arch = "wasm32", test))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
 pub struct LanguageCodeValues {
@@ -2165,94 +2328,181 @@ pub enum WorkStatus {
     Recalled,
 }
 
+impl WorkStatus {
+    /// Whether the work is no longer available, and hence requires a withdrawn date
+    #[cfg(any(target_arch = "wasm32", test))]
+    pub fn is_withdrawn(&self) -> bool {
+        matches!(self, WorkStatus::OutOfPrint | WorkStatus::WithdrawnFromSale)
+    }
+}
+
+#[cfg(any(target_arch = "wasm32", test))]
+macro_rules! enum_values {
+    ($($values:ty => $value:ty,)*) => {
+        $(
+            impl EnumValues for $values {
+                type Value = $value;
+
+                fn value(&self) -> $value {
+                    self.name.clone()
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(any(target_arch = "wasm32", test))]
+enum_values! {
+    WorkTypeValues => WorkType,
+    WorkStatusValues => WorkStatus,
+    ContributionTypeValues => ContributionType,
+    PublicationTypeValues => PublicationType,
+    SubjectTypeValues => SubjectType,
+    SeriesTypeValues => SeriesType,
+    LanguageCodeValues => LanguageCode,
+    LanguageRelationValues => LanguageRelation,
+    CurrencyCodeValues => CurrencyCode,
+    LocationPlatformValues => LocationPlatform,
+    CountryCodeValues => CountryCode,
+    RelationTypeValues => RelationType,
+}
+
 #[derive(Error, Debug, PartialEq, Eq)]
 /// Represents anything that can go wrong in Thoth
 ///
 /// This type is not intended to be exhaustively matched, and new variants may
 /// be added in the future without a major version bump.
+// It mirrors the errors of the API, most of which only the app's forms report
+#[cfg_attr(not(any(target_arch = "wasm32", test)), allow(dead_code))]
 pub enum ThothError {
-    #[error("{0} is not a valid {1} code")]
+    #[error("{}", ERROR_INVALID_SUBJECT_CODE.fill(&[.0.as_str(), .1.as_str()]))]
     InvalidSubjectCode(String, String),
-    #[error("Database error: {0}")]
+    #[error("{}", ERROR_DATABASE.fill(&[.0.as_str()]))]
     DatabaseError(String),
     #[error("{0}")]
-    DatabaseConstraintError(&'static str),
-    #[error("Internal error: {0}")]
+    DatabaseConstraintError(String),
+    #[error("{}", ERROR_INTERNAL.fill(&[.0.as_str()]))]
     InternalError(String),
-    #[error("Invalid credentials.")]
+    #[error("{}", ERROR_UNAUTHORISED)]
     Unauthorised,
-    #[error("Failed to validate token.")]
+    #[error("{}", ERROR_INVALID_TOKEN)]
     InvalidToken,
-    #[error("No record was found for the given ID.")]
+    #[error("{}", ERROR_ENTITY_NOT_FOUND)]
     EntityNotFound,
-    #[error("Issue's Work and Series cannot have different Imprints.")]
+    #[error("{}", ERROR_ISSUE_IMPRINTS)]
     IssueImprintsError,
-    #[error("{0} is not a valid metadata specification")]
+    #[error("{}", ERROR_INVALID_METADATA_SPECIFICATION.fill(&[.0.as_str()]))]
     InvalidMetadataSpecification(String),
-    #[error("Invalid UUID supplied.")]
+    #[error("{}", ERROR_INVALID_UUID)]
     InvalidUuid,
-    #[error("CSV Error: {0}")]
+    #[error("{}", ERROR_CSV.fill(&[.0.as_str()]))]
     CsvError(String),
-    #[error("MARC Error: {0}")]
+    #[error("{}", ERROR_MARC.fill(&[.0.as_str()]))]
     MarcError(String),
-    #[error("Could not generate {0}: {1}")]
+    #[error("{}", ERROR_INCOMPLETE_METADATA_RECORD.fill(&[.0.as_str(), .1.as_str()]))]
     IncompleteMetadataRecord(String, String),
-    #[error("{0} is not a validly formatted ORCID and will not be saved")]
+    #[error("{}", ERROR_ORCID_PARSE.fill(&[.0.as_str()]))]
     OrcidParseError(String),
-    #[error("{0} is not a validly formatted DOI and will not be saved")]
+    #[error("{}", ERROR_DOI_PARSE.fill(&[.0.as_str()]))]
     DoiParseError(String),
-    #[error("{0} is not a validly formatted ISBN and will not be saved")]
+    #[error("{}", ERROR_ISBN_PARSE.fill(&[.0.as_str()]))]
     IsbnParseError(String),
-    #[error("{0} is not a validly formatted ROR ID and will not be saved")]
+    #[error("{}", ERROR_ROR_PARSE.fill(&[.0.as_str()]))]
     RorParseError(String),
-    #[error("Cannot parse ORCID: no value provided")]
+    #[error("{}", ERROR_ORCID_EMPTY)]
     OrcidEmptyError,
-    #[error("Cannot parse DOI: no value provided")]
+    #[error("{}", ERROR_DOI_EMPTY)]
     DoiEmptyError,
-    #[error("Cannot parse ISBN: no value provided")]
+    #[error("{}", ERROR_ISBN_EMPTY)]
     IsbnEmptyError,
-    #[error("Cannot parse ROR ID: no value provided")]
+    #[error("{}", ERROR_ROR_EMPTY)]
     RorEmptyError,
-    #[error("Works of type Book Chapter cannot have ISBNs in their Publications.")]
+    #[error("{}", ERROR_LICENSE_PARSE.fill(&[.0.as_str()]))]
+    LicenseParseError(String),
+    #[error("{}", ERROR_LICENSE_EMPTY)]
+    LicenseEmptyError,
+    #[error("{}", ERROR_SUBJECT_CODE_EMPTY)]
+    SubjectCodeEmptyError,
+    #[error("{}", ERROR_UNLISTED_SUBJECT_CODE.fill(&[.0.as_str(), .1.as_str()]))]
+    UnlistedSubjectCode(String, String),
+    #[error("{0}")]
+    InvalidSearchQuery(String),
+    #[error("{}", ERROR_CHAPTER_ISBN)]
     ChapterIsbnError,
-    #[error(
-        "Works of type Book Chapter cannot have Width, Height, Depth or Weight in their Publications."
-    )]
+    #[error("{}", ERROR_CHAPTER_DIMENSION)]
     ChapterDimensionError,
-    #[error("Each Publication must have exactly one canonical Location.")]
+    #[error("{}", ERROR_CANONICAL_LOCATION)]
     CanonicalLocationError,
-    #[error(
-        "Canonical Locations for digital Publications must have both a Landing Page and a Full Text URL."
-    )]
+    #[error("{}", ERROR_LOCATION_URL)]
     LocationUrlError,
-    #[error("When specifying Weight, both values (g and oz) must be supplied.")]
+    #[error("{}", ERROR_WEIGHT_EMPTY)]
     WeightEmptyError,
-    #[error("When specifying Width, both values (mm and in) must be supplied.")]
+    #[error("{}", ERROR_WIDTH_EMPTY)]
     WidthEmptyError,
-    #[error("When specifying Height, both values (mm and in) must be supplied.")]
+    #[error("{}", ERROR_HEIGHT_EMPTY)]
     HeightEmptyError,
-    #[error("When specifying Depth, both values (mm and in) must be supplied.")]
+    #[error("{}", ERROR_DEPTH_EMPTY)]
     DepthEmptyError,
-    #[error(
-        "Width/Height/Depth/Weight are only applicable to physical (Paperback/Hardback) Publications."
-    )]
+    #[error("{}", ERROR_DIMENSION_DIGITAL)]
     DimensionDigitalError,
-    #[error(
-        "Price values must be greater than zero. To indicate an unpriced Publication, omit all Prices."
-    )]
+    #[error("{}", ERROR_PRICE_ZERO)]
     PriceZeroError,
     #[error("{0}")]
     RequestError(String),
     #[error("{0}")]
     GraphqlError(String),
-    #[error("Withdrawn Date must be later than Publication Date.")]
+    #[error("{}", ERROR_WITHDRAWN_DATE_BEFORE_PUBLICATION)]
     WithdrawnDateBeforePublicationDateError,
-    #[error("Withdrawn Date can only be added to an Out of Print or Withdrawn From Sale Work.")]
+    #[error("{}", ERROR_WITHDRAWN_DATE)]
     WithdrawnDateError,
-    #[error("An Out of Print or Withdrawn From Sale Work must have a Withdrawn Date.")]
+    #[error("{}", ERROR_NO_WITHDRAWN_DATE)]
     NoWithdrawnDateError,
 }
 
+/// Parts of the messages the API reports when a mutation violates a database constraint.
+///
+/// The API (`thoth-errors`, `database_errors.rs`) replaces the PostgreSQL error of a violated
+/// unique constraint with a message naming the duplicated value, e.g. "A work with this DOI already
+/// exists.", which all end alike. Violations of constraints it has no message for are reported as
+/// a `Database error:` followed by the PostgreSQL message, whose wording is stable across
+/// PostgreSQL versions. Matching on these parts rather than on whole messages keeps recognising
+/// them when the API words a message differently or adds one for a new constraint.
+const DATABASE_CONSTRAINT_PATTERNS: &[&str] = &[
+    " already exists",
+    "violates unique constraint",
+    "violates foreign key constraint",
+    "violates check constraint",
+    "violates not-null constraint",
+];
+
+impl ThothError {
+    /// Recognise errors reported by the API which the UI handles specifically, e.g. dropping
+    /// the session on authentication failures or pointing at the offending field on constraints
+    fn from_message(message: String) -> ThothError {
+        let message = message.trim();
+        // Only a pair of quotes around the whole message, as quotes within it may end it too
+        let message = message
+            .strip_prefix('"')
+            .and_then(|m| m.strip_suffix('"'))
+            .unwrap_or(message)
+            .to_string();
+        if message == ERROR_INVALID_TOKEN.english() {
+            ThothError::InvalidToken
+        } else if message == ERROR_UNAUTHORISED.english() || message == "Unauthorized" {
+            ThothError::Unauthorised
+        } else if message == ERROR_ISSUE_IMPRINTS.english() {
+            ThothError::IssueImprintsError
+        } else if DATABASE_CONSTRAINT_PATTERNS
+            .iter()
+            .any(|pattern| message.contains(pattern))
+        {
+            ThothError::DatabaseConstraintError(message)
+        } else {
+            ThothError::GraphqlError(message)
+        }
+    }
+}
+
 #[cfg(not(target_arch = "wasm32"))]
 impl juniper::IntoFieldError for ThothError {
     fn into_field_error(self) -> juniper::FieldError {
@@ -2280,6 +2530,7 @@ impl juniper::IntoFieldError for ThothError {
     }
 }
 
+#[cfg(any(target_arch = "wasm32", test))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
 pub struct PublicationTypeValues {
@@ -2294,12 +2545,12 @@ impl From<yewtil::fetch::FetchError> for ThothError {
             FetchError::DeserializeError { error: _, content } => {
                 let message: Result<GraqphqlErrorMessage> = serde_json::from_str(&content);
                 match message {
-                    Ok(m) => ThothError::GraphqlError(m.to_string()),
+                    Ok(m) => ThothError::from_message(m.to_string()),
                     Err(_) => ThothError::RequestError(content),
                 }
             }
             FetchError::CouldNotCreateFetchFuture => {
-                ThothError::RequestError("Could not connect to the API.".to_string())
+                ThothError::RequestError(ERROR_API_UNREACHABLE.to_string())
             }
             _ => ThothError::RequestError(error.to_string()),
         }
@@ -2405,16 +2656,19 @@ impl fmt::Display for Doi {
     }
 }
 
+#[cfg(any(target_arch = "wasm32", test))]
 pub trait UrlIdentifier {
     fn domain(&self) -> &'static str;
 }
 
+#[cfg(any(target_arch = "wasm32", test))]
 impl UrlIdentifier for Doi {
     fn domain(&self) -> &'static str {
         DOI_DOMAIN
     }
 }
 
+#[cfg(any(target_arch = "wasm32", test))]
 impl Contribution {
     pub fn contribution_id(&self) -> Uuid {
         self.contribution_id
@@ -2465,11 +2719,13 @@ impl Contribution {
     }
 }
 
+#[cfg(any(target_arch = "wasm32", test))]
 pub struct AffiliationOrderBy {
     pub field: AffiliationField,
     pub direction: Direction,
 }
 
+#[cfg(any(target_arch = "wasm32", test))]
 pub enum AffiliationField {
     AffiliationId,
     ContributionId,
@@ -2480,6 +2736,7 @@ pub enum AffiliationField {
     UpdatedAt,
 }
 
+#[cfg(any(target_arch = "wasm32", test))]
 pub type FieldResult<T, S = DefaultScalarValue> = Result<T, FieldError<S>>;
 
 #[derive(Debug, PartialEq, Clone, GraphQLScalarValue)]
@@ -2571,7 +2828,7 @@ impl<'de> de::Visitor<'de> for DefaultScalarValueVisitor {
     where
         E: de::Error,
     {
-        if value >= i64::from(i32::min_value()) && value <= i64::from(i32::max_value()) {
+        if value >= i64::from(i32::MIN) && value <= i64::from(i32::MAX) {
             Ok(DefaultScalarValue::Int(value as i32))
         } else {
             // Browser's JSON.stringify serialize all numbers having no
@@ -2587,7 +2844,7 @@ impl<'de> de::Visitor<'de> for DefaultScalarValueVisitor {
     where
         E: de::Error,
     {
-        if value <= i32::max_value() as u64 {
+        if value <= i32::MAX as u64 {
             self.visit_i64(value as i64)
         } else {
             // Browser's JSON.stringify serialize all numbers having no
@@ -2614,12 +2871,14 @@ impl<'de> de::Visitor<'de> for DefaultScalarValueVisitor {
         Ok(DefaultScalarValue::String(value))
     }
 }
+#[cfg(any(target_arch = "wasm32", test))]
 #[derive(Debug, PartialEq)]
 pub struct FieldError<S = DefaultScalarValue> {
     message: String,
     extensions: Value<S>,
 }
 
+#[cfg(any(target_arch = "wasm32", test))]
 impl<S> FieldError<S> {
     /// Construct a new error with additional data
     ///
@@ -2686,6 +2945,7 @@ impl<S> FieldError<S> {
     }
 }
 
+#[cfg(any(target_arch = "wasm32", test))]
 #[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
 pub struct Affiliation {
@@ -2698,6 +2958,7 @@ pub struct Affiliation {
     pub updated_at: Timestamp,
 }
 
+#[cfg(any(target_arch = "wasm32", test))]
 pub trait ListString {
     const BULLET_SEPARATOR: &'static str = " • ";
     const COMMA_SEPARATOR: &'static str = ", ";
@@ -2709,6 +2970,7 @@ pub trait ListString {
     fn separated_list_item(&self, is_small: bool, separator: &str) -> Html;
 }
 
+#[cfg(any(target_arch = "wasm32", test))]
 impl ListString for Contribution {
     fn separated_list_item(&self, is_small: bool, separator: &str) -> Html {
         // Only include contributions marked as "Main" in summary list
@@ -2734,32 +2996,162 @@ impl ListString for Contribution {
     }
 }
 
+impl Timestamp {
+    /// Date and time in the RFC 3339 form used by sitemaps and Atom feeds
+    pub fn to_rfc3339(&self) -> String {
+        self.0.to_rfc3339_opts(chrono::SecondsFormat::Secs, true)
+    }
+}
+
 impl fmt::Display for Timestamp {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> fmt::Result {
         write!(f, "{}", &self.0.format("%F %T"))
     }
 }
 
+#[cfg(any(target_arch = "wasm32", test))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
 pub struct WorkTypeValues {
     pub name: WorkType,
 }
 
+#[cfg(any(target_arch = "wasm32", test))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
 pub struct WorkStatusValues {
     pub name: WorkStatus,
 }
 
+#[cfg(any(target_arch = "wasm32", test))]
 #[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
 pub struct WorkStatusDefinition {
     pub enum_values: Vec<WorkStatusValues>,
 }
 
+#[cfg(any(target_arch = "wasm32", test))]
 #[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
 pub struct WorkTypeDefinition {
     pub enum_values: Vec<WorkTypeValues>,
 }
+
+#[cfg(any(target_arch = "wasm32", test))]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ContributionTypeDefinition {
+    pub enum_values: Vec<ContributionTypeValues>,
+}
+
+#[cfg(any(target_arch = "wasm32", test))]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct RelationTypeDefinition {
+    pub enum_values: Vec<RelationTypeValues>,
+}
+
+#[cfg(any(target_arch = "wasm32", test))]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct PublicationTypeDefinition {
+    pub enum_values: Vec<PublicationTypeValues>,
+}
+
+#[cfg(any(target_arch = "wasm32", test))]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct SubjectTypeDefinition {
+    pub enum_values: Vec<SubjectTypeValues>,
+}
+
+#[cfg(any(target_arch = "wasm32", test))]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct LanguageCodeDefinition {
+    pub enum_values: Vec<LanguageCodeValues>,
+}
+
+#[cfg(any(target_arch = "wasm32", test))]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct LanguageRelationDefinition {
+    pub enum_values: Vec<LanguageRelationValues>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn contributions_may_leave_out_their_contributor() {
+        let contribution: Contribution = serde_json::from_str(
+            r#"{
+                "contributionId": "00000000-0000-0000-0000-000000000001",
+                "workId": "00000000-0000-0000-0000-000000000002",
+                "contributorId": "00000000-0000-0000-0000-000000000003",
+                "contributionType": "AUTHOR",
+                "mainContribution": true,
+                "createdAt": "2024-01-01T00:00:00Z",
+                "updatedAt": "2024-01-01T00:00:00Z",
+                "lastName": "Doe",
+                "fullName": "Jane Doe",
+                "contributionOrdinal": 1
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(contribution.full_name, "Jane Doe");
+        assert!(contribution.contributor.contributor_id.is_nil());
+    }
+
+    #[test]
+    fn constraint_violations_are_recognised_by_their_wording() {
+        assert_eq!(
+            ThothError::from_message("\"A work with this DOI already exists.\"".to_string()),
+            ThothError::DatabaseConstraintError("A work with this DOI already exists.".to_string())
+        );
+        for message in [
+            "A work with this DOI already exists.",
+            "Database error: duplicate key value violates unique constraint \"doi_uniq_idx\"",
+            "Database error: update or delete on table \"work\" violates foreign key constraint \"issue_work_id_fkey\" on table \"issue\"",
+        ] {
+            assert_eq!(
+                ThothError::from_message(message.to_string()),
+                ThothError::DatabaseConstraintError(message.to_string())
+            );
+        }
+        assert_eq!(
+            ThothError::from_message("Invalid credentials.".to_string()),
+            ThothError::Unauthorised
+        );
+        assert_eq!(
+            ThothError::from_message("Something else went wrong".to_string()),
+            ThothError::GraphqlError("Something else went wrong".to_string())
+        );
+    }
+
+    #[test]
+    fn errors_are_shown_in_the_current_locale() {
+        use crate::i18n::{set_locale, Locale};
+
+        let error = ThothError::IsbnParseError("978-0".to_string());
+        assert_eq!(
+            error.to_string(),
+            "978-0 is not a validly formatted ISBN and will not be saved"
+        );
+        set_locale(Locale::De);
+        assert_eq!(
+            error.to_string(),
+            "978-0 ist keine gültig formatierte ISBN und wird nicht gespeichert"
+        );
+        assert_eq!(
+            ThothError::UnlistedSubjectCode("XYZ".to_string(), "Thema".to_string()).to_string(),
+            "XYZ ist kein Thema-Code"
+        );
+        // The API reports errors in English whatever the locale of the app
+        assert_eq!(
+            ThothError::from_message("Failed to validate token.".to_string()),
+            ThothError::InvalidToken
+        );
+    }
+}
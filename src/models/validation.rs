@@ -0,0 +1,224 @@
+use chrono::NaiveDate;
+
+use super::utils::{
+    Publication, SubjectType, ThothError, ThothResult, WorkType, WorkWithRelations,
+};
+
+fn parse_date(date: &Option<String>) -> Option<NaiveDate> {
+    date.as_ref()
+        .and_then(|d| NaiveDate::parse_from_str(d, "%Y-%m-%d").ok())
+}
+
+/// Evaluate the business rules enforced by the API against a work and its relations,
+/// so that invalid records can be spotted before they are rejected downstream.
+///
+/// Each distinct error is reported once, regardless of how many publications trigger it.
+pub fn validate(work: &WorkWithRelations) -> Vec<ThothError> {
+    let mut errors = validate_withdrawn_date(work);
+    for publication in work.publications.iter().flatten() {
+        errors.extend(validate_publication(&work.work_type, publication));
+    }
+    let mut unique: Vec<ThothError> = vec![];
+    for error in errors {
+        if !unique.contains(&error) {
+            unique.push(error);
+        }
+    }
+    unique
+}
+
+/// Check the rules governing a work's withdrawn date, mirroring the checks made by the API:
+///
+/// - only Out of Print or Withdrawn From Sale works may have a withdrawn date
+/// - Out of Print and Withdrawn From Sale works must have a withdrawn date
+/// - the withdrawn date must not be earlier than the publication date. The API accepts a work
+///   withdrawn on the day it was published, despite its error message asking for a later date.
+pub fn validate_withdrawn_date(work: &WorkWithRelations) -> Vec<ThothError> {
+    let mut errors = vec![];
+    let withdrawn_date = parse_date(&work.withdrawn_date);
+    match (work.work_status.is_withdrawn(), withdrawn_date) {
+        (false, Some(_)) => errors.push(ThothError::WithdrawnDateError),
+        (true, None) => errors.push(ThothError::NoWithdrawnDateError),
+        _ => {}
+    }
+    if let (Some(withdrawn), Some(published)) = (withdrawn_date, parse_date(&work.publication_date))
+    {
+        if withdrawn < published {
+            errors.push(ThothError::WithdrawnDateBeforePublicationDateError);
+        }
+    }
+    errors
+}
+
+/// Check a single publication against the rules for ISBNs, dimensions, prices and locations
+pub fn validate_publication(work_type: &WorkType, publication: &Publication) -> Vec<ThothError> {
+    let mut errors = validate_publication_details(work_type, publication);
+    if publication
+        .prices
+        .iter()
+        .flatten()
+        .any(|price| price.unit_price <= 0.0)
+    {
+        errors.push(ThothError::PriceZeroError);
+    }
+    let locations = publication.locations.clone().unwrap_or_default();
+    if !locations.is_empty() {
+        let canonical: Vec<_> = locations.iter().filter(|l| l.canonical).collect();
+        if canonical.len() != 1 {
+            errors.push(ThothError::CanonicalLocationError);
+        }
+        if !publication.publication_type.is_physical()
+            && canonical
+                .iter()
+                .any(|l| l.landing_page.is_none() || l.full_text_url.is_none())
+        {
+            errors.push(ThothError::LocationUrlError);
+        }
+    }
+    errors
+}
+
+/// Check the fields stored on the publication itself, i.e. its ISBN and dimensions
+pub fn validate_publication_details(
+    work_type: &WorkType,
+    publication: &Publication,
+) -> Vec<ThothError> {
+    let mut errors = vec![];
+    if *work_type == WorkType::BookChapter {
+        if publication.isbn.is_some() {
+            errors.push(ThothError::ChapterIsbnError);
+        }
+        if publication.has_dimensions() {
+            errors.push(ThothError::ChapterDimensionError);
+        }
+    }
+    errors.extend(validate_dimensions(publication));
+    errors
+}
+
+/// Dimensions are only allowed on physical publications, and must be given in both units
+pub fn validate_dimensions(publication: &Publication) -> Vec<ThothError> {
+    let mut errors = vec![];
+    if !publication.publication_type.is_physical() && publication.has_dimensions() {
+        errors.push(ThothError::DimensionDigitalError);
+    }
+    let pairs = [
+        (
+            publication.weight_g,
+            publication.weight_oz,
+            ThothError::WeightEmptyError,
+        ),
+        (
+            publication.width_mm,
+            publication.width_in,
+            ThothError::WidthEmptyError,
+        ),
+        (
+            publication.height_mm,
+            publication.height_in,
+            ThothError::HeightEmptyError,
+        ),
+        (
+            publication.depth_mm,
+            publication.depth_in,
+            ThothError::DepthEmptyError,
+        ),
+    ];
+    for (metric, imperial, error) in pairs {
+        if metric.is_some() != imperial.is_some() {
+            errors.push(error);
+        }
+    }
+    errors
+}
+
+/// Subjects must have a code, which for schemes with a controlled vocabulary must be listed in
+/// the scheme's code list
+pub fn validate_subject_code(subject_type: &SubjectType, subject_code: &str) -> ThothResult<()> {
+    if subject_code.trim().is_empty() {
+        Err(ThothError::SubjectCodeEmptyError)
+    } else if subject_type.code_list().is_some() && subject_type.find_code(subject_code).is_none() {
+        Err(ThothError::UnlistedSubjectCode(
+            subject_code.trim().to_string(),
+            subject_type.to_string(),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::utils::{Price, WorkStatus};
+
+    fn withdrawn_work(publication_date: &str, withdrawn_date: &str) -> WorkWithRelations {
+        WorkWithRelations {
+            work_status: WorkStatus::OutOfPrint,
+            publication_date: Some(publication_date.to_string()),
+            withdrawn_date: Some(withdrawn_date.to_string()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn prices_must_be_positive() {
+        let priced = |unit_price| Publication {
+            prices: Some(vec![Price {
+                unit_price,
+                ..Default::default()
+            }]),
+            ..Default::default()
+        };
+        let work_type = WorkType::Monograph;
+        assert!(validate_publication(&work_type, &priced(12.5)).is_empty());
+        for unit_price in [0.0, -1.0] {
+            assert_eq!(
+                validate_publication(&work_type, &priced(unit_price)),
+                vec![ThothError::PriceZeroError]
+            );
+        }
+    }
+
+    #[test]
+    fn withdrawn_date_may_equal_publication_date() {
+        let work = withdrawn_work("2020-05-01", "2020-05-01");
+        assert!(validate_withdrawn_date(&work).is_empty());
+        let work = withdrawn_work("2020-05-01", "2020-04-30");
+        assert_eq!(
+            validate_withdrawn_date(&work),
+            vec![ThothError::WithdrawnDateBeforePublicationDateError]
+        );
+    }
+
+    #[test]
+    fn subject_codes_must_be_listed_in_controlled_schemes() {
+        assert_eq!(
+            validate_subject_code(&SubjectType::Thema, " "),
+            Err(ThothError::SubjectCodeEmptyError)
+        );
+        assert_eq!(validate_subject_code(&SubjectType::Thema, "ab"), Ok(()));
+        assert_eq!(
+            validate_subject_code(&SubjectType::Thema, "ZZZ9"),
+            Err(ThothError::UnlistedSubjectCode(
+                "ZZZ9".to_string(),
+                SubjectType::Thema.to_string()
+            ))
+        );
+        assert_eq!(
+            validate_subject_code(&SubjectType::Bisac, "XYZ000000"),
+            Err(ThothError::UnlistedSubjectCode(
+                "XYZ000000".to_string(),
+                SubjectType::Bisac.to_string()
+            ))
+        );
+        assert_eq!(
+            validate_subject_code(&SubjectType::Keyword, "anything"),
+            Ok(())
+        );
+        assert_eq!(
+            validate_subject_code(&SubjectType::Keyword, ""),
+            Err(ThothError::SubjectCodeEmptyError)
+        );
+    }
+}
@@ -0,0 +1,104 @@
+//! Native build mode which renders the catalogue to static HTML, so that crawlers and readers
+//! without JavaScript see the same records as the app. Each page is a copy of the built
+//! `index.html` holding the rendered content, which the app replaces once it has loaded.
+//!
+//! This is not server-side rendering: Yew 0.19 can neither render components outside the browser
+//! nor hydrate existing markup, so the pages are written here after the app's own, and replaced
+//! rather than hydrated when it starts.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::catalogue::{self, Catalogue};
+use crate::config::config;
+use crate::i18n::{self, Locale};
+use crate::models::book::books_query::Variables;
+use crate::models::utils::{Direction, ThothError, ThothResult, WorkField, WorkOrderBy};
+
+pub mod page;
+
+use page::{path, Page};
+
+pub const USAGE: &str = "Usage: thoth-ui prerender [--dist DIR] [--config FILE] [--fixture FILE]
+                           [--locale CODE]
+
+  --dist DIR       Built app to prerender into, containing index.html [default: pkg]
+  --config FILE    Runtime configuration, as served to the app [default: DIR/config.json]
+  --fixture FILE   JSON array of works to render instead of querying the GraphQL API
+  --locale CODE    Language to render pages in, e.g. de or fr [default: en]";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Options {
+    pub dist: PathBuf,
+    pub config: Option<PathBuf>,
+    pub fixture: Option<PathBuf>,
+    pub locale: Locale,
+}
+
+impl Options {
+    pub fn from_args(mut args: impl Iterator<Item = String>) -> ThothResult<Self> {
+        let mut options = Options {
+            dist: PathBuf::from("pkg"),
+            config: None,
+            fixture: None,
+            locale: Locale::default(),
+        };
+        while let Some(arg) = args.next() {
+            let mut value = || {
+                args.next()
+                    .map(PathBuf::from)
+                    .ok_or_else(|| ThothError::InternalError(format!("{arg} requires a value")))
+            };
+            match arg.as_str() {
+                "--dist" => options.dist = value()?,
+                "--config" => options.config = Some(value()?),
+                "--fixture" => options.fixture = Some(value()?),
+                "--locale" => options.locale = value()?.to_string_lossy().parse()?,
+                _ => {
+                    return Err(ThothError::InternalError(format!(
+                        "unrecognised option {arg}\n\n{USAGE}"
+                    )))
+                }
+            }
+        }
+        Ok(options)
+    }
+}
+
+/// Render the books list and every work's page into the built app
+pub async fn run(options: Options) -> ThothResult<()> {
+    i18n::set_locale(options.locale);
+    catalogue::load_config(&options.dist, options.config.as_deref())?;
+    let template = fs::read_to_string(options.dist.join("index.html"))?;
+    // Most recently published first, as listed by the app
+    let variables = Variables {
+        order: Some(WorkOrderBy {
+            field: WorkField::PublicationDate,
+            direction: Direction::Desc,
+        }),
+        publishers: config().publisher_scope(),
+        ..Default::default()
+    };
+    let works = Catalogue::new(options.fixture.as_deref())?
+        .works(variables, |_| true, None)
+        .await?;
+    let site_name = &config().branding.name;
+    write(&options.dist, &Page::books(&works, site_name), &template)?;
+    for work in &works {
+        write(&options.dist, &Page::book(work, site_name), &template)?;
+    }
+    println!(
+        "Prerendered {} works into {}",
+        works.len(),
+        options.dist.display()
+    );
+    Ok(())
+}
+
+/// Save a page where the server looks for its route, i.e. `<route>/index.html`
+fn write(dist: &Path, page: &Page, template: &str) -> ThothResult<()> {
+    let dir = dist.join(path(&page.route).trim_start_matches('/'));
+    fs::create_dir_all(&dir)?;
+    fs::write(dir.join("index.html"), page.to_document(template)?)?;
+    Ok(())
+}
@@ -0,0 +1,455 @@
+use regex::{Captures, NoExpand, Regex};
+use serde_json::Value;
+use yew_router::Routable;
+
+use crate::i18n::format::{format_date, format_decimal, format_measure};
+use crate::i18n::{locale, Localized, Message};
+use crate::models::book::head_metadata::{HeadMetadata, MetaKey, MetaTag, PAGE_ATTRIBUTE};
+use crate::models::utils::{
+    Contribution, Subject, SubjectType, ThothError, ThothResult, WorkField, WorkWithRelations,
+    DOI_DOMAIN,
+};
+use crate::route::{AppRoute, PRERENDERED_ID};
+use crate::string::{
+    CATALOGUE_HEADING, DETAIL_ABSTRACT, DETAIL_CONTRIBUTORS, DETAIL_DIMENSIONS, DETAIL_ISBN,
+    DETAIL_KEYWORDS, DETAIL_PRICES, DETAIL_PUBLISHED, DETAIL_PUBLISHER, FIELD_DOI,
+};
+
+/// A route rendered ahead of time, without the interactive parts of the app
+#[derive(Debug, Clone, PartialEq)]
+pub struct Page {
+    pub route: AppRoute,
+    pub title: String,
+    pub meta: Vec<MetaTag>,
+    pub json_ld: Option<Value>,
+    pub body: String,
+}
+
+impl Page {
+    /// List of every work, linking to each one's own page
+    pub fn books(works: &[WorkWithRelations], site_name: &str) -> Self {
+        let rows: String = works
+            .iter()
+            .map(|work| {
+                format!(
+                    "<tr><td><a href=\"{}\">{}</a></td><td>{}</td><td>{}</td><td>{}</td></tr>",
+                    escape(&path(&AppRoute::BookDetail {
+                        book_id: work.work_id
+                    })),
+                    escape(&work.full_title),
+                    escape(&contributors(work)),
+                    escape(&work.imprint.publisher.publisher_name),
+                    escape(&published(work)),
+                )
+            })
+            .collect();
+        let body = format!(
+            "<section class=\"section\"><div class=\"container\">\
+             <h1 class=\"title\">{}</h1>\
+             <table class=\"table is-fullwidth is-hoverable\">\
+             <thead><tr><th>{}</th><th>{}</th><th>{}</th><th>{}</th></tr></thead>\
+             <tbody>{}</tbody></table></div></section>",
+            escape(CATALOGUE_HEADING.as_str()),
+            escape(&WorkField::FullTitle.localized()),
+            escape(DETAIL_CONTRIBUTORS.as_str()),
+            escape(DETAIL_PUBLISHER.as_str()),
+            escape(DETAIL_PUBLISHED.as_str()),
+            rows,
+        );
+        Page {
+            route: AppRoute::Books,
+            title: format!("{CATALOGUE_HEADING} - {site_name}"),
+            meta: vec![
+                MetaTag {
+                    key: MetaKey::Property("og:site_name"),
+                    content: site_name.to_string(),
+                },
+                MetaTag {
+                    key: MetaKey::Property("og:title"),
+                    content: CATALOGUE_HEADING.to_string(),
+                },
+            ],
+            json_ld: None,
+            body,
+        }
+    }
+
+    /// Bibliographic record of a single work, described in the head as it is by the app
+    pub fn book(work: &WorkWithRelations, site_name: &str) -> Self {
+        let metadata = HeadMetadata::from_work(work, site_name);
+        let mut details = vec![];
+        let mut detail = |label: Message, value: String| {
+            if !value.is_empty() {
+                details.push(format!(
+                    "<dt>{}</dt><dd>{}</dd>",
+                    escape(label.as_str()),
+                    value
+                ));
+            }
+        };
+        detail(DETAIL_CONTRIBUTORS, escape(&contributors(work)));
+        detail(
+            DETAIL_PUBLISHER,
+            escape(&work.imprint.publisher.publisher_name),
+        );
+        detail(DETAIL_PUBLISHED, escape(&published(work)));
+        if let Some(doi) = &work.doi {
+            let url = format!("{DOI_DOMAIN}{doi}");
+            detail(
+                FIELD_DOI,
+                format!("<a href=\"{}\">{}</a>", escape(&url), escape(&url)),
+            );
+        }
+        let isbns: Vec<String> = work
+            .publications
+            .iter()
+            .flatten()
+            .filter_map(|p| {
+                p.isbn
+                    .as_ref()
+                    .map(|isbn| format!("{} ({})", isbn, p.publication_type.localized()))
+            })
+            .collect();
+        detail(DETAIL_ISBN, escape(&isbns.join(", ")));
+        detail(DETAIL_PRICES, escape(&prices(work)));
+        detail(DETAIL_DIMENSIONS, escape(&dimensions(work)));
+        let mut keywords: Vec<&Subject> = work
+            .subjects
+            .iter()
+            .flatten()
+            .filter(|s| s.subject_type == SubjectType::Keyword)
+            .collect();
+        keywords.sort_by_key(|s| s.subject_ordinal);
+        let keywords: Vec<&str> = keywords.iter().map(|s| s.subject_code.as_str()).collect();
+        detail(DETAIL_KEYWORDS, escape(&keywords.join(", ")));
+
+        let cover = match &work.cover_url {
+            Some(url) => format!(
+                "<figure class=\"media-left\"><p class=\"image\"><img src=\"{}\" alt=\"{}\"></p></figure>",
+                escape(url),
+                escape(work.cover_caption.as_deref().unwrap_or(&work.full_title)),
+            ),
+            None => String::new(),
+        };
+        let summary = match work.long_abstract.as_ref().or(work.short_abstract.as_ref()) {
+            Some(text) => format!(
+                "<h2>{}</h2><p>{}</p>",
+                escape(DETAIL_ABSTRACT.as_str()),
+                escape(text)
+            ),
+            None => String::new(),
+        };
+        let body = format!(
+            "<section class=\"section\"><div class=\"container\"><article class=\"media\">{}\
+             <div class=\"media-content content\"><h1 class=\"title\">{}</h1><dl>{}</dl>{}</div>\
+             </article><p><a href=\"{}\">{}</a></p></div></section>",
+            cover,
+            escape(&work.full_title),
+            details.concat(),
+            summary,
+            escape(&path(&AppRoute::Books)),
+            escape(CATALOGUE_HEADING.as_str()),
+        );
+        Page {
+            route: AppRoute::BookDetail {
+                book_id: work.work_id,
+            },
+            title: metadata.title,
+            meta: metadata.meta,
+            json_ld: Some(metadata.json_ld),
+            body,
+        }
+    }
+
+    /// Fill in a copy of the app's `index.html`: the page's title and metadata go in the head,
+    /// and its content replaces the loading screen until the app takes over
+    pub fn to_document(&self, template: &str) -> ThothResult<String> {
+        let missing = |tag: &str| ThothError::InternalError(format!("index.html has no {tag}"));
+        let head_end = template.find("</head>").ok_or_else(|| missing("</head>"))?;
+        let body_start = template.find("<body>").ok_or_else(|| missing("<body>"))? + "<body>".len();
+        let body_end = template
+            .rfind("</body>")
+            .ok_or_else(|| missing("</body>"))?;
+
+        let mut head = String::new();
+        for tag in &self.meta {
+            let (attribute, key) = tag.key.attribute();
+            head.push_str(&format!(
+                "<meta {}=\"{}\" content=\"{}\" {}>",
+                attribute,
+                key,
+                escape(&tag.content),
+                PAGE_ATTRIBUTE
+            ));
+        }
+        if let Some(json_ld) = &self.json_ld {
+            // A literal "</" would end the script element early
+            head.push_str(&format!(
+                "<script type=\"application/ld+json\" {}>{}</script>",
+                PAGE_ATTRIBUTE,
+                json_ld.to_string().replace("</", "<\\/")
+            ));
+        }
+        let mut document = format!(
+            "{}{}{}<div id=\"{}\">{}</div>{}",
+            &template[..head_end],
+            head,
+            &template[head_end..body_start],
+            PRERENDERED_ID,
+            self.body,
+            &template[body_end..],
+        );
+
+        // Declare the language the page is written in, unless the template already does
+        let html = Regex::new(r"<html([^>]*)>").unwrap();
+        document = html
+            .replace(&document, |caps: &Captures| {
+                match caps[1].contains("lang=") {
+                    true => caps[0].to_string(),
+                    false => format!("<html lang=\"{}\"{}>", locale().code(), &caps[1]),
+                }
+            })
+            .into_owned();
+        let title = Regex::new(r"(?s)<title>.*?</title>").unwrap();
+        document = title
+            .replace(
+                &document,
+                NoExpand(&format!("<title>{}</title>", escape(&self.title))),
+            )
+            .into_owned();
+        // The page's own description replaces the generic one
+        if self
+            .meta
+            .iter()
+            .any(|tag| tag.key == MetaKey::Name("description"))
+        {
+            let generic =
+                Regex::new(r#"(?s)<meta\s+name="description"\s+content="[^"]*"\s*/?>"#).unwrap();
+            document = generic.replace(&document, "").into_owned();
+        }
+        Ok(document)
+    }
+}
+
+/// Absolute path of a route, as linked to by the app
+pub fn path(route: &AppRoute) -> String {
+    format!("/{}", route.to_path().trim_start_matches('/'))
+}
+
+/// Publication date as written in the current locale
+fn published(work: &WorkWithRelations) -> String {
+    work.publication_date
+        .as_deref()
+        .map(format_date)
+        .unwrap_or_default()
+}
+
+/// Main contributors in order, as listed in the app
+fn contributors(work: &WorkWithRelations) -> String {
+    let mut main: Vec<&Contribution> = work
+        .contributions
+        .iter()
+        .flatten()
+        .filter(|c| c.main_contribution)
+        .collect();
+    main.sort_by_key(|c| c.contribution_ordinal);
+    main.iter()
+        .map(|c| c.full_name.as_str())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Prices of each publication, e.g. "24.99 GBP (Paperback)"
+fn prices(work: &WorkWithRelations) -> String {
+    work.publications
+        .iter()
+        .flatten()
+        .flat_map(|p| {
+            p.prices.iter().flatten().map(|price| {
+                format!(
+                    "{} {} ({})",
+                    format_decimal(price.unit_price, 2),
+                    price.currency_code,
+                    p.publication_type.localized()
+                )
+            })
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Metric size and weight of each physical publication, e.g. "Paperback: 156 × 234 mm, 480 g"
+fn dimensions(work: &WorkWithRelations) -> String {
+    work.publications
+        .iter()
+        .flatten()
+        .filter_map(|p| {
+            let size: Vec<String> = [p.width_mm, p.height_mm, p.depth_mm]
+                .into_iter()
+                .flatten()
+                .map(format_measure)
+                .collect();
+            let mut measures = vec![];
+            if !size.is_empty() {
+                measures.push(format!("{} mm", size.join(" × ")));
+            }
+            if let Some(weight) = p.weight_g {
+                measures.push(format!("{} g", format_measure(weight)));
+            }
+            match measures.is_empty() {
+                true => None,
+                false => Some(format!(
+                    "{}: {}",
+                    p.publication_type.localized(),
+                    measures.join(", ")
+                )),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
+/// Escape text for use within element content or a double-quoted attribute
+pub fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::i18n::{set_locale, Locale};
+
+    const TEMPLATE: &str = "<html><head><title>Title</title>\
+        <meta name=\"description\" content=\"Generic\"></head>\
+        <body><p>Loading</p></body></html>";
+
+    fn works() -> Vec<WorkWithRelations> {
+        serde_json::from_str(include_str!("../../fixtures/works.json")).unwrap()
+    }
+
+    #[test]
+    fn book_page_shows_the_record() {
+        let page = Page::book(&works()[0], "Example Books");
+        assert_eq!(
+            page.route,
+            AppRoute::BookDetail {
+                book_id: works()[0].work_id
+            }
+        );
+        assert_eq!(
+            page.title,
+            "Trade & Empire: A History of Europe - Example Books"
+        );
+        for fragment in [
+            "<img src=\"https://books.example.org/covers/trade-and-empire.jpg\" \
+             alt=\"Harbour of Antwerp, 1520\">",
+            "<h1 class=\"title\">Trade &amp; Empire: A History of Europe</h1>",
+            // Main contributors only, in their order on the work
+            "<dt>Contributors</dt><dd>Jane Smith, José Núñez</dd>",
+            "<dt>Published</dt><dd>4 May 2021</dd>",
+            "<dt>DOI</dt><dd><a href=\"https://doi.org/10.11647/OBP.0001\">\
+             https://doi.org/10.11647/OBP.0001</a></dd>",
+            "<dt>ISBN</dt><dd>978-1-80064-000-1 (Paperback), 978-1-80064-001-8 (PDF)</dd>",
+            "<dt>Keywords</dt><dd>trade, empire</dd>",
+            "<dt>Prices</dt><dd>24.99 GBP (Paperback), 29.90 EUR (Paperback)</dd>",
+            "<dt>Dimensions</dt><dd>Paperback: 156 × 234 mm, 480.5 g</dd>",
+            // The long abstract in full, rather than the short one used for descriptions
+            "<h2>Abstract</h2><p>A longer account of how trade shaped Europe since 1500",
+        ] {
+            assert!(page.body.contains(fragment), "{fragment}\n{}", page.body);
+        }
+        assert_eq!(
+            page.meta,
+            HeadMetadata::from_work(&works()[0], "Example Books").meta
+        );
+    }
+
+    #[test]
+    fn empty_details_are_left_out_of_the_document() {
+        let page = Page::book(&works()[1], "Example Books");
+        // The page has no description of its own, so the generic one is kept
+        assert_eq!(
+            page.to_document(TEMPLATE).unwrap(),
+            "<html lang=\"en\"><head><title>Ports of Call - Example Books</title>\
+             <meta name=\"description\" content=\"Generic\">\
+             <meta name=\"citation_title\" content=\"Ports of Call\" data-page-metadata>\
+             <meta name=\"citation_author\" content=\"Min Lee\" data-page-metadata>\
+             <meta name=\"citation_publication_date\" content=\"2031/01/15\" data-page-metadata>\
+             <meta name=\"citation_publisher\" content=\"Example Press\" data-page-metadata>\
+             <meta property=\"og:type\" content=\"book\" data-page-metadata>\
+             <meta property=\"og:site_name\" content=\"Example Books\" data-page-metadata>\
+             <meta property=\"og:title\" content=\"Ports of Call\" data-page-metadata>\
+             <meta property=\"book:release_date\" content=\"2031-01-15\" data-page-metadata>\
+             <meta name=\"twitter:card\" content=\"summary\" data-page-metadata>\
+             <script type=\"application/ld+json\" data-page-metadata>\
+             {\"@context\":\"https://schema.org\",\"@type\":\"Book\",\"name\":\"Ports of Call\",\
+             \"editor\":[{\"@type\":\"Person\",\"name\":\"Min Lee\",\"familyName\":\"Lee\"}],\
+             \"publisher\":{\"@type\":\"Organization\",\"name\":\"Example Press\"},\
+             \"datePublished\":\"2031-01-15\"}</script></head>\
+             <body><div id=\"prerendered\"><section class=\"section\"><div class=\"container\">\
+             <article class=\"media\"><div class=\"media-content content\">\
+             <h1 class=\"title\">Ports of Call</h1>\
+             <dl><dt>Contributors</dt><dd>Min Lee</dd><dt>Publisher</dt><dd>Example Press</dd>\
+             <dt>Published</dt><dd>15 January 2031</dd></dl></div></article>\
+             <p><a href=\"/books\">Books</a></p></div></section></div></body></html>"
+        );
+    }
+
+    #[test]
+    fn document_is_built_from_the_app_template() {
+        let mut work = works()[0].clone();
+        work.title = "Trade & Empire</script>".to_string();
+        work.full_title = work.title.clone();
+        let document = Page::book(&work, "Example Books")
+            .to_document(include_str!("../../index.html"))
+            .unwrap();
+        assert!(document.contains("<html lang=\"en\">"));
+        assert!(
+            document.contains("<title>Trade &amp; Empire&lt;/script&gt; - Example Books</title>")
+        );
+        // The page's description replaces the generic one
+        assert!(!document.contains("Bibliographical metadata management system."));
+        assert!(document.contains(
+            "<meta name=\"description\" content=\"How trade shaped &lt;Europe&gt; since 1500.\" \
+             data-page-metadata>"
+        ));
+        // Markup in the structured data cannot end its script element
+        assert!(document.contains("\"name\":\"Trade & Empire<\\/script>\""));
+        assert_eq!(document.matches("</script>").count(), 2);
+        // The record replaces the loading screen, and the app is still loaded
+        assert!(document.contains("<body><div id=\"prerendered\"><section class=\"section\">"));
+        assert!(!document.contains("book__pg"));
+        assert!(document.contains("<link data-trunk rel=\"rust\" />"));
+    }
+
+    #[test]
+    fn pages_are_written_in_the_current_locale() {
+        set_locale(Locale::De);
+        let document = Page::books(&works(), "Example Books")
+            .to_document("<html lang=\"de-AT\"><head></head><body></body></html>")
+            .unwrap();
+        assert!(document.starts_with("<html lang=\"de-AT\"><head>"));
+        assert!(document.contains("<td>Example Press</td><td>4. Mai 2021</td>"));
+        assert!(document.contains("<a href=\"/books/5b6e1f1a-0000-4000-8000-000000000002\">"));
+        let body = Page::book(&works()[0], "Example Books").body;
+        assert!(body.contains("<dd>24,99 GBP (Taschenbuch), 29,90 EUR (Taschenbuch)</dd>"));
+        assert!(body.contains("<dd>Taschenbuch: 156 × 234 mm, 480,5 g</dd>"));
+    }
+
+    #[test]
+    fn templates_without_a_body_are_rejected() {
+        let page = Page::book(&works()[1], "Example Books");
+        assert!(page.to_document("<html><head></head></html>").is_err());
+    }
+}
@@ -2,12 +2,19 @@ use uuid::Uuid;
 //use uuid::Uuid;
 use yew_router::prelude::*;
 
+/// Element holding a page's prerendered content, which is shown until the app can render it itself
+pub const PRERENDERED_ID: &str = "prerendered";
+
 #[derive(Debug, Clone, PartialEq, Eq, Routable)]
 pub enum AppRoute {
     #[at("/books")]
     Books,
+    #[at("/books/new")]
+    NewBook,
     #[at("books/:book_id")]
     BookDetail { book_id: Uuid },
+    #[at("/login")]
+    Login,
     #[not_found]
     #[at("/error")]
     Error,
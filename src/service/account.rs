@@ -0,0 +1,121 @@
+use yewtil::fetch::{Fetch, FetchAction, FetchRequest, Json, MethodBody};
+
+use crate::config::config;
+use crate::models::account::{AccountDetails, LoginCredentials};
+use crate::service::cache::QueryCache;
+
+const TOKEN_KEY: &str = "thoth.token";
+
+pub type FetchLogin = Fetch<LoginRequest, AccountDetails>;
+pub type FetchActionLogin = FetchAction<AccountDetails>;
+pub type FetchRenewToken = Fetch<RenewTokenRequest, AccountDetails>;
+pub type FetchActionRenewToken = FetchAction<AccountDetails>;
+
+/// Persists the session token in local storage, so that it survives page reloads
+/// and can be read synchronously when building API requests.
+#[derive(Debug, Clone, Default)]
+pub struct AccountService {}
+
+impl AccountService {
+    pub fn new() -> Self {
+        AccountService {}
+    }
+
+    fn storage(&self) -> Option<web_sys::Storage> {
+        web_sys::window().and_then(|window| window.local_storage().ok().flatten())
+    }
+
+    pub fn get_token(&self) -> Option<String> {
+        self.storage()
+            .and_then(|storage| storage.get_item(TOKEN_KEY).ok().flatten())
+    }
+
+    pub fn set_token(&self, token: String) {
+        // Logging in, rather than renewing a token, may change which records are visible
+        if !self.is_loggedin() {
+            QueryCache::clear();
+        }
+        if let Some(storage) = self.storage() {
+            // failing to persist the token only means the user will have to log in again
+            let _ = storage.set_item(TOKEN_KEY, &token);
+        }
+    }
+
+    pub fn is_loggedin(&self) -> bool {
+        self.get_token().is_some()
+    }
+
+    pub fn logout(&self) {
+        QueryCache::clear();
+        if let Some(storage) = self.storage() {
+            let _ = storage.remove_item(TOKEN_KEY);
+        }
+    }
+
+    /// Headers to be sent with every API request, including the session token if logged in
+    pub fn headers(&self) -> Vec<(String, String)> {
+        let json = ("Content-Type".into(), "application/json".into());
+        match self.get_token() {
+            Some(token) => {
+                let auth = ("Authorization".into(), format!("Bearer {token}"));
+                vec![json, auth]
+            }
+            None => vec![json],
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct LoginRequest {
+    pub body: LoginCredentials,
+}
+
+impl FetchRequest for LoginRequest {
+    type RequestBody = LoginCredentials;
+    type ResponseBody = AccountDetails;
+    type Format = Json;
+
+    fn url(&self) -> String {
+        format!("{}/account/login", config().graphql_api)
+    }
+
+    fn method(&self) -> MethodBody<'_, Self::RequestBody> {
+        MethodBody::Post(&self.body)
+    }
+
+    fn headers(&self) -> Vec<(String, String)> {
+        let json = ("Content-Type".into(), "application/json".into());
+        vec![json]
+    }
+
+    fn use_cors(&self) -> bool {
+        true
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct RenewTokenRequest {
+    pub body: (),
+}
+
+impl FetchRequest for RenewTokenRequest {
+    type RequestBody = ();
+    type ResponseBody = AccountDetails;
+    type Format = Json;
+
+    fn url(&self) -> String {
+        format!("{}/account/token/renew", config().graphql_api)
+    }
+
+    fn method(&self) -> MethodBody<'_, Self::RequestBody> {
+        MethodBody::Post(&self.body)
+    }
+
+    fn headers(&self) -> Vec<(String, String)> {
+        AccountService::new().headers()
+    }
+
+    fn use_cors(&self) -> bool {
+        true
+    }
+}
@@ -0,0 +1,54 @@
+use chrono::{DateTime, Duration, Utc};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+/// How long a cached response is reused before the query is sent again
+const CACHE_TTL_MINUTES: i64 = 10;
+
+thread_local! {
+    // Responses keyed by the serialised request body, i.e. the query and its variables
+    static CACHE: RefCell<HashMap<String, (DateTime<Utc>, serde_json::Value)>> =
+        RefCell::new(HashMap::new());
+}
+
+/// Session-wide cache of query responses which do not change between pages, such as the imprint
+/// and enum lists needed by editors, so that navigating between records does not refetch them.
+pub struct QueryCache {}
+
+impl QueryCache {
+    fn key<B: Serialize>(request_body: &B) -> Option<String> {
+        serde_json::to_string(request_body).ok()
+    }
+
+    /// The cached response to a request, if one was stored recently enough
+    pub fn get<B: Serialize, T: DeserializeOwned>(request_body: &B) -> Option<T> {
+        let key = Self::key(request_body)?;
+        let expiry = Utc::now() - Duration::minutes(CACHE_TTL_MINUTES);
+        CACHE.with(|cache| {
+            let mut cache = cache.borrow_mut();
+            match cache.get(&key) {
+                Some((stored_at, value)) if *stored_at > expiry => {
+                    serde_json::from_value(value.clone()).ok()
+                }
+                Some(_) => {
+                    cache.remove(&key);
+                    None
+                }
+                None => None,
+            }
+        })
+    }
+
+    pub fn insert<B: Serialize, T: Serialize>(request_body: &B, response: &T) {
+        if let (Some(key), Ok(value)) = (Self::key(request_body), serde_json::to_value(response)) {
+            CACHE.with(|cache| cache.borrow_mut().insert(key, (Utc::now(), value)));
+        }
+    }
+
+    /// Forget all responses, e.g. when the user changes and may be permitted to see other records
+    pub fn clear() {
+        CACHE.with(|cache| cache.borrow_mut().clear());
+    }
+}
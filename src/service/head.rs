@@ -0,0 +1,71 @@
+use web_sys::{Document, Element};
+
+use crate::config::config;
+use crate::models::book::head_metadata::{HeadMetadata, PAGE_ATTRIBUTE};
+
+// Holds the content a static tag in `index.html` had before the page overrode it
+const DEFAULT_ATTRIBUTE: &str = "data-page-metadata-default";
+
+/// Keeps the document head describing the record shown, for crawlers and link previews which
+/// read the rendered page rather than `index.html`.
+pub struct PageHead {}
+
+impl PageHead {
+    fn document() -> Option<(Document, Element)> {
+        let document = web_sys::window()?.document()?;
+        let head = document.query_selector("head").ok()??;
+        Some((document, head))
+    }
+
+    /// Replace any previous page's metadata with `metadata`
+    pub fn set(metadata: &HeadMetadata) {
+        Self::clear();
+        let (document, head) = match Self::document() {
+            Some(document) => document,
+            None => return,
+        };
+        document.set_title(&metadata.title);
+        for tag in &metadata.meta {
+            let (attribute, key) = tag.key.attribute();
+            // Override a static tag rather than adding a conflicting one, e.g. the description
+            let selector = format!("meta[{attribute}=\"{key}\"]:not([{PAGE_ATTRIBUTE}])");
+            if let Ok(Some(existing)) = head.query_selector(&selector) {
+                if existing.get_attribute(DEFAULT_ATTRIBUTE).is_none() {
+                    let default = existing.get_attribute("content").unwrap_or_default();
+                    existing.set_attribute(DEFAULT_ATTRIBUTE, &default).ok();
+                    existing.set_attribute("content", &tag.content).ok();
+                    continue;
+                }
+            }
+            if let Ok(element) = document.create_element("meta") {
+                element.set_attribute(attribute, key).ok();
+                element.set_attribute("content", &tag.content).ok();
+                element.set_attribute(PAGE_ATTRIBUTE, "").ok();
+                head.append_child(&element).ok();
+            }
+        }
+        if let Ok(script) = document.create_element("script") {
+            script.set_attribute("type", "application/ld+json").ok();
+            script.set_attribute(PAGE_ATTRIBUTE, "").ok();
+            script.set_text_content(Some(&metadata.json_ld.to_string()));
+            head.append_child(&script).ok();
+        }
+    }
+
+    /// Remove the current page's metadata, restoring the site-wide title and static tags
+    pub fn clear() {
+        let (document, head) = match Self::document() {
+            Some(document) => document,
+            None => return,
+        };
+        document.set_title(&config().branding.name);
+        while let Ok(Some(element)) = head.query_selector(&format!("[{PAGE_ATTRIBUTE}]")) {
+            element.remove();
+        }
+        while let Ok(Some(element)) = head.query_selector(&format!("[{DEFAULT_ATTRIBUTE}]")) {
+            let default = element.get_attribute(DEFAULT_ATTRIBUTE).unwrap_or_default();
+            element.set_attribute("content", &default).ok();
+            element.remove_attribute(DEFAULT_ATTRIBUTE).ok();
+        }
+    }
+}
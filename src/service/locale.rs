@@ -0,0 +1,58 @@
+use crate::i18n::{self, Locale};
+
+const LOCALE_KEY: &str = "thoth.locale";
+
+/// Persists the chosen interface language in local storage, so that it is kept between visits.
+/// Until a language has been chosen, the browser's preferred language is used if it is supported.
+#[derive(Debug, Clone, Default)]
+pub struct LocaleService {}
+
+impl LocaleService {
+    pub fn new() -> Self {
+        LocaleService {}
+    }
+
+    fn storage(&self) -> Option<web_sys::Storage> {
+        web_sys::window().and_then(|window| window.local_storage().ok().flatten())
+    }
+
+    fn stored(&self) -> Option<Locale> {
+        let code = self.storage()?.get_item(LOCALE_KEY).ok().flatten()?;
+        Locale::from_code(&code)
+    }
+
+    fn preferred(&self) -> Option<Locale> {
+        let language = web_sys::window()?.navigator().language()?;
+        Locale::from_code(&language)
+    }
+
+    /// Switch the interface to the stored or preferred locale, returning it
+    pub fn init(&self) -> Locale {
+        let locale = self
+            .stored()
+            .or_else(|| self.preferred())
+            .unwrap_or_default();
+        Self::apply(locale);
+        locale
+    }
+
+    /// Switch the interface to `locale`, and remember the choice
+    pub fn set(&self, locale: Locale) {
+        if let Some(storage) = self.storage() {
+            // failing to persist the choice only means it will have to be made again next visit
+            let _ = storage.set_item(LOCALE_KEY, locale.code());
+        }
+        Self::apply(locale);
+    }
+
+    fn apply(locale: Locale) {
+        i18n::set_locale(locale);
+        // Lets the browser pick fonts, hyphenation and spell checking for the language
+        if let Some(html) = web_sys::window()
+            .and_then(|w| w.document())
+            .and_then(|d| d.document_element())
+        {
+            let _ = html.set_attribute("lang", locale.code());
+        }
+    }
+}
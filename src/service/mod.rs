@@ -0,0 +1,5 @@
+pub mod account;
+pub mod cache;
+pub mod head;
+pub mod locale;
+pub mod unsaved;
@@ -0,0 +1,154 @@
+use std::cell::RefCell;
+use wasm_bindgen::closure::Closure;
+use wasm_bindgen::JsCast;
+use web_sys::{BeforeUnloadEvent, Element, MouseEvent, PopStateEvent, Window};
+
+use crate::string::UNSAVED_CHANGES_WARNING;
+
+/// Handlers installed on the window while any guard is alive
+struct Handlers {
+    beforeunload: Closure<dyn FnMut(BeforeUnloadEvent)>,
+    click: Closure<dyn FnMut(MouseEvent)>,
+    popstate: Closure<dyn FnMut(PopStateEvent)>,
+    // Address of the page holding the changes, restored if going back or forward is cancelled
+    href: String,
+}
+
+impl Handlers {
+    fn install(window: &Window) -> Self {
+        let beforeunload = Closure::wrap(Box::new(|e: BeforeUnloadEvent| {
+            // Browsers show their own wording, but still require a return value to be set
+            e.prevent_default();
+            e.set_return_value(UNSAVED_CHANGES_WARNING.as_str());
+        }) as Box<dyn FnMut(BeforeUnloadEvent)>);
+        // In-app links are followed by the router without unloading the page, so they are
+        // intercepted before it sees them, while the event is still being captured
+        let click = Closure::wrap(Box::new(|e: MouseEvent| {
+            // Links opened in another tab or window leave this page as it is
+            if e.button() != 0 || e.ctrl_key() || e.meta_key() || e.shift_key() || e.alt_key() {
+                return;
+            }
+            let in_app_link = e
+                .target()
+                .and_then(|target| target.dyn_into::<Element>().ok())
+                .and_then(|element| element.closest("a[href]").ok().flatten())
+                .filter(|link| !link.has_attribute("target"))
+                .and_then(|link| link.get_attribute("href"))
+                .is_some_and(|href| href.starts_with('/') && !href.starts_with("//"));
+            if in_app_link && !UnsavedChangesGuard::confirm_discard() {
+                e.prevent_default();
+                e.stop_immediate_propagation();
+            }
+        }) as Box<dyn FnMut(MouseEvent)>);
+        let popstate = Closure::wrap(Box::new(|e: PopStateEvent| {
+            if UnsavedChangesGuard::confirm_discard() {
+                return;
+            }
+            // The address has already changed: put it back, and keep the router from following it
+            e.stop_immediate_propagation();
+            let href = GUARDS.with(|guards| guards.borrow().1.as_ref().map(|h| h.href.clone()));
+            if let (Some(window), Some(href)) = (web_sys::window(), href) {
+                if let Ok(history) = window.history() {
+                    let _ =
+                        history.push_state_with_url(&wasm_bindgen::JsValue::NULL, "", Some(&href));
+                }
+            }
+        }) as Box<dyn FnMut(PopStateEvent)>);
+        window.set_onbeforeunload(Some(beforeunload.as_ref().unchecked_ref()));
+        let _ = window.add_event_listener_with_callback_and_bool(
+            "click",
+            click.as_ref().unchecked_ref(),
+            true,
+        );
+        let _ = window.add_event_listener_with_callback_and_bool(
+            "popstate",
+            popstate.as_ref().unchecked_ref(),
+            true,
+        );
+        Handlers {
+            beforeunload,
+            click,
+            popstate,
+            href: window.location().href().unwrap_or_default(),
+        }
+    }
+
+    fn uninstall(&self, window: &Window) {
+        window.set_onbeforeunload(None);
+        let _ = window.remove_event_listener_with_callback_and_bool(
+            "click",
+            self.click.as_ref().unchecked_ref(),
+            true,
+        );
+        let _ = window.remove_event_listener_with_callback_and_bool(
+            "popstate",
+            self.popstate.as_ref().unchecked_ref(),
+            true,
+        );
+    }
+}
+
+thread_local! {
+    // Number of live guards, and the handlers installed while there are any
+    static GUARDS: RefCell<(usize, Option<Handlers>)> = const { RefCell::new((0, None)) };
+}
+
+/// Held by a form while it has unsaved changes. As long as any guard is alive the user is asked
+/// for confirmation before the page is closed, reloaded or navigated away from, whether through
+/// the browser, an in-app link or the back and forward buttons.
+#[derive(Debug)]
+pub struct UnsavedChangesGuard {}
+
+impl UnsavedChangesGuard {
+    pub fn new() -> Self {
+        GUARDS.with(|guards| {
+            let mut guards = guards.borrow_mut();
+            guards.0 += 1;
+            if guards.1.is_none() {
+                guards.1 = web_sys::window().map(|window| Handlers::install(&window));
+            }
+        });
+        UnsavedChangesGuard {}
+    }
+
+    /// Whether any form currently holds unsaved changes
+    pub fn is_active() -> bool {
+        GUARDS.with(|guards| guards.borrow().0 > 0)
+    }
+
+    /// Ask the user whether unsaved changes may be discarded, if there are any.
+    /// For in-app actions which the `beforeunload` handler cannot intercept.
+    pub fn confirm_discard() -> bool {
+        if !Self::is_active() {
+            return true;
+        }
+        web_sys::window()
+            .and_then(|window| {
+                window
+                    .confirm_with_message(UNSAVED_CHANGES_WARNING.as_str())
+                    .ok()
+            })
+            .unwrap_or(true)
+    }
+}
+
+impl Default for UnsavedChangesGuard {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for UnsavedChangesGuard {
+    fn drop(&mut self) {
+        GUARDS.with(|guards| {
+            let mut guards = guards.borrow_mut();
+            guards.0 = guards.0.saturating_sub(1);
+            if guards.0 == 0 {
+                if let (Some(window), Some(handlers)) = (web_sys::window(), guards.1.as_ref()) {
+                    handlers.uninstall(&window);
+                }
+                guards.1 = None;
+            }
+        });
+    }
+}
@@ -1,6 +1,13 @@
+use crate::i18n::Message;
+
+/// Interface text in English, which the locale catalogues translate under the same names
 macro_rules! strings {
     ($($name:ident => $content:expr,)*) => (
-        $(pub const $name: &str = $content;)*
+        // The catalogue is shared, and the native build steps only render a few of its strings
+        $(
+            #[cfg_attr(not(any(target_arch = "wasm32", test)), allow(dead_code))]
+            pub const $name: Message = Message::new(stringify!($name), $content);
+        )*
     )
 }
 
@@ -8,7 +15,237 @@ strings! {
   RELOAD_BUTTON => "Reload",
   NEXT_PAGE_BUTTON => "Next page",
   PREVIOUS_PAGE_BUTTON => "Previous",
+  PAGE_SIZE_LABEL => "Per page",
+  JUMP_TO_PAGE => "Go to page",
+  INFINITE_SCROLL => "Infinite scroll",
   PAGINATION_COUNT_BOOKS => "Displaying books",
   SEARCH_WORKS => "Search by title, DOI, internal reference, abstract or landing page",
+  SEARCH_BUTTON => "Search",
+  ADVANCED_SEARCH => "Advanced search",
+  ADVANCED_SEARCH_ANY => "Any",
+  ADVANCED_SEARCH_WORDS => "All of these words",
+  ADVANCED_SEARCH_PHRASE => "This exact phrase",
+  ADVANCED_SEARCH_EXCLUDE => "None of these words in the title",
+  ADVANCED_SEARCH_AUTHOR => "Contributor",
+  ADVANCED_SEARCH_ISBN => "ISBN",
+  ADVANCED_SEARCH_SUBJECT => "Subject code or heading",
+  ADVANCED_SEARCH_YEAR_FROM => "Published from",
+  ADVANCED_SEARCH_YEAR_TO => "Published until",
+  ADVANCED_SEARCH_TYPE => "Work type",
+  ADVANCED_SEARCH_STATUS => "Status",
+  ADVANCED_SEARCH_LICENSE => "Licence",
+  SUGGESTION_WORK => "Title",
+  SUGGESTION_CONTRIBUTOR => "Contributor",
+  SUGGESTION_SERIES => "Series",
+  FACET_SCOPE => "Showing works of",
+  FACET_WORK_TYPE => "Type",
+  FACET_WORK_STATUS => "Status",
+  FACET_PUBLICATION_YEAR => "Publication year",
+  FACET_LANGUAGE => "Language",
+  FACET_SUBJECT_SCHEME => "Subject scheme",
+  FACET_OPEN_LICENSE => "Open access only",
+  FACET_COUNTS_TRUNCATED => "Counted among the first {} books",
+  CLEAR_FILTERS_BUTTON => "Clear filters",
+  UNRECOGNISED_LICENSE => "Unrecognised licence, please check the licence URL",
+  STATUS_FORTHCOMING => "Forthcoming",
+  STATUS_EXPECTED_ON => "Expected publication date",
+  STATUS_WITHDRAWN_FROM_SALE => "Withdrawn from sale",
+  STATUS_OUT_OF_PRINT => "Out of print",
+  STATUS_WITHDRAWN_ON => "Withdrawn on",
+  STATUS_CANCELLED => "This title has been cancelled and will not be published.",
+  STATUS_POSTPONED_INDEFINITELY => "Publication of this title has been postponed indefinitely.",
+  METADATA_HEALTH => "Metadata health",
+  METADATA_HEALTHY => "No issues found.",
+  LOGIN_TITLE => "Log in",
+  LOGIN_BUTTON => "Log in",
+  LOGOUT_BUTTON => "Log out",
+  EMAIL_LABEL => "Email",
+  PASSWORD_LABEL => "Password",
+  SESSION_EXPIRED => "Your session has expired, please log in again.",
+  EDIT_BUTTON => "Edit",
+  SAVE_BUTTON => "Save",
+  CANCEL_BUTTON => "Cancel",
+  SAVE_SUCCESS => "Saved",
+  SAVE_FAILED => "The work could not be saved",
+  YES => "Yes",
+  NO => "No",
+  SELECT_IMPRINT => "Select Imprint",
+  SELECT_PUBLISHER => "Select Publisher",
+  SELECT_CONTRIBUTOR => "Select Contributor",
+  CONTRIBUTIONS_INFO => "Contributions below are saved automatically upon change. Drag a contribution to change its position.",
+  ADD_CONTRIBUTION => "Add contribution",
+  SEARCH_CONTRIBUTORS => "Search contributors",
+  REMOVE_BUTTON => "Remove",
   RELATIONS_INFO => "Relations below are saved automatically upon change.",
+  ADD_RELATION => "Add relation",
+  SEARCH_WORKS_TO_RELATE => "Search works",
+  SELECT_WORK => "Select Work",
+  MOVE_UP_BUTTON => "Move up",
+  MOVE_DOWN_BUTTON => "Move down",
+  PUBLICATIONS_INFO => "Publications below are saved individually. Dimensions entered in one unit are converted to the other, and only apply to physical publications.",
+  ADD_PUBLICATION => "Add publication",
+  CHAPTER_PUBLICATION_INFO => "Publications of book chapters cannot have an ISBN or dimensions.",
+  SUBJECTS_INFO => "Subjects below are saved automatically upon change. BIC, BISAC and Thema codes must be listed in their code lists.",
+  ADD_SUBJECT => "Add subject",
+  SUBJECT_CODE_SEARCH => "Type a code or heading",
+  ADD_KEYWORDS => "Add",
+  KEYWORDS_INFO => "Separate keywords with commas or semicolons, or press Enter to add them.",
+  NEW_BUTTON => "New",
+  NEW_WORK_TITLE => "New work",
+  STEP_TYPE_AND_IMPRINT => "Type & imprint",
+  STEP_METADATA => "Metadata",
+  STEP_CONTRIBUTIONS => "Contributors",
+  STEP_PUBLICATIONS => "Publications",
+  STEP_LANGUAGES => "Languages",
+  STEP_SUBJECTS => "Subjects",
+  NEXT_STEP_BUTTON => "Next",
+  PREVIOUS_STEP_BUTTON => "Back",
+  CREATE_WORK_BUTTON => "Create work",
+  IMPRINT_REQUIRED => "Please select an imprint.",
+  TITLE_REQUIRED => "Please enter a title.",
+  CREATE_WORK_FAILED => "The work could not be created, and anything saved so far has been removed",
+  ADD_LANGUAGE => "Add language",
+  UNSAVED_CHANGES => "Unsaved changes",
+  UNSAVED_CHANGES_WARNING => "You have unsaved changes which will be lost. Continue?",
+  EDIT_CONFLICT => "This work was changed by someone else after you started editing it. Review their changes below, highlighting fields you also edited, then either save your edits, which keeps their changes to the fields you left alone, or discard your changes.",
+  YOUR_VALUE => "Your value",
+  CURRENT_VALUE => "Current value",
+  OVERWRITE_BUTTON => "Save my version",
+  DISCARD_CHANGES_BUTTON => "Discard my changes",
+  CATALOGUE_HEADING => "Books",
+  DETAIL_CONTRIBUTORS => "Contributors",
+  DETAIL_PUBLISHER => "Publisher",
+  DETAIL_PUBLISHED => "Published",
+  DETAIL_ISBN => "ISBN",
+  DETAIL_KEYWORDS => "Keywords",
+  DETAIL_ABSTRACT => "Abstract",
+  FEED_TITLE => "New and forthcoming titles",
+  FEED_DESCRIPTION => "Recently published and forthcoming titles, with their DOIs, covers, contributors and abstracts.",
+  PAGINATION_OF => "of",
+  LOCAL_FILTER_TRUNCATED => "Only the first {} results were searched: narrow the search to find the others",
+  LANGUAGE_LABEL => "Language",
+  WEBSITE_BUTTON => "Website",
+  PAGE_NOT_FOUND => "Page not found",
+  NOT_IMPLEMENTED => "Not Implemented",
+  CONFIG_ERROR => "The app cannot start, as its configuration is invalid: {}",
+  SECTION_EXPORT_METADATA => "Export Metadata",
+  SECTION_METADATA => "Metadata",
+  SECTION_LOCATIONS => "Locations",
+  SECTION_CONTRIBUTORS => "Contributors",
+  SECTION_SUBJECTS => "Subjects",
+  SECTION_CONTRIBUTIONS => "Contributions",
+  SECTION_RELATIONS => "Relations",
+  SECTION_PUBLICATIONS => "Publications",
+  METADATA_FIELDS => "Metadata fields",
+  LOCATIONS_TABLE => "Locations by platform and publication type",
+  COVER_ALT => "Book cover placeholder",
+  VISIT_BOOK_PAGE => "Visit book page",
+  FIELD_TITLE => "Title",
+  FIELD_CONTRIBUTOR => "Contributor",
+  FIELD_DOI => "DOI",
+  FIELD_LANDING_PAGE => "Landing page",
+  FIELD_LICENSE => "License",
+  FIELD_COPYRIGHT => "Copyright",
+  FIELD_PUBLISHER => "Publisher",
+  FIELD_PUBLICATION_PLACE => "Publication place",
+  FIELD_PUBLISHED_ON => "Published on",
+  FIELD_ISBN => "ISBN",
+  FIELD_LONG_ABSTRACT => "Long abstract",
+  FIELD_PRINT_LENGTH => "Print length",
+  FIELD_LANGUAGE => "Language",
+  FIELD_DIMENSIONS => "Dimensions",
+  FIELD_LCCN => "LCCN",
+  FIELD_KEYWORDS => "Keywords",
+  FIELD_FUNDING => "Funding",
+  FIELD_SUBJECT_CODE => "Subject Code",
+  FIELD_BIOGRAPHY => "Biography",
+  FIELD_RELATED_WORK => "Related Work",
+  FIELD_RELATION_TYPE => "Relation Type",
+  FIELD_WORK_TYPE => "Work Type",
+  FIELD_WORK_STATUS => "Work Status",
+  FIELD_IMPRINT => "Imprint",
+  FIELD_SUBTITLE => "Subtitle",
+  FIELD_REFERENCE => "Internal Reference",
+  FIELD_EDITION => "Edition",
+  FIELD_PUBLICATION_DATE => "Publication Date",
+  FIELD_WITHDRAWN_DATE => "Withdrawn Date",
+  FIELD_PLACE => "Place of Publication",
+  FIELD_PAGE_COUNT => "Page Count",
+  FIELD_PAGE_BREAKDOWN => "Page Breakdown",
+  FIELD_FIRST_PAGE => "First Page",
+  FIELD_LAST_PAGE => "Last Page",
+  FIELD_IMAGE_COUNT => "Image Count",
+  FIELD_TABLE_COUNT => "Table Count",
+  FIELD_AUDIO_COUNT => "Audio Count",
+  FIELD_VIDEO_COUNT => "Video Count",
+  FIELD_COPYRIGHT_HOLDER => "Copyright Holder",
+  FIELD_LCCN_NUMBER => "Library of Congress Number (LCCN)",
+  FIELD_OCLC => "OCLC Number",
+  FIELD_SHORT_ABSTRACT => "Short Abstract",
+  FIELD_GENERAL_NOTE => "General Note",
+  FIELD_BIBLIOGRAPHY_NOTE => "Bibliography Note",
+  FIELD_TOC => "Table of Content",
+  FIELD_COVER_URL => "Cover URL",
+  FIELD_COVER_CAPTION => "Cover Caption",
+  FIELD_CONTRIBUTION_TYPE => "Contribution Type",
+  FIELD_MAIN => "Main",
+  FIELD_PUBLICATION_TYPE => "Publication Type",
+  FIELD_LANGUAGE_RELATION => "Language Relation",
+  FIELD_SUBJECT_TYPE => "Subject Type",
+  FIELD_WIDTH => "Width",
+  FIELD_HEIGHT => "Height",
+  FIELD_DEPTH => "Depth",
+  FIELD_WEIGHT => "Weight",
+  PAGE_COUNT => "{} pages",
+  ORCID_RECORD => "{}'s ORCID record",
+  CONTRIBUTOR_WEBSITE => "{}'s website",
+  TRANSLATED_FROM => "Translated from {}",
+  TRANSLATED_INTO => "Translated into {}",
+  YEAR_FROM_PLACEHOLDER => "From",
+  YEAR_TO_PLACEHOLDER => "To",
+  ERROR_INVALID_SUBJECT_CODE => "{} is not a valid {} code",
+  ERROR_DATABASE => "Database error: {}",
+  ERROR_INTERNAL => "Internal error: {}",
+  ERROR_UNAUTHORISED => "Invalid credentials.",
+  ERROR_INVALID_TOKEN => "Failed to validate token.",
+  ERROR_ENTITY_NOT_FOUND => "No record was found for the given ID.",
+  ERROR_ISSUE_IMPRINTS => "Issue's Work and Series cannot have different Imprints.",
+  ERROR_INVALID_METADATA_SPECIFICATION => "{} is not a valid metadata specification",
+  ERROR_INVALID_UUID => "Invalid UUID supplied.",
+  ERROR_CSV => "CSV Error: {}",
+  ERROR_MARC => "MARC Error: {}",
+  ERROR_INCOMPLETE_METADATA_RECORD => "Could not generate {}: {}",
+  ERROR_ORCID_PARSE => "{} is not a validly formatted ORCID and will not be saved",
+  ERROR_DOI_PARSE => "{} is not a validly formatted DOI and will not be saved",
+  ERROR_ISBN_PARSE => "{} is not a validly formatted ISBN and will not be saved",
+  ERROR_ROR_PARSE => "{} is not a validly formatted ROR ID and will not be saved",
+  ERROR_ORCID_EMPTY => "Cannot parse ORCID: no value provided",
+  ERROR_DOI_EMPTY => "Cannot parse DOI: no value provided",
+  ERROR_ISBN_EMPTY => "Cannot parse ISBN: no value provided",
+  ERROR_ROR_EMPTY => "Cannot parse ROR ID: no value provided",
+  ERROR_LICENSE_PARSE => "{} is not a recognised licence",
+  ERROR_LICENSE_EMPTY => "Cannot parse licence: no value provided",
+  ERROR_SUBJECT_CODE_EMPTY => "Cannot add subject: no code provided",
+  ERROR_UNLISTED_SUBJECT_CODE => "{} is not a {} code",
+  ERROR_CHAPTER_ISBN => "Works of type Book Chapter cannot have ISBNs in their Publications.",
+  ERROR_CHAPTER_DIMENSION => "Works of type Book Chapter cannot have Width, Height, Depth or Weight in their Publications.",
+  ERROR_CANONICAL_LOCATION => "Each Publication must have exactly one canonical Location.",
+  ERROR_LOCATION_URL => "Canonical Locations for digital Publications must have both a Landing Page and a Full Text URL.",
+  ERROR_WEIGHT_EMPTY => "When specifying Weight, both values (g and oz) must be supplied.",
+  ERROR_WIDTH_EMPTY => "When specifying Width, both values (mm and in) must be supplied.",
+  ERROR_HEIGHT_EMPTY => "When specifying Height, both values (mm and in) must be supplied.",
+  ERROR_DEPTH_EMPTY => "When specifying Depth, both values (mm and in) must be supplied.",
+  ERROR_DIMENSION_DIGITAL => "Width/Height/Depth/Weight are only applicable to physical (Paperback/Hardback) Publications.",
+  ERROR_PRICE_ZERO => "Price values must be greater than zero. To indicate an unpriced Publication, omit all Prices.",
+  ERROR_WITHDRAWN_DATE_BEFORE_PUBLICATION => "Withdrawn Date must be later than Publication Date.",
+  ERROR_WITHDRAWN_DATE => "Withdrawn Date can only be added to an Out of Print or Withdrawn From Sale Work.",
+  ERROR_NO_WITHDRAWN_DATE => "An Out of Print or Withdrawn From Sale Work must have a Withdrawn Date.",
+  ERROR_API_UNREACHABLE => "Could not connect to the API.",
+  SEARCH_INVALID_YEAR => "{} is not a valid year",
+  SEARCH_MISSING_VALUE => "No value given for {}:",
+  SEARCH_UNKNOWN_WORK_TYPE => "{} is not a recognised work type",
+  SEARCH_UNKNOWN_WORK_STATUS => "{} is not a recognised work status",
+  SEARCH_UNCLOSED_QUOTE => "Missing closing quote after \"{}",
+  DETAIL_PRICES => "Prices",
+  DETAIL_DIMENSIONS => "Dimensions",
 }